@@ -0,0 +1,1335 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// The tailoring algorithm in this file is adapted from ICU4C's
+// `CollationBuilder` and, therefore, is subject to the ICU license as
+// described in LICENSE.
+
+//! This module holds the builder that compiles LDML collation rules
+//! into the data structs that the data provider serves for CLDR
+//! tailorings.
+
+mod parser;
+mod reorder;
+mod tries;
+
+use crate::elements::{
+    CollationElement, CollationElement32, Tag, COMBINING_DIACRITICS_BASE, COMMON_SEC_AND_TER_CE,
+    COMMON_TERTIARY_CE, CONTRACT_HAS_STARTER, CONTRACT_NEXT_CCC, CONTRACT_TRAILING_CCC,
+    FFFD_CE32_VALUE, NO_CE_PRIMARY, OPTIMIZED_DIACRITICS_MAX_COUNT, SPECIAL_CE32_LOW_BYTE,
+    TERTIARY_MASK, UNASSIGNED_IMPLICIT_BYTE,
+};
+use crate::options::CollatorOptionsBitField;
+use crate::provider::{
+    CollationData, CollationDiacritics, CollationDiacriticsV1, CollationJamoV1, CollationMetadata,
+    CollationMetadataV1, CollationReordering, CollationReorderingV1, CollationRootV1,
+    CollationSpecialPrimariesV1, CollationTailoringV1,
+};
+use crate::{
+    preferences::CollationCaseFirst, AlternateHandling, Collator, CollatorBorrowed, MaxVariable,
+    Strength,
+};
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use displaydoc::Display;
+use icu_normalizer::provider::{CanonicalDecompositionDataV2, CanonicalDecompositionTablesV1};
+use icu_properties::props::{CanonicalCombiningClass, Script};
+use icu_properties::provider::{ScriptNameToValueV2, ScriptV1};
+use icu_properties::{CodePointMapData, CodePointMapDataBorrowed, PropertyParser};
+use icu_provider::prelude::*;
+use parser::{RuleItem, RuleSettings};
+use reorder::ReorderGroups;
+use zerovec::ZeroVec;
+
+/// The maximum number of collation elements that a mapping can expand to.
+const MAX_EXPANSION_LENGTH: usize = 31;
+
+const COMMON_WEIGHT16: u32 = 0x0500;
+/// Tailored secondaries after the common weight start above the range
+/// that sort keys use for compressed runs of common secondaries.
+const LAST_COMMON_SECONDARY: u32 = 0x4500;
+/// The first secondary byte of secondary CEs in the root collation.
+const SECONDARY_BOUNDARY: u32 = 0x8000;
+/// The first tertiary byte of tertiary CEs in the root collation.
+const TERTIARY_BOUNDARY: u32 = 0x3C00;
+/// The lowest byte value in a weight. 1 is the level separator in sort keys.
+const MIN_WEIGHT_BYTE: u32 = 2;
+
+/// An error from compiling collation rules with [`TailoringBuilder`].
+///
+/// The offsets are byte offsets into the rule string.
+#[derive(Display, Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum CollationRulesError {
+    /// The rules are malformed.
+    #[displaydoc("Syntax error in collation rules at {0}")]
+    Syntax(usize),
+    /// The rule uses a feature of the rule syntax that is not supported,
+    /// such as prefixes, `[import]`, or special reset positions, or
+    /// tailors a Hangul jamo.
+    #[displaydoc("Unsupported collation rule at {0}")]
+    Unsupported(usize),
+    /// The rule needs more weights than fit into the gap between
+    /// neighboring root collation elements, an expansion is too long, or
+    /// the reordered scripts need more primary lead bytes than available.
+    #[displaydoc("Collation rule at {0} does not fit the collation data")]
+    Overflow(usize),
+    /// An error loading the root collation data.
+    #[displaydoc("{0}")]
+    Data(DataError),
+}
+
+impl core::error::Error for CollationRulesError {}
+
+impl From<DataError> for CollationRulesError {
+    fn from(e: DataError) -> Self {
+        Self::Data(e)
+    }
+}
+
+/// The collation data compiled from a rule string by [`TailoringBuilder`].
+///
+/// The data structs are the ones the data provider serves for the CLDR
+/// tailorings. A [`Collator`] is created from a `Tailoring` with
+/// [`Collator::try_new_with_tailoring`].
+#[derive(Debug, Clone)]
+pub struct Tailoring {
+    pub(crate) data: CollationData<'static>,
+    pub(crate) diacritics: Option<CollationDiacritics<'static>>,
+    pub(crate) reordering: Option<CollationReordering<'static>>,
+    pub(crate) metadata: CollationMetadata,
+    pub(crate) defaults: CollatorOptionsBitField,
+}
+
+impl Tailoring {
+    /// The tailored mappings, which fall back to the root collation.
+    pub fn data(&self) -> &CollationData<'static> {
+        &self.data
+    }
+
+    /// The secondary weights for the start of the Combining Diacritics
+    /// block if the rules tailor any of those characters, or `None` if the
+    /// root table applies.
+    pub fn diacritics(&self) -> Option<&CollationDiacritics<'static>> {
+        self.diacritics.as_ref()
+    }
+
+    /// The permutation of primary weights for the `[reorder]` setting, or
+    /// `None` if the rules keep the root order of scripts.
+    pub fn reordering(&self) -> Option<&CollationReordering<'static>> {
+        self.reordering.as_ref()
+    }
+
+    /// The settings from the rules as far as the metadata can express them.
+    pub fn metadata(&self) -> CollationMetadata {
+        self.metadata
+    }
+}
+
+/// Compiles collation rules in the [LDML syntax] into [`Tailoring`]s.
+///
+/// This supports resets (including `&[before 1]`), relations of all
+/// strengths (including the starred forms), contractions, expansions
+/// (`/`), and the settings `strength`, `alternate`, `maxVariable`,
+/// `caseFirst`, `caseLevel`, `backwards 2`, `numericOrdering`, and
+/// `reorder`. Prefixes, `[import]`, special reset positions such as
+/// `[first regular]`, `&[before 2]`, `&[before 3]`, and tailoring Hangul
+/// jamo are not supported and result in [`CollationRulesError::Unsupported`].
+///
+/// [LDML syntax]: https://www.unicode.org/reports/tr35/tr35-collation.html#Rules
+///
+/// # Examples
+///
+/// ```
+/// use core::cmp::Ordering;
+/// use icu::collator::*;
+///
+/// let builder = TailoringBuilder::try_new().unwrap();
+/// let tailoring = builder.build("&c < ch <<< Ch <<< CH").unwrap();
+/// let collator =
+///     Collator::try_new_with_tailoring(tailoring, Default::default()).unwrap();
+/// let collator = collator.as_borrowed();
+///
+/// assert_eq!(collator.compare("chat", "cukr"), Ordering::Greater);
+/// assert_eq!(collator.compare("chat", "dort"), Ordering::Less);
+/// assert_eq!(collator.compare("chat", "Chat"), Ordering::Less);
+/// ```
+#[derive(Debug)]
+pub struct TailoringBuilder {
+    root: Collator,
+    elements: RootElements,
+    reorder_groups: ReorderGroups,
+    script_names: PropertyParser<Script>,
+}
+
+impl TailoringBuilder {
+    /// Creates a builder for tailorings of the root collation from compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new() -> Result<Self, DataError> {
+        let root = CollatorBorrowed::try_new(Default::default(), Default::default())?;
+        Ok(Self::new_with_root(
+            root.static_to_owned(),
+            CodePointMapData::<Script>::new(),
+            PropertyParser::<Script>::new().static_to_owned(),
+        ))
+    }
+
+    icu_provider::gen_buffer_data_constructors!(
+        () -> error: DataError,
+        functions: [
+            try_new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(provider: &D) -> Result<Self, DataError>
+    where
+        D: DataProvider<CollationSpecialPrimariesV1>
+            + DataProvider<CollationRootV1>
+            + DataProvider<CollationTailoringV1>
+            + DataProvider<CollationDiacriticsV1>
+            + DataProvider<CollationJamoV1>
+            + DataProvider<CollationMetadataV1>
+            + DataProvider<CollationReorderingV1>
+            + DataProvider<CanonicalDecompositionDataV2>
+            + DataProvider<CanonicalDecompositionTablesV1>
+            + DataProvider<ScriptV1>
+            + DataProvider<ScriptNameToValueV2>
+            + ?Sized,
+    {
+        let root = Collator::try_new_unstable(provider, Default::default(), Default::default())?;
+        let scripts = CodePointMapData::<Script>::try_new_unstable(provider)?;
+        Ok(Self::new_with_root(
+            root,
+            scripts.as_borrowed(),
+            PropertyParser::<Script>::try_new_unstable(provider)?,
+        ))
+    }
+
+    fn new_with_root(
+        root: Collator,
+        scripts: CodePointMapDataBorrowed<Script>,
+        script_names: PropertyParser<Script>,
+    ) -> Self {
+        let elements = RootElements::new(&root.as_borrowed());
+        let reorder_groups = ReorderGroups::new(&root.as_borrowed(), &elements, scripts);
+        Self {
+            root,
+            elements,
+            reorder_groups,
+            script_names,
+        }
+    }
+
+    /// Compiles `rules` into a tailoring of the root collation.
+    pub fn build(&self, rules: &str) -> Result<Tailoring, CollationRulesError> {
+        let parsed = parser::parse(rules)?;
+        let mut builder = Builder::new(self.root.as_borrowed(), &self.elements);
+        for item in parsed.items {
+            match item {
+                RuleItem::Reset {
+                    before,
+                    string,
+                    offset,
+                } => builder.add_reset(before, &string, offset)?,
+                RuleItem::Relation {
+                    strength,
+                    string,
+                    extension,
+                    offset,
+                } => builder.add_relation(strength, &string, &extension, offset)?,
+            }
+        }
+        let reordering = match &parsed.settings.reorder {
+            Some((offset, codes)) => {
+                self.reorder_groups
+                    .reordering(self.script_names.as_borrowed(), codes, *offset)?
+            }
+            None => None,
+        };
+        builder.finish(&parsed.settings, reordering)
+    }
+}
+
+/// Appends the collation elements that `ce32` from `data` stands for when
+/// `c` occurs without context.
+fn push_ces(data: &CollationData, c: char, ce32: CollationElement32, out: &mut Vec<u64>) {
+    if let Some(ce) = ce32.to_ce_self_contained() {
+        out.push(ce.bits());
+        return;
+    }
+    match ce32.tag() {
+        Tag::Expansion32 => {
+            out.extend(data.get_ce32s(ce32.index(), ce32.len()).iter().map(|ce32| {
+                CollationElement32::new(ce32)
+                    .to_ce_self_contained_or_gigo()
+                    .bits()
+            }))
+        }
+        Tag::Expansion => out.extend(data.get_ces(ce32.index(), ce32.len()).iter()),
+        Tag::Prefix | Tag::Contraction => {
+            push_ces(data, c, data.get_default(ce32.index()), out);
+        }
+        Tag::Digit => push_ces(data, c, data.get_ce32(ce32.index()), out),
+        Tag::Offset => out.push(data.ce_from_offset_ce32(c, ce32).bits()),
+        Tag::Implicit => out.push(CollationElement::new_implicit_from_char(c).bits()),
+        // Not used in root data.
+        _ => debug_assert!(false),
+    }
+}
+
+/// Calls `f` with each suffix of the contraction `ce32` from `data` and its CE32.
+fn for_each_contraction_suffix(
+    data: &CollationData,
+    ce32: CollationElement32,
+    mut f: impl FnMut(&[u16], CollationElement32),
+) {
+    let trie: Vec<u16> = data
+        .contexts
+        .get_subslice(ce32.index() + 2..data.contexts.len())
+        .map(|trie| trie.iter().collect())
+        .unwrap_or_default();
+    let result = tries::for_each_char16_trie_entry(&trie, &mut |suffix, value| {
+        f(suffix, CollationElement32::new(value))
+    });
+    // GIGO case
+    debug_assert!(result.is_some());
+}
+
+/// The weights of all distinct collation elements of the root collation,
+/// sorted, without case bits or quaternary weights: the primary in bits
+/// 63..32, the secondary in bits 31..16, and the tertiary in bits 15..0.
+///
+/// This is an uncompressed form of ICU4C's `RootElements`.
+#[derive(Debug)]
+struct RootElements(Vec<u64>);
+
+fn weights_from_ce(ce: u64) -> u64 {
+    (ce & 0xFFFF_FFFF_FFFF_0000) | (ce & u64::from(TERTIARY_MASK))
+}
+
+impl RootElements {
+    fn new(root: &CollatorBorrowed) -> Self {
+        let data = root.root_data();
+        let mut ces = Vec::new();
+        for range in data.trie.iter_ranges() {
+            let ce32 = CollationElement32::new(range.value);
+            let (start, end) = (*range.range.start(), *range.range.end());
+            if ce32.to_ce_self_contained().is_none() {
+                match ce32.tag() {
+                    // Unassigned code points are not tailorable and Hangul
+                    // syllables decompose to the jamo collected below.
+                    Tag::Implicit | Tag::Fallback | Tag::Hangul => continue,
+                    Tag::Offset => {
+                        ces.extend(
+                            (start..=end)
+                                .filter_map(char::from_u32)
+                                .map(|c| data.ce_from_offset_ce32(c, ce32).bits()),
+                        );
+                        continue;
+                    }
+                    Tag::Contraction => {
+                        if let Some(c) = char::from_u32(start) {
+                            for_each_contraction_suffix(data, ce32, |_, suffix_ce32| {
+                                push_ces(data, c, suffix_ce32, &mut ces)
+                            });
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            if let Some(c) = char::from_u32(start) {
+                push_ces(data, c, ce32, &mut ces);
+            }
+        }
+        for (c, ce32) in ('\u{1100}'..).zip(root.jamo_ce32s().iter()) {
+            push_ces(data, c, CollationElement32::new(ce32), &mut ces);
+        }
+        ces.extend(
+            root.diacritic_secondaries()
+                .iter()
+                .map(|s| (u64::from(s) << 16) | COMMON_TERTIARY_CE),
+        );
+        let mut elements: Vec<u64> = ces.into_iter().map(weights_from_ce).collect();
+        elements.sort_unstable();
+        elements.dedup();
+        Self(elements)
+    }
+
+    /// The greatest root primary below `p`, or 0.
+    fn last_primary_below(&self, p: u32) -> u32 {
+        let i = self.0.partition_point(|&e| (e >> 32) < u64::from(p));
+        i.checked_sub(1)
+            .and_then(|i| self.0.get(i))
+            .map_or(0, |&e| (e >> 32) as u32)
+    }
+
+    fn primary_after(&self, p: u32) -> Option<u32> {
+        let i = self.0.partition_point(|&e| (e >> 32) as u32 <= p);
+        self.0.get(i).map(|&e| (e >> 32) as u32)
+    }
+
+    fn primary_before(&self, p: u32) -> Option<u32> {
+        let i = self.0.partition_point(|&e| ((e >> 32) as u32) < p);
+        i.checked_sub(1)
+            .and_then(|i| self.0.get(i))
+            .map(|&e| (e >> 32) as u32)
+            .filter(|&before| before != 0)
+    }
+
+    /// The next root secondary for `p` after `s`, or the limit for secondaries
+    /// of `p` if there is none.
+    fn secondary_after(&self, p: u32, s: u32) -> u32 {
+        let key = (u64::from(p) << 32) | (u64::from(s) << 16) | 0xFFFF;
+        match self.0.get(self.0.partition_point(|&e| e <= key)) {
+            Some(&e) if (e >> 32) as u32 == p => (e >> 16) as u16 as u32,
+            _ if p == 0 => 0x10000,
+            _ => SECONDARY_BOUNDARY,
+        }
+    }
+
+    /// The next root tertiary for `p` and `s` after `t`, or the limit for
+    /// tertiaries of `p` and `s` if there is none.
+    fn tertiary_after(&self, p: u32, s: u32, t: u32) -> u32 {
+        let key = (u64::from(p) << 32) | (u64::from(s) << 16) | u64::from(t);
+        match self.0.get(self.0.partition_point(|&e| e <= key)) {
+            Some(&e) if e >> 16 == key >> 16 => e as u16 as u32,
+            _ if p == 0 && s == 0 => 0x4000,
+            _ => TERTIARY_BOUNDARY,
+        }
+    }
+
+    /// The lowest secondary of a secondary CE.
+    fn first_secondary(&self) -> u32 {
+        match self.0.get(self.0.partition_point(|&e| e < 0x10000)) {
+            Some(&e) if e >> 32 == 0 => (e >> 16) as u32,
+            _ => 0x10000,
+        }
+    }
+
+    /// The lowest tertiary of a tertiary CE.
+    fn first_tertiary(&self) -> u32 {
+        match self.0.iter().find(|&&e| e != 0) {
+            Some(&e) if e >> 16 == 0 => e as u32,
+            _ => 0x4000,
+        }
+    }
+}
+
+/// A collation element while building: either one of the root collation
+/// or a reference to a node whose weights are assigned at the end.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum BuilderCe {
+    Root(u64),
+    Node {
+        index: usize,
+        strength: Strength,
+        case: u16,
+    },
+}
+
+impl BuilderCe {
+    /// ICU4C's `ceStrength()`: the level of the first non-zero weight.
+    fn strength(self) -> Strength {
+        match self {
+            BuilderCe::Root(ce) if ce >> 56 != 0 => Strength::Primary,
+            BuilderCe::Root(ce) if (ce as u32) >> 24 != 0 => Strength::Secondary,
+            BuilderCe::Root(ce) if ce != 0 => Strength::Tertiary,
+            BuilderCe::Root(_) => Strength::Identical,
+            BuilderCe::Node { strength, .. } => strength,
+        }
+    }
+}
+
+/// A node in the doubly-linked lists of root and tailored weights that
+/// hang off each root primary, in collation order.
+#[derive(Debug, Copy, Clone)]
+struct Node {
+    /// The 32-bit primary or 16-bit secondary or tertiary of a root node.
+    weight: u32,
+    strength: Strength,
+    tailored: bool,
+    previous: usize,
+    /// 0 for the end of the list.
+    next: usize,
+    /// The rule offset of a tailored node, for error reporting.
+    offset: usize,
+}
+
+impl Default for Node {
+    fn default() -> Self {
+        Node {
+            weight: 0,
+            strength: Strength::Primary,
+            tailored: false,
+            previous: 0,
+            next: 0,
+            offset: 0,
+        }
+    }
+}
+
+/// The state of compiling one rule string.
+struct Builder<'a> {
+    root: CollatorBorrowed<'a>,
+    elements: &'a RootElements,
+    /// Node 0 is the root node for primary 0.
+    nodes: Vec<Node>,
+    /// The indexes of the root primary nodes, sorted by primary.
+    root_primary_indexes: Vec<usize>,
+    /// The collation elements of the current reset position.
+    ces: Vec<BuilderCe>,
+    /// NFD strings to their tailored collation elements.
+    mappings: BTreeMap<String, Vec<BuilderCe>>,
+    /// The length in bytes of the longest key of `mappings`.
+    max_mapping_len: usize,
+}
+
+impl<'a> Builder<'a> {
+    fn new(root: CollatorBorrowed<'a>, elements: &'a RootElements) -> Self {
+        Self {
+            root,
+            elements,
+            nodes: alloc::vec![Node::default()],
+            root_primary_indexes: alloc::vec![0],
+            ces: Vec::new(),
+            mappings: BTreeMap::new(),
+            max_mapping_len: 0,
+        }
+    }
+
+    fn node(&self, index: usize) -> Node {
+        self.nodes.get(index).copied().unwrap_or_default()
+    }
+
+    fn add_reset(
+        &mut self,
+        before: Option<Strength>,
+        s: &str,
+        offset: usize,
+    ) -> Result<(), CollationRulesError> {
+        let nfd = self.root.decompose(s);
+        self.ces = self.get_ces(&nfd);
+        if self.ces.len() > MAX_EXPANSION_LENGTH {
+            return Err(CollationRulesError::Overflow(offset));
+        }
+        if before.is_none() {
+            return Ok(());
+        }
+        // &[before 1]: the parser rejects other strengths.
+        let mut index = self.find_or_insert_node_for_ces(Strength::Primary, offset)?;
+        // Skip backwards over weaker nodes.
+        while self.node(index).strength > Strength::Primary {
+            index = self.node(index).previous;
+        }
+        let node = self.node(index);
+        if node.tailored {
+            // Reset to just before this tailored primary node.
+            index = node.previous;
+        } else {
+            let p = self
+                .elements
+                .primary_before(node.weight)
+                .ok_or(CollationRulesError::Unsupported(offset))?;
+            index = self.find_or_insert_node_for_primary(p);
+            // Tailor after the last node between the adjacent root primaries.
+            while self.node(index).next != 0 {
+                index = self.node(index).next;
+            }
+        }
+        if let Some(last) = self.ces.last_mut() {
+            *last = BuilderCe::Node {
+                index,
+                strength: Strength::Primary,
+                case: 0,
+            };
+        }
+        Ok(())
+    }
+
+    fn add_relation(
+        &mut self,
+        strength: Strength,
+        s: &str,
+        extension: &str,
+        offset: usize,
+    ) -> Result<(), CollationRulesError> {
+        let nfd = self.root.decompose(s);
+        // The collation element iterator reads conjoining jamo from the
+        // root jamo table, bypassing the tailoring.
+        if nfd.chars().any(|c| ('\u{1100}'..='\u{11FF}').contains(&c)) {
+            return Err(CollationRulesError::Unsupported(offset));
+        }
+        if strength != Strength::Identical {
+            let index = self.find_or_insert_node_for_ces(strength, offset)?;
+            let Some(&ce) = self.ces.last() else {
+                return Err(CollationRulesError::Syntax(offset));
+            };
+            if let BuilderCe::Root(ce) = ce {
+                if (strength == Strength::Primary && ce >> 32 == 0)
+                    || (strength == Strength::Quaternary && ce == 0)
+                {
+                    // There is no gap for tailoring a primary after an
+                    // ignorable or a quaternary after a completely
+                    // ignorable collation element.
+                    return Err(CollationRulesError::Unsupported(offset));
+                }
+            }
+            let index = self.insert_tailored_node_after(index, strength, offset);
+            if let Some(last) = self.ces.last_mut() {
+                *last = BuilderCe::Node {
+                    index,
+                    strength: ce.strength().min(strength),
+                    case: 0,
+                };
+            }
+        }
+        self.set_case_bits(&nfd);
+        let ces_len = self.ces.len();
+        if !extension.is_empty() {
+            let extension = self.root.decompose(extension);
+            let extension_ces = self.get_ces(&extension);
+            self.ces.extend(extension_ces);
+        }
+        if self.ces.len() > MAX_EXPANSION_LENGTH {
+            return Err(CollationRulesError::Overflow(offset));
+        }
+        if self.get_ces(&nfd) != self.ces {
+            self.max_mapping_len = self.max_mapping_len.max(nfd.len());
+            self.mappings.insert(nfd, self.ces.clone());
+        }
+        self.ces.truncate(ces_len);
+        Ok(())
+    }
+
+    /// Truncates the reset CEs to the last one at least as strong as
+    /// `strength` and returns the index of the node for that position.
+    fn find_or_insert_node_for_ces(
+        &mut self,
+        strength: Strength,
+        offset: usize,
+    ) -> Result<usize, CollationRulesError> {
+        let ce = loop {
+            match self.ces.last() {
+                None => {
+                    self.ces.push(BuilderCe::Root(0));
+                    break BuilderCe::Root(0);
+                }
+                Some(&ce) if ce.strength() <= strength => break ce,
+                Some(_) => {
+                    self.ces.pop();
+                }
+            }
+        };
+        match ce {
+            BuilderCe::Node { index, .. } => Ok(index),
+            BuilderCe::Root(ce) => {
+                if (ce >> 56) as u8 == UNASSIGNED_IMPLICIT_BYTE {
+                    // Unassigned code points do not have root nodes.
+                    return Err(CollationRulesError::Unsupported(offset));
+                }
+                let mut index = self.find_or_insert_node_for_primary((ce >> 32) as u32);
+                if strength >= Strength::Secondary {
+                    let lower32 = ce as u32;
+                    index = self.find_or_insert_weak_node(
+                        index,
+                        lower32 >> 16,
+                        Strength::Secondary,
+                        offset,
+                    )?;
+                    if strength >= Strength::Tertiary {
+                        index = self.find_or_insert_weak_node(
+                            index,
+                            lower32 & u32::from(TERTIARY_MASK),
+                            Strength::Tertiary,
+                            offset,
+                        )?;
+                    }
+                }
+                Ok(index)
+            }
+        }
+    }
+
+    fn find_or_insert_node_for_primary(&mut self, p: u32) -> usize {
+        match self
+            .root_primary_indexes
+            .binary_search_by_key(&p, |&i| self.node(i).weight)
+        {
+            Ok(i) => self
+                .root_primary_indexes
+                .get(i)
+                .copied()
+                .unwrap_or_default(),
+            Err(i) => {
+                let index = self.nodes.len();
+                self.nodes.push(Node {
+                    weight: p,
+                    ..Default::default()
+                });
+                self.root_primary_indexes.insert(i, index);
+                index
+            }
+        }
+    }
+
+    /// Finds or inserts the node for the root secondary or tertiary `weight`
+    /// in the list after `index`.
+    fn find_or_insert_weak_node(
+        &mut self,
+        index: usize,
+        weight: u32,
+        strength: Strength,
+        offset: usize,
+    ) -> Result<usize, CollationRulesError> {
+        if weight == COMMON_WEIGHT16 {
+            // The parent node stands for the common weight.
+            return Ok(index);
+        }
+        if weight != 0 && weight < COMMON_WEIGHT16 {
+            // Root weights below common are not tailorable.
+            return Err(CollationRulesError::Unsupported(offset));
+        }
+        let mut index = index;
+        let mut next;
+        loop {
+            next = self.node(index).next;
+            if next == 0 {
+                break;
+            }
+            let node = self.node(next);
+            // Stop before a stronger node.
+            if node.strength < strength {
+                break;
+            }
+            if node.strength == strength && !node.tailored {
+                if node.weight == weight {
+                    return Ok(next);
+                }
+                if node.weight > weight {
+                    break;
+                }
+            }
+            index = next;
+        }
+        Ok(self.insert_node_between(
+            index,
+            next,
+            Node {
+                weight,
+                strength,
+                ..Default::default()
+            },
+        ))
+    }
+
+    /// Inserts a tailored node of `strength` after the node at `index` and
+    /// after any weaker nodes following it.
+    fn insert_tailored_node_after(
+        &mut self,
+        index: usize,
+        strength: Strength,
+        offset: usize,
+    ) -> usize {
+        let mut index = index;
+        let mut next;
+        loop {
+            next = self.node(index).next;
+            if next == 0 || self.node(next).strength <= strength {
+                break;
+            }
+            index = next;
+        }
+        self.insert_node_between(
+            index,
+            next,
+            Node {
+                strength,
+                tailored: true,
+                offset,
+                ..Default::default()
+            },
+        )
+    }
+
+    fn insert_node_between(&mut self, previous: usize, next: usize, node: Node) -> usize {
+        let index = self.nodes.len();
+        self.nodes.push(Node {
+            previous,
+            next,
+            ..node
+        });
+        if let Some(previous) = self.nodes.get_mut(previous) {
+            previous.next = index;
+        }
+        if next != 0 {
+            if let Some(next) = self.nodes.get_mut(next) {
+                next.previous = index;
+            }
+        }
+        index
+    }
+
+    /// Appends the root collation elements of `s` to `out`.
+    fn push_root_ces(&self, s: &str, out: &mut Vec<u64>) {
+        let mut iter = self.root.collation_elements(s.chars());
+        loop {
+            let ce = iter.next();
+            if ce.primary() == NO_CE_PRIMARY {
+                break;
+            }
+            out.push(ce.bits());
+        }
+    }
+
+    /// The current collation elements of the NFD string `s`: the tailored
+    /// ones for the longest mappings and the root ones for the rest.
+    fn get_ces(&self, s: &str) -> Vec<BuilderCe> {
+        let mut out = Vec::new();
+        let mut root_ces = Vec::new();
+        let mut run_start = 0;
+        let mut i = 0;
+        while let Some(rest) = s.get(i..).filter(|rest| !rest.is_empty()) {
+            let longest = rest
+                .char_indices()
+                .map(|(j, c)| j + c.len_utf8())
+                .take_while(|&len| len <= self.max_mapping_len)
+                .filter_map(|len| Some((len, self.mappings.get(rest.get(..len)?)?)))
+                .last();
+            if let Some((len, ces)) = longest {
+                self.push_root_ces(s.get(run_start..i).unwrap_or_default(), &mut root_ces);
+                out.extend(root_ces.drain(..).map(BuilderCe::Root));
+                out.extend_from_slice(ces);
+                i += len;
+                run_start = i;
+            } else {
+                i += rest.chars().next().map_or(1, char::len_utf8);
+            }
+        }
+        self.push_root_ces(s.get(run_start..).unwrap_or_default(), &mut root_ces);
+        out.extend(root_ces.into_iter().map(BuilderCe::Root));
+        out
+    }
+
+    /// Sets the case bits of the current collation elements from the root
+    /// collation elements of the NFD string `s`, like ICU4C's `setCaseBits()`.
+    fn set_case_bits(&mut self, s: &str) {
+        let tailored_primaries = self
+            .ces
+            .iter()
+            .filter(|ce| ce.strength() == Strength::Primary)
+            .count();
+        let mut cases: u64 = 0;
+        if tailored_primaries > 0 {
+            let mut root_ces = Vec::new();
+            self.push_root_ces(s, &mut root_ces);
+            let mut last_case = 0;
+            let mut root_primaries = 0;
+            for ce in root_ces {
+                if ce >> 32 == 0 {
+                    continue;
+                }
+                root_primaries += 1;
+                let case = (ce >> 14) & 3;
+                if root_primaries < tailored_primaries {
+                    cases |= case << ((root_primaries - 1) * 2);
+                } else if root_primaries == tailored_primaries {
+                    last_case = case;
+                } else if case != last_case {
+                    // The remaining root primaries differ in case: mixed.
+                    last_case = 1;
+                    break;
+                }
+            }
+            if root_primaries >= tailored_primaries {
+                cases |= last_case << ((tailored_primaries - 1) * 2);
+            }
+        }
+        for ce in self.ces.iter_mut() {
+            let case = match ce.strength() {
+                Strength::Primary => {
+                    let case = ((cases & 3) << 14) as u16;
+                    cases >>= 2;
+                    case
+                }
+                // Tertiary CEs must have uppercase bits.
+                Strength::Tertiary => 0x8000,
+                _ => 0,
+            };
+            match ce {
+                BuilderCe::Root(ce) => *ce = (*ce & !0xC000) | u64::from(case),
+                BuilderCe::Node { case: c, .. } => *c = case,
+            }
+        }
+    }
+
+    /// Assigns weights to the tailored nodes and returns the collation
+    /// element for each node, like ICU4C's `makeTailoredCEs()`.
+    fn make_tailored_ces(&self) -> Result<Vec<u64>, CollationRulesError> {
+        let mut ces = alloc::vec![0u64; self.nodes.len()];
+        for &root_index in &self.root_primary_indexes {
+            let mut p = self.node(root_index).weight;
+            let mut s = if p == 0 { 0 } else { COMMON_WEIGHT16 };
+            let mut t = s;
+            let mut q = 0;
+            let mut p_tailored = false;
+            let mut s_tailored = false;
+            let mut t_tailored = false;
+            let mut primaries = Vec::new().into_iter();
+            let mut secondaries = Vec::new().into_iter();
+            let mut tertiaries = Vec::new().into_iter();
+            if let Some(ce) = ces.get_mut(root_index) {
+                *ce = make_ce(p, s, t, q);
+            }
+            let mut index = self.node(root_index).next;
+            while index != 0 {
+                let node = self.node(index);
+                let overflow = CollationRulesError::Overflow(node.offset);
+                match node.strength {
+                    Strength::Quaternary => {
+                        if q == 3 {
+                            return Err(overflow);
+                        }
+                        q += 1;
+                    }
+                    Strength::Tertiary => {
+                        if node.tailored {
+                            if !t_tailored {
+                                let count = self.count_tailored_nodes(index, Strength::Tertiary);
+                                let limit = if t == 0 {
+                                    // Gap at the beginning of the tertiary CE range.
+                                    t = TERTIARY_BOUNDARY - 0x100;
+                                    self.elements.first_tertiary()
+                                } else if !p_tailored && !s_tailored {
+                                    self.elements.tertiary_after(p, s, t)
+                                } else {
+                                    TERTIARY_BOUNDARY
+                                };
+                                tertiaries = allocate_weights16(t, limit, count, 0x3F)
+                                    .ok_or(overflow)?
+                                    .into_iter();
+                                t_tailored = true;
+                            }
+                            t = tertiaries.next().ok_or(overflow)?;
+                        } else {
+                            t = node.weight;
+                            t_tailored = false;
+                        }
+                        q = 0;
+                    }
+                    Strength::Secondary => {
+                        if node.tailored {
+                            if !s_tailored {
+                                let count = self.count_tailored_nodes(index, Strength::Secondary);
+                                let limit = if s == 0 {
+                                    // Gap at the beginning of the secondary CE range.
+                                    s = SECONDARY_BOUNDARY - 0x100;
+                                    self.elements.first_secondary()
+                                } else if !p_tailored {
+                                    self.elements.secondary_after(p, s)
+                                } else {
+                                    SECONDARY_BOUNDARY
+                                };
+                                if s == COMMON_WEIGHT16 {
+                                    s = LAST_COMMON_SECONDARY;
+                                }
+                                secondaries = allocate_weights16(s, limit, count, 0xFF)
+                                    .ok_or(overflow)?
+                                    .into_iter();
+                                s_tailored = true;
+                            }
+                            s = secondaries.next().ok_or(overflow)?;
+                        } else {
+                            s = node.weight;
+                            s_tailored = false;
+                        }
+                        t = if s == 0 { 0 } else { COMMON_WEIGHT16 };
+                        t_tailored = false;
+                        q = 0;
+                    }
+                    _ => {
+                        if !p_tailored {
+                            let count = self.count_tailored_nodes(index, Strength::Primary);
+                            let limit = self.elements.primary_after(p).ok_or(overflow)?;
+                            primaries = allocate_weights(p, u64::from(limit), count, 4, 0xFF)
+                                .ok_or(overflow)?
+                                .into_iter();
+                            p_tailored = true;
+                        }
+                        p = primaries.next().ok_or(overflow)?;
+                        s = COMMON_WEIGHT16;
+                        s_tailored = false;
+                        t = COMMON_WEIGHT16;
+                        t_tailored = false;
+                        q = 0;
+                    }
+                }
+                if let Some(ce) = ces.get_mut(index) {
+                    *ce = make_ce(p, s, t, q);
+                }
+                index = node.next;
+            }
+        }
+        Ok(ces)
+    }
+
+    /// Counts the consecutive tailored nodes of `strength` starting at
+    /// `index`, skipping weaker nodes.
+    fn count_tailored_nodes(&self, index: usize, strength: Strength) -> usize {
+        let mut count = 0;
+        let mut index = index;
+        while index != 0 {
+            let node = self.node(index);
+            if node.strength < strength {
+                break;
+            }
+            if node.strength == strength {
+                if !node.tailored {
+                    break;
+                }
+                count += 1;
+            }
+            index = node.next;
+        }
+        count
+    }
+
+    fn finish(
+        self,
+        settings: &RuleSettings,
+        reordering: Option<CollationReordering<'static>>,
+    ) -> Result<Tailoring, CollationRulesError> {
+        let node_ces = self.make_tailored_ces()?;
+        let root_data = self.root.root_data();
+
+        // Split the mappings into single characters and contractions.
+        let mut singles: BTreeMap<char, Vec<u64>> = BTreeMap::new();
+        let mut contractions: BTreeMap<char, BTreeMap<Vec<u16>, Vec<u64>>> = BTreeMap::new();
+        for (s, ces) in &self.mappings {
+            let ces = ces
+                .iter()
+                .map(|ce| match *ce {
+                    BuilderCe::Root(ce) => ce,
+                    BuilderCe::Node { index, case, .. } => {
+                        node_ces.get(index).copied().unwrap_or_default() | u64::from(case)
+                    }
+                })
+                .collect();
+            let mut chars = s.chars();
+            let Some(first) = chars.next() else {
+                continue;
+            };
+            if chars.as_str().is_empty() {
+                singles.insert(first, ces);
+            } else {
+                contractions
+                    .entry(first)
+                    .or_default()
+                    .insert(chars.as_str().encode_utf16().collect(), ces);
+            }
+        }
+
+        let mut generator = DataGenerator::default();
+        let mut values: BTreeMap<u32, u32> = BTreeMap::new();
+        let starters: Vec<char> = singles.keys().chain(contractions.keys()).copied().collect();
+        for c in starters {
+            if values.contains_key(&u32::from(c)) {
+                continue;
+            }
+            let mut default = Vec::new();
+            if let Some(ces) = singles.get(&c) {
+                default.clone_from(ces);
+            } else {
+                self.push_root_ces(c.encode_utf8(&mut [0; 4]), &mut default);
+            }
+            let default_ce32 = generator.encode(&default);
+            let mut suffixes = contractions.remove(&c).unwrap_or_default();
+            let root_ce32 = root_data.ce32_for_char(c);
+            if root_ce32.to_ce_self_contained().is_none() && root_ce32.tag() == Tag::Contraction {
+                // Keep the root contractions that are not tailored.
+                for_each_contraction_suffix(root_data, root_ce32, |suffix, ce32| {
+                    suffixes.entry(suffix.to_vec()).or_insert_with(|| {
+                        let mut ces = Vec::new();
+                        push_ces(root_data, c, ce32, &mut ces);
+                        ces
+                    });
+                });
+            }
+            let ce32 = if suffixes.is_empty() {
+                default_ce32
+            } else {
+                generator.encode_contraction(default_ce32, &suffixes, |c| self.root.ccc(c))
+            };
+            values.insert(u32::from(c), ce32);
+        }
+
+        let mut metadata = CollationMetadata {
+            bits: CollationMetadata::TAILORED_MASK
+                | settings.max_variable.unwrap_or(MaxVariable::Punctuation) as u32,
+        };
+        let diacritics = values
+            .range(COMBINING_DIACRITICS_BASE as u32..)
+            .next()
+            .map(|(&c, _)| c as usize - COMBINING_DIACRITICS_BASE)
+            .filter(|&len| len < OPTIMIZED_DIACRITICS_MAX_COUNT)
+            .map(|len| {
+                metadata.bits |= CollationMetadata::TAILORED_DIACRITICS_MASK;
+                let secondaries: Vec<u16> =
+                    self.root.diacritic_secondaries().iter().take(len).collect();
+                CollationDiacritics {
+                    secondaries: ZeroVec::alloc_from_slice(&secondaries),
+                }
+            });
+        if reordering.is_some() {
+            metadata.bits |= CollationMetadata::REORDERING_MASK;
+        }
+        if settings.backward_second_level {
+            metadata.bits |= CollationMetadata::BACWARD_SECOND_LEVEL_MASK;
+        }
+        if settings.alternate_handling == Some(AlternateHandling::Shifted) {
+            metadata.bits |= CollationMetadata::ALTERNATE_SHIFTED_MASK;
+        }
+        match settings.case_first {
+            Some(CollationCaseFirst::Upper) => {
+                metadata.bits |=
+                    CollationMetadata::CASE_FIRST_MASK | CollationMetadata::UPPER_FIRST_MASK
+            }
+            Some(CollationCaseFirst::Lower) => metadata.bits |= CollationMetadata::CASE_FIRST_MASK,
+            _ => {}
+        }
+
+        // The same as for the metadata of CLDR tailorings plus the settings
+        // that the metadata cannot express.
+        let mut defaults = CollatorOptionsBitField::default();
+        if metadata.alternate_shifted() {
+            defaults.set_alternate_handling(Some(AlternateHandling::Shifted));
+        }
+        if metadata.backward_second_level() {
+            defaults.set_backward_second_level(Some(true));
+        }
+        defaults.set_case_first(Some(metadata.case_first()));
+        defaults.set_max_variable(Some(metadata.max_variable()));
+        if settings.strength.is_some() {
+            defaults.set_strength(settings.strength);
+        }
+        if settings.case_level.is_some() {
+            defaults.set_case_level(settings.case_level);
+        }
+        if settings.numeric.is_some() {
+            defaults.set_numeric(settings.numeric);
+        }
+
+        Ok(Tailoring {
+            data: CollationData {
+                trie: tries::build_code_point_trie(
+                    &values,
+                    u32::from(SPECIAL_CE32_LOW_BYTE),
+                    FFFD_CE32_VALUE,
+                ),
+                ces: ZeroVec::alloc_from_slice(&generator.ces),
+                ce32s: ZeroVec::new(),
+                contexts: ZeroVec::alloc_from_slice(&generator.contexts),
+            },
+            diacritics,
+            reordering,
+            metadata,
+            defaults,
+        })
+    }
+}
+
+fn make_ce(p: u32, s: u32, t: u32, q: u32) -> u64 {
+    (u64::from(p) << 32) | (u64::from(s) << 16) | u64::from(t) | u64::from(q << 6)
+}
+
+/// The number of significant bytes of the left-aligned `weight`.
+fn weight_length(weight: u32) -> u32 {
+    if weight == 0 {
+        0
+    } else {
+        4 - weight.trailing_zeros() / 8
+    }
+}
+
+fn weight_byte(weight: u32, position: u32) -> u32 {
+    (weight >> (32 - 8 * position)) & 0xFF
+}
+
+fn set_weight_byte(weight: u32, position: u32, byte: u32) -> u32 {
+    let shift = 32 - 8 * position;
+    (weight & !(0xFF << shift)) | (byte << shift)
+}
+
+fn truncate_weight(weight: u32, length: u32) -> u32 {
+    if length >= 4 {
+        weight
+    } else {
+        weight & !(u32::MAX >> (8 * length))
+    }
+}
+
+/// The next weight after `weight` at `length` bytes, with carry.
+fn increment_weight(weight: u32, length: u32, max_byte: u32) -> Option<u32> {
+    let byte = weight_byte(weight, length);
+    if byte < max_byte {
+        Some(set_weight_byte(
+            weight,
+            length,
+            (byte + 1).max(MIN_WEIGHT_BYTE),
+        ))
+    } else if length > 1 {
+        increment_weight(
+            set_weight_byte(weight, length, MIN_WEIGHT_BYTE),
+            length - 1,
+            max_byte,
+        )
+    } else {
+        None
+    }
+}
+
+/// Allocates `count` ascending weights strictly between `lower` and `upper`,
+/// with all weights left-aligned in 32 bits (`upper` may be `1 << 32`).
+///
+/// The weights have the shortest length possible. None of them is a prefix
+/// of `lower` or `upper` or has `lower` as a prefix, so that they also sort
+/// correctly in sort keys.
+fn allocate_weights(
+    lower: u32,
+    upper: u64,
+    count: usize,
+    max_length: u32,
+    max_byte: u32,
+) -> Option<Vec<u32>> {
+    let lower_length = weight_length(lower);
+    for length in 1..=max_length {
+        let common_length = length.min(lower_length);
+        let mut weight = if common_length == 0 {
+            0
+        } else if let Some(weight) = increment_weight(
+            truncate_weight(lower, common_length),
+            common_length,
+            max_byte,
+        ) {
+            weight
+        } else {
+            continue;
+        };
+        for position in common_length + 1..=length {
+            weight = set_weight_byte(weight, position, MIN_WEIGHT_BYTE);
+        }
+        let limit = if upper > u64::from(u32::MAX) {
+            upper
+        } else {
+            u64::from(truncate_weight(upper as u32, length))
+        };
+        let mut weights = Vec::with_capacity(count);
+        while u64::from(weight) < limit {
+            weights.push(weight);
+            if weights.len() == count {
+                return Some(weights);
+            }
+            match increment_weight(weight, length, max_byte) {
+                Some(next) => weight = next,
+                None => break,
+            }
+        }
+    }
+    None
+}
+
+/// [`allocate_weights`] for 16-bit secondary and tertiary weights.
+fn allocate_weights16(lower: u32, upper: u32, count: usize, max_byte: u32) -> Option<Vec<u32>> {
+    Some(
+        allocate_weights(lower << 16, u64::from(upper) << 16, count, 2, max_byte)?
+            .into_iter()
+            .map(|w| w >> 16)
+            .collect(),
+    )
+}
+
+/// The expansion and contraction tables of the tailoring data.
+#[derive(Default)]
+struct DataGenerator {
+    ces: Vec<u64>,
+    contexts: Vec<u16>,
+}
+
+impl DataGenerator {
+    /// Encodes `ces` as a CE32, adding an expansion if necessary.
+    fn encode(&mut self, ces: &[u64]) -> u32 {
+        if let [ce] = ces {
+            let p = (*ce >> 32) as u32;
+            let lower32 = *ce as u32;
+            if lower32 == COMMON_SEC_AND_TER_CE as u32 && p & 0xFF == 0 && p != 0 {
+                // Long primary
+                return p | u32::from(SPECIAL_CE32_LOW_BYTE) | Tag::LongPrimary as u32;
+            }
+            let t = (lower32 >> 8) & 0xFF;
+            if p & 0xFFFF == 0 && lower32 & 0x00FF_00FF == 0 && t < u32::from(SPECIAL_CE32_LOW_BYTE)
+            {
+                // Simple CE32
+                return p | ((lower32 >> 16) & 0xFF00) | t;
+            }
+        }
+        if ces.is_empty() {
+            return 0;
+        }
+        let ces = ces.get(..MAX_EXPANSION_LENGTH).unwrap_or(ces);
+        let index = self
+            .ces
+            .windows(ces.len())
+            .position(|w| w == ces)
+            .unwrap_or_else(|| {
+                let index = self.ces.len();
+                self.ces.extend_from_slice(ces);
+                index
+            });
+        ((index as u32) << 13)
+            | ((ces.len() as u32) << 8)
+            | u32::from(SPECIAL_CE32_LOW_BYTE)
+            | Tag::Expansion as u32
+    }
+
+    /// Adds the default and the suffix trie of a contraction and returns
+    /// its CE32.
+    fn encode_contraction(
+        &mut self,
+        default_ce32: u32,
+        suffixes: &BTreeMap<Vec<u16>, Vec<u64>>,
+        ccc: impl Fn(char) -> CanonicalCombiningClass,
+    ) -> u32 {
+        let mut entries = BTreeMap::new();
+        let mut flags = CONTRACT_NEXT_CCC;
+        for (suffix, ces) in suffixes {
+            entries.insert(suffix.clone(), self.encode(ces));
+            let chars: Vec<char> = char::decode_utf16(suffix.iter().copied())
+                .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+                .collect();
+            let is_starter = |c: &char| ccc(*c) == CanonicalCombiningClass::NotReordered;
+            if chars.first().map_or(true, is_starter) {
+                flags &= !CONTRACT_NEXT_CCC;
+            }
+            if chars.last().is_some_and(|c| !is_starter(c)) {
+                flags |= CONTRACT_TRAILING_CCC;
+            }
+            if chars.iter().any(is_starter) {
+                flags |= CONTRACT_HAS_STARTER;
+            }
+        }
+        let index = self.contexts.len() as u32;
+        self.contexts.push((default_ce32 >> 16) as u16);
+        self.contexts.push(default_ce32 as u16);
+        self.contexts.extend(tries::write_char16_trie(&entries));
+        (index << 13) | flags | u32::from(SPECIAL_CE32_LOW_BYTE) | Tag::Contraction as u32
+    }
+}
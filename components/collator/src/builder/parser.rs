@@ -0,0 +1,484 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// The rule syntax handling in this file is adapted from ICU4C's
+// `CollationRuleParser` and, therefore, is subject to the ICU license as
+// described in LICENSE.
+
+//! The parser for the LDML collation rule syntax.
+//!
+//! See <https://www.unicode.org/reports/tr35/tr35-collation.html#Rules>.
+
+use super::CollationRulesError;
+use crate::{preferences::CollationCaseFirst, AlternateHandling, MaxVariable, Strength};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// A reset or relation, in the order in which they appear in the rules.
+#[derive(Debug)]
+pub(crate) enum RuleItem {
+    /// `&string` or `&[before n]string`
+    Reset {
+        before: Option<Strength>,
+        string: String,
+        offset: usize,
+    },
+    /// `< string`, `<< string / extension` etc. `Strength::Identical`
+    /// stands for `=`.
+    Relation {
+        strength: Strength,
+        string: String,
+        extension: String,
+        offset: usize,
+    },
+}
+
+/// The settings given in brackets.
+#[derive(Debug, Default)]
+pub(crate) struct RuleSettings {
+    pub(crate) strength: Option<Strength>,
+    pub(crate) alternate_handling: Option<AlternateHandling>,
+    pub(crate) max_variable: Option<MaxVariable>,
+    pub(crate) case_first: Option<CollationCaseFirst>,
+    pub(crate) case_level: Option<bool>,
+    pub(crate) backward_second_level: bool,
+    pub(crate) numeric: Option<bool>,
+    /// The offset of the last `[reorder]` setting and its reorder codes.
+    pub(crate) reorder: Option<(usize, Vec<String>)>,
+}
+
+/// The result of parsing a rule string.
+#[derive(Debug, Default)]
+pub(crate) struct ParsedRules {
+    pub(crate) items: Vec<RuleItem>,
+    pub(crate) settings: RuleSettings,
+}
+
+/// ICU4C's `PatternProps::isWhiteSpace()`
+fn is_white_space(c: char) -> bool {
+    matches!(
+        c,
+        '\u{9}'..='\u{D}' | ' ' | '\u{85}' | '\u{200E}' | '\u{200F}' | '\u{2028}' | '\u{2029}'
+    )
+}
+
+/// ASCII punctuation and symbols need to be quoted or escaped in strings.
+fn is_syntax_char(c: char) -> bool {
+    matches!(c, '!'..='/' | ':'..='@' | '['..='`' | '{'..='~')
+}
+
+struct Parser<'a> {
+    rules: &'a str,
+    pos: usize,
+    parsed: ParsedRules,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.rules.get(self.pos..).and_then(|s| s.chars().next())
+    }
+
+    fn rest(&self) -> &'a str {
+        self.rules.get(self.pos..).unwrap_or_default()
+    }
+
+    fn syntax_error(&self) -> CollationRulesError {
+        CollationRulesError::Syntax(self.pos)
+    }
+
+    fn skip_white_space(&mut self) {
+        while let Some(c) = self.peek() {
+            if !is_white_space(c) {
+                break;
+            }
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn skip_comment(&mut self) {
+        while let Some(c) = self.peek() {
+            self.pos += c.len_utf8();
+            if matches!(
+                c,
+                '\n' | '\u{C}' | '\r' | '\u{85}' | '\u{2028}' | '\u{2029}'
+            ) {
+                break;
+            }
+        }
+    }
+
+    fn parse(&mut self) -> Result<(), CollationRulesError> {
+        while let Some(c) = self.peek() {
+            match c {
+                c if is_white_space(c) => self.pos += c.len_utf8(),
+                '&' => self.parse_rule_chain()?,
+                '[' => self.parse_setting()?,
+                '#' => self.skip_comment(),
+                '@' => {
+                    // Old syntax for [backwards 2]
+                    self.parsed.settings.backward_second_level = true;
+                    self.pos += 1;
+                }
+                // Old syntax for Thai/Lao prevowel reordering, which is
+                // handled by the root collation.
+                '!' => self.pos += 1,
+                _ => return Err(self.syntax_error()),
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_rule_chain(&mut self) -> Result<(), CollationRulesError> {
+        let reset_strength = self.parse_reset_and_position()?;
+        let mut is_first_relation = true;
+        loop {
+            self.skip_white_space();
+            let offset = self.pos;
+            let Some((strength, starred)) = self.parse_relation_operator() else {
+                if self.peek() == Some('#') {
+                    self.skip_comment();
+                    continue;
+                }
+                if is_first_relation {
+                    return Err(CollationRulesError::Syntax(offset));
+                }
+                return Ok(());
+            };
+            if is_first_relation && reset_strength.is_some_and(|s| s != strength) {
+                // &[before n] must be followed by a relation of strength n.
+                return Err(CollationRulesError::Syntax(offset));
+            }
+            if starred {
+                self.parse_starred_characters(strength, offset)?;
+            } else {
+                self.parse_relation_strings(strength, offset)?;
+            }
+            is_first_relation = false;
+        }
+    }
+
+    fn parse_reset_and_position(&mut self) -> Result<Option<Strength>, CollationRulesError> {
+        let offset = self.pos;
+        // Skip the '&'.
+        self.pos += 1;
+        self.skip_white_space();
+        let mut before = None;
+        if let Some(after_before) = self.rest().strip_prefix("[before") {
+            let trimmed = after_before.trim_start_matches(is_white_space);
+            if trimmed.len() < after_before.len() {
+                let strength = match trimmed.get(..2) {
+                    Some("1]") => Some(Strength::Primary),
+                    Some("2]") => Some(Strength::Secondary),
+                    Some("3]") => Some(Strength::Tertiary),
+                    _ => None,
+                };
+                if let Some(strength) = strength {
+                    if strength != Strength::Primary {
+                        return Err(CollationRulesError::Unsupported(self.pos));
+                    }
+                    before = Some(strength);
+                    self.pos = self.rules.len() - trimmed.len() + 2;
+                    self.skip_white_space();
+                }
+            }
+        }
+        if self.peek() == Some('[') {
+            // Special reset positions such as [first regular] are not supported.
+            return Err(CollationRulesError::Unsupported(self.pos));
+        }
+        let string = self.parse_tailoring_string()?;
+        self.parsed.items.push(RuleItem::Reset {
+            before,
+            string,
+            offset,
+        });
+        Ok(before)
+    }
+
+    fn parse_relation_operator(&mut self) -> Option<(Strength, bool)> {
+        let rest = self.rest();
+        let (strength, len, may_star) = if rest.starts_with("<<<<") {
+            (Strength::Quaternary, 4, true)
+        } else if rest.starts_with("<<<") {
+            (Strength::Tertiary, 3, true)
+        } else if rest.starts_with("<<") {
+            (Strength::Secondary, 2, true)
+        } else if rest.starts_with('<') {
+            (Strength::Primary, 1, true)
+        } else if rest.starts_with(';') {
+            (Strength::Secondary, 1, false)
+        } else if rest.starts_with(',') {
+            (Strength::Tertiary, 1, false)
+        } else if rest.starts_with('=') {
+            (Strength::Identical, 1, true)
+        } else {
+            return None;
+        };
+        self.pos += len;
+        let starred = may_star && self.peek() == Some('*');
+        if starred {
+            self.pos += 1;
+        }
+        Some((strength, starred))
+    }
+
+    fn parse_relation_strings(
+        &mut self,
+        strength: Strength,
+        offset: usize,
+    ) -> Result<(), CollationRulesError> {
+        let string = self.parse_tailoring_string()?;
+        let mut extension = String::new();
+        match self.peek() {
+            Some('|') => {
+                // Context before the relation string (prefix).
+                return Err(CollationRulesError::Unsupported(self.pos));
+            }
+            Some('/') => {
+                self.pos += 1;
+                extension = self.parse_tailoring_string()?;
+            }
+            _ => {}
+        }
+        self.parsed.items.push(RuleItem::Relation {
+            strength,
+            string,
+            extension,
+            offset,
+        });
+        Ok(())
+    }
+
+    fn parse_starred_characters(
+        &mut self,
+        strength: Strength,
+        offset: usize,
+    ) -> Result<(), CollationRulesError> {
+        self.skip_white_space();
+        let mut raw = self.parse_string()?;
+        if raw.is_empty() {
+            return Err(self.syntax_error());
+        }
+        loop {
+            let mut prev = None;
+            for c in raw.chars() {
+                self.push_single(strength, c, offset);
+                prev = Some(c);
+            }
+            if self.peek() != Some('-') {
+                break;
+            }
+            let Some(start) = prev else {
+                return Err(self.syntax_error());
+            };
+            self.pos += 1;
+            raw = self.parse_string()?;
+            let mut chars = raw.chars();
+            let Some(end) = chars.next() else {
+                return Err(self.syntax_error());
+            };
+            if end < start {
+                return Err(self.syntax_error());
+            }
+            // `start` has already been added, and the characters after `end`
+            // are added at the top of the loop.
+            for c in (u32::from(start) + 1..=u32::from(end)).filter_map(char::from_u32) {
+                if ('\u{FFFD}'..='\u{FFFF}').contains(&c) {
+                    return Err(self.syntax_error());
+                }
+                self.push_single(strength, c, offset);
+            }
+            raw = String::from(chars.as_str());
+        }
+        Ok(())
+    }
+
+    fn push_single(&mut self, strength: Strength, c: char, offset: usize) {
+        self.parsed.items.push(RuleItem::Relation {
+            strength,
+            string: String::from(c),
+            extension: String::new(),
+            offset,
+        });
+    }
+
+    fn parse_tailoring_string(&mut self) -> Result<String, CollationRulesError> {
+        self.skip_white_space();
+        let s = self.parse_string()?;
+        if s.is_empty() {
+            return Err(self.syntax_error());
+        }
+        Ok(s)
+    }
+
+    /// Parses a string up to the next unquoted white space or syntax character.
+    fn parse_string(&mut self) -> Result<String, CollationRulesError> {
+        let mut raw = String::new();
+        while let Some(c) = self.peek() {
+            if c == '\'' {
+                self.pos += 1;
+                if self.peek() == Some('\'') {
+                    // Double apostrophe, encodes a single one.
+                    raw.push('\'');
+                    self.pos += 1;
+                    continue;
+                }
+                // Quote literal text until the next single apostrophe.
+                loop {
+                    let Some(c) = self.peek() else {
+                        return Err(self.syntax_error());
+                    };
+                    self.pos += c.len_utf8();
+                    if c == '\'' {
+                        if self.peek() == Some('\'') {
+                            // Double apostrophe inside quoted literal text,
+                            // still encodes a single apostrophe.
+                            self.pos += 1;
+                        } else {
+                            break;
+                        }
+                    }
+                    raw.push(c);
+                }
+            } else if c == '\\' {
+                self.pos += 1;
+                let Some(c) = self.peek() else {
+                    return Err(self.syntax_error());
+                };
+                raw.push(c);
+                self.pos += c.len_utf8();
+            } else if is_syntax_char(c) || is_white_space(c) {
+                break;
+            } else {
+                raw.push(c);
+                self.pos += c.len_utf8();
+            }
+        }
+        if raw.chars().any(|c| ('\u{FFFD}'..='\u{FFFF}').contains(&c)) {
+            return Err(self.syntax_error());
+        }
+        Ok(raw)
+    }
+
+    fn parse_setting(&mut self) -> Result<(), CollationRulesError> {
+        let offset = self.pos;
+        // Find the matching ']', allowing for nested brackets in the
+        // UnicodeSet arguments of some settings.
+        let mut depth = 0;
+        let mut end = None;
+        let mut escaped = false;
+        for (i, c) in self.rest().char_indices() {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '[' => depth += 1,
+                ']' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        end = Some(i);
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+        let Some(end) = end else {
+            return Err(self.syntax_error());
+        };
+        let content = self.rest().get(1..end).unwrap_or_default();
+        let mut words = content.split(is_white_space).filter(|w| !w.is_empty());
+        let name = words.next().unwrap_or_default();
+        let value = words.next().unwrap_or_default();
+        let single_value = words.next().is_none();
+        let settings = &mut self.parsed.settings;
+        let syntax = Err(CollationRulesError::Syntax(offset));
+        let unsupported = Err(CollationRulesError::Unsupported(offset));
+        match name {
+            "strength" if single_value => {
+                settings.strength = Some(match value {
+                    "1" => Strength::Primary,
+                    "2" => Strength::Secondary,
+                    "3" => Strength::Tertiary,
+                    "4" => Strength::Quaternary,
+                    "I" => Strength::Identical,
+                    _ => return syntax,
+                });
+            }
+            "alternate" if single_value => {
+                settings.alternate_handling = Some(match value {
+                    "non-ignorable" => AlternateHandling::NonIgnorable,
+                    "shifted" => AlternateHandling::Shifted,
+                    _ => return syntax,
+                });
+            }
+            "maxVariable" if single_value => {
+                settings.max_variable = Some(match value {
+                    "space" => MaxVariable::Space,
+                    "punct" => MaxVariable::Punctuation,
+                    "symbol" => MaxVariable::Symbol,
+                    "currency" => MaxVariable::Currency,
+                    _ => return syntax,
+                });
+            }
+            "caseFirst" if single_value => {
+                settings.case_first = Some(match value {
+                    "off" => CollationCaseFirst::False,
+                    "lower" => CollationCaseFirst::Lower,
+                    "upper" => CollationCaseFirst::Upper,
+                    _ => return syntax,
+                });
+            }
+            "backwards" if single_value => {
+                if value != "2" {
+                    return syntax;
+                }
+                settings.backward_second_level = true;
+            }
+            "caseLevel" | "numericOrdering" | "normalization" | "hiraganaQ" if single_value => {
+                let on = match value {
+                    "on" => true,
+                    "off" => false,
+                    _ => return syntax,
+                };
+                match name {
+                    "caseLevel" => settings.case_level = Some(on),
+                    "numericOrdering" => settings.numeric = Some(on),
+                    // Input is always normalized.
+                    "normalization" => {}
+                    // The special Hiragana quaternary weights are not supported.
+                    _ if on => return unsupported,
+                    _ => {}
+                }
+            }
+            "reorder" => {
+                settings.reorder = Some((
+                    offset,
+                    content
+                        .split(is_white_space)
+                        .skip(1)
+                        .filter(|code| !code.is_empty())
+                        .map(String::from)
+                        .collect(),
+                ));
+            }
+            // A performance hint without effect on the order.
+            "optimize" => {}
+            "import" | "suppressContractions" => return unsupported,
+            _ => return syntax,
+        }
+        self.pos += end + 1;
+        Ok(())
+    }
+}
+
+/// Parses `rules` into the sequence of resets and relations and the settings.
+pub(crate) fn parse(rules: &str) -> Result<ParsedRules, CollationRulesError> {
+    let mut parser = Parser {
+        rules,
+        pos: 0,
+        parsed: ParsedRules::default(),
+    };
+    parser.parse()?;
+    Ok(parser.parsed)
+}
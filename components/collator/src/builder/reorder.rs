@@ -0,0 +1,393 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// The reordering algorithm in this file is adapted from ICU4C's
+// `CollationData` and `CollationSettings` and, therefore, is subject to the
+// ICU license as described in LICENSE.
+
+//! Script reordering for the `[reorder]` setting.
+
+use super::{for_each_contraction_suffix, push_ces, CollationRulesError, RootElements};
+use crate::elements::Tag;
+use crate::provider::CollationReordering;
+use crate::CollatorBorrowed;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use icu_properties::props::Script;
+use icu_properties::{CodePointMapDataBorrowed, PropertyParserBorrowed};
+use zerovec::ZeroVec;
+
+/// ICU4C's `UCOL_REORDER_CODE_FIRST`: the code of the first special
+/// reorder group. The special groups follow in the order of [`SPECIAL_GROUPS`].
+const REORDER_CODE_FIRST: u16 = 0x1000;
+/// The names of the special reorder groups of the Common script.
+const SPECIAL_GROUPS: [&str; 5] = ["space", "punct", "symbol", "currency", "digit"];
+/// ICU4C's `USCRIPT_KATAKANA_OR_HIRAGANA`, which reorders together with Hiragana.
+const KATAKANA_OR_HIRAGANA: u16 = 54;
+/// Primary lead bytes below this byte are never reordered.
+const FIRST_REORDERABLE_BYTE: u16 = 3;
+/// Primary lead bytes from this byte on are never reordered.
+const TRAIL_WEIGHT_BYTE: u16 = 0xFF;
+/// Marks a reserved range in the lead byte table of
+/// [`ReorderGroups::make_reorder_ranges`]: it can move along with
+/// whatever range precedes it.
+const DONT_CARE: u8 = 0xFF;
+
+/// Whether the primary lead bytes of reordered ranges did not fit or
+/// the list of reorder codes is invalid.
+#[derive(Debug)]
+enum ReorderError {
+    Overflow,
+    Invalid,
+}
+
+/// The reorder groups of the root collation: the scripts and the special
+/// groups of the Common script, in primary order.
+///
+/// This is the script data of ICU4C's `CollationData`. The root collation
+/// marks the first primary of each group with a contraction of U+FDD1 and
+/// a sample character of the group.
+#[derive(Debug)]
+pub(super) struct ReorderGroups {
+    /// The upper 16 bits of the first primary of each group, starting with
+    /// 0 for the range that is never reordered and ending with the limit of
+    /// the last group.
+    script_starts: Vec<u16>,
+    /// Maps reorder codes to indexes into `script_starts`.
+    scripts_index: BTreeMap<u16, usize>,
+    /// The ranges of unused lead bytes, which are reserved for future
+    /// scripts, indexed like `script_starts`.
+    reserved: Vec<bool>,
+}
+
+impl ReorderGroups {
+    pub(super) fn new(
+        root: &CollatorBorrowed,
+        elements: &RootElements,
+        scripts: CodePointMapDataBorrowed<Script>,
+    ) -> Self {
+        let data = root.root_data();
+        let mut samples: Vec<(u32, Script)> = Vec::new();
+        let ce32 = data.ce32_for_char('\u{FDD1}');
+        if ce32.to_ce_self_contained().is_none() && ce32.tag() == Tag::Contraction {
+            for_each_contraction_suffix(data, ce32, |suffix, ce32| {
+                let mut sample = char::decode_utf16(suffix.iter().copied());
+                if let (Some(Ok(c)), None) = (sample.next(), sample.next()) {
+                    let mut ces = Vec::new();
+                    push_ces(data, '\u{FDD1}', ce32, &mut ces);
+                    if let Some(&ce) = ces.first() {
+                        samples.push(((ce >> 32) as u32, scripts.get(c)));
+                    }
+                }
+            });
+        }
+        samples.sort_unstable_by_key(|&(p, _)| p);
+
+        // The first primary of a group that starts a lead byte is rounded
+        // down to the start of the lead byte.
+        let mut groups: Vec<(u16, Vec<u16>)> = Vec::new();
+        let mut specials = (REORDER_CODE_FIRST..).take(SPECIAL_GROUPS.len());
+        for (p, script) in samples {
+            let code = if script == Script::Common {
+                let Some(code) = specials.next() else {
+                    continue;
+                };
+                code
+            } else {
+                script.to_icu4c_value()
+            };
+            let lead_byte_start = p & 0xFF00_0000;
+            let start = if elements.last_primary_below(p) < lead_byte_start {
+                lead_byte_start
+            } else {
+                p
+            };
+            let start = (start >> 16) as u16;
+            match groups.last_mut() {
+                Some((last, codes)) if *last == start => codes.push(code),
+                _ => groups.push((start, alloc::vec![code])),
+            }
+        }
+
+        let mut script_starts = alloc::vec![0];
+        let mut scripts_index = BTreeMap::new();
+        let mut reserved = alloc::vec![false];
+        let limits = groups
+            .iter()
+            .skip(1)
+            .map(|&(start, _)| start)
+            .chain([TRAIL_WEIGHT_BYTE << 8]);
+        for ((start, codes), limit) in groups.iter().zip(limits) {
+            if *start < FIRST_REORDERABLE_BYTE << 8 {
+                continue;
+            }
+            for &code in codes {
+                scripts_index.entry(code).or_insert(script_starts.len());
+                if code == Script::Hiragana.to_icu4c_value() {
+                    scripts_index.insert(KATAKANA_OR_HIRAGANA, script_starts.len());
+                }
+            }
+            script_starts.push(*start);
+            reserved.push(false);
+            // Lead bytes without root primaries before the next group
+            // form a reserved range.
+            let last_lead_byte = (elements.last_primary_below(u32::from(limit) << 16) >> 24) as u16;
+            if last_lead_byte + 1 < limit >> 8 && (last_lead_byte + 1) << 8 > *start {
+                script_starts.push((last_lead_byte + 1) << 8);
+                reserved.push(true);
+            }
+        }
+        script_starts.push(TRAIL_WEIGHT_BYTE << 8);
+        Self {
+            script_starts,
+            scripts_index,
+            reserved,
+        }
+    }
+
+    /// Computes the reordering for the `[reorder]` setting with the given
+    /// reorder codes at `offset` in the rules, or `None` for the root order.
+    pub(super) fn reordering(
+        &self,
+        names: PropertyParserBorrowed<Script>,
+        codes: &[String],
+        offset: usize,
+    ) -> Result<Option<CollationReordering<'static>>, CollationRulesError> {
+        let codes = codes
+            .iter()
+            .map(|name| reorder_code(names, name))
+            .collect::<Option<Vec<u16>>>()
+            .ok_or(CollationRulesError::Syntax(offset))?;
+        let ranges = self
+            .make_reorder_ranges(&codes, false)
+            .map_err(|e| match e {
+                ReorderError::Overflow => CollationRulesError::Overflow(offset),
+                ReorderError::Invalid => CollationRulesError::Syntax(offset),
+            })?;
+        // ICU4C's `CollationSettings::setReordering()`
+        let Some(&last) = ranges.last() else {
+            return Ok(None);
+        };
+        let min_high_no_reorder = last & 0xFFFF_0000;
+
+        // Write the lead byte permutation table. Set a 0 for each lead byte
+        // that has a range boundary in the middle.
+        let mut table: [u8; 256] = core::array::from_fn(|b| b as u8);
+        let mut b = 0;
+        let mut first_split_byte_range_index = None;
+        for (i, &pair) in ranges.iter().enumerate() {
+            let limit1 = (pair >> 24) as usize;
+            for (lead_byte, entry) in table.iter_mut().enumerate().take(limit1).skip(b) {
+                *entry = (lead_byte as u32).wrapping_add(pair) as u8;
+            }
+            b = b.max(limit1);
+            // Check the second byte of the limit.
+            if pair & 0xFF_0000 != 0 {
+                if let Some(entry) = table.get_mut(limit1) {
+                    *entry = 0;
+                }
+                b = limit1 + 1;
+                first_split_byte_range_index.get_or_insert(i);
+            }
+        }
+        // The ranges before the first split byte are handled by the table alone.
+        let ranges = match first_split_byte_range_index {
+            Some(i) => ranges.get(i..).unwrap_or_default(),
+            None => &[],
+        };
+        Ok(Some(CollationReordering {
+            min_high_no_reorder,
+            reorder_table: ZeroVec::alloc_from_slice(&table),
+            reorder_ranges: ZeroVec::alloc_from_slice(ranges),
+        }))
+    }
+
+    fn script_index(&self, code: u16) -> usize {
+        self.scripts_index.get(&code).copied().unwrap_or(0)
+    }
+
+    fn script_start(&self, index: usize) -> i32 {
+        self.script_starts.get(index).copied().map_or(0, i32::from)
+    }
+
+    /// ICU4C's `CollationData::makeReorderRanges()`: the (limit, offset)
+    /// pairs for the reordered lead bytes with the limit in the upper 16
+    /// bits and the signed lead byte offset in the lower 16 bits.
+    fn make_reorder_ranges(
+        &self,
+        codes: &[u16],
+        latin_must_move: bool,
+    ) -> Result<Vec<u32>, ReorderError> {
+        let others = Script::Unknown.to_icu4c_value();
+        let latin = Script::Latin.to_icu4c_value();
+        if codes.is_empty() || codes == [others] {
+            return Ok(Vec::new());
+        }
+        let last = self.script_starts.len() - 1;
+        // Maps each group to a new lead byte.
+        let mut table: Vec<u8> = self
+            .reserved
+            .iter()
+            .map(|&reserved| if reserved { DONT_CARE } else { 0 })
+            .collect();
+        table.push(0);
+        let mut low_start = i32::from(FIRST_REORDERABLE_BYTE) << 8;
+        let mut high_limit = i32::from(TRAIL_WEIGHT_BYTE) << 8;
+
+        // The special reorder codes in the list.
+        let specials = codes
+            .iter()
+            .filter_map(|&code| code.checked_sub(REORDER_CODE_FIRST))
+            .filter(|&i| usize::from(i) < SPECIAL_GROUPS.len())
+            .fold(0u32, |specials, i| specials | (1 << i));
+
+        // Start with the special groups that are not in the list.
+        for i in 0..SPECIAL_GROUPS.len() as u16 {
+            let index = self.script_index(REORDER_CODE_FIRST + i);
+            if index != 0 && specials & (1 << i) == 0 {
+                low_start = self.add_low_script_range(&mut table, index, low_start);
+            }
+        }
+
+        // Skip the reserved range before Latin if Latin is the first
+        // script, so that it does not move unnecessarily.
+        let mut skipped_reserved = 0;
+        if specials == 0 && codes.first() == Some(&latin) && !latin_must_move {
+            let index = self.script_index(latin);
+            if index != 0 {
+                let start = self.script_start(index);
+                skipped_reserved = start - low_start;
+                low_start = start;
+            }
+        }
+
+        // Reorder according to the list, continuing from the bottom of the
+        // primary range.
+        let mut has_reorder_to_end = false;
+        let mut codes_iter = codes.iter().copied();
+        while let Some(code) = codes_iter.next() {
+            if code == others {
+                // Put the remaining groups at the top, starting with the
+                // last one.
+                has_reorder_to_end = true;
+                for code in codes_iter.by_ref().rev() {
+                    if code == others {
+                        return Err(ReorderError::Invalid);
+                    }
+                    let index = self.script_index(code);
+                    if index == 0 {
+                        continue;
+                    }
+                    if table.get(index) != Some(&0) {
+                        // Duplicate or equivalent script.
+                        return Err(ReorderError::Invalid);
+                    }
+                    high_limit = self.add_high_script_range(&mut table, index, high_limit);
+                }
+                break;
+            }
+            let index = self.script_index(code);
+            if index == 0 {
+                continue;
+            }
+            if table.get(index) != Some(&0) {
+                // Duplicate or equivalent script.
+                return Err(ReorderError::Invalid);
+            }
+            low_start = self.add_low_script_range(&mut table, index, low_start);
+        }
+
+        // Put all remaining groups into the middle.
+        for index in 1..last {
+            if table.get(index) != Some(&0) {
+                continue;
+            }
+            let start = self.script_start(index);
+            if !has_reorder_to_end && start > low_start {
+                // No need to move this group.
+                low_start = start;
+            }
+            low_start = self.add_low_script_range(&mut table, index, low_start);
+        }
+        if low_start > high_limit {
+            if low_start - (skipped_reserved & 0xFF00) <= high_limit {
+                // Try not skipping the reserved range before Latin.
+                return self.make_reorder_ranges(codes, true);
+            }
+            return Err(ReorderError::Overflow);
+        }
+
+        // Turn the lead bytes into a list of (limit, offset) pairs.
+        let mut ranges = Vec::new();
+        let mut offset = 0;
+        let mut index = 1;
+        loop {
+            let mut next_offset = offset;
+            while index < last {
+                let new_lead_byte = table.get(index).copied().unwrap_or(DONT_CARE);
+                if new_lead_byte != DONT_CARE {
+                    next_offset = i32::from(new_lead_byte) - (self.script_start(index) >> 8);
+                    if next_offset != offset {
+                        break;
+                    }
+                }
+                index += 1;
+            }
+            if offset != 0 || index < last {
+                ranges.push(((self.script_start(index) as u32) << 16) | (offset as u32 & 0xFFFF));
+            }
+            if index == last {
+                break;
+            }
+            offset = next_offset;
+            index += 1;
+        }
+        Ok(ranges)
+    }
+
+    /// ICU4C's `CollationData::addLowScriptRange()`
+    fn add_low_script_range(&self, table: &mut [u8], index: usize, mut low_start: i32) -> i32 {
+        let start = self.script_start(index);
+        if (start & 0xFF) < (low_start & 0xFF) {
+            low_start += 0x100;
+        }
+        if let Some(entry) = table.get_mut(index) {
+            *entry = (low_start >> 8) as u8;
+        }
+        let limit = self.script_start(index + 1);
+        ((low_start & 0xFF00) + ((limit & 0xFF00) - (start & 0xFF00))) | (limit & 0xFF)
+    }
+
+    /// ICU4C's `CollationData::addHighScriptRange()`
+    fn add_high_script_range(&self, table: &mut [u8], index: usize, mut high_limit: i32) -> i32 {
+        let limit = self.script_start(index + 1);
+        if (limit & 0xFF) > (high_limit & 0xFF) {
+            high_limit -= 0x100;
+        }
+        let start = self.script_start(index);
+        high_limit =
+            ((high_limit & 0xFF00) - ((limit & 0xFF00) - (start & 0xFF00))) | (start & 0xFF);
+        if let Some(entry) = table.get_mut(index) {
+            *entry = (high_limit >> 8) as u8;
+        }
+        high_limit
+    }
+}
+
+/// ICU4C's `CollationRuleParser::getReorderCode()`: a special group name,
+/// a script code, or `others`.
+fn reorder_code(names: PropertyParserBorrowed<Script>, name: &str) -> Option<u16> {
+    if let Some(i) = SPECIAL_GROUPS
+        .iter()
+        .position(|group| group.eq_ignore_ascii_case(name))
+    {
+        return Some(REORDER_CODE_FIRST + i as u16);
+    }
+    if let Some(script) = names.get_loose(name) {
+        return Some(script.to_icu4c_value());
+    }
+    name.eq_ignore_ascii_case("others")
+        .then(|| Script::Unknown.to_icu4c_value())
+}
@@ -0,0 +1,470 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// The serialization formats written and read in this file are those of
+// ICU4C's `UCharsTrie` and `UCPTrie` and, therefore, the code is subject
+// to the ICU license as described in LICENSE.
+
+//! Serializers for the trie formats used by `CollationData`: the
+//! contraction suffix tries (`Char16Trie`) and the main code point
+//! to CE32 mapping (a small-type `CodePointTrie`).
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use icu_collections::codepointtrie::{CodePointTrie, CodePointTrieHeader, TrieType};
+use zerovec::ZeroVec;
+
+// See components/collections/src/char16trie/trie.rs for the reader.
+const MAX_BRANCH_LINEAR_SUB_NODE_LENGTH: usize = 5;
+const MIN_LINEAR_MATCH: u16 = 0x30;
+const MAX_LINEAR_MATCH_LENGTH: usize = 0x10;
+const MIN_VALUE_LEAD: u16 = 0x40;
+const NODE_TYPE_MASK: u16 = MIN_VALUE_LEAD - 1;
+const VALUE_IS_FINAL: u16 = 0x8000;
+const MIN_TWO_UNIT_VALUE_LEAD: u16 = 0x4000;
+const THREE_UNIT_VALUE_LEAD: u16 = 0x7fff;
+const MIN_TWO_UNIT_NODE_VALUE_LEAD: u16 = 0x4040;
+const THREE_UNIT_NODE_VALUE_LEAD: u16 = 0x7fc0;
+const MIN_TWO_UNIT_DELTA_LEAD: u16 = 0xfc00;
+const THREE_UNIT_DELTA_LEAD: u16 = 0xffff;
+
+/// Appends a value or a non-final jump delta in the compact value encoding.
+/// `final_bit` is `VALUE_IS_FINAL` for final values and 0 for deltas.
+fn push_value(out: &mut Vec<u16>, value: u32, final_bit: u16) {
+    if value < u32::from(MIN_TWO_UNIT_VALUE_LEAD) {
+        out.push(final_bit | value as u16);
+    } else if value < (u32::from(THREE_UNIT_VALUE_LEAD - MIN_TWO_UNIT_VALUE_LEAD) << 16) {
+        out.push(final_bit | (MIN_TWO_UNIT_VALUE_LEAD + (value >> 16) as u16));
+        out.push(value as u16);
+    } else {
+        out.push(final_bit | THREE_UNIT_VALUE_LEAD);
+        out.push((value >> 16) as u16);
+        out.push(value as u16);
+    }
+}
+
+fn value_len(value: u32) -> usize {
+    if value < u32::from(MIN_TWO_UNIT_VALUE_LEAD) {
+        1
+    } else if value < (u32::from(THREE_UNIT_VALUE_LEAD - MIN_TWO_UNIT_VALUE_LEAD) << 16) {
+        2
+    } else {
+        3
+    }
+}
+
+/// Appends the jump delta of a branch split.
+fn push_jump_delta(out: &mut Vec<u16>, delta: usize) {
+    let delta = delta as u32;
+    if delta < u32::from(MIN_TWO_UNIT_DELTA_LEAD) {
+        out.push(delta as u16);
+    } else if delta < (u32::from(THREE_UNIT_DELTA_LEAD - MIN_TWO_UNIT_DELTA_LEAD) << 16) {
+        out.push(MIN_TWO_UNIT_DELTA_LEAD + (delta >> 16) as u16);
+        out.push(delta as u16);
+    } else {
+        out.push(THREE_UNIT_DELTA_LEAD);
+        out.push((delta >> 16) as u16);
+        out.push(delta as u16);
+    }
+}
+
+/// Serializes a `Char16Trie` that maps each of the (distinct, non-empty) UTF-16
+/// strings of `entries` to its value.
+pub(crate) fn write_char16_trie(entries: &BTreeMap<Vec<u16>, u32>) -> Vec<u16> {
+    let entries: Vec<(&[u16], u32)> = entries.iter().map(|(k, v)| (&k[..], *v)).collect();
+    write_node(&entries, 0)
+}
+
+/// Writes the node for `entries`, all of which share their first `depth` units.
+/// `entries` is sorted and non-empty.
+/// Trie entries sorted by their strings.
+type Entries<'a> = [(&'a [u16], u32)];
+
+fn write_node(entries: &Entries, depth: usize) -> Vec<u16> {
+    let mut out = Vec::new();
+    let mut entries = entries;
+    let mut value = None;
+    if let Some(((first, v), rest)) = entries.split_first() {
+        if first.len() == depth {
+            if rest.is_empty() {
+                push_value(&mut out, *v, VALUE_IS_FINAL);
+                return out;
+            }
+            value = Some(*v);
+            entries = rest;
+        }
+    }
+    let body = write_node_body(entries, depth);
+    if let Some(v) = value {
+        // Intermediate values are always written in the three-unit form
+        // after the node lead.
+        let (lead, body_rest) = body.split_first().map(|(l, r)| (*l, r)).unwrap_or((0, &[]));
+        out.push(THREE_UNIT_NODE_VALUE_LEAD | (lead & NODE_TYPE_MASK));
+        out.push((v >> 16) as u16);
+        out.push(v as u16);
+        out.extend_from_slice(body_rest);
+    } else {
+        out = body;
+    }
+    out
+}
+
+fn unit_at(s: &[u16], i: usize) -> u16 {
+    s.get(i).copied().unwrap_or(0)
+}
+
+/// Writes a branch or linear-match node for `entries`, all of which are longer
+/// than `depth`.
+fn write_node_body(entries: &Entries, depth: usize) -> Vec<u16> {
+    let mut groups: Vec<(u16, &Entries)> = Vec::new();
+    let mut start = 0;
+    for i in 1..=entries.len() {
+        let unit = entries.get(start).map(|e| unit_at(e.0, depth));
+        if i == entries.len() || entries.get(i).map(|e| unit_at(e.0, depth)) != unit {
+            groups.push((unit.unwrap_or(0), entries.get(start..i).unwrap_or_default()));
+            start = i;
+        }
+    }
+    let mut out = Vec::new();
+    if let [(_, group)] = groups[..] {
+        // Linear match: the common prefix, but stop before a string ends.
+        let shortest = group.iter().map(|e| e.0.len()).min().unwrap_or(depth + 1);
+        let first = group.first().map(|e| e.0).unwrap_or_default();
+        let mut len = 1;
+        while depth + len < shortest
+            && len < MAX_LINEAR_MATCH_LENGTH
+            && group
+                .iter()
+                .all(|e| unit_at(e.0, depth + len) == unit_at(first, depth + len))
+        {
+            len += 1;
+        }
+        out.push(MIN_LINEAR_MATCH + (len - 1) as u16);
+        out.extend_from_slice(first.get(depth..depth + len).unwrap_or_default());
+        out.extend(write_node(group, depth + len));
+        return out;
+    }
+    let count = groups.len() - 1;
+    if count < usize::from(MIN_LINEAR_MATCH) {
+        out.push(count as u16);
+    } else {
+        out.push(0);
+        out.push(count as u16);
+    }
+    write_branch_sub_node(&mut out, &groups, depth);
+    out
+}
+
+fn write_branch_sub_node(out: &mut Vec<u16>, groups: &[(u16, &Entries)], depth: usize) {
+    if groups.len() > MAX_BRANCH_LINEAR_SUB_NODE_LENGTH {
+        let (lower, upper) = groups.split_at(groups.len() / 2);
+        let mut upper_units = Vec::new();
+        write_branch_sub_node(&mut upper_units, upper, depth);
+        out.push(upper.first().map(|g| g.0).unwrap_or(0));
+        push_jump_delta(out, upper_units.len());
+        out.extend(upper_units);
+        write_branch_sub_node(out, lower, depth);
+        return;
+    }
+    let Some(((last_unit, last_group), rest)) = groups.split_last() else {
+        return;
+    };
+    // Each entry but the last is followed by a final value or by the delta to
+    // its sub-node. The last entry's sub-node follows inline, and the other
+    // sub-nodes follow it in reverse order, so that the deltas can be
+    // computed back to front.
+    let mut heads: Vec<Vec<u16>> = Vec::new();
+    let mut tail = write_node(last_group, depth + 1);
+    let mut after = 1 + tail.len();
+    let mut tails: Vec<Vec<u16>> = Vec::new();
+    for (unit, group) in rest.iter().rev() {
+        let mut head = alloc::vec![*unit];
+        if let [(s, v)] = group[..] {
+            if s.len() == depth + 1 {
+                push_value(&mut head, v, VALUE_IS_FINAL);
+                after += head.len();
+                heads.push(head);
+                continue;
+            }
+        }
+        let sub = write_node(group, depth + 1);
+        let tails_len: usize = tails.iter().map(Vec::len).sum();
+        // The delta is relative to the end of its own units, so the
+        // encoded length of the delta does not matter.
+        let delta = after + tails_len;
+        push_value(&mut head, delta as u32, 0);
+        debug_assert_eq!(head.len(), 1 + value_len(delta as u32));
+        after += head.len();
+        heads.push(head);
+        tails.push(sub);
+    }
+    for head in heads.iter().rev() {
+        out.extend_from_slice(head);
+    }
+    out.push(*last_unit);
+    out.append(&mut tail);
+    for sub in &tails {
+        out.extend_from_slice(sub);
+    }
+}
+
+/// Calls `f` with each string and value stored in the serialized
+/// `Char16Trie` `trie`. Returns `None` for malformed data.
+pub(crate) fn for_each_char16_trie_entry(
+    trie: &[u16],
+    f: &mut impl FnMut(&[u16], u32),
+) -> Option<()> {
+    let mut prefix = Vec::new();
+    enumerate_node(trie, 0, &mut prefix, f)
+}
+
+fn read_value(trie: &[u16], pos: usize, lead: u16) -> Option<u32> {
+    Some(if lead < MIN_TWO_UNIT_VALUE_LEAD {
+        u32::from(lead)
+    } else if lead < THREE_UNIT_VALUE_LEAD {
+        (u32::from(lead - MIN_TWO_UNIT_VALUE_LEAD) << 16) | u32::from(*trie.get(pos)?)
+    } else {
+        (u32::from(*trie.get(pos)?) << 16) | u32::from(*trie.get(pos + 1)?)
+    })
+}
+
+fn read_node_value(trie: &[u16], pos: usize, lead: u16) -> Option<(u32, usize)> {
+    Some(if lead < MIN_TWO_UNIT_NODE_VALUE_LEAD {
+        (u32::from(lead >> 6) - 1, pos)
+    } else if lead < THREE_UNIT_NODE_VALUE_LEAD {
+        (
+            (u32::from((lead & 0x7fc0) - MIN_TWO_UNIT_NODE_VALUE_LEAD) << 10)
+                | u32::from(*trie.get(pos)?),
+            pos + 1,
+        )
+    } else {
+        (
+            (u32::from(*trie.get(pos)?) << 16) | u32::from(*trie.get(pos + 1)?),
+            pos + 2,
+        )
+    })
+}
+
+fn enumerate_node(
+    trie: &[u16],
+    pos: usize,
+    prefix: &mut Vec<u16>,
+    f: &mut impl FnMut(&[u16], u32),
+) -> Option<()> {
+    let mut node = *trie.get(pos)?;
+    let mut pos = pos + 1;
+    if node >= MIN_VALUE_LEAD {
+        if node & VALUE_IS_FINAL != 0 {
+            f(prefix, read_value(trie, pos, node & !VALUE_IS_FINAL)?);
+            return Some(());
+        }
+        let (value, next) = read_node_value(trie, pos, node)?;
+        f(prefix, value);
+        pos = next;
+        node &= NODE_TYPE_MASK;
+    }
+    if node < MIN_LINEAR_MATCH {
+        let mut length = usize::from(node);
+        if length == 0 {
+            length = usize::from(*trie.get(pos)?);
+            pos += 1;
+        }
+        enumerate_branch(trie, pos, length + 1, prefix, f)
+    } else {
+        let length = usize::from(node - MIN_LINEAR_MATCH) + 1;
+        let old_len = prefix.len();
+        prefix.extend_from_slice(trie.get(pos..pos + length)?);
+        enumerate_node(trie, pos + length, prefix, f)?;
+        prefix.truncate(old_len);
+        Some(())
+    }
+}
+
+fn enumerate_branch(
+    trie: &[u16],
+    pos: usize,
+    length: usize,
+    prefix: &mut Vec<u16>,
+    f: &mut impl FnMut(&[u16], u32),
+) -> Option<()> {
+    let mut pos = pos;
+    let mut length = length;
+    while length > MAX_BRANCH_LINEAR_SUB_NODE_LENGTH {
+        // Skip the pivot unit.
+        pos += 1;
+        let delta_lead = *trie.get(pos)?;
+        let (delta, after) = if delta_lead < MIN_TWO_UNIT_DELTA_LEAD {
+            (usize::from(delta_lead), pos + 1)
+        } else if delta_lead == THREE_UNIT_DELTA_LEAD {
+            (
+                (usize::from(*trie.get(pos + 1)?) << 16) | usize::from(*trie.get(pos + 2)?),
+                pos + 3,
+            )
+        } else {
+            (
+                (usize::from(delta_lead - MIN_TWO_UNIT_DELTA_LEAD) << 16)
+                    | usize::from(*trie.get(pos + 1)?),
+                pos + 2,
+            )
+        };
+        enumerate_branch(trie, after + delta, length >> 1, prefix, f)?;
+        length -= length >> 1;
+        pos = after;
+    }
+    for _ in 1..length {
+        prefix.push(*trie.get(pos)?);
+        let lead = *trie.get(pos + 1)?;
+        let value_lead = lead & !VALUE_IS_FINAL;
+        if lead & VALUE_IS_FINAL != 0 {
+            f(prefix, read_value(trie, pos + 2, value_lead)?);
+        } else {
+            let delta = read_value(trie, pos + 2, value_lead)? as usize;
+            let target = pos + 1 + value_len(delta as u32) + delta;
+            enumerate_node(trie, target, prefix, f)?;
+        }
+        prefix.pop();
+        pos += 1 + value_len(read_value(trie, pos + 2, value_lead)?);
+    }
+    prefix.push(*trie.get(pos)?);
+    enumerate_node(trie, pos + 1, prefix, f)?;
+    prefix.pop();
+    Some(())
+}
+
+// See components/collections/src/codepointtrie/impl_const.rs
+const SMALL_LIMIT: u32 = 0x1000;
+const SMALL_INDEX_LENGTH: usize = 0x40;
+const FAST_DATA_BLOCK_LENGTH: usize = 0x40;
+const DATA_BLOCK_LENGTH: usize = 0x10;
+const INDEX_2_BLOCK_LENGTH: usize = 0x20;
+const INDEX_3_BLOCK_LENGTH: usize = 0x20;
+const CP_PER_INDEX_2_ENTRY: u32 = 0x200;
+const CP_PER_INDEX_1_ENTRY: u32 = 0x4000;
+
+/// Builds a small-type `CodePointTrie` that maps the code points in `values`
+/// to their values and all other code points to `null_value`.
+pub(crate) fn build_code_point_trie(
+    values: &BTreeMap<u32, u32>,
+    null_value: u32,
+    error_value: u32,
+) -> CodePointTrie<'static, u32> {
+    let value_at = |c: u32| values.get(&c).copied().unwrap_or(null_value);
+    let high_start = values
+        .keys()
+        .next_back()
+        .map(|&c| (c + CP_PER_INDEX_2_ENTRY) & !(CP_PER_INDEX_2_ENTRY - 1))
+        .unwrap_or(0)
+        .max(SMALL_LIMIT + CP_PER_INDEX_2_ENTRY);
+
+    let mut data: Vec<u32> = Vec::new();
+    let mut block_offsets: BTreeMap<Vec<u32>, u32> = BTreeMap::new();
+    let mut add_block = |block: Vec<u32>, data: &mut Vec<u32>| -> u32 {
+        *block_offsets.entry(block).or_insert_with_key(|block| {
+            let offset = data.len() as u32;
+            data.extend_from_slice(block);
+            offset
+        })
+    };
+
+    let mut index: Vec<u16> = Vec::new();
+    for start in (0..SMALL_LIMIT).step_by(FAST_DATA_BLOCK_LENGTH) {
+        let block = (start..start + FAST_DATA_BLOCK_LENGTH as u32)
+            .map(value_at)
+            .collect();
+        index.push(add_block(block, &mut data) as u16);
+    }
+
+    // Data offsets for the 16-code point blocks from `SMALL_LIMIT` up to
+    // `high_start`, grouped into index-3 blocks.
+    let mut index3_blocks: Vec<Vec<u32>> = Vec::new();
+    for start in (SMALL_LIMIT..high_start).step_by(CP_PER_INDEX_2_ENTRY as usize) {
+        index3_blocks.push(
+            (start..start + CP_PER_INDEX_2_ENTRY)
+                .step_by(DATA_BLOCK_LENGTH)
+                .map(|block_start| {
+                    let block = (block_start..block_start + DATA_BLOCK_LENGTH as u32)
+                        .map(value_at)
+                        .collect();
+                    add_block(block, &mut data)
+                })
+                .collect(),
+        );
+    }
+    let eighteen_bit = data.len() > 0xffff;
+
+    let index1_length = high_start.div_ceil(CP_PER_INDEX_1_ENTRY) as usize;
+    let index2_start = SMALL_INDEX_LENGTH + index1_length;
+    // The index-2 entries for `SMALL_LIMIT` and up, indexed from code point 0.
+    let mut index2: Vec<u16> = alloc::vec![0; (SMALL_LIMIT / CP_PER_INDEX_2_ENTRY) as usize];
+    let mut index3: Vec<u16> = Vec::new();
+    let mut index3_offsets: BTreeMap<&[u32], u16> = BTreeMap::new();
+    // Index-3 blocks are placed after all index-2 blocks, whose total length
+    // is not known before deduplication; record relative offsets first.
+    for block in &index3_blocks {
+        let offset = *index3_offsets.entry(block).or_insert_with(|| {
+            let offset = index3.len() as u16;
+            if eighteen_bit {
+                for group in block.chunks(8) {
+                    let mut high_bits = 0u16;
+                    for (j, &o) in group.iter().enumerate() {
+                        high_bits |= ((o >> 16) as u16 & 3) << (14 - 2 * j);
+                    }
+                    index3.push(high_bits);
+                    index3.extend(group.iter().map(|&o| o as u16));
+                }
+            } else {
+                index3.extend(block.iter().map(|&o| o as u16));
+            }
+            offset
+        });
+        index2.push(offset);
+    }
+    index2.resize(index1_length * INDEX_2_BLOCK_LENGTH, 0);
+
+    let mut index2_offsets: BTreeMap<&[u16], u16> = BTreeMap::new();
+    let mut index2_blocks: Vec<u16> = Vec::new();
+    let mut index1: Vec<u16> = Vec::new();
+    for block in index2.chunks(INDEX_2_BLOCK_LENGTH) {
+        let offset = *index2_offsets.entry(block).or_insert_with(|| {
+            let offset = index2_blocks.len() as u16;
+            index2_blocks.extend_from_slice(block);
+            offset
+        });
+        index1.push(offset);
+    }
+    let index3_start = (index2_start + index2_blocks.len()) as u16;
+    let index3_flag = if eighteen_bit { 0x8000 } else { 0 };
+    // The entries for code points below `SMALL_LIMIT` and above `high_start`
+    // are never read, so they can be adjusted like the others.
+    for entry in index2_blocks.iter_mut() {
+        *entry = index3_flag | (*entry + index3_start);
+    }
+    index.extend(index1.iter().map(|&o| o + index2_start as u16));
+    index.extend(index2_blocks);
+    index.extend(index3);
+    debug_assert_eq!(
+        INDEX_3_BLOCK_LENGTH,
+        CP_PER_INDEX_2_ENTRY as usize / DATA_BLOCK_LENGTH
+    );
+
+    data.push(null_value);
+    data.push(error_value);
+
+    let header = CodePointTrieHeader {
+        high_start,
+        shifted12_high_start: ((high_start + 0xfff) >> 12) as u16,
+        index3_null_offset: 0xffff,
+        data_null_offset: 0xfffff,
+        null_value,
+        trie_type: TrieType::Small,
+    };
+    // `try_new` only fails for empty data.
+    #[allow(clippy::unwrap_used)]
+    CodePointTrie::try_new(
+        header,
+        ZeroVec::alloc_from_slice(&index),
+        ZeroVec::alloc_from_slice(&data),
+    )
+    .unwrap()
+}
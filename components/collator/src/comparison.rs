@@ -7,11 +7,13 @@
 // described in LICENSE.
 
 //! This module holds the `Collator` struct whose `compare_impl()` contains
-//! the comparison of collation element sequences.
+//! the comparison of collation element sequences and whose
+//! `write_sort_key_impl()` contains the equivalent sort key generation.
 
 use crate::elements::{
-    CollationElement, CollationElements, NonPrimary, JAMO_COUNT, NO_CE, NO_CE_PRIMARY,
-    NO_CE_SECONDARY, NO_CE_TERTIARY, OPTIMIZED_DIACRITICS_MAX_COUNT, QUATERNARY_MASK,
+    ccc_from_trie_value, starts_combining_sequence, CollationElement, CollationElements,
+    NonPrimary, CASE_MASK, JAMO_COUNT, NO_CE, NO_CE_PRIMARY, NO_CE_SECONDARY, NO_CE_TERTIARY,
+    OPTIMIZED_DIACRITICS_MAX_COUNT, QUATERNARY_MASK,
 };
 use crate::options::CollatorOptionsBitField;
 use crate::provider::CollationData;
@@ -26,11 +28,15 @@ use crate::provider::CollationRootV1;
 use crate::provider::CollationSpecialPrimaries;
 use crate::provider::CollationSpecialPrimariesV1;
 use crate::provider::CollationTailoringV1;
+use crate::sort_key::{CollationKeySink, LEVEL_SEPARATOR_BYTE, MERGE_SEPARATOR_BYTE};
 use crate::{
     preferences::CollationCaseFirst, preferences::CollationNumericOrdering,
     preferences::CollationType, AlternateHandling, CollatorOptions, MaxVariable,
-    ResolvedCollatorOptions, Strength,
+    ResolvedCollatorOptions, Strength, Tailoring,
 };
+#[cfg(feature = "compiled_data")]
+use crate::{CollationRulesError, TailoringBuilder};
+use alloc::string::String;
 use core::cmp::Ordering;
 use core::convert::TryFrom;
 use icu_normalizer::provider::CanonicalDecompositionDataV2;
@@ -38,13 +44,54 @@ use icu_normalizer::provider::CanonicalDecompositionTablesV1;
 use icu_normalizer::provider::DecompositionData;
 use icu_normalizer::provider::DecompositionTables;
 use icu_normalizer::Decomposition;
+use icu_properties::props::CanonicalCombiningClass;
 use icu_provider::prelude::*;
 use smallvec::SmallVec;
 use utf16_iter::Utf16CharsEx;
 use utf8_iter::Utf8CharsEx;
 use zerovec::ule::AsULE;
+use zerovec::ZeroSlice;
 
-const MERGE_SEPARATOR_PRIMARY: u32 = 0x02000000; // for U+FFFE
+pub(crate) const MERGE_SEPARATOR_PRIMARY: u32 = 0x02000000; // for U+FFFE
+
+// Sort key compression constants from CollationKeys in ICU4C.
+const COMMON_WEIGHT16: u16 = 0x0500;
+
+const SEC_COMMON_LOW: u8 = 0x05;
+const SEC_COMMON_MIDDLE: u8 = SEC_COMMON_LOW + 0x20;
+const SEC_COMMON_HIGH: u8 = SEC_COMMON_LOW + 0x40;
+const SEC_COMMON_MAX_COUNT: u32 = 0x21;
+
+const CASE_LOWER_FIRST_COMMON_LOW: u8 = 1;
+const CASE_LOWER_FIRST_COMMON_MIDDLE: u8 = 7;
+const CASE_LOWER_FIRST_COMMON_HIGH: u8 = 13;
+const CASE_LOWER_FIRST_COMMON_MAX_COUNT: u32 = 7;
+
+const CASE_UPPER_FIRST_COMMON_LOW: u8 = 3;
+const CASE_UPPER_FIRST_COMMON_MAX_COUNT: u32 = 13;
+
+const TER_ONLY_COMMON_LOW: u8 = 0x05;
+const TER_ONLY_COMMON_MIDDLE: u8 = TER_ONLY_COMMON_LOW + 0x60;
+const TER_ONLY_COMMON_HIGH: u8 = TER_ONLY_COMMON_LOW + 0xC0;
+const TER_ONLY_COMMON_MAX_COUNT: u32 = 0x61;
+
+const TER_LOWER_FIRST_COMMON_LOW: u8 = 0x05;
+const TER_LOWER_FIRST_COMMON_MIDDLE: u8 = TER_LOWER_FIRST_COMMON_LOW + 0x20;
+const TER_LOWER_FIRST_COMMON_HIGH: u8 = TER_LOWER_FIRST_COMMON_LOW + 0x40;
+const TER_LOWER_FIRST_COMMON_MAX_COUNT: u32 = 0x21;
+
+const TER_UPPER_FIRST_COMMON_LOW: u8 = 0x85;
+const TER_UPPER_FIRST_COMMON_MIDDLE: u8 = TER_UPPER_FIRST_COMMON_LOW + 0x20;
+const TER_UPPER_FIRST_COMMON_HIGH: u8 = TER_UPPER_FIRST_COMMON_LOW + 0x40;
+const TER_UPPER_FIRST_COMMON_MAX_COUNT: u32 = 0x21;
+
+const QUAT_COMMON_LOW: u8 = 0x1C;
+const QUAT_COMMON_MIDDLE: u8 = QUAT_COMMON_LOW + 0x70;
+const QUAT_COMMON_HIGH: u8 = QUAT_COMMON_LOW + 0xE0;
+const QUAT_COMMON_MAX_COUNT: u32 = 0x71;
+/// Primary weights shifted to quaternary level must be encoded with
+/// a lead byte below the common-weight compression range.
+const QUAT_SHIFTED_LIMIT_BYTE: u8 = QUAT_COMMON_LOW - 1;
 
 struct AnyQuaternaryAccumulator(u32);
 
@@ -374,6 +421,173 @@ impl Collator {
             lithuanian_dot_above: locale_dependent.lithuanian_dot_above,
         })
     }
+
+    /// Creates a collator for a [`Tailoring`] compiled from collation rules,
+    /// with the root collation from compiled data.
+    ///
+    /// The settings from the rules are the defaults for `options`.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new_with_tailoring(
+        tailoring: Tailoring,
+        options: CollatorOptions,
+    ) -> Result<Self, DataError> {
+        let provider = &crate::provider::Baked;
+        Self::try_new_with_tailoring_internal(
+            DataPayload::from_static_ref(crate::provider::Baked::SINGLETON_COLLATION_ROOT_V1),
+            DataPayload::from_static_ref(
+                icu_normalizer::provider::Baked::SINGLETON_CANONICAL_DECOMPOSITION_DATA_V2,
+            ),
+            DataPayload::from_static_ref(
+                icu_normalizer::provider::Baked::SINGLETON_CANONICAL_DECOMPOSITION_TABLES_V1,
+            ),
+            DataPayload::from_static_ref(crate::provider::Baked::SINGLETON_COLLATION_JAMO_V1),
+            || {
+                Ok(DataPayload::from_static_ref(
+                    crate::provider::Baked::SINGLETON_COLLATION_SPECIAL_PRIMARIES_V1,
+                ))
+            },
+            || provider.load(Default::default()).map(|r| r.payload),
+            tailoring,
+            options,
+        )
+    }
+
+    icu_provider::gen_buffer_data_constructors!(
+        (tailoring: Tailoring, options: CollatorOptions) -> error: DataError,
+        functions: [
+            try_new_with_tailoring: skip,
+            try_new_with_tailoring_with_buffer_provider,
+            try_new_with_tailoring_unstable,
+            Self
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new_with_tailoring)]
+    pub fn try_new_with_tailoring_unstable<D>(
+        provider: &D,
+        tailoring: Tailoring,
+        options: CollatorOptions,
+    ) -> Result<Self, DataError>
+    where
+        D: DataProvider<CollationSpecialPrimariesV1>
+            + DataProvider<CollationRootV1>
+            + DataProvider<CollationDiacriticsV1>
+            + DataProvider<CollationJamoV1>
+            + DataProvider<CanonicalDecompositionDataV2>
+            + DataProvider<CanonicalDecompositionTablesV1>
+            + ?Sized,
+    {
+        Self::try_new_with_tailoring_internal(
+            provider.load(Default::default())?.payload,
+            provider.load(Default::default())?.payload,
+            provider.load(Default::default())?.payload,
+            provider.load(Default::default())?.payload,
+            || provider.load(Default::default()).map(|r| r.payload),
+            || provider.load(Default::default()).map(|r| r.payload),
+            tailoring,
+            options,
+        )
+    }
+
+    /// Creates a collator for the given [LDML collation rules] and options,
+    /// with the root collation from compiled data.
+    ///
+    /// This is a shorthand for compiling the rules with a [`TailoringBuilder`]
+    /// and calling [`Collator::try_new_with_tailoring`]. Use the builder
+    /// directly to compile several rule strings.
+    ///
+    /// [LDML collation rules]: https://www.unicode.org/reports/tr35/tr35-collation.html#Rules
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use icu::collator::Collator;
+    ///
+    /// let collator =
+    ///     Collator::try_new_from_rules("&a < æ", Default::default()).unwrap();
+    /// let collator = collator.as_borrowed();
+    ///
+    /// assert_eq!(collator.compare("æ", "b"), Ordering::Less);
+    /// ```
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new_from_rules(
+        rules: &str,
+        options: CollatorOptions,
+    ) -> Result<Self, CollationRulesError> {
+        let tailoring = TailoringBuilder::try_new()?.build(rules)?;
+        Ok(Self::try_new_with_tailoring(tailoring, options)?)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn try_new_with_tailoring_internal(
+        root: DataPayload<CollationRootV1>,
+        decompositions: DataPayload<CanonicalDecompositionDataV2>,
+        tables: DataPayload<CanonicalDecompositionTablesV1>,
+        jamo: DataPayload<CollationJamoV1>,
+        special_primaries: impl FnOnce() -> Result<DataPayload<CollationSpecialPrimariesV1>, DataError>,
+        root_diacritics: impl FnOnce() -> Result<DataPayload<CollationDiacriticsV1>, DataError>,
+        tailoring: Tailoring,
+        options: CollatorOptions,
+    ) -> Result<Self, DataError> {
+        let diacritics = match tailoring.diacritics {
+            Some(diacritics) => DataPayload::from_owned(diacritics),
+            None => {
+                let diacritics = root_diacritics()?;
+                if diacritics.get().secondaries.len() != OPTIMIZED_DIACRITICS_MAX_COUNT {
+                    return Err(
+                        DataError::custom("invalid").with_marker(CollationDiacriticsV1::INFO)
+                    );
+                }
+                diacritics
+            }
+        };
+
+        let mut merged_options = CollatorOptionsBitField::from(options);
+        merged_options.set_defaults(tailoring.defaults);
+
+        // TODO: redesign Korean search collation handling
+        if jamo.get().ce32s.len() != JAMO_COUNT {
+            return Err(DataError::custom("invalid").with_marker(CollationJamoV1::INFO));
+        }
+
+        let special_primaries = if merged_options.alternate_handling() == AlternateHandling::Shifted
+            || merged_options.numeric()
+        {
+            let special_primaries = special_primaries()?;
+            // `variant_count` isn't stable yet:
+            // https://github.com/rust-lang/rust/issues/73662
+            if special_primaries.get().last_primaries.len() <= (MaxVariable::Currency as usize) {
+                return Err(
+                    DataError::custom("invalid").with_marker(CollationSpecialPrimariesV1::INFO)
+                );
+            }
+            Some(special_primaries)
+        } else {
+            None
+        };
+
+        Ok(Collator {
+            special_primaries,
+            root,
+            tailoring: Some(DataPayload::from_owned(tailoring.data)),
+            jamo,
+            diacritics,
+            options: merged_options,
+            reordering: tailoring.reordering.map(DataPayload::from_owned),
+            decompositions,
+            tables,
+            lithuanian_dot_above: false,
+        })
+    }
 }
 
 /// Compares strings according to culturally-relevant ordering,
@@ -536,6 +750,78 @@ impl CollatorBorrowed<'_> {
         ret
     }
 
+    /// Writes the sort key of a guaranteed well-formed UTF-8 string into `sink`.
+    ///
+    /// Comparing two sort keys bytewise (e.g. via [`Ord`] on `[u8]`) gives the same
+    /// result as calling [`compare`](Self::compare) on the original strings with this
+    /// collator. Sort keys are only comparable with other keys written by a collator
+    /// with the same [`ResolvedCollatorOptions`] and the same data.
+    ///
+    /// The bytes are not compatible with sort keys produced by ICU4C or ICU4J.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::collator::*;
+    /// use icu::locale::locale;
+    ///
+    /// let collator = Collator::try_new(locale!("sv").into(), Default::default()).unwrap();
+    ///
+    /// let mut names = ["Ödman", "Zetterberg", "Andersson", "Åberg"];
+    /// let mut keys = names.map(|name| {
+    ///     let mut key = Vec::new();
+    ///     collator.write_sort_key_to(name, &mut key).unwrap();
+    ///     (key, name)
+    /// });
+    /// keys.sort();
+    ///
+    /// assert_eq!(
+    ///     keys.map(|(_, name)| name),
+    ///     ["Andersson", "Zetterberg", "Åberg", "Ödman"]
+    /// );
+    /// ```
+    pub fn write_sort_key_to<S>(&self, s: &str, sink: &mut S) -> Result<(), S::Error>
+    where
+        S: CollationKeySink + ?Sized,
+    {
+        self.write_sort_key_impl(s.chars(), sink)?;
+        if self.options.strength() == Strength::Identical {
+            self.write_identical_level(s.chars(), sink)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the sort key of potentially ill-formed UTF-8 into `sink`. Ill-formed input
+    /// is treated as if errors had been replaced with REPLACEMENT CHARACTERs according
+    /// to the WHATWG Encoding Standard.
+    ///
+    /// See [`write_sort_key_to`](Self::write_sort_key_to) for details.
+    pub fn write_sort_key_utf8_to<S>(&self, s: &[u8], sink: &mut S) -> Result<(), S::Error>
+    where
+        S: CollationKeySink + ?Sized,
+    {
+        self.write_sort_key_impl(s.chars(), sink)?;
+        if self.options.strength() == Strength::Identical {
+            self.write_identical_level(s.chars(), sink)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the sort key of potentially ill-formed UTF-16 into `sink`. Unpaired
+    /// surrogates are treated as if each one was a REPLACEMENT CHARACTER.
+    ///
+    /// See [`write_sort_key_to`](Self::write_sort_key_to) for details.
+    pub fn write_sort_key_utf16_to<S>(&self, s: &[u16], sink: &mut S) -> Result<(), S::Error>
+    where
+        S: CollationKeySink + ?Sized,
+    {
+        self.write_sort_key_impl(s.chars(), sink)?;
+        if self.options.strength() == Strength::Identical {
+            self.write_identical_level(s.chars(), sink)?;
+        }
+        Ok(())
+    }
+
     fn compare_impl<I: Iterator<Item = char>>(&self, left_chars: I, right_chars: I) -> Ordering {
         let tailoring: &CollationData = if let Some(tailoring) = &self.tailoring {
             tailoring
@@ -1073,4 +1359,563 @@ impl CollatorBorrowed<'_> {
 
         Ordering::Equal
     }
+
+    /// The primary weight one above the last variable primary, or 0 if
+    /// variable collation elements aren't shifted.
+    // Attribute belongs closer to `unwrap`, but
+    // https://github.com/rust-lang/rust/issues/15701
+    #[allow(clippy::unwrap_used)]
+    pub(crate) fn variable_top(&self) -> u32 {
+        if self.options.alternate_handling() == AlternateHandling::NonIgnorable {
+            0
+        } else {
+            // +1 so that we can use "<" and primary ignorables test out early.
+            self.special_primaries
+                .as_ref()
+                // `unwrap()` is OK, because we've ensured in the constructor that value
+                // is `Some` if we have alternate handling.
+                .unwrap()
+                .last_primary_for_group(self.options.max_variable())
+                + 1
+        }
+    }
+
+    /// Creates the collation element iterator for `chars` with the tailoring,
+    /// numeric mode, and other settings of this collator.
+    pub(crate) fn collation_elements<I>(&self, chars: I) -> CollationElements<'_, I>
+    where
+        I: Iterator<Item = char>,
+    {
+        let tailoring: &CollationData = if let Some(tailoring) = &self.tailoring {
+            tailoring
+        } else {
+            // See the comment in `compare_impl()`.
+            self.root
+        };
+
+        // Attribute belongs on inner expression, but
+        // https://github.com/rust-lang/rust/issues/15701
+        #[allow(clippy::unwrap_used)]
+        let numeric_primary = if self.options.numeric() {
+            Some(
+                self.special_primaries
+                    .as_ref()
+                    // `unwrap` is OK, because we've ensured `Some` in the constructor
+                    .unwrap()
+                    .numeric_primary,
+            )
+        } else {
+            None
+        };
+
+        // Attribute belongs on inner expression, but
+        // https://github.com/rust-lang/rust/issues/15701
+        #[allow(clippy::unwrap_used)]
+        CollationElements::new(
+            chars,
+            self.root,
+            tailoring,
+            <&[<u32 as AsULE>::ULE; JAMO_COUNT]>::try_from(self.jamo.ce32s.as_ule_slice()).unwrap(), // `unwrap` OK, because length already validated
+            &self.diacritics.secondaries,
+            self.decompositions,
+            self.tables,
+            numeric_primary,
+            self.lithuanian_dot_above,
+        )
+    }
+
+    /// Whether `c` begins a new combining character sequence, i.e. whether
+    /// text can be split before `c` without splitting a canonical
+    /// decomposition or a run of combining marks.
+    pub(crate) fn starts_combining_sequence(&self, c: char) -> bool {
+        starts_combining_sequence(self.decompositions.trie.get32(u32::from(c)))
+    }
+
+    /// The root collation data.
+    pub(crate) fn root_data(&self) -> &CollationData {
+        self.root
+    }
+
+    /// The CE32s for the Hangul Jamo block.
+    pub(crate) fn jamo_ce32s(&self) -> &ZeroSlice<u32> {
+        &self.jamo.ce32s
+    }
+
+    /// The secondary weights for the start of the Combining Diacritics block.
+    pub(crate) fn diacritic_secondaries(&self) -> &ZeroSlice<u16> {
+        &self.diacritics.secondaries
+    }
+
+    /// The canonical decomposition (NFD) of `s`.
+    pub(crate) fn decompose(&self, s: &str) -> String {
+        Decomposition::new(s.chars(), self.decompositions, self.tables).collect()
+    }
+
+    /// The canonical combining class of `c`.
+    pub(crate) fn ccc(&self, c: char) -> CanonicalCombiningClass {
+        ccc_from_trie_value(self.decompositions.trie.get32(u32::from(c)))
+    }
+
+    /// Writes the identical level: the NFD form of the input as UTF-8,
+    /// which compares bytewise in code point order like
+    /// `Decomposition::cmp` does in `compare()`.
+    fn write_identical_level<I, S>(&self, chars: I, sink: &mut S) -> Result<(), S::Error>
+    where
+        I: Iterator<Item = char>,
+        S: CollationKeySink + ?Sized,
+    {
+        sink.write_byte(LEVEL_SEPARATOR_BYTE)?;
+        let mut buf = [0u8; 4];
+        for c in Decomposition::new(chars, self.decompositions, self.tables) {
+            sink.write(c.encode_utf8(&mut buf).as_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// Writes the sort key levels up to and including the quaternary level.
+    ///
+    /// The algorithm comes from CollationKeys::writeSortKeyUpToQuaternary in ICU4C,
+    /// except that primary weights are not compressed. Each level must be kept
+    /// consistent with the corresponding level in `compare_impl()`.
+    fn write_sort_key_impl<I, S>(&self, chars: I, sink: &mut S) -> Result<(), S::Error>
+    where
+        I: Iterator<Item = char>,
+        S: CollationKeySink + ?Sized,
+    {
+        let variable_top = self.variable_top();
+        let mut iter = self.collation_elements(chars);
+
+        let strength = self.options.strength();
+        let write_secondary = strength >= Strength::Secondary;
+        let write_case = self.options.case_level();
+        let write_quaternary = strength >= Strength::Quaternary;
+        let tertiary_mask = self.options.tertiary_mask();
+        let backward_second_level = self.options.backward_second_level();
+        let upper_first = self.options.upper_first();
+        let shifted = self.options.alternate_handling() != AlternateHandling::NonIgnorable;
+
+        let mut secondaries: SmallVec<[u8; 32]> = SmallVec::new();
+        let mut cases: SmallVec<[u8; 32]> = SmallVec::new();
+        let mut tertiaries: SmallVec<[u8; 32]> = SmallVec::new();
+        let mut quaternaries: SmallVec<[u8; 32]> = SmallVec::new();
+
+        let mut common_cases = 0u32;
+        let mut common_secondaries = 0u32;
+        let mut common_tertiaries = 0u32;
+        let mut common_quaternaries = 0u32;
+
+        let mut prev_secondary = 0u16;
+        let mut sec_segment_start = 0usize;
+
+        loop {
+            let mut ce = iter.next();
+            let mut p = ce.primary();
+            if p < variable_top && p > MERGE_SEPARATOR_PRIMARY {
+                // Variable CE, shift it to quaternary level.
+                // Ignore all following primary ignorables, and shift further variable CEs.
+                if common_quaternaries != 0 {
+                    common_quaternaries -= 1;
+                    while common_quaternaries >= QUAT_COMMON_MAX_COUNT {
+                        quaternaries.push(QUAT_COMMON_MIDDLE);
+                        common_quaternaries -= QUAT_COMMON_MAX_COUNT;
+                    }
+                    // Shifted primary weights are lower than the common weight.
+                    quaternaries.push(QUAT_COMMON_LOW + common_quaternaries as u8);
+                    common_quaternaries = 0;
+                }
+                loop {
+                    if write_quaternary {
+                        if let Some(reordering) = &self.reordering {
+                            p = reordering.reorder(p);
+                        }
+                        if (p >> 24) >= u32::from(QUAT_SHIFTED_LIMIT_BYTE) {
+                            // Prevent shifted primary lead bytes from
+                            // overlapping with the common compression range.
+                            quaternaries.push(QUAT_SHIFTED_LIMIT_BYTE);
+                        }
+                        append_weight32(&mut quaternaries, p);
+                    }
+                    loop {
+                        ce = iter.next();
+                        p = ce.primary();
+                        if p != 0 {
+                            break;
+                        }
+                    }
+                    if !(p < variable_top && p > MERGE_SEPARATOR_PRIMARY) {
+                        break;
+                    }
+                }
+            }
+
+            // `ce` could be primary ignorable, or NO_CE, or the merge separator,
+            // or a regular primary CE, but it is not variable.
+            // If `ce` is NO_CE, then write nothing for the primary level but
+            // terminate compression on all levels and then exit the loop.
+            if p > NO_CE_PRIMARY {
+                if let Some(reordering) = &self.reordering {
+                    p = reordering.reorder(p);
+                }
+                let bytes = p.to_be_bytes();
+                let len = if bytes[1] == 0 {
+                    1
+                } else if bytes[2] == 0 {
+                    2
+                } else if bytes[3] == 0 {
+                    3
+                } else {
+                    4
+                };
+                // Index in range by construction
+                #[allow(clippy::indexing_slicing)]
+                sink.write(&bytes[..len])?;
+            }
+
+            let non_primary = ce.non_primary();
+            let lower32 = non_primary.bits();
+            if lower32 == 0 {
+                // Completely ignorable, no secondary/case/tertiary/quaternary
+                continue;
+            }
+
+            if write_secondary {
+                let s = non_primary.secondary();
+                if s == 0 {
+                    // Secondary ignorable
+                } else if s == COMMON_WEIGHT16
+                    && (!backward_second_level || p != MERGE_SEPARATOR_PRIMARY)
+                {
+                    // `s` is a common secondary weight, and
+                    // backwards-secondary is off or the CE is not the merge separator.
+                    common_secondaries += 1;
+                } else if !backward_second_level {
+                    if common_secondaries != 0 {
+                        common_secondaries -= 1;
+                        while common_secondaries >= SEC_COMMON_MAX_COUNT {
+                            secondaries.push(SEC_COMMON_MIDDLE);
+                            common_secondaries -= SEC_COMMON_MAX_COUNT;
+                        }
+                        let b = if s < COMMON_WEIGHT16 {
+                            SEC_COMMON_LOW + common_secondaries as u8
+                        } else {
+                            SEC_COMMON_HIGH - common_secondaries as u8
+                        };
+                        secondaries.push(b);
+                        common_secondaries = 0;
+                    }
+                    append_weight16(&mut secondaries, s);
+                } else {
+                    if common_secondaries != 0 {
+                        common_secondaries -= 1;
+                        // Append reverse weights. The level will be re-reversed later.
+                        let remainder = common_secondaries % SEC_COMMON_MAX_COUNT;
+                        let b = if prev_secondary < COMMON_WEIGHT16 {
+                            SEC_COMMON_LOW + remainder as u8
+                        } else {
+                            SEC_COMMON_HIGH - remainder as u8
+                        };
+                        secondaries.push(b);
+                        common_secondaries -= remainder;
+                        // `common_secondaries` is now a multiple of `SEC_COMMON_MAX_COUNT`.
+                        while common_secondaries > 0 {
+                            secondaries.push(SEC_COMMON_MIDDLE);
+                            common_secondaries -= SEC_COMMON_MAX_COUNT;
+                        }
+                    }
+                    if 0 < p && p <= MERGE_SEPARATOR_PRIMARY {
+                        // The backwards secondary level compares secondary weights backwards
+                        // within segments separated by the merge separator (U+FFFE).
+                        if let Some(segment) = secondaries.get_mut(sec_segment_start..) {
+                            segment.reverse();
+                        }
+                        secondaries.push(if p == NO_CE_PRIMARY {
+                            LEVEL_SEPARATOR_BYTE
+                        } else {
+                            MERGE_SEPARATOR_BYTE
+                        });
+                        prev_secondary = 0;
+                        sec_segment_start = secondaries.len();
+                    } else {
+                        append_reverse_weight16(&mut secondaries, s);
+                        prev_secondary = s;
+                    }
+                }
+            }
+
+            if write_case {
+                let ignore = if strength == Strength::Primary {
+                    // Primary+caseLevel: Ignore case level weights of primary ignorables.
+                    p == 0
+                } else {
+                    // Otherwise: Ignore case level weights of secondary ignorables.
+                    lower32 <= 0xFFFF
+                };
+                if !ignore {
+                    // Case bits & tertiary lead byte
+                    let mut c = ((lower32 >> 8) & 0xFF) as u8;
+                    debug_assert_ne!(c & 0xC0, 0xC0);
+                    if (c & 0xC0) == 0 && c > LEVEL_SEPARATOR_BYTE {
+                        common_cases += 1;
+                    } else {
+                        if !upper_first {
+                            // lowerFirst: Compress common weights to nibbles 1..7..13, mixed=14, upper=15.
+                            // If there are only common (=lowest) weights in the whole level,
+                            // then we need not write anything.
+                            // Level length differences are handled already on the next-higher level.
+                            if common_cases != 0 && (c > LEVEL_SEPARATOR_BYTE || !cases.is_empty())
+                            {
+                                common_cases -= 1;
+                                while common_cases >= CASE_LOWER_FIRST_COMMON_MAX_COUNT {
+                                    cases.push(CASE_LOWER_FIRST_COMMON_MIDDLE << 4);
+                                    common_cases -= CASE_LOWER_FIRST_COMMON_MAX_COUNT;
+                                }
+                                let b = if c <= LEVEL_SEPARATOR_BYTE {
+                                    CASE_LOWER_FIRST_COMMON_LOW + common_cases as u8
+                                } else {
+                                    CASE_LOWER_FIRST_COMMON_HIGH - common_cases as u8
+                                };
+                                cases.push(b << 4);
+                                common_cases = 0;
+                            }
+                            if c > LEVEL_SEPARATOR_BYTE {
+                                // 14 or 15
+                                c = (CASE_LOWER_FIRST_COMMON_HIGH + (c >> 6)) << 4;
+                            }
+                        } else {
+                            // upperFirst: Compress common weights to nibbles 3..15, mixed=2, upper=1.
+                            // The compressed common case weights only go up from the "low" value
+                            // because with upperFirst the common weight is the highest one.
+                            if common_cases != 0 {
+                                common_cases -= 1;
+                                while common_cases >= CASE_UPPER_FIRST_COMMON_MAX_COUNT {
+                                    cases.push(CASE_UPPER_FIRST_COMMON_LOW << 4);
+                                    common_cases -= CASE_UPPER_FIRST_COMMON_MAX_COUNT;
+                                }
+                                cases.push((CASE_UPPER_FIRST_COMMON_LOW + common_cases as u8) << 4);
+                                common_cases = 0;
+                            }
+                            if c > LEVEL_SEPARATOR_BYTE {
+                                // 2 or 1
+                                c = (CASE_UPPER_FIRST_COMMON_LOW - (c >> 6)) << 4;
+                            }
+                        }
+                        // `c` is a separator byte 01,
+                        // or a left-shifted nibble 0x10, 0x20, ... 0xF0.
+                        cases.push(c);
+                    }
+                }
+            }
+
+            if let Some(tertiary_mask) = tertiary_mask {
+                let mut t = non_primary.tertiary_case_quarternary(tertiary_mask);
+                debug_assert_ne!(lower32 & 0xC000, 0xC000);
+                if t == 0 {
+                    // Tertiary ignorable; `compare_impl()` skips these, too.
+                } else if t == COMMON_WEIGHT16 {
+                    common_tertiaries += 1;
+                } else if (tertiary_mask & CASE_MASK) == 0 {
+                    // Tertiary weights without case bits.
+                    // Move lead bytes 06..3F to C6..FF for a large common-weight range.
+                    if common_tertiaries != 0 {
+                        common_tertiaries -= 1;
+                        while common_tertiaries >= TER_ONLY_COMMON_MAX_COUNT {
+                            tertiaries.push(TER_ONLY_COMMON_MIDDLE);
+                            common_tertiaries -= TER_ONLY_COMMON_MAX_COUNT;
+                        }
+                        let b = if t < COMMON_WEIGHT16 {
+                            TER_ONLY_COMMON_LOW + common_tertiaries as u8
+                        } else {
+                            TER_ONLY_COMMON_HIGH - common_tertiaries as u8
+                        };
+                        tertiaries.push(b);
+                        common_tertiaries = 0;
+                    }
+                    if t > COMMON_WEIGHT16 {
+                        t += 0xC000;
+                    }
+                    append_weight16(&mut tertiaries, t);
+                } else if !upper_first {
+                    // Tertiary weights with caseFirst=lowerFirst.
+                    // Move lead bytes 06..BF to 46..FF for the common-weight range.
+                    if common_tertiaries != 0 {
+                        common_tertiaries -= 1;
+                        while common_tertiaries >= TER_LOWER_FIRST_COMMON_MAX_COUNT {
+                            tertiaries.push(TER_LOWER_FIRST_COMMON_MIDDLE);
+                            common_tertiaries -= TER_LOWER_FIRST_COMMON_MAX_COUNT;
+                        }
+                        let b = if t < COMMON_WEIGHT16 {
+                            TER_LOWER_FIRST_COMMON_LOW + common_tertiaries as u8
+                        } else {
+                            TER_LOWER_FIRST_COMMON_HIGH - common_tertiaries as u8
+                        };
+                        tertiaries.push(b);
+                        common_tertiaries = 0;
+                    }
+                    if t > COMMON_WEIGHT16 {
+                        t += 0x4000;
+                    }
+                    append_weight16(&mut tertiaries, t);
+                } else {
+                    // Tertiary weights with caseFirst=upperFirst.
+                    // Do not change the artificial uppercase weight of a tertiary CE (0.0.ut),
+                    // to keep tertiary CEs well-formed.
+                    // Their case+tertiary weights must be greater than those of
+                    // primary and secondary CEs.
+                    //
+                    // Separator         01 -> 01      (unchanged)
+                    // Lowercase     02..04 -> 82..84  (includes uncased)
+                    // Common weight     05 -> 85..C5  (common-weight compression range)
+                    // Lowercase     06..3F -> C6..FF
+                    // Mixed case    42..7F -> 42..7F
+                    // Uppercase     82..BF -> 02..3F
+                    // Tertiary CE   86..BF -> C6..FF
+                    if t <= NO_CE_TERTIARY {
+                        // Keep separators unchanged.
+                    } else if lower32 > 0xFFFF {
+                        // Invert case bits of primary & secondary CEs.
+                        t ^= 0xC000;
+                        if t < (u16::from(TER_UPPER_FIRST_COMMON_HIGH) << 8) {
+                            t -= 0x4000;
+                        }
+                    } else {
+                        // Keep uppercase bits of tertiary CEs.
+                        debug_assert!((0x8600..=0xBFFF).contains(&t));
+                        t += 0x4000;
+                    }
+                    if common_tertiaries != 0 {
+                        common_tertiaries -= 1;
+                        while common_tertiaries >= TER_UPPER_FIRST_COMMON_MAX_COUNT {
+                            tertiaries.push(TER_UPPER_FIRST_COMMON_MIDDLE);
+                            common_tertiaries -= TER_UPPER_FIRST_COMMON_MAX_COUNT;
+                        }
+                        let b = if t < (u16::from(TER_UPPER_FIRST_COMMON_LOW) << 8) {
+                            TER_UPPER_FIRST_COMMON_LOW + common_tertiaries as u8
+                        } else {
+                            TER_UPPER_FIRST_COMMON_HIGH - common_tertiaries as u8
+                        };
+                        tertiaries.push(b);
+                        common_tertiaries = 0;
+                    }
+                    append_weight16(&mut tertiaries, t);
+                }
+            }
+
+            if write_quaternary {
+                let q = lower32 & 0xFFFF;
+                if (q & 0xC0) == 0 && q > u32::from(NO_CE_TERTIARY) {
+                    common_quaternaries += 1;
+                } else if q == u32::from(NO_CE_TERTIARY) && !shifted && quaternaries.is_empty() {
+                    // If alternate=non-ignorable and there are only common quaternary weights,
+                    // then we need not write anything.
+                    // The only weights greater than the merge separator and less than the common weight
+                    // are shifted primary weights, which are not generated for alternate=non-ignorable.
+                    // There are also exactly as many quaternary weights as tertiary weights,
+                    // so level length differences are handled already on tertiary level.
+                    // Any above-common quaternary weight will compare greater regardless.
+                    quaternaries.push(LEVEL_SEPARATOR_BYTE);
+                } else {
+                    let q = if q == u32::from(NO_CE_TERTIARY) {
+                        LEVEL_SEPARATOR_BYTE
+                    } else {
+                        0xFC + ((q >> 6) & 3) as u8
+                    };
+                    if common_quaternaries != 0 {
+                        common_quaternaries -= 1;
+                        while common_quaternaries >= QUAT_COMMON_MAX_COUNT {
+                            quaternaries.push(QUAT_COMMON_MIDDLE);
+                            common_quaternaries -= QUAT_COMMON_MAX_COUNT;
+                        }
+                        let b = if q < QUAT_COMMON_LOW {
+                            QUAT_COMMON_LOW + common_quaternaries as u8
+                        } else {
+                            QUAT_COMMON_HIGH - common_quaternaries as u8
+                        };
+                        quaternaries.push(b);
+                        common_quaternaries = 0;
+                    }
+                    quaternaries.push(q);
+                }
+            }
+
+            if p == NO_CE_PRIMARY {
+                break;
+            }
+        }
+
+        // Append the beyond-primary levels. Each level buffer ends with
+        // the separator written for NO_CE, which is dropped in favor of
+        // the separator written in front of the level.
+        if write_secondary {
+            sink.write_byte(LEVEL_SEPARATOR_BYTE)?;
+            sink.write(drop_last(&secondaries))?;
+        }
+
+        if write_case {
+            sink.write_byte(LEVEL_SEPARATOR_BYTE)?;
+            // Write pairs of nibbles as bytes, except separator bytes as themselves.
+            let mut b = 0u8;
+            for &c in drop_last(&cases) {
+                debug_assert!((c & 0xF) == 0 && c != 0);
+                if b == 0 {
+                    b = c;
+                } else {
+                    sink.write_byte(b | (c >> 4))?;
+                    b = 0;
+                }
+            }
+            if b != 0 {
+                sink.write_byte(b)?;
+            }
+        }
+
+        if tertiary_mask.is_some() {
+            sink.write_byte(LEVEL_SEPARATOR_BYTE)?;
+            sink.write(drop_last(&tertiaries))?;
+        }
+
+        if write_quaternary {
+            sink.write_byte(LEVEL_SEPARATOR_BYTE)?;
+            sink.write(drop_last(&quaternaries))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Appends a 16-bit weight, omitting a zero low byte.
+fn append_weight16(level: &mut SmallVec<[u8; 32]>, w: u16) {
+    debug_assert_ne!(w, 0);
+    let [b0, b1] = w.to_be_bytes();
+    level.push(b0);
+    if b1 != 0 {
+        level.push(b1);
+    }
+}
+
+/// Appends a 16-bit weight in reverse byte order for a level
+/// that is reversed once complete.
+fn append_reverse_weight16(level: &mut SmallVec<[u8; 32]>, w: u16) {
+    debug_assert_ne!(w, 0);
+    let [b0, b1] = w.to_be_bytes();
+    if b1 != 0 {
+        level.push(b1);
+    }
+    level.push(b0);
+}
+
+/// Appends a 32-bit weight, omitting trailing zero bytes.
+fn append_weight32(level: &mut SmallVec<[u8; 32]>, w: u32) {
+    debug_assert_ne!(w, 0);
+    let bytes = w.to_be_bytes();
+    let mut len = 4;
+    while len > 1 && bytes.get(len - 1) == Some(&0) {
+        len -= 1;
+    }
+    level.extend_from_slice(bytes.get(..len).unwrap_or_default());
+}
+
+/// Returns the level without its trailing NO_CE separator.
+fn drop_last(level: &[u8]) -> &[u8] {
+    debug_assert_eq!(level.last(), Some(&LEVEL_SEPARATOR_BYTE));
+    level.split_last().map(|(_, rest)| rest).unwrap_or_default()
 }
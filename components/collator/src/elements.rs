@@ -84,10 +84,21 @@ fn decomposition_starts_with_non_starter(trie_value: u32) -> bool {
     trie_value_has_ccc(trie_value)
 }
 
+/// Checks if a trie value signifies a character that starts a new
+/// combining character sequence, i.e. a character that neither has
+/// a non-zero canonical combining class nor decomposes to a sequence
+/// that starts with a non-starter.
+///
+/// See components/normalizer/trie-value-format.md
+pub(crate) fn starts_combining_sequence(trie_value: u32) -> bool {
+    !decomposition_starts_with_non_starter(trie_value)
+        && !trie_value_indicates_special_non_starter_decomposition(trie_value)
+}
+
 /// Extracts a canonical combining class (possibly zero) from a trie value.
 ///
 /// See components/normalizer/trie-value-format.md
-fn ccc_from_trie_value(trie_value: u32) -> CanonicalCombiningClass {
+pub(crate) fn ccc_from_trie_value(trie_value: u32) -> CanonicalCombiningClass {
     if trie_value_has_ccc(trie_value) {
         CanonicalCombiningClass::from_icu4c_value(trie_value as u8)
     } else {
@@ -106,7 +117,7 @@ const HANGUL_S_COUNT: u32 = 11172;
 
 pub(crate) const JAMO_COUNT: usize = 256; // 0x1200 - 0x1100
 
-const COMBINING_DIACRITICS_BASE: usize = 0x0300;
+pub(crate) const COMBINING_DIACRITICS_BASE: usize = 0x0300;
 const OPTIMIZED_DIACRITICS_LIMIT: usize = 0x034F;
 pub(crate) const OPTIMIZED_DIACRITICS_MAX_COUNT: usize =
     OPTIMIZED_DIACRITICS_LIMIT - COMBINING_DIACRITICS_BASE;
@@ -118,14 +129,15 @@ pub(crate) const QUATERNARY_MASK: u16 = 0xC0;
 // A CE32 is special if its low byte is this or greater.
 // Impossible case bits 11 mark special CE32s.
 // This value itself is used to indicate a fallback to the base collator.
-const SPECIAL_CE32_LOW_BYTE: u8 = 0xC0;
-const FALLBACK_CE32: CollationElement32 = CollationElement32(SPECIAL_CE32_LOW_BYTE as u32);
+pub(crate) const SPECIAL_CE32_LOW_BYTE: u8 = 0xC0;
+pub(crate) const FALLBACK_CE32: CollationElement32 =
+    CollationElement32(SPECIAL_CE32_LOW_BYTE as u32);
 const LONG_PRIMARY_CE32_LOW_BYTE: u8 = 0xC1; // SPECIAL_CE32_LOW_BYTE | LONG_PRIMARY_TAG
 const COMMON_SECONDARY_CE: u64 = 0x05000000;
-const COMMON_TERTIARY_CE: u64 = 0x0500;
-const COMMON_SEC_AND_TER_CE: u64 = COMMON_SECONDARY_CE | COMMON_TERTIARY_CE;
+pub(crate) const COMMON_TERTIARY_CE: u64 = 0x0500;
+pub(crate) const COMMON_SEC_AND_TER_CE: u64 = COMMON_SECONDARY_CE | COMMON_TERTIARY_CE;
 
-const UNASSIGNED_IMPLICIT_BYTE: u8 = 0xFE;
+pub(crate) const UNASSIGNED_IMPLICIT_BYTE: u8 = 0xFE;
 
 // /// Set if there is no match for the single (no-suffix) character itself.
 // /// This is only possible if there is a prefix.
@@ -135,11 +147,11 @@ const UNASSIGNED_IMPLICIT_BYTE: u8 = 0xFE;
 // const CONTRACT_SINGLE_CP_NO_MATCH: u32 = 0x100;
 
 /// Set if the first character of every contraction suffix has lccc!=0.
-const CONTRACT_NEXT_CCC: u32 = 0x200;
+pub(crate) const CONTRACT_NEXT_CCC: u32 = 0x200;
 /// Set if any contraction suffix ends with lccc!=0.
-const CONTRACT_TRAILING_CCC: u32 = 0x400;
+pub(crate) const CONTRACT_TRAILING_CCC: u32 = 0x400;
 /// Set if at least one contraction suffix contains a starter
-const CONTRACT_HAS_STARTER: u32 = 0x800;
+pub(crate) const CONTRACT_HAS_STARTER: u32 = 0x800;
 
 // const NO_CE32: CollationElement32 = CollationElement32::default();
 // constants named NO_CE* : End of input. Only used in runtime code, not stored in data.
@@ -510,6 +522,13 @@ impl CollationElement {
         CollationElement::new_from_primary(primary)
     }
 
+    /// The raw 64-bit value in the layout described on the type, as
+    /// stored in the `ces` of `CollationData`.
+    #[inline(always)]
+    pub fn bits(self) -> u64 {
+        self.0
+    }
+
     #[inline(always)]
     pub fn clone_with_non_primary_zeroed(self) -> Self {
         CollationElement(self.0 & 0xFFFFFFFF00000000)
@@ -306,6 +306,9 @@
 //! assert_eq!(collator_num_on.compare("a10b", "a2b"), Ordering::Greater);
 //! ```
 
+extern crate alloc;
+
+mod builder;
 mod comparison;
 #[cfg(doc)]
 pub mod docs;
@@ -318,7 +321,12 @@ mod elements;
 
 mod options;
 pub mod provider;
+mod search;
+mod sort_key;
 
+pub use builder::CollationRulesError;
+pub use builder::Tailoring;
+pub use builder::TailoringBuilder;
 pub use comparison::Collator;
 pub use comparison::CollatorBorrowed;
 pub use comparison::CollatorPreferences;
@@ -329,6 +337,12 @@ pub use options::CollatorOptions;
 pub use options::MaxVariable;
 pub use options::ResolvedCollatorOptions;
 pub use options::Strength;
+pub use search::SearchMatches;
+pub use search::SearchMatchesUtf16;
+pub use search::StringSearch;
+pub use sort_key::CollationKeySink;
+pub use sort_key::PartialSortKey;
+pub use sort_key::SortKeyBound;
 
 /// Locale preferences used by this crate
 pub mod preferences {
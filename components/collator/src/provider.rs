@@ -394,15 +394,15 @@ pub struct CollationMetadata {
 }
 
 impl CollationMetadata {
-    const MAX_VARIABLE_MASK: u32 = 0b11;
-    const TAILORED_MASK: u32 = 1 << 3;
-    const TAILORED_DIACRITICS_MASK: u32 = 1 << 4;
-    const REORDERING_MASK: u32 = 1 << 5;
+    pub(crate) const MAX_VARIABLE_MASK: u32 = 0b11;
+    pub(crate) const TAILORED_MASK: u32 = 1 << 3;
+    pub(crate) const TAILORED_DIACRITICS_MASK: u32 = 1 << 4;
+    pub(crate) const REORDERING_MASK: u32 = 1 << 5;
     const LITHUANIAN_DOT_ABOVE_MASK: u32 = 1 << 6;
-    const BACWARD_SECOND_LEVEL_MASK: u32 = 1 << 7;
-    const ALTERNATE_SHIFTED_MASK: u32 = 1 << 8;
-    const CASE_FIRST_MASK: u32 = 1 << 9;
-    const UPPER_FIRST_MASK: u32 = 1 << 10;
+    pub(crate) const BACWARD_SECOND_LEVEL_MASK: u32 = 1 << 7;
+    pub(crate) const ALTERNATE_SHIFTED_MASK: u32 = 1 << 8;
+    pub(crate) const CASE_FIRST_MASK: u32 = 1 << 9;
+    pub(crate) const UPPER_FIRST_MASK: u32 = 1 << 10;
 
     #[inline(always)]
     pub(crate) fn max_variable(self) -> MaxVariable {
@@ -0,0 +1,485 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module holds `StringSearch`, which finds the ranges of a text whose
+//! collation elements are equal to those of a pattern at the strength of
+//! a collator, in the spirit of ICU4C's `usearch`.
+
+use crate::comparison::MERGE_SEPARATOR_PRIMARY;
+use crate::elements::{CollationElements, CASE_MASK, NO_CE_PRIMARY, TERTIARY_MASK};
+use crate::{CollatorBorrowed, Strength};
+use alloc::vec::Vec;
+use core::cell::Cell;
+use core::ops::Range;
+use smallvec::SmallVec;
+
+/// Keeps the primary weight of a collation element.
+const PRIMARY_KEY_MASK: u64 = 0xFFFF_FFFF_0000_0000;
+/// Keeps the primary and secondary weights of a collation element.
+const SECONDARY_KEY_MASK: u64 = PRIMARY_KEY_MASK | 0xFFFF_0000;
+/// Keeps the primary, secondary, case, and tertiary weights of a collation
+/// element, but not the quaternary bits.
+const TERTIARY_KEY_MASK: u64 = SECONDARY_KEY_MASK | (CASE_MASK | TERTIARY_MASK) as u64;
+
+/// Finds the occurrences of a pattern in texts using the language-dependent
+/// equivalence of a [`CollatorBorrowed`].
+///
+/// A range of the text matches if its collation elements, reduced to the
+/// levels up to the [`Strength`] of the collator, are equal to those of the
+/// pattern. Collation elements that are ignorable at that strength are not
+/// taken into account. With
+/// [`AlternateHandling::Shifted`](crate::AlternateHandling::Shifted),
+/// variable collation elements (spaces and punctuation by default) are
+/// ignorable below [`Strength::Quaternary`].
+///
+/// Matches start and end at the boundaries of combining character sequences,
+/// so a base character is never matched without its combining marks, and
+/// they do not start or end in the middle of a contraction of the tailoring.
+///
+/// A pattern that is completely ignorable at the strength of the collator
+/// matches nothing.
+///
+/// # Examples
+///
+/// ```
+/// use icu::collator::*;
+/// use icu::locale::locale;
+///
+/// let mut options = CollatorOptions::default();
+/// options.strength = Some(Strength::Primary);
+/// let collator = Collator::try_new(locale!("en").into(), options).unwrap();
+///
+/// let search = StringSearch::new(&collator, "resume");
+/// let text = "My Résumé, your resume";
+///
+/// let matches: Vec<_> = search.find_iter(text).map(|r| &text[r]).collect();
+/// assert_eq!(matches, ["Résumé", "resume"]);
+///
+/// // Matches can also be found starting from the end of the text.
+/// let last = search.find_iter(text).next_back().unwrap();
+/// assert_eq!(&text[last], "resume");
+/// ```
+#[derive(Debug)]
+pub struct StringSearch<'a> {
+    collator: &'a CollatorBorrowed<'a>,
+    /// The search keys of the pattern; see `SearchKeys`.
+    pattern: Vec<u64>,
+}
+
+impl<'a> StringSearch<'a> {
+    /// Creates a search for a guaranteed well-formed UTF-8 pattern.
+    pub fn new(collator: &'a CollatorBorrowed<'a>, pattern: &str) -> Self {
+        Self::new_impl(collator, pattern.chars())
+    }
+
+    /// Creates a search for a potentially ill-formed UTF-16 pattern.
+    /// Unpaired surrogates are treated as if each one was a REPLACEMENT CHARACTER.
+    pub fn new_utf16(collator: &'a CollatorBorrowed<'a>, pattern: &[u16]) -> Self {
+        Self::new_impl(
+            collator,
+            char::decode_utf16(pattern.iter().copied())
+                .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER)),
+        )
+    }
+
+    fn new_impl<I: Iterator<Item = char>>(collator: &'a CollatorBorrowed<'a>, pattern: I) -> Self {
+        let mut ret = Self {
+            collator,
+            pattern: Vec::new(),
+        };
+        ret.pattern = ret.keys(pattern).collect();
+        ret
+    }
+
+    /// Returns the byte range of the first match in a guaranteed well-formed
+    /// UTF-8 text, if any.
+    pub fn find(&self, haystack: &str) -> Option<Range<usize>> {
+        self.next_match(haystack, 0, haystack.len())
+    }
+
+    /// Returns the code unit range of the first match in a potentially
+    /// ill-formed UTF-16 text, if any.
+    pub fn find_utf16(&self, haystack: &[u16]) -> Option<Range<usize>> {
+        self.next_match(haystack, 0, haystack.len())
+    }
+
+    /// Returns an iterator over the byte ranges of the non-overlapping
+    /// matches in a guaranteed well-formed UTF-8 text.
+    ///
+    /// The iterator is double-ended: iterating from the back finds the
+    /// matches starting from the end of the text. Since matches are
+    /// non-overlapping, mixing both directions on overlapping candidates may
+    /// yield different ranges than iterating in one direction only.
+    pub fn find_iter<'s, 'h>(&'s self, haystack: &'h str) -> SearchMatches<'s, 'h> {
+        SearchMatches {
+            search: self,
+            haystack,
+            front: 0,
+            back: haystack.len(),
+        }
+    }
+
+    /// Returns an iterator over the code unit ranges of the non-overlapping
+    /// matches in a potentially ill-formed UTF-16 text.
+    ///
+    /// Unpaired surrogates in the text are treated as if each one was
+    /// a REPLACEMENT CHARACTER. See [`StringSearch::find_iter`] for the
+    /// iteration order.
+    pub fn find_iter_utf16<'s, 'h>(&'s self, haystack: &'h [u16]) -> SearchMatchesUtf16<'s, 'h> {
+        SearchMatchesUtf16 {
+            search: self,
+            haystack,
+            front: 0,
+            back: haystack.len(),
+        }
+    }
+
+    fn keys<I: Iterator<Item = char>>(&self, chars: I) -> SearchKeys<'_, I> {
+        let strength = self.collator.resolved_options().strength;
+        SearchKeys {
+            iter: self.collator.collation_elements(chars),
+            variable_top: self.collator.variable_top(),
+            mask: match strength {
+                Strength::Primary => PRIMARY_KEY_MASK,
+                Strength::Secondary => SECONDARY_KEY_MASK,
+                _ => TERTIARY_KEY_MASK,
+            },
+            keep_variable: strength >= Strength::Quaternary,
+            after_variable: false,
+        }
+    }
+
+    /// Whether a match may start or end at `index`.
+    fn is_boundary<T: SearchText + ?Sized>(&self, text: &T, index: usize) -> bool {
+        match text.char_at(index) {
+            Some((c, _)) => self.collator.starts_combining_sequence(c),
+            None => true,
+        }
+    }
+
+    /// Finds the first match in `text[front..back]`.
+    fn next_match<T: SearchText + ?Sized>(
+        &self,
+        text: &T,
+        front: usize,
+        back: usize,
+    ) -> Option<Range<usize>> {
+        let mut start = front;
+        while start < back {
+            if self.is_boundary(text, start) {
+                if let Some(end) = self.match_at(text, start, back) {
+                    return Some(start..end);
+                }
+            }
+            start += text.char_at(start)?.1;
+        }
+        None
+    }
+
+    /// Finds the last match in `text[front..back]`.
+    fn next_match_back<T: SearchText + ?Sized>(
+        &self,
+        text: &T,
+        front: usize,
+        back: usize,
+    ) -> Option<Range<usize>> {
+        let mut start = back;
+        while start > front {
+            start -= text.char_before(start)?.1;
+            if self.is_boundary(text, start) {
+                if let Some(end) = self.match_at(text, start, back) {
+                    return Some(start..end);
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns the end of the match that starts at the boundary `start`
+    /// and ends at or before `limit`, if there is one.
+    fn match_at<T: SearchText + ?Sized>(
+        &self,
+        text: &T,
+        start: usize,
+        limit: usize,
+    ) -> Option<usize> {
+        if self.pattern.is_empty() {
+            return None;
+        }
+
+        // The collation elements of the text from `start` on have to begin
+        // with those of the pattern. This rejects most candidates after the
+        // first key and ensures that the match doesn't end in the middle of
+        // a contraction.
+        let consumed = Cell::new(start);
+        let mut keys = self.keys(TextChars::new(text, start, limit, &consumed));
+        if !self.pattern.iter().all(|&p| keys.next() == Some(p)) {
+            return None;
+        }
+        // Producing the keys of the pattern read characters up to `read`
+        // (possibly more due to lookahead), so the match ends at or before
+        // the first boundary at or after `read`.
+        let read = consumed.get();
+
+        // Leading ignorable characters are not part of the match; the match
+        // starts at a later boundary instead.
+        let mut first_end = start;
+        while let Some((_, len)) = text.char_at(first_end) {
+            first_end += len;
+            if self.is_boundary(text, first_end) {
+                break;
+            }
+        }
+        let ignored = Cell::new(start);
+        self.keys(TextChars::new(text, start, first_end.min(limit), &ignored))
+            .next()?;
+
+        // The match must not start in the middle of a contraction or of a
+        // context-sensitive mapping either: the keys of the text from the
+        // previous boundary on have to continue with those of the pattern.
+        if start > 0 {
+            let mut previous = start;
+            while let Some((_, len)) = text.char_before(previous) {
+                previous -= len;
+                if self.is_boundary(text, previous) {
+                    break;
+                }
+            }
+            let ignored = Cell::new(previous);
+            let prefix: SmallVec<[u64; 8]> = self
+                .keys(TextChars::new(text, previous, start, &ignored))
+                .collect();
+            let mut keys = self.keys(TextChars::new(text, previous, limit, &ignored));
+            if !prefix
+                .iter()
+                .chain(self.pattern.iter())
+                .all(|&k| keys.next() == Some(k))
+            {
+                return None;
+            }
+        }
+
+        // The match ends at the first boundary where the keys of the text
+        // since `start` are exactly those of the pattern.
+        let mut end = start;
+        while end < limit {
+            end += text.char_at(end)?.1;
+            if !self.is_boundary(text, end) {
+                continue;
+            }
+            let ignored = Cell::new(start);
+            if self
+                .keys(TextChars::new(text, start, end, &ignored))
+                .eq(self.pattern.iter().copied())
+            {
+                return Some(end);
+            }
+            if end >= read {
+                break;
+            }
+        }
+        None
+    }
+}
+
+/// An iterator over the matches of a [`StringSearch`] in a UTF-8 text.
+///
+/// The items are byte ranges of the text.
+///
+/// Created by [`StringSearch::find_iter`].
+#[derive(Debug)]
+pub struct SearchMatches<'s, 'h> {
+    search: &'s StringSearch<'s>,
+    haystack: &'h str,
+    front: usize,
+    back: usize,
+}
+
+impl Iterator for SearchMatches<'_, '_> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ret = self
+            .search
+            .next_match(self.haystack, self.front, self.back)?;
+        self.front = ret.end;
+        Some(ret)
+    }
+}
+
+impl DoubleEndedIterator for SearchMatches<'_, '_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let ret = self
+            .search
+            .next_match_back(self.haystack, self.front, self.back)?;
+        self.back = ret.start;
+        Some(ret)
+    }
+}
+
+/// An iterator over the matches of a [`StringSearch`] in a UTF-16 text.
+///
+/// The items are code unit ranges of the text.
+///
+/// Created by [`StringSearch::find_iter_utf16`].
+#[derive(Debug)]
+pub struct SearchMatchesUtf16<'s, 'h> {
+    search: &'s StringSearch<'s>,
+    haystack: &'h [u16],
+    front: usize,
+    back: usize,
+}
+
+impl Iterator for SearchMatchesUtf16<'_, '_> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ret = self
+            .search
+            .next_match(self.haystack, self.front, self.back)?;
+        self.front = ret.end;
+        Some(ret)
+    }
+}
+
+impl DoubleEndedIterator for SearchMatchesUtf16<'_, '_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let ret = self
+            .search
+            .next_match_back(self.haystack, self.front, self.back)?;
+        self.back = ret.start;
+        Some(ret)
+    }
+}
+
+/// Turns collation elements into the keys that are compared for search:
+/// collation elements masked to the strength of the collator, with
+/// ignorable ones left out.
+struct SearchKeys<'data, I>
+where
+    I: Iterator<Item = char>,
+{
+    iter: CollationElements<'data, I>,
+    variable_top: u32,
+    mask: u64,
+    /// Whether shifted variable collation elements are kept as their
+    /// primary weight (at quaternary strength) rather than left out.
+    keep_variable: bool,
+    /// Whether the last non-ignorable collation element was variable,
+    /// in which case primary ignorables are ignorable, too.
+    after_variable: bool,
+}
+
+impl<I> Iterator for SearchKeys<'_, I>
+where
+    I: Iterator<Item = char>,
+{
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        loop {
+            let ce = self.iter.next();
+            let p = ce.primary();
+            if p == NO_CE_PRIMARY {
+                return None;
+            }
+            if p < self.variable_top && p > MERGE_SEPARATOR_PRIMARY {
+                self.after_variable = true;
+                if self.keep_variable {
+                    return Some(u64::from(p) << 32);
+                }
+                continue;
+            }
+            if p != 0 {
+                self.after_variable = false;
+            } else if self.after_variable {
+                continue;
+            }
+            let key = ((u64::from(p) << 32) | u64::from(ce.non_primary().bits())) & self.mask;
+            if key != 0 {
+                return Some(key);
+            }
+        }
+    }
+}
+
+/// A text that can be searched: `str` indexed by bytes or `[u16]`
+/// indexed by code units.
+trait SearchText {
+    /// Returns the character that starts at `index` and its length.
+    fn char_at(&self, index: usize) -> Option<(char, usize)>;
+    /// Returns the character that ends at `index` and its length.
+    fn char_before(&self, index: usize) -> Option<(char, usize)>;
+}
+
+impl SearchText for str {
+    fn char_at(&self, index: usize) -> Option<(char, usize)> {
+        let c = self.get(index..)?.chars().next()?;
+        Some((c, c.len_utf8()))
+    }
+
+    fn char_before(&self, index: usize) -> Option<(char, usize)> {
+        let c = self.get(..index)?.chars().next_back()?;
+        Some((c, c.len_utf8()))
+    }
+}
+
+impl SearchText for [u16] {
+    fn char_at(&self, index: usize) -> Option<(char, usize)> {
+        let units = self.get(index..)?;
+        Some(match char::decode_utf16(units.iter().copied()).next()? {
+            Ok(c) => (c, c.len_utf16()),
+            Err(_) => (char::REPLACEMENT_CHARACTER, 1),
+        })
+    }
+
+    fn char_before(&self, index: usize) -> Option<(char, usize)> {
+        let (&last, rest) = self.get(..index)?.split_last()?;
+        if let Some(&first) = rest.last() {
+            if let Some(Ok(c)) = char::decode_utf16([first, last]).next() {
+                if c.len_utf16() == 2 {
+                    return Some((c, 2));
+                }
+            }
+        }
+        Some((
+            char::from_u32(u32::from(last)).unwrap_or(char::REPLACEMENT_CHARACTER),
+            1,
+        ))
+    }
+}
+
+/// The characters of `text[start..limit]` that record in `consumed` the end
+/// of the last character that was read.
+struct TextChars<'t, T: ?Sized> {
+    text: &'t T,
+    position: usize,
+    limit: usize,
+    consumed: &'t Cell<usize>,
+}
+
+impl<'t, T: SearchText + ?Sized> TextChars<'t, T> {
+    fn new(text: &'t T, start: usize, limit: usize, consumed: &'t Cell<usize>) -> Self {
+        Self {
+            text,
+            position: start,
+            limit,
+            consumed,
+        }
+    }
+}
+
+impl<T: SearchText + ?Sized> Iterator for TextChars<'_, T> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if self.position >= self.limit {
+            return None;
+        }
+        let (c, len) = self.text.char_at(self.position)?;
+        self.position += len;
+        self.consumed.set(self.position);
+        Some(c)
+    }
+}
@@ -0,0 +1,228 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Various collation-related algorithms and constants in this file are
+// adapted from ICU4C and, therefore, are subject to the ICU license as
+// described in LICENSE.
+
+//! This module holds the byte sinks that sort keys are written into
+//! and the computation of sort key bounds. The sort key generation
+//! itself lives next to `compare_impl()` in the `comparison` module.
+
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use smallvec::SmallVec;
+
+/// The byte that separates the levels of a sort key.
+pub(crate) const LEVEL_SEPARATOR_BYTE: u8 = 1;
+
+/// The byte that corresponds to the merge separator (U+FFFE)
+/// on levels other than the primary level.
+pub(crate) const MERGE_SEPARATOR_BYTE: u8 = 2;
+
+/// A destination for the bytes of a sort key.
+///
+/// Sort keys are written one byte or one run of bytes at a time.
+/// Implementations exist for growable buffers ([`Vec<u8>`] and
+/// [`SmallVec<[u8; N]>`](SmallVec)) and for writing a truncated key
+/// into a fixed-size buffer ([`PartialSortKey`]).
+pub trait CollationKeySink {
+    /// The error type returned when the sink cannot accept more bytes.
+    type Error;
+
+    /// Writes a single byte.
+    fn write_byte(&mut self, byte: u8) -> Result<(), Self::Error>;
+
+    /// Writes a run of bytes.
+    fn write(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        for &b in bytes {
+            self.write_byte(b)?;
+        }
+        Ok(())
+    }
+}
+
+impl CollationKeySink for Vec<u8> {
+    type Error = Infallible;
+
+    #[inline]
+    fn write_byte(&mut self, byte: u8) -> Result<(), Self::Error> {
+        self.push(byte);
+        Ok(())
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.extend_from_slice(bytes);
+        Ok(())
+    }
+}
+
+impl<const N: usize> CollationKeySink for SmallVec<[u8; N]> {
+    type Error = Infallible;
+
+    #[inline]
+    fn write_byte(&mut self, byte: u8) -> Result<(), Self::Error> {
+        self.push(byte);
+        Ok(())
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.extend_from_slice(bytes);
+        Ok(())
+    }
+}
+
+/// A [`CollationKeySink`] that writes a prefix of a sort key into a
+/// caller-provided fixed-size buffer.
+///
+/// Bytes that do not fit are dropped, but they are still counted, so that
+/// [`PartialSortKey::required_len`] reports the length of the complete key.
+///
+/// Truncated keys preserve order in one direction only: if the truncated key
+/// of `a` compares less than the truncated key of `b`, then `a` sorts before
+/// `b`. If two truncated keys compare equal and at least one of them is
+/// [truncated](PartialSortKey::is_truncated), the strings must be compared
+/// by other means, e.g. [`CollatorBorrowed::compare`](crate::CollatorBorrowed::compare).
+///
+/// # Examples
+///
+/// ```
+/// use icu::collator::*;
+///
+/// let collator = Collator::try_new(Default::default(), Default::default()).unwrap();
+///
+/// let mut buffer = [0u8; 4];
+/// let mut key = PartialSortKey::new(&mut buffer);
+/// collator.write_sort_key_to("international", &mut key).unwrap();
+///
+/// assert_eq!(key.as_bytes().len(), 4);
+/// assert!(key.is_truncated());
+/// assert!(key.required_len() > 4);
+/// ```
+#[derive(Debug)]
+pub struct PartialSortKey<'a> {
+    buffer: &'a mut [u8],
+    written: usize,
+    required: usize,
+}
+
+impl<'a> PartialSortKey<'a> {
+    /// Creates a sink that writes at most `buffer.len()` bytes into `buffer`.
+    pub fn new(buffer: &'a mut [u8]) -> Self {
+        Self {
+            buffer,
+            written: 0,
+            required: 0,
+        }
+    }
+
+    /// The bytes of the key that fit into the buffer.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.buffer.get(..self.written).unwrap_or_default()
+    }
+
+    /// The length of the complete sort key, including the bytes that did not fit.
+    pub fn required_len(&self) -> usize {
+        self.required
+    }
+
+    /// Whether some bytes of the sort key did not fit into the buffer.
+    pub fn is_truncated(&self) -> bool {
+        self.required > self.written
+    }
+}
+
+impl CollationKeySink for PartialSortKey<'_> {
+    type Error = Infallible;
+
+    #[inline]
+    fn write_byte(&mut self, byte: u8) -> Result<(), Self::Error> {
+        if let Some(slot) = self.buffer.get_mut(self.written) {
+            *slot = byte;
+            self.written += 1;
+        }
+        self.required += 1;
+        Ok(())
+    }
+}
+
+/// The kind of bound computed by [`SortKeyBound::write_bound_to`].
+///
+/// Bounds are used for range queries over stored sort keys, for example
+/// in order to find all strings that start with a given prefix or that
+/// are equal to a given string when only some levels are considered.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SortKeyBound {
+    /// A key that compares less than or equal to the keys of all strings
+    /// that are equal to the source string on the requested levels.
+    Lower,
+    /// A key that compares greater than the keys of all strings that are
+    /// equal to the source string on the requested levels, as long as the
+    /// strings do not extend the source string with further primary weights.
+    Upper,
+    /// A key that compares greater than the keys of all strings that are
+    /// equal to the source string on the requested levels, or that start
+    /// with the source string.
+    UpperLong,
+}
+
+impl SortKeyBound {
+    /// Writes the bound of this kind for the sort key `key` into `sink`.
+    ///
+    /// Only the first `levels` levels of `key` are taken into account. If
+    /// `key` has fewer levels, the complete key is used.
+    ///
+    /// This is the equivalent of ICU4C's `ucol_getBound`, except that no
+    /// terminating zero byte is written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::collator::*;
+    ///
+    /// let mut options = CollatorOptions::default();
+    /// options.strength = Some(Strength::Secondary);
+    /// let collator = Collator::try_new(Default::default(), options).unwrap();
+    ///
+    /// let mut key = Vec::new();
+    /// collator.write_sort_key_to("smith", &mut key).unwrap();
+    ///
+    /// let mut lower = Vec::new();
+    /// SortKeyBound::Lower.write_bound_to(&key, 1, &mut lower).unwrap();
+    /// let mut upper = Vec::new();
+    /// SortKeyBound::Upper.write_bound_to(&key, 1, &mut upper).unwrap();
+    ///
+    /// // Case and accent variants of "smith" fall within the primary-level bounds.
+    /// for s in ["Smith", "smíth", "SMITH"] {
+    ///     let mut k = Vec::new();
+    ///     collator.write_sort_key_to(s, &mut k).unwrap();
+    ///     assert!(lower <= k && k < upper);
+    /// }
+    /// ```
+    pub fn write_bound_to<S>(self, key: &[u8], levels: usize, sink: &mut S) -> Result<(), S::Error>
+    where
+        S: CollationKeySink + ?Sized,
+    {
+        let mut remaining = levels;
+        let end = key
+            .iter()
+            .position(|&b| {
+                if b == LEVEL_SEPARATOR_BYTE {
+                    remaining = remaining.saturating_sub(1);
+                }
+                remaining == 0
+            })
+            .unwrap_or(key.len());
+        // `end` is at most `key.len()` by construction.
+        sink.write(key.get(..end).unwrap_or(key))?;
+        match self {
+            SortKeyBound::Lower => Ok(()),
+            SortKeyBound::Upper => sink.write_byte(MERGE_SEPARATOR_BYTE),
+            SortKeyBound::UpperLong => sink.write(&[0xFF, 0xFF]),
+        }
+    }
+}
@@ -39,6 +39,24 @@ const _: () = {
     icu_normalizer_data::impl_uts46_decomposition_data_v2!(TestingProvider);
 };
 
+impl DataProvider<icu_properties::provider::ScriptV1> for TestingProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<icu_properties::provider::ScriptV1>, DataError> {
+        icu_properties::provider::Baked.load(req)
+    }
+}
+
+impl DataProvider<icu_properties::provider::ScriptNameToValueV2> for TestingProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<icu_properties::provider::ScriptNameToValueV2>, DataError> {
+        icu_properties::provider::Baked.load(req)
+    }
+}
+
 type StackString = arraystring::ArrayString<arraystring::typenum::U32>;
 
 /// Parse a string of space-separated hexadecimal code points (ending in end of input or semicolon)
@@ -2021,3 +2039,576 @@ fn test_prefs_overrides() {
 // TODO: Test that nn and nb are aliases for no
 
 // TODO: Consider testing ff-Adlm for supplementary-plane tailoring, including contractions
+
+fn sort_key(collator: &CollatorBorrowed, s: &str) -> Vec<u8> {
+    let mut key = Vec::new();
+    collator.write_sort_key_to(s, &mut key).unwrap();
+    key
+}
+
+fn check_sort_keys_against_compare(collator: &CollatorBorrowed, strings: &[&str]) {
+    let mut prev: Option<(&str, Vec<u8>)> = None;
+    for &s in strings {
+        let key = sort_key(collator, s);
+        if let Some((prev_str, prev_key)) = &prev {
+            assert_eq!(
+                prev_key.cmp(&key),
+                collator.compare(prev_str, s),
+                "{prev_str:?} vs {s:?} with {:?}",
+                collator.resolved_options()
+            );
+        }
+        prev = Some((s, key));
+    }
+}
+
+#[test]
+fn test_sort_key_conformance() {
+    for (dict, alternate_handling) in [
+        (
+            &include_bytes!("data/CollationTest_CLDR_SHIFTED.txt")[..],
+            AlternateHandling::Shifted,
+        ),
+        (
+            &include_bytes!("data/CollationTest_CLDR_NON_IGNORABLE.txt")[..],
+            AlternateHandling::NonIgnorable,
+        ),
+    ] {
+        let strings = dict
+            .split(|b| b == &b'\n')
+            .filter(|line| !line.is_empty() && !line.starts_with(b"#"))
+            .filter_map(parse_hex)
+            .collect::<Vec<_>>();
+        let strings = strings.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+
+        for strength in [Strength::Secondary, Strength::Quaternary] {
+            let mut options = CollatorOptions::default();
+            options.strength = Some(strength);
+            options.alternate_handling = Some(alternate_handling);
+            let collator = Collator::try_new(Default::default(), options).unwrap();
+            check_sort_keys_against_compare(&collator, &strings);
+        }
+    }
+}
+
+#[test]
+fn test_sort_key_options() {
+    let words = include_str!("data/riwords.txt")
+        .lines()
+        .filter(|line| !line.starts_with('#') && !line.starts_with('\u{FEFF}'))
+        .step_by(41)
+        .chain([
+            "a",
+            "A",
+            "á",
+            "Á",
+            "ab",
+            "aB",
+            "Ab",
+            "AB",
+            "a b",
+            "a-b",
+            "ab1",
+            "ab10",
+            "ab2",
+            "côte",
+            "coté",
+            "côté",
+            "cote",
+            "dejavu",
+            "dejAvu",
+            "déjavu",
+            "ⓓⓔⓐⓛ",
+            "DEAL",
+            "",
+        ])
+        .collect::<Vec<_>>();
+
+    for strength in [
+        Strength::Primary,
+        Strength::Secondary,
+        Strength::Tertiary,
+        Strength::Quaternary,
+        Strength::Identical,
+    ] {
+        for case_level in [CaseLevel::Off, CaseLevel::On] {
+            for case_first in [
+                CollationCaseFirst::False,
+                CollationCaseFirst::Lower,
+                CollationCaseFirst::Upper,
+            ] {
+                for backward_second_level in [BackwardSecondLevel::Off, BackwardSecondLevel::On] {
+                    let mut options = CollatorOptions::default();
+                    options.strength = Some(strength);
+                    options.case_level = Some(case_level);
+                    options.backward_second_level = Some(backward_second_level);
+                    options.alternate_handling = Some(AlternateHandling::Shifted);
+                    let mut prefs = CollatorPreferences::default();
+                    prefs.case_first = Some(case_first);
+                    prefs.numeric_ordering = Some(CollationNumericOrdering::True);
+                    let collator = Collator::try_new(prefs, options).unwrap();
+
+                    let mut by_compare = words.clone();
+                    by_compare.sort_by(|a, b| collator.compare(a, b));
+                    check_sort_keys_against_compare(&collator, &by_compare);
+
+                    let mut by_key = words.clone();
+                    by_key.sort_by_cached_key(|s| sort_key(&collator, s));
+                    for (a, b) in by_key.iter().zip(by_compare.iter()) {
+                        assert_eq!(collator.compare(a, b), Ordering::Equal);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_sort_key_reordering() {
+    let collator = Collator::try_new(locale!("th").into(), Default::default()).unwrap();
+    let strings = ["a", "ก", "1", "-", "ข", "b", " ", "ก-ข"];
+    let mut sorted = strings;
+    sorted.sort_by(|a, b| collator.compare(a, b));
+    check_sort_keys_against_compare(&collator, &sorted);
+    for a in strings {
+        for b in strings {
+            assert_eq!(
+                sort_key(&collator, a).cmp(&sort_key(&collator, b)),
+                collator.compare(a, b)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_sort_key_utf8_utf16() {
+    let collator = Collator::try_new(Default::default(), Default::default()).unwrap();
+    for s in ["", "abc", "Résumé", "\u{1F926}\u{1F3FC}", "a\u{0308}"] {
+        let key = sort_key(&collator, s);
+
+        let mut utf8_key = Vec::new();
+        collator
+            .write_sort_key_utf8_to(s.as_bytes(), &mut utf8_key)
+            .unwrap();
+        assert_eq!(key, utf8_key);
+
+        let utf16 = s.encode_utf16().collect::<Vec<_>>();
+        let mut utf16_key = Vec::new();
+        collator
+            .write_sort_key_utf16_to(&utf16, &mut utf16_key)
+            .unwrap();
+        assert_eq!(key, utf16_key);
+    }
+
+    // Ill-formed input is treated as REPLACEMENT CHARACTER
+    let mut ill_formed = Vec::new();
+    collator
+        .write_sort_key_utf16_to(&[0x61, 0xD800], &mut ill_formed)
+        .unwrap();
+    assert_eq!(ill_formed, sort_key(&collator, "a\u{FFFD}"));
+}
+
+#[test]
+fn test_partial_sort_key() {
+    let collator = Collator::try_new(Default::default(), Default::default()).unwrap();
+    let full = sort_key(&collator, "partial");
+
+    let mut buffer = [0u8; 64];
+    let mut partial = PartialSortKey::new(&mut buffer);
+    collator.write_sort_key_to("partial", &mut partial).unwrap();
+    assert!(!partial.is_truncated());
+    assert_eq!(partial.as_bytes(), &full[..]);
+
+    let mut buffer = [0u8; 3];
+    let mut partial = PartialSortKey::new(&mut buffer);
+    collator.write_sort_key_to("partial", &mut partial).unwrap();
+    assert!(partial.is_truncated());
+    assert_eq!(partial.required_len(), full.len());
+    assert_eq!(partial.as_bytes(), &full[..3]);
+}
+
+#[test]
+fn test_sort_key_bounds() {
+    let mut options = CollatorOptions::default();
+    options.strength = Some(Strength::Tertiary);
+    let collator = Collator::try_new(Default::default(), options).unwrap();
+
+    let key = sort_key(&collator, "smith");
+    let mut lower = Vec::new();
+    SortKeyBound::Lower
+        .write_bound_to(&key, 1, &mut lower)
+        .unwrap();
+    let mut upper = Vec::new();
+    SortKeyBound::Upper
+        .write_bound_to(&key, 1, &mut upper)
+        .unwrap();
+    let mut upper_long = Vec::new();
+    SortKeyBound::UpperLong
+        .write_bound_to(&key, 1, &mut upper_long)
+        .unwrap();
+
+    for s in ["smith", "Smith", "SMITH", "smíth"] {
+        let k = sort_key(&collator, s);
+        assert!(lower <= k && k < upper, "{s}");
+        assert!(k < upper_long, "{s}");
+    }
+    for s in ["smithson", "Smithers"] {
+        let k = sort_key(&collator, s);
+        assert!(k > upper, "{s}");
+        assert!(k < upper_long, "{s}");
+    }
+    for s in ["smit", "smiti", "smjth"] {
+        let k = sort_key(&collator, s);
+        assert!(k < lower || k > upper_long, "{s}");
+    }
+
+    // Requesting all levels gives the complete key for the lower bound.
+    let mut all = Vec::new();
+    SortKeyBound::Lower
+        .write_bound_to(&key, 3, &mut all)
+        .unwrap();
+    assert_eq!(all, key);
+}
+
+fn search_matches<'t>(collator: &CollatorBorrowed, pattern: &str, text: &'t str) -> Vec<&'t str> {
+    let search = StringSearch::new(collator, pattern);
+    search.find_iter(text).map(|r| &text[r]).collect()
+}
+
+#[test]
+fn test_search_strength() {
+    let text = "Résumé, resume, RESUME, re\u{301}sume\u{301}";
+
+    let mut options = CollatorOptions::default();
+    options.strength = Some(Strength::Primary);
+    let collator = Collator::try_new(locale!("en").into(), options).unwrap();
+    assert_eq!(
+        search_matches(&collator, "resume", text),
+        ["Résumé", "resume", "RESUME", "re\u{301}sume\u{301}"]
+    );
+
+    options.strength = Some(Strength::Secondary);
+    let collator = Collator::try_new(locale!("en").into(), options).unwrap();
+    assert_eq!(
+        search_matches(&collator, "resume", text),
+        ["resume", "RESUME"]
+    );
+    // Canonically equivalent forms match each other.
+    assert_eq!(
+        search_matches(&collator, "résumé", text),
+        ["Résumé", "re\u{301}sume\u{301}"]
+    );
+
+    options.strength = Some(Strength::Tertiary);
+    let collator = Collator::try_new(locale!("en").into(), options).unwrap();
+    assert_eq!(search_matches(&collator, "resume", text), ["resume"]);
+    assert_eq!(search_matches(&collator, "RESUME", text), ["RESUME"]);
+
+    // An ignorable pattern matches nothing.
+    assert!(search_matches(&collator, "\u{301}", text).is_empty());
+    assert!(search_matches(&collator, "", text).is_empty());
+}
+
+#[test]
+fn test_search_boundaries() {
+    let mut options = CollatorOptions::default();
+    options.strength = Some(Strength::Primary);
+    let collator = Collator::try_new(locale!("en").into(), options).unwrap();
+
+    // The combining marks belong to the match.
+    assert_eq!(
+        search_matches(&collator, "a", "xa\u{301}\u{316}x"),
+        ["a\u{301}\u{316}"]
+    );
+
+    options.strength = Some(Strength::Tertiary);
+    let collator = Collator::try_new(locale!("en").into(), options).unwrap();
+    // A base character doesn't match without its combining marks.
+    assert!(search_matches(&collator, "a", "xa\u{301}x").is_empty());
+
+    // Matches do not split the "ch" contraction of Slovak.
+    let collator = Collator::try_new(locale!("sk").into(), options).unwrap();
+    assert!(search_matches(&collator, "c", "chata").is_empty());
+    assert!(search_matches(&collator, "h", "chata").is_empty());
+    assert_eq!(search_matches(&collator, "ch", "chata"), ["ch"]);
+    assert_eq!(search_matches(&collator, "c", "cesta"), ["c"]);
+}
+
+#[test]
+fn test_search_alternate_handling() {
+    let text = "e-mail, email, e mail";
+
+    let mut options = CollatorOptions::default();
+    options.strength = Some(Strength::Primary);
+    options.alternate_handling = Some(AlternateHandling::NonIgnorable);
+    let collator = Collator::try_new(locale!("en").into(), options).unwrap();
+    assert_eq!(search_matches(&collator, "email", text), ["email"]);
+
+    options.alternate_handling = Some(AlternateHandling::Shifted);
+    let collator = Collator::try_new(locale!("en").into(), options).unwrap();
+    assert_eq!(
+        search_matches(&collator, "email", text),
+        ["e-mail", "email", "e mail"]
+    );
+
+    options.strength = Some(Strength::Quaternary);
+    let collator = Collator::try_new(locale!("en").into(), options).unwrap();
+    assert_eq!(search_matches(&collator, "e-mail", text), ["e-mail"]);
+}
+
+#[test]
+fn test_search_backward() {
+    let mut options = CollatorOptions::default();
+    options.strength = Some(Strength::Primary);
+    let collator = Collator::try_new(locale!("en").into(), options).unwrap();
+    let search = StringSearch::new(&collator, "aa");
+
+    let text = "aaa Aa áa";
+    let forward: Vec<_> = search.find_iter(text).collect();
+    assert_eq!(forward, [0..2, 4..6, 7..10]);
+    let backward: Vec<_> = search.find_iter(text).rev().collect();
+    assert_eq!(backward, [7..10, 4..6, 1..3]);
+
+    let mut both = search.find_iter(text);
+    assert_eq!(both.next(), Some(0..2));
+    assert_eq!(both.next_back(), Some(7..10));
+    assert_eq!(both.next_back(), Some(4..6));
+    assert_eq!(both.next(), None);
+    assert_eq!(both.next_back(), None);
+}
+
+#[test]
+fn test_search_utf16() {
+    let mut options = CollatorOptions::default();
+    options.strength = Some(Strength::Primary);
+    let collator = Collator::try_new(locale!("en").into(), options).unwrap();
+
+    // Contains a supplementary character and an unpaired surrogate.
+    let text: Vec<u16> = "𝒜 Résumé "
+        .encode_utf16()
+        .chain([0xD800])
+        .chain(" resume".encode_utf16())
+        .collect();
+    let pattern: Vec<u16> = "resume".encode_utf16().collect();
+    let search = StringSearch::new_utf16(&collator, &pattern);
+    let forward: Vec<_> = search.find_iter_utf16(&text).collect();
+    assert_eq!(forward, [3..9, 12..18]);
+    let backward: Vec<_> = search.find_iter_utf16(&text).rev().collect();
+    assert_eq!(backward, [12..18, 3..9]);
+    assert_eq!(search.find_utf16(&text), Some(3..9));
+
+    // The UTF-8 and UTF-16 patterns are equivalent.
+    let search = StringSearch::new(&collator, "resume");
+    assert_eq!(search.find_utf16(&text), Some(3..9));
+}
+
+fn rules_collator(rules: &str) -> Collator {
+    let builder = TailoringBuilder::try_new_unstable(&TestingProvider).unwrap();
+    let tailoring = builder.build(rules).unwrap();
+    Collator::try_new_with_tailoring_unstable(&TestingProvider, tailoring, Default::default())
+        .unwrap()
+}
+
+fn assert_sorted(collator: &CollatorBorrowed, strings: &[&str]) {
+    for pair in strings.windows(2) {
+        assert_eq!(
+            collator.compare(pair[0], pair[1]),
+            Ordering::Less,
+            "{:?} < {:?}",
+            pair[0],
+            pair[1]
+        );
+    }
+}
+
+#[test]
+fn test_rules_primary() {
+    let collator = rules_collator("&a < æ <<< Æ");
+    let collator = collator.as_borrowed();
+    assert_sorted(&collator, &["a", "az", "æ", "Æ", "b"]);
+    assert_eq!(collator.compare("æ", "ae"), Ordering::Greater);
+    assert_eq!(collator.compare("ab", "Ab"), Ordering::Less);
+}
+
+#[test]
+fn test_rules_weaker_relations() {
+    let collator = rules_collator("&b << x <<< X &d <<< q = r");
+    let collator = collator.as_borrowed();
+    assert_sorted(&collator, &["b", "x", "X", "bb", "c", "d", "q", "db"]);
+    assert_eq!(collator.compare("q", "r"), Ordering::Equal);
+
+    let mut options = CollatorOptions::default();
+    options.strength = Some(Strength::Primary);
+    let tailoring = TailoringBuilder::try_new_unstable(&TestingProvider)
+        .unwrap()
+        .build("&b << x")
+        .unwrap();
+    let primary =
+        Collator::try_new_with_tailoring_unstable(&TestingProvider, tailoring, options).unwrap();
+    assert_eq!(primary.as_borrowed().compare("x", "b"), Ordering::Equal);
+}
+
+#[test]
+fn test_rules_contraction() {
+    let collator = rules_collator("&h < ch <<< cH <<< Ch <<< CH");
+    let collator = collator.as_borrowed();
+    assert_sorted(
+        &collator,
+        &["c", "cz", "d", "h", "hz", "ch", "cH", "Ch", "CH", "i"],
+    );
+    // Root contractions starting with the same character are preserved.
+    let collator = rules_collator("&z < l·");
+    let collator = collator.as_borrowed();
+    assert_eq!(collator.compare("l·", "z"), Ordering::Greater);
+    assert_eq!(collator.compare("ŀ", "l·"), Ordering::Less);
+}
+
+#[test]
+fn test_rules_before_and_star() {
+    let collator = rules_collator("&[before 1]b < x &c <* pqr-t");
+    let collator = collator.as_borrowed();
+    assert_sorted(
+        &collator,
+        &["a", "az", "x", "b", "c", "p", "q", "r", "s", "t", "d"],
+    );
+}
+
+#[test]
+fn test_rules_expansion() {
+    let collator = rules_collator("&x < ä/e");
+    let collator = collator.as_borrowed();
+    assert_sorted(&collator, &["x", "xz", "ä", "äz", "y"]);
+}
+
+#[test]
+fn test_rules_settings() {
+    let collator = rules_collator("[caseFirst upper][numericOrdering on]");
+    let collator = collator.as_borrowed();
+    assert_eq!(collator.compare("A", "a"), Ordering::Less);
+    assert_eq!(collator.compare("a10", "a9"), Ordering::Greater);
+
+    let collator = rules_collator("[strength 1]");
+    assert_eq!(collator.as_borrowed().compare("a", "Á"), Ordering::Equal);
+
+    let collator = rules_collator("[alternate shifted]");
+    assert_eq!(collator.as_borrowed().compare("a-b", "ab"), Ordering::Equal);
+}
+
+#[test]
+fn test_rules_match_cldr() {
+    // The CLDR rules for Czech and Spanish.
+    for (rules, locale) in [
+        (
+            "&C<č<<<Č &H<ch<<<cH<<<Ch<<<CH &R<ř<<<Ř &S<š<<<Š &Z<ž<<<Ž",
+            locale!("cs"),
+        ),
+        ("&N<ñ<<<Ñ", locale!("es")),
+    ] {
+        let from_rules = rules_collator(rules);
+        let from_rules = from_rules.as_borrowed();
+        let cldr = Collator::try_new_unstable(&TestingProvider, locale.into(), Default::default())
+            .unwrap();
+        let cldr = cldr.as_borrowed();
+        let words = [
+            "chata", "Chata", "cukr", "čaj", "Čaj", "hrad", "ihned", "řeka", "rak", "šum", "sova",
+            "žena", "zebra", "niño", "nino", "Niño", "nube", "ñu", "Ñu", "oso", "n", "ñ", "a", "é",
+            "e", "",
+        ];
+        for a in words {
+            for b in words {
+                assert_eq!(
+                    from_rules.compare(a, b),
+                    cldr.compare(a, b),
+                    "{rules}: {a:?} vs {b:?}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_rules_reorder() {
+    let collator = rules_collator("[reorder Grek digit]");
+    let collator = collator.as_borrowed();
+    assert_sorted(&collator, &["α", "β", "1", "a", "б"]);
+
+    let collator = rules_collator("[reorder Latn others Cyrl]");
+    let collator = collator.as_borrowed();
+    assert_sorted(&collator, &["1", "a", "α", "б"]);
+
+    let collator = rules_collator("[reorder others]");
+    assert_eq!(collator.as_borrowed().compare("a", "α"), Ordering::Less);
+}
+
+#[test]
+fn test_rules_reorder_match_cldr() {
+    let builder = TailoringBuilder::try_new_unstable(&TestingProvider).unwrap();
+    // The `[reorder]` settings of the CLDR rules.
+    for (rules, locale) in [
+        ("[reorder Cyrl]", locale!("ru")),
+        ("[reorder Latn Cyrl]", locale!("sr-Latn")),
+        ("[reorder Arab]", locale!("ar")),
+        ("[reorder Thai]", locale!("th")),
+        ("[reorder Latn Kana Hani]", locale!("ja")),
+        (
+            "[reorder Beng Deva Guru Gujr Orya Taml Telu Knda Mlym Sinh]",
+            locale!("bn"),
+        ),
+    ] {
+        let tailoring = builder.build(rules).unwrap();
+        let cldr: DataResponse<CollationReorderingV1> = TestingProvider
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_locale(&locale.into()),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(tailoring.reordering(), Some(cldr.payload.get()), "{rules}");
+    }
+}
+
+#[test]
+fn test_rules_diacritics() {
+    let collator = rules_collator("&a < \u{0308}");
+    let collator = collator.as_borrowed();
+    assert_sorted(&collator, &["a", "á", "à", "aa", "ä", "ab", "b"]);
+    assert_eq!(collator.compare("a\u{0308}", "ä"), Ordering::Equal);
+}
+
+#[test]
+fn test_rules_errors() {
+    let builder = TailoringBuilder::try_new_unstable(&TestingProvider).unwrap();
+    assert_eq!(
+        builder.build("&a < b < ").unwrap_err(),
+        CollationRulesError::Syntax(9)
+    );
+    assert_eq!(
+        builder.build("a < b").unwrap_err(),
+        CollationRulesError::Syntax(0)
+    );
+    assert_eq!(
+        builder.build("&a < b &c < d|e").unwrap_err(),
+        CollationRulesError::Unsupported(13)
+    );
+    assert_eq!(
+        builder.build("[import de]").unwrap_err(),
+        CollationRulesError::Unsupported(0)
+    );
+    assert_eq!(
+        builder.build("&ᄀ < ᄁ").unwrap_err(),
+        CollationRulesError::Unsupported(5)
+    );
+    assert_eq!(
+        builder.build("&[before 1]a <<< x").unwrap_err(),
+        CollationRulesError::Syntax(13)
+    );
+    assert_eq!(
+        builder
+            .build("&a < b [reorder Latn Unknownscript]")
+            .unwrap_err(),
+        CollationRulesError::Syntax(7)
+    );
+    assert_eq!(
+        builder.build("[reorder Hira Kana]").unwrap_err(),
+        CollationRulesError::Syntax(0)
+    );
+}
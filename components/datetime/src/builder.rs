@@ -101,6 +101,9 @@
 
 use crate::fieldsets::{self, enums::*, Combo};
 use crate::options::*;
+use crate::provider::fields::{self, FieldLength, FieldSymbol};
+use crate::provider::skeleton::{reference::Skeleton, SkeletonError};
+use alloc::string::String;
 
 /// An enumeration over all possible date and calendar period field sets
 /// without options.
@@ -193,6 +196,46 @@ pub enum BuilderError {
 
 impl core::error::Error for BuilderError {}
 
+/// An error that occurs when creating a [`FieldSetBuilder`] from a skeleton string.
+///
+/// See [`FieldSetBuilder::try_from_skeleton_str`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, displaydoc::Display)]
+#[non_exhaustive]
+pub enum SkeletonParseError {
+    /// The skeleton contains a character that is not a field symbol
+    #[displaydoc("Unknown symbol in skeleton: {0}")]
+    UnknownSymbol(char),
+    /// A field in the skeleton has a length that is not valid for its symbol
+    InvalidFieldLength,
+    /// The skeleton contains the same kind of field more than once
+    DuplicateField,
+    /// The skeleton contains a field that cannot be expressed in a field set,
+    /// such as the day of the year or a time zone ID
+    #[displaydoc("Unsupported field in skeleton: {0}")]
+    UnsupportedField(char),
+    /// The fields in the skeleton do not form a field set, such as year and day without month
+    UnsupportedFields,
+}
+
+impl core::error::Error for SkeletonParseError {}
+
+impl SkeletonParseError {
+    fn from_skeleton_error(value: SkeletonError) -> Self {
+        match value {
+            SkeletonError::SymbolUnknown(ch) => Self::UnknownSymbol(ch),
+            SkeletonError::SymbolInvalid(b) => Self::UnknownSymbol(char::from(b)),
+            SkeletonError::SkeletonHasVariant => Self::UnknownSymbol('-'),
+            SkeletonError::SymbolUnimplemented(ch) | SkeletonError::UnimplementedField(ch) => {
+                Self::UnsupportedField(ch)
+            }
+            SkeletonError::DuplicateField => Self::DuplicateField,
+            SkeletonError::InvalidFieldLength | SkeletonError::Fields(_) => {
+                Self::InvalidFieldLength
+            }
+        }
+    }
+}
+
 /// Serde impls: We can't directly use `derive(Serialize)` and also hide null fields
 /// due to <https://github.com/serde-rs/serde/issues/2191>
 #[cfg(all(feature = "serde", feature = "experimental"))]
@@ -368,6 +411,210 @@ impl FieldSetBuilder {
         Self::default()
     }
 
+    /// Creates a [`FieldSetBuilder`] from a UTS 35 skeleton string, such as `"yMMMEd"` or `"jjmm"`.
+    ///
+    /// This eases migration from APIs that select patterns with classical skeletons. The fields
+    /// in the skeleton choose [`Self::date_fields`], [`Self::time_precision`], and
+    /// [`Self::zone_style`], and the field widths choose the remaining options:
+    ///
+    /// - The month width, or else the weekday width, chooses [`Self::length`]: numeric months
+    ///   map to [`Length::Short`], abbreviated names to [`Length::Medium`], and wide names to
+    ///   [`Length::Long`].
+    /// - A two-digit month or day sets [`Alignment::Column`].
+    /// - `G` sets [`YearStyle::WithEra`], `yy` sets [`YearStyle::Auto`], and any other year
+    ///   width sets [`YearStyle::Full`].
+    /// - `S` fields set the number of [fractional second digits](FractionalSecondDigits).
+    ///
+    /// The hour symbols `h`, `H`, `K`, `k`, `j`, `J`, and `C` all display the hour in the
+    /// locale's hour cycle, and day period symbols are ignored. To choose an hour cycle, set it
+    /// in the formatter preferences.
+    ///
+    /// An error is returned for fields that cannot be expressed with a
+    /// [field set](crate::fieldsets), such as the day of the year (`D`) or the quarter (`Q`).
+    /// Combinations of fields that are valid in the skeleton may still be rejected when the
+    /// builder is built; for example, a month and a time of day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::Date;
+    /// use icu::datetime::fieldsets::builder::*;
+    /// use icu::datetime::DateTimeFormatter;
+    /// use icu::locale::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let field_set = FieldSetBuilder::try_from_skeleton_str("yMMMEd")
+    ///     .unwrap()
+    ///     .build_date()
+    ///     .unwrap();
+    ///
+    /// let formatter =
+    ///     DateTimeFormatter::try_new(locale!("en-US").into(), field_set).unwrap();
+    ///
+    /// assert_writeable_eq!(
+    ///     formatter.format(&Date::try_new_iso(2025, 1, 3).unwrap()),
+    ///     "Fri, Jan 3, 2025"
+    /// );
+    ///
+    /// // Quarters are not supported in field sets:
+    /// assert_eq!(
+    ///     FieldSetBuilder::try_from_skeleton_str("yQQQ"),
+    ///     Err(SkeletonParseError::UnsupportedField('Q'))
+    /// );
+    /// ```
+    pub fn try_from_skeleton_str(skeleton: &str) -> Result<Self, SkeletonParseError> {
+        let mut normalized = String::with_capacity(skeleton.len());
+        let mut fractional_second_digits: u8 = 0;
+        for ch in skeleton.chars() {
+            match ch {
+                // The hour cycle is a preference, so all hour symbols are equivalent.
+                'j' | 'J' | 'C' => normalized.push('h'),
+                // Fractional seconds are not a field symbol in skeletons.
+                'S' => fractional_second_digits = fractional_second_digits.saturating_add(1),
+                ch if ch.is_ascii_alphabetic() => normalized.push(ch),
+                ch => return Err(SkeletonParseError::UnknownSymbol(ch)),
+            }
+        }
+        let skeleton = Skeleton::try_from(normalized.as_str())
+            .map_err(SkeletonParseError::from_skeleton_error)?;
+
+        fn set<T>(slot: &mut Option<T>, value: T) -> Result<(), SkeletonParseError> {
+            match slot.replace(value) {
+                Some(_) => Err(SkeletonParseError::DuplicateField),
+                None => Ok(()),
+            }
+        }
+
+        let mut era = None;
+        let mut year = None;
+        let mut month = None;
+        let mut day = None;
+        let mut weekday = None;
+        let mut hour = None;
+        let mut minute = None;
+        let mut second = None;
+        let mut zone_style = None;
+        for field in skeleton.fields_iter() {
+            let length = field.length;
+            if let FieldLength::NumericOverride(_) = length {
+                return Err(SkeletonParseError::InvalidFieldLength);
+            }
+            match field.symbol {
+                FieldSymbol::Era => set(&mut era, ())?,
+                FieldSymbol::Year(fields::Year::Calendar) => set(&mut year, length)?,
+                FieldSymbol::Month(_) => set(&mut month, length)?,
+                FieldSymbol::Day(fields::Day::DayOfMonth) => set(&mut day, length)?,
+                FieldSymbol::Weekday(fields::Weekday::Format) => set(&mut weekday, length)?,
+                // Numeric local and standalone weekdays are not supported.
+                FieldSymbol::Weekday(_) if length >= FieldLength::Three => {
+                    set(&mut weekday, length)?
+                }
+                FieldSymbol::DayPeriod(_) => (),
+                FieldSymbol::Hour(_) => set(&mut hour, ())?,
+                FieldSymbol::Minute => set(&mut minute, ())?,
+                FieldSymbol::Second(fields::Second::Second) => set(&mut second, ())?,
+                FieldSymbol::TimeZone(zone) => {
+                    let style = match (zone, length) {
+                        (
+                            fields::TimeZone::SpecificNonLocation,
+                            FieldLength::One | FieldLength::Two | FieldLength::Three,
+                        ) => ZoneStyle::SpecificShort,
+                        (fields::TimeZone::SpecificNonLocation, FieldLength::Four) => {
+                            ZoneStyle::SpecificLong
+                        }
+                        (fields::TimeZone::LocalizedOffset, FieldLength::One) => {
+                            ZoneStyle::LocalizedOffsetShort
+                        }
+                        (fields::TimeZone::LocalizedOffset, FieldLength::Four) => {
+                            ZoneStyle::LocalizedOffsetLong
+                        }
+                        (fields::TimeZone::GenericNonLocation, FieldLength::One) => {
+                            ZoneStyle::GenericShort
+                        }
+                        (fields::TimeZone::GenericNonLocation, FieldLength::Four) => {
+                            ZoneStyle::GenericLong
+                        }
+                        (fields::TimeZone::Location, FieldLength::Three) => ZoneStyle::ExemplarCity,
+                        (fields::TimeZone::Location, FieldLength::Four) => ZoneStyle::Location,
+                        (
+                            fields::TimeZone::SpecificNonLocation
+                            | fields::TimeZone::LocalizedOffset
+                            | fields::TimeZone::GenericNonLocation,
+                            _,
+                        ) => return Err(SkeletonParseError::InvalidFieldLength),
+                        // Time zone IDs and ISO 8601 offsets
+                        (
+                            fields::TimeZone::Location
+                            | fields::TimeZone::Iso
+                            | fields::TimeZone::IsoWithZ,
+                            _,
+                        ) => return Err(SkeletonParseError::UnsupportedField(field.symbol.into())),
+                    };
+                    set(&mut zone_style, style)?
+                }
+                _ => return Err(SkeletonParseError::UnsupportedField(field.symbol.into())),
+            }
+        }
+
+        let date_fields = match (year, month, day, weekday) {
+            (None, None, Some(_), None) => Some(DateFields::D),
+            (None, Some(_), Some(_), None) => Some(DateFields::MD),
+            (Some(_), Some(_), Some(_), None) => Some(DateFields::YMD),
+            (None, None, Some(_), Some(_)) => Some(DateFields::DE),
+            (None, Some(_), Some(_), Some(_)) => Some(DateFields::MDE),
+            (Some(_), Some(_), Some(_), Some(_)) => Some(DateFields::YMDE),
+            (None, None, None, Some(_)) => Some(DateFields::E),
+            (None, Some(_), None, None) => Some(DateFields::M),
+            (Some(_), Some(_), None, None) => Some(DateFields::YM),
+            (Some(_), None, None, None) => Some(DateFields::Y),
+            (None, None, None, None) => None,
+            _ => return Err(SkeletonParseError::UnsupportedFields),
+        };
+
+        let year_style = match (era, year) {
+            (Some(()), Some(_)) => Some(YearStyle::WithEra),
+            (Some(()), None) => return Err(SkeletonParseError::UnsupportedFields),
+            (None, Some(FieldLength::Two)) => Some(YearStyle::Auto),
+            (None, Some(_)) => Some(YearStyle::Full),
+            (None, None) => None,
+        };
+
+        let length = match month.or(weekday) {
+            Some(FieldLength::One | FieldLength::Two) if month.is_some() => Some(Length::Short),
+            Some(FieldLength::Four) => Some(Length::Long),
+            Some(FieldLength::Five | FieldLength::Six) => Some(Length::Short),
+            Some(_) => Some(Length::Medium),
+            None => None,
+        };
+
+        let alignment = if month == Some(FieldLength::Two) || day == Some(FieldLength::Two) {
+            Some(Alignment::Column)
+        } else {
+            None
+        };
+
+        let time_precision = match (hour, minute, second, fractional_second_digits) {
+            (None, None, None, 0) => None,
+            (Some(()), None, None, 0) => Some(TimePrecision::Hour),
+            (Some(()), Some(()), None, 0) => Some(TimePrecision::Minute),
+            (Some(()), Some(()), Some(()), 0) => Some(TimePrecision::Second),
+            (Some(()), Some(()), Some(()), digits) => Some(TimePrecision::FractionalSecond(
+                FractionalSecondDigits::try_from(digits)
+                    .map_err(|_| SkeletonParseError::InvalidFieldLength)?,
+            )),
+            _ => return Err(SkeletonParseError::UnsupportedFields),
+        };
+
+        Ok(Self {
+            length,
+            date_fields,
+            time_precision,
+            zone_style,
+            alignment,
+            year_style,
+        })
+    }
+
     fn build_date_or_calendar_period_without_checking_options(
         &mut self,
     ) -> Result<DateOrCalendarPeriodFieldSet, BuilderError> {
@@ -838,4 +1085,98 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_skeletons() {
+        #[rustfmt::skip]
+        let cases: &[(&str, FieldSetBuilder)] = &[
+            ("yMMMEd", FieldSetBuilder {
+                length: Some(Length::Medium),
+                date_fields: Some(DateFields::YMDE),
+                year_style: Some(YearStyle::Full),
+                ..Default::default()
+            }),
+            ("yMd", FieldSetBuilder {
+                length: Some(Length::Short),
+                date_fields: Some(DateFields::YMD),
+                year_style: Some(YearStyle::Full),
+                ..Default::default()
+            }),
+            ("yyMMdd", FieldSetBuilder {
+                length: Some(Length::Short),
+                date_fields: Some(DateFields::YMD),
+                year_style: Some(YearStyle::Auto),
+                alignment: Some(Alignment::Column),
+                ..Default::default()
+            }),
+            ("GyMMMM", FieldSetBuilder {
+                length: Some(Length::Long),
+                date_fields: Some(DateFields::YM),
+                year_style: Some(YearStyle::WithEra),
+                ..Default::default()
+            }),
+            ("EEEE", FieldSetBuilder {
+                length: Some(Length::Long),
+                date_fields: Some(DateFields::E),
+                ..Default::default()
+            }),
+            ("jjmm", FieldSetBuilder {
+                time_precision: Some(TimePrecision::Minute),
+                ..Default::default()
+            }),
+            ("Hmsv", FieldSetBuilder {
+                time_precision: Some(TimePrecision::Second),
+                zone_style: Some(ZoneStyle::GenericShort),
+                ..Default::default()
+            }),
+            ("hmsSSSzzzz", FieldSetBuilder {
+                time_precision: Some(TimePrecision::FractionalSecond(FractionalSecondDigits::F3)),
+                zone_style: Some(ZoneStyle::SpecificLong),
+                ..Default::default()
+            }),
+            ("MMMdjZZZZ", FieldSetBuilder {
+                length: Some(Length::Medium),
+                date_fields: Some(DateFields::MD),
+                time_precision: Some(TimePrecision::Hour),
+                zone_style: Some(ZoneStyle::LocalizedOffsetLong),
+                ..Default::default()
+            }),
+            ("VVV", FieldSetBuilder {
+                zone_style: Some(ZoneStyle::ExemplarCity),
+                ..Default::default()
+            }),
+        ];
+        for (skeleton, expected) in cases {
+            let builder = FieldSetBuilder::try_from_skeleton_str(skeleton).unwrap();
+            assert_eq!(&builder, expected, "{skeleton}");
+            builder.build_composite().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_skeleton_errors() {
+        let cases: &[(&str, SkeletonParseError)] = &[
+            ("yMd!", SkeletonParseError::UnknownSymbol('!')),
+            ("yD", SkeletonParseError::UnsupportedField('D')),
+            ("yQQQ", SkeletonParseError::UnsupportedField('Q')),
+            ("yw", SkeletonParseError::UnsupportedField('w')),
+            ("hmVV", SkeletonParseError::UnsupportedField('V')),
+            ("ee", SkeletonParseError::UnsupportedField('e')),
+            ("yMyy", SkeletonParseError::DuplicateField),
+            ("jH", SkeletonParseError::DuplicateField),
+            ("MMMMMMM", SkeletonParseError::InvalidFieldLength),
+            ("zzzzz", SkeletonParseError::InvalidFieldLength),
+            ("yd", SkeletonParseError::UnsupportedFields),
+            ("Gd", SkeletonParseError::UnsupportedFields),
+            ("ms", SkeletonParseError::UnsupportedFields),
+            ("hS", SkeletonParseError::UnsupportedFields),
+        ];
+        for (skeleton, expected) in cases {
+            assert_eq!(
+                FieldSetBuilder::try_from_skeleton_str(skeleton),
+                Err(*expected),
+                "{skeleton}"
+            );
+        }
+    }
 }
@@ -0,0 +1,658 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Formatting of intervals between two datetimes, such as "Jan 3 – 7, 2025".
+
+use crate::error::DateTimeFormatterLoadError;
+use crate::external_loaders::*;
+use crate::fieldsets::enums::CompositeFieldSet;
+use crate::format::datetime::try_write_pattern_items;
+use crate::input::ExtractedInput;
+use crate::neo::{DateTimeFormatter, DateTimeFormatterPreferences, FormattedDateTime};
+use crate::provider::fields::{self, Field, FieldLength, FieldSymbol};
+use crate::provider::neo::{DateTimeIntervalPatterns, DateTimeIntervalPatternsV1};
+use crate::provider::pattern::{runtime, PatternItem};
+use crate::scaffold::*;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::mem::discriminant;
+use icu_pattern::DoublePlaceholderPattern;
+use icu_provider::prelude::*;
+use writeable::{impl_display_with_writeable, Writeable};
+use zerofrom::ZeroFrom;
+use zerovec::ule::AsULE;
+
+/// [`DateTimeIntervalFormatter`] formats the interval between two datetimes,
+/// such as "Jan 3 – 7, 2025" or "10:00 – 11:30 AM".
+///
+/// Both datetimes are formatted with the same field set. The formatter finds the greatest
+/// field in which they differ and uses the locale's interval pattern for it, which shows
+/// the shared fields only once. If the locale has no such pattern, the two datetimes are
+/// formatted in full and combined with the locale's fallback pattern, as in
+/// "Jan 3, 2025 – Feb 7, 2026".
+///
+/// Interval patterns only exist for date-only and time-only field sets; other field sets,
+/// such as a date with a time, always use the fallback pattern when the datetimes differ.
+///
+/// # Examples
+///
+/// ```
+/// use icu::calendar::Date;
+/// use icu::datetime::fieldsets::YMD;
+/// use icu::datetime::DateTimeIntervalFormatter;
+/// use icu::locale::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let formatter =
+///     DateTimeIntervalFormatter::try_new(locale!("en").into(), YMD::medium())
+///         .unwrap();
+///
+/// let jan_3 = Date::try_new_gregorian(2025, 1, 3).unwrap();
+/// let jan_7 = Date::try_new_gregorian(2025, 1, 7).unwrap();
+/// let feb_2 = Date::try_new_gregorian(2025, 2, 2).unwrap();
+///
+/// assert_writeable_eq!(
+///     formatter.format(&jan_3, &jan_7),
+///     "Jan 3\u{2009}–\u{2009}7, 2025"
+/// );
+/// assert_writeable_eq!(
+///     formatter.format(&jan_3, &feb_2),
+///     "Jan 3\u{2009}–\u{2009}Feb 2, 2025"
+/// );
+///
+/// // Equal dates are formatted once:
+/// assert_writeable_eq!(formatter.format(&jan_3, &jan_3), "Jan 3, 2025");
+/// ```
+///
+/// Times use the hour cycle of the locale:
+///
+/// ```
+/// use icu::datetime::fieldsets::T;
+/// use icu::datetime::input::Time;
+/// use icu::datetime::DateTimeIntervalFormatter;
+/// use icu::locale::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let formatter =
+///     DateTimeIntervalFormatter::try_new(locale!("en").into(), T::short().hm()).unwrap();
+///
+/// let start = Time::try_new(10, 0, 0, 0).unwrap();
+/// let end = Time::try_new(11, 30, 0, 0).unwrap();
+///
+/// assert_writeable_eq!(
+///     formatter.format(&start, &end),
+///     "10:00\u{2009}–\u{2009}11:30\u{202f}AM"
+/// );
+/// ```
+#[derive(Debug)]
+pub struct DateTimeIntervalFormatter<FSet: DateTimeNamesMarker> {
+    formatter: DateTimeFormatter<FSet>,
+    patterns: DataPayload<DateTimeIntervalPatternsV1>,
+    /// The interval patterns for each pattern that the formatter can select.
+    resolved: Vec<ResolvedPatterns>,
+}
+
+impl<FSet: DateTimeMarkers> DateTimeIntervalFormatter<FSet>
+where
+    FSet::D: DateDataMarkers,
+    FSet::T: TimeMarkers,
+    FSet::Z: ZoneMarkers,
+    FSet: GetField<CompositeFieldSet>,
+{
+    /// Creates a new [`DateTimeIntervalFormatter`] from compiled data.
+    ///
+    /// The calendar is selected from the preferences in the same way as for
+    /// [`DateTimeFormatter`].
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        prefs: DateTimeFormatterPreferences,
+        field_set: FSet,
+    ) -> Result<Self, DateTimeFormatterLoadError>
+    where
+        crate::provider::Baked: AllAnyCalendarFormattingDataMarkers<FSet>,
+    {
+        Self::try_new_internal(
+            &crate::provider::Baked,
+            &ExternalLoaderCompiledData,
+            prefs,
+            field_set.get_field(),
+        )
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(BUFFER, Self::try_new)]
+    #[cfg(feature = "serde")]
+    pub fn try_new_with_buffer_provider<P>(
+        provider: &P,
+        prefs: DateTimeFormatterPreferences,
+        field_set: FSet,
+    ) -> Result<Self, DateTimeFormatterLoadError>
+    where
+        P: BufferProvider + ?Sized,
+    {
+        Self::try_new_internal(
+            &provider.as_deserializing(),
+            &ExternalLoaderBuffer(provider),
+            prefs,
+            field_set.get_field(),
+        )
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<P>(
+        provider: &P,
+        prefs: DateTimeFormatterPreferences,
+        field_set: FSet,
+    ) -> Result<Self, DateTimeFormatterLoadError>
+    where
+        P: ?Sized
+            + AllAnyCalendarFormattingDataMarkers<FSet>
+            + AllAnyCalendarExternalDataMarkers
+            + DataProvider<DateTimeIntervalPatternsV1>,
+    {
+        Self::try_new_internal(
+            provider,
+            &ExternalLoaderUnstable(provider),
+            prefs,
+            field_set.get_field(),
+        )
+    }
+
+    fn try_new_internal<P, L>(
+        provider: &P,
+        loader: &L,
+        prefs: DateTimeFormatterPreferences,
+        field_set: CompositeFieldSet,
+    ) -> Result<Self, DateTimeFormatterLoadError>
+    where
+        P: ?Sized
+            + AllAnyCalendarFormattingDataMarkers<FSet>
+            + DataProvider<DateTimeIntervalPatternsV1>,
+        L: DecimalFormatterLoader + AnyCalendarLoader,
+    {
+        let formatter = DateTimeFormatter::try_new_internal(provider, loader, prefs, field_set)?;
+        let locale = DateTimeIntervalPatternsV1::make_locale(prefs.locale_preferences);
+        let patterns = provider
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_locale(&locale),
+                ..Default::default()
+            })?
+            .payload;
+        let mut resolved = Vec::<ResolvedPatterns>::new();
+        for pattern in formatter.selection.iter_patterns() {
+            let items = pattern.iter_items().collect::<Vec<_>>();
+            if resolved.iter().all(|r| r.items != items) {
+                resolved.push(ResolvedPatterns::new(patterns.get(), items));
+            }
+        }
+        Ok(Self {
+            formatter,
+            patterns,
+            resolved,
+        })
+    }
+}
+
+impl<FSet: DateTimeMarkers> DateTimeIntervalFormatter<FSet>
+where
+    FSet::D: DateInputMarkers,
+    FSet::T: TimeMarkers,
+    FSet::Z: ZoneMarkers,
+{
+    /// Formats the interval between two datetimes after first converting them
+    /// to the formatter's calendar.
+    ///
+    /// If `start` and `end` are equal at the precision of the field set, the result is
+    /// the same as formatting `start` on its own.
+    pub fn format<'a, I>(&'a self, start: &I, end: &I) -> FormattedDateTimeInterval<'a>
+    where
+        I: ?Sized + ConvertCalendar,
+        I::Converted<'a>: Sized + AllInputMarkers<FSet>,
+    {
+        let start = self.format_one(start);
+        let end = self.format_one(end);
+        let kind = IntervalKind::select(&self.resolved, &start, &end);
+        FormattedDateTimeInterval {
+            start,
+            end,
+            kind,
+            fallback: &self.patterns.get().fallback,
+        }
+    }
+
+    fn format_one<'a, I>(&'a self, datetime: &I) -> FormattedDateTime<'a>
+    where
+        I: ?Sized + ConvertCalendar,
+        I::Converted<'a>: Sized + AllInputMarkers<FSet>,
+    {
+        let datetime = datetime.to_calendar(&self.formatter.calendar);
+        let input =
+            ExtractedInput::extract_from_neo_input::<FSet::D, FSet::T, FSet::Z, I::Converted<'a>>(
+                &datetime,
+            );
+        FormattedDateTime {
+            pattern: self.formatter.selection.select(&input),
+            input,
+            names: self.formatter.names.as_borrowed(),
+        }
+    }
+}
+
+/// The interval patterns for a pattern of a single datetime, resolved when the formatter is
+/// created.
+#[derive(Debug)]
+struct ResolvedPatterns {
+    /// The pattern for a single datetime.
+    items: Vec<PatternItem>,
+    /// The interval patterns by greatest difference.
+    intervals: Vec<(char, SplitPattern)>,
+}
+
+/// An interval pattern whose items before `split` are formatted with the start and the rest
+/// with the end.
+#[derive(Debug)]
+struct SplitPattern {
+    items: Vec<PatternItem>,
+    split: usize,
+}
+
+impl ResolvedPatterns {
+    fn new(patterns: &DateTimeIntervalPatterns, items: Vec<PatternItem>) -> Self {
+        let fields = items
+            .iter()
+            .filter_map(|item| match item {
+                PatternItem::Field(field) => Some(*field),
+                PatternItem::Literal(_) => None,
+            })
+            .collect::<Vec<_>>();
+        let intervals = interval_skeleton(&fields)
+            .and_then(|skeleton| patterns.patterns.get0(&skeleton))
+            .map(|by_difference| {
+                by_difference
+                    .iter1()
+                    .filter_map(|(difference, pattern)| {
+                        let pattern = runtime::Pattern::zero_from(pattern);
+                        Some((
+                            char::from_unaligned(*difference),
+                            SplitPattern::try_new(&fields, &pattern)?,
+                        ))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self { items, intervals }
+    }
+}
+
+impl SplitPattern {
+    /// Finds where an interval pattern repeats a field, returning `None` if it never does or
+    /// if it has fields that the pattern for a single datetime does not have.
+    fn try_new(fields: &[Field], pattern: &runtime::Pattern) -> Option<Self> {
+        let mut seen = Vec::<Field>::new();
+        let mut split = None;
+        let mut items = Vec::with_capacity(pattern.items.len());
+        for item in pattern.items.iter() {
+            let item = match item {
+                PatternItem::Field(field) => {
+                    // Use the field lengths of the pattern for a single datetime, for which
+                    // the names have been loaded.
+                    let &field = fields
+                        .iter()
+                        .find(|f| discriminant(&f.symbol) == discriminant(&field.symbol))?;
+                    if split.is_none() && seen.contains(&field) {
+                        split = Some(items.len());
+                    }
+                    seen.push(field);
+                    PatternItem::Field(field)
+                }
+                literal => literal,
+            };
+            items.push(item);
+        }
+        Some(Self {
+            items,
+            split: split?,
+        })
+    }
+}
+
+/// How the two datetimes of an interval are combined.
+#[derive(Debug)]
+enum IntervalKind<'a> {
+    /// The datetimes are equal at the precision of the pattern.
+    Single,
+    /// The datetimes are formatted with an interval pattern.
+    Split(&'a SplitPattern),
+    /// Both datetimes are formatted in full and combined with the fallback pattern.
+    Fallback,
+}
+
+impl<'a> IntervalKind<'a> {
+    fn select(
+        resolved: &'a [ResolvedPatterns],
+        start: &FormattedDateTime,
+        end: &FormattedDateTime,
+    ) -> Self {
+        if !start.pattern.iter_items().eq(end.pattern.iter_items()) {
+            return Self::Fallback;
+        }
+        let fields = start.pattern.iter_items().filter_map(|item| match item {
+            PatternItem::Field(field) => Some(field),
+            PatternItem::Literal(_) => None,
+        });
+        let Some(difference) = greatest_difference(fields, &start.input, &end.input) else {
+            return Self::Single;
+        };
+        resolved
+            .iter()
+            .find(|r| r.items.iter().copied().eq(start.pattern.iter_items()))
+            .and_then(|r| r.intervals.iter().find(|&&(d, _)| d == difference))
+            .map(|(_, pattern)| Self::Split(pattern))
+            .unwrap_or(Self::Fallback)
+    }
+}
+
+/// Returns the pattern character of the greatest field that differs between
+/// the two inputs, considering only fields down to the smallest one in the pattern.
+fn greatest_difference(
+    fields: impl Iterator<Item = Field>,
+    start: &ExtractedInput,
+    end: &ExtractedInput,
+) -> Option<char> {
+    // Whether each of the levels below is in the pattern
+    let mut in_pattern = [false; 8];
+    let mut hour_symbol = 'H';
+    for field in fields {
+        let level = match field.symbol {
+            FieldSymbol::Era => 0,
+            FieldSymbol::Year(_) => 1,
+            FieldSymbol::Month(_) => 2,
+            FieldSymbol::Day(_) | FieldSymbol::Weekday(_) => 3,
+            FieldSymbol::DayPeriod(_) => 4,
+            FieldSymbol::Hour(hour) => {
+                if matches!(hour, fields::Hour::H11 | fields::Hour::H12) {
+                    hour_symbol = 'h';
+                }
+                5
+            }
+            FieldSymbol::Minute => 6,
+            FieldSymbol::Second(_) | FieldSymbol::DecimalSecond(_) => 7,
+            _ => continue,
+        };
+        if let Some(in_pattern) = in_pattern.get_mut(level) {
+            *in_pattern = true;
+        }
+    }
+    let has_day_period = in_pattern.get(4).copied().unwrap_or_default();
+    let is_pm = |input: &ExtractedInput| input.hour.map(|h| h.number() >= 12);
+
+    // Each level is the difference key and whether it differs
+    let levels = [
+        (
+            'G',
+            start.year.and_then(|y| y.standard_era()) != end.year.and_then(|y| y.standard_era()),
+        ),
+        (
+            'y',
+            start.year.map(|y| y.extended_year) != end.year.map(|y| y.extended_year),
+        ),
+        ('M', start.month != end.month),
+        (
+            'd',
+            start.day_of_month != end.day_of_month || start.day_of_year != end.day_of_year,
+        ),
+        ('a', has_day_period && is_pm(start) != is_pm(end)),
+        (hour_symbol, start.hour != end.hour),
+        ('m', start.minute != end.minute),
+        (
+            's',
+            start.second != end.second || start.subsecond != end.subsecond,
+        ),
+    ];
+    let smallest = in_pattern.iter().rposition(|&in_pattern| in_pattern)?;
+    levels
+        .iter()
+        .take(smallest + 1)
+        .find(|&&(_, differs)| differs)
+        .map(|&(key, _)| key)
+}
+
+/// Returns the CLDR skeleton for the fields of a pattern, such as `yMMMd` for `MMM d, y`,
+/// or `None` if the pattern contains fields without interval patterns.
+fn interval_skeleton(fields: &[Field]) -> Option<String> {
+    let mut parts = Vec::with_capacity(fields.len());
+    for field in fields {
+        let part = match field.symbol {
+            FieldSymbol::Era => (0, "G"),
+            FieldSymbol::Year(fields::Year::Calendar) => (1, "y"),
+            FieldSymbol::Month(_) => (
+                2,
+                match field.length {
+                    FieldLength::Three => "MMM",
+                    FieldLength::Four => "MMMM",
+                    FieldLength::One | FieldLength::Two => "M",
+                    _ => return None,
+                },
+            ),
+            FieldSymbol::Weekday(fields::Weekday::Format) => (3, "E"),
+            FieldSymbol::Day(fields::Day::DayOfMonth) => (4, "d"),
+            // implied by the hour
+            FieldSymbol::DayPeriod(fields::DayPeriod::AmPm) => continue,
+            FieldSymbol::Hour(fields::Hour::H11 | fields::Hour::H12) => (5, "h"),
+            FieldSymbol::Hour(fields::Hour::H23 | fields::Hour::H24) => (5, "H"),
+            FieldSymbol::Minute => (6, "m"),
+            FieldSymbol::Second(fields::Second::Second) => (7, "s"),
+            FieldSymbol::TimeZone(fields::TimeZone::GenericNonLocation) => (8, "v"),
+            _ => return None,
+        };
+        parts.push(part);
+    }
+    parts.sort_unstable();
+    parts.dedup();
+    Some(parts.into_iter().map(|(_, part)| part).collect())
+}
+
+/// An intermediate type during a datetime interval formatting operation.
+///
+/// Not intended to be stored: convert to a string first.
+#[derive(Debug)]
+pub struct FormattedDateTimeInterval<'a> {
+    start: FormattedDateTime<'a>,
+    end: FormattedDateTime<'a>,
+    kind: IntervalKind<'a>,
+    fallback: &'a DoublePlaceholderPattern,
+}
+
+impl Writeable for FormattedDateTimeInterval<'_> {
+    fn write_to_parts<S: writeable::PartsWrite + ?Sized>(
+        &self,
+        sink: &mut S,
+    ) -> Result<(), fmt::Error> {
+        let (items, split) = match self.kind {
+            IntervalKind::Single => return self.start.write_to_parts(sink),
+            IntervalKind::Fallback => {
+                return self
+                    .fallback
+                    .interpolate((&self.start, &self.end))
+                    .write_to_parts(sink)
+            }
+            IntervalKind::Split(pattern) => pattern.items.split_at(pattern.split),
+        };
+        let metadata = self.start.pattern.metadata();
+        let mut result = try_write_pattern_items(
+            metadata,
+            items.iter().copied(),
+            &self.start.input,
+            &self.start.names,
+            self.start.names.decimal_formatter,
+            sink,
+        )?;
+        if result.is_ok() {
+            result = try_write_pattern_items(
+                metadata,
+                split.iter().copied(),
+                &self.end.input,
+                &self.end.names,
+                self.end.names.decimal_formatter,
+                sink,
+            )?;
+        }
+        // As in FormattedDateTime, the interval pattern only contains fields of the
+        // pattern for a single datetime, so all names have been loaded.
+        if let Err(e) = result {
+            debug_assert!(
+                false,
+                "unexpected error in FormattedDateTimeInterval: {e:?}"
+            );
+        }
+        Ok(())
+    }
+}
+
+impl_display_with_writeable!(FormattedDateTimeInterval<'_>);
+
+#[cfg(test)]
+#[cfg(feature = "compiled_data")]
+mod tests {
+    use super::*;
+    use crate::fieldsets::{MD, T, YMD, YMDE, YMDT};
+    use crate::input::DateTime;
+    use icu_calendar::Date;
+    use icu_locale_core::locale;
+    use icu_time::Time;
+    use writeable::assert_writeable_eq;
+
+    #[test]
+    fn test_date_intervals() {
+        let formatter =
+            DateTimeIntervalFormatter::try_new(locale!("en").into(), YMD::medium()).unwrap();
+        let date = |y, m, d| Date::try_new_gregorian(y, m, d).unwrap();
+
+        assert_writeable_eq!(
+            formatter.format(&date(2024, 12, 30), &date(2025, 1, 2)),
+            "Dec 30, 2024\u{2009}–\u{2009}Jan 2, 2025"
+        );
+        assert_writeable_eq!(
+            formatter.format(&date(2025, 1, 3), &date(2025, 1, 7)),
+            "Jan 3\u{2009}–\u{2009}7, 2025"
+        );
+
+        let formatter =
+            DateTimeIntervalFormatter::try_new(locale!("en").into(), YMD::long()).unwrap();
+        assert_writeable_eq!(
+            formatter.format(&date(2025, 1, 3), &date(2025, 2, 7)),
+            "January 3\u{2009}–\u{2009}February 7, 2025"
+        );
+
+        // The year is not in the pattern, so there is no interval pattern
+        let formatter =
+            DateTimeIntervalFormatter::try_new(locale!("en").into(), MD::medium()).unwrap();
+        assert_writeable_eq!(
+            formatter.format(&date(2024, 12, 31), &date(2025, 1, 1)),
+            "Dec 31\u{2009}–\u{2009}Jan 1"
+        );
+    }
+
+    #[test]
+    fn test_wide_months() {
+        let formatter =
+            DateTimeIntervalFormatter::try_new(locale!("en").into(), YMD::long()).unwrap();
+        let date = |y, m, d| Date::try_new_gregorian(y, m, d).unwrap();
+
+        // CLDR only has a pattern for abbreviated months, which datagen matches
+        assert_writeable_eq!(
+            formatter.format(&date(2025, 1, 3), &date(2025, 1, 7)),
+            "January 3\u{2009}–\u{2009}7, 2025"
+        );
+    }
+
+    #[test]
+    fn test_field_order() {
+        let date = |y, m, d| Date::try_new_gregorian(y, m, d).unwrap();
+
+        // The day comes before the month, so the month is only shown with the end
+        let formatter =
+            DateTimeIntervalFormatter::try_new(locale!("fr").into(), YMD::long()).unwrap();
+        assert_writeable_eq!(
+            formatter.format(&date(2025, 1, 3), &date(2025, 1, 7)),
+            "3–7 janvier 2025"
+        );
+        assert_writeable_eq!(
+            formatter.format(&date(2025, 1, 3), &date(2025, 2, 7)),
+            "3 janvier\u{2009}–\u{2009}7 février 2025"
+        );
+
+        let formatter =
+            DateTimeIntervalFormatter::try_new(locale!("fr").into(), YMDE::long()).unwrap();
+        assert_writeable_eq!(
+            formatter.format(&date(2025, 1, 3), &date(2025, 1, 7)),
+            "vendredi 3\u{2009}–\u{2009}mardi 7 janvier 2025"
+        );
+
+        // The weekday comes after the day, and all fields are repeated
+        let formatter =
+            DateTimeIntervalFormatter::try_new(locale!("ja").into(), YMDE::long()).unwrap();
+        assert_writeable_eq!(
+            formatter.format(&date(2025, 1, 3), &date(2025, 1, 7)),
+            "2025/1/3(金曜日)～2025/1/7(火曜日)"
+        );
+    }
+
+    #[test]
+    fn test_fallback() {
+        let formatter =
+            DateTimeIntervalFormatter::try_new(locale!("en").into(), YMDT::medium()).unwrap();
+        let datetime = |d, h| DateTime {
+            date: Date::try_new_gregorian(2025, 1, d).unwrap(),
+            time: Time::try_new(h, 0, 0, 0).unwrap(),
+        };
+
+        // There are no interval patterns for dates with times
+        assert_writeable_eq!(
+            formatter.format(&datetime(3, 10), &datetime(7, 11)),
+            "Jan 3, 2025, 10:00:00\u{202f}AM\u{2009}–\u{2009}Jan 7, 2025, 11:00:00\u{202f}AM"
+        );
+        assert_writeable_eq!(
+            formatter.format(&datetime(3, 10), &datetime(3, 11)),
+            "Jan 3, 2025, 10:00:00\u{202f}AM\u{2009}–\u{2009}Jan 3, 2025, 11:00:00\u{202f}AM"
+        );
+        assert_writeable_eq!(
+            formatter.format(&datetime(3, 10), &datetime(3, 10)),
+            "Jan 3, 2025, 10:00:00\u{202f}AM"
+        );
+    }
+
+    #[test]
+    fn test_time_intervals() {
+        let time = |h, m| Time::try_new(h, m, 0, 0).unwrap();
+
+        let formatter =
+            DateTimeIntervalFormatter::try_new(locale!("en").into(), T::short().hm()).unwrap();
+        assert_writeable_eq!(
+            formatter.format(&time(10, 0), &time(14, 30)),
+            "10:00\u{202f}AM\u{2009}–\u{2009}2:30\u{202f}PM"
+        );
+        assert_writeable_eq!(
+            formatter.format(&time(10, 0), &time(10, 45)),
+            "10:00\u{2009}–\u{2009}10:45\u{202f}AM"
+        );
+        // Seconds are not displayed
+        assert_writeable_eq!(
+            formatter.format(&time(10, 0), &Time::try_new(10, 0, 30, 0).unwrap()),
+            "10:00\u{202f}AM"
+        );
+
+        let formatter =
+            DateTimeIntervalFormatter::try_new(locale!("en-u-hc-h23").into(), T::short().hm())
+                .unwrap();
+        assert_writeable_eq!(
+            formatter.format(&time(10, 0), &time(14, 30)),
+            "10:00\u{2009}–\u{2009}14:30"
+        );
+    }
+}
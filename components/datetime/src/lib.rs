@@ -95,6 +95,7 @@ mod external_loaders;
 pub mod fieldsets;
 mod format;
 pub mod input;
+mod interval;
 mod neo;
 pub mod options;
 pub mod parts;
@@ -106,6 +107,8 @@ pub(crate) mod size_test_macro;
 
 pub use error::{DateTimeFormatterLoadError, DateTimeWriteError, MismatchedCalendarError};
 
+pub use interval::DateTimeIntervalFormatter;
+pub use interval::FormattedDateTimeInterval;
 pub use neo::DateTimeFormatter;
 pub use neo::DateTimeFormatterPreferences;
 pub use neo::FixedCalendarDateTimeFormatter;
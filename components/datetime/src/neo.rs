@@ -128,7 +128,7 @@ macro_rules! gen_buffer_constructors_with_external_loader {
     };
 }
 
-size_test!(FixedCalendarDateTimeFormatter<icu_calendar::Gregorian, crate::fieldsets::YMD>, typed_neo_year_month_day_formatter_size, 328);
+size_test!(FixedCalendarDateTimeFormatter<icu_calendar::Gregorian, crate::fieldsets::YMD>, typed_neo_year_month_day_formatter_size, 336);
 
 /// [`FixedCalendarDateTimeFormatter`] is a formatter capable of formatting dates and/or times from
 /// a calendar selected at compile time.
@@ -366,7 +366,7 @@ where
 size_test!(
     DateTimeFormatter<crate::fieldsets::YMD>,
     neo_year_month_day_formatter_size,
-    384
+    392
 );
 
 /// [`DateTimeFormatter`] is a formatter capable of formatting dates and/or times from
@@ -376,7 +376,7 @@ size_test!(
 #[doc = neo_year_month_day_formatter_size!()]
 #[derive(Debug)]
 pub struct DateTimeFormatter<FSet: DateTimeNamesMarker> {
-    selection: DateTimeZonePatternSelectionData,
+    pub(crate) selection: DateTimeZonePatternSelectionData,
     pub(crate) names: RawDateTimeNames<FSet>,
     pub(crate) calendar: AnyCalendar,
 }
@@ -478,7 +478,7 @@ where
     FSet::T: TimeMarkers,
     FSet::Z: ZoneMarkers,
 {
-    fn try_new_internal<P, L>(
+    pub(crate) fn try_new_internal<P, L>(
         provider: &P,
         loader: &L,
         prefs: DateTimeFormatterPreferences,
@@ -961,9 +961,9 @@ pub type TimeFormatter<FSet> = FixedCalendarDateTimeFormatter<(), FSet>;
 /// Not intended to be stored: convert to a string first.
 #[derive(Debug)]
 pub struct FormattedDateTime<'a> {
-    pattern: DateTimeZonePatternDataBorrowed<'a>,
-    input: ExtractedInput,
-    names: RawDateTimeNamesBorrowed<'a>,
+    pub(crate) pattern: DateTimeZonePatternDataBorrowed<'a>,
+    pub(crate) input: ExtractedInput,
+    pub(crate) names: RawDateTimeNamesBorrowed<'a>,
 }
 
 impl Writeable for FormattedDateTime<'_> {
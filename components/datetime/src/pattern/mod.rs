@@ -12,6 +12,7 @@
 
 mod formatter;
 mod names;
+mod parser;
 #[allow(clippy::module_inception)] // the file pattern.rs should contain DateTimePattern
 mod pattern;
 
@@ -28,6 +29,8 @@ pub(crate) use names::RawDateTimeNamesBorrowed;
 pub(crate) use names::TimeZoneDataPayloadsBorrowed;
 pub use names::WeekdayNameLength;
 pub use names::YearNameLength;
+pub use parser::DateTimeParseError;
+pub use parser::DateTimePatternParser;
 pub use pattern::DateTimePattern;
 
 pub(crate) enum GetNameForMonthError {
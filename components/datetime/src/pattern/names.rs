@@ -3,9 +3,9 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use super::{
-    DateTimePattern, DateTimePatternFormatter, GetNameForCyclicYearError, GetNameForDayPeriodError,
-    GetNameForEraError, GetNameForMonthError, GetNameForWeekdayError, MonthPlaceholderValue,
-    PatternLoadError,
+    DateTimePattern, DateTimePatternFormatter, DateTimePatternParser, GetNameForCyclicYearError,
+    GetNameForDayPeriodError, GetNameForEraError, GetNameForMonthError, GetNameForWeekdayError,
+    MonthPlaceholderValue, PatternLoadError,
 };
 use crate::error::ErrorField;
 use crate::fieldsets::enums::{CompositeDateTimeFieldSet, CompositeFieldSet};
@@ -367,7 +367,7 @@ where
 size_test!(
     FixedCalendarDateTimeNames<icu_calendar::Gregorian>,
     typed_date_time_names_size,
-    312
+    320
 );
 
 /// A low-level type that formats datetime patterns with localized names.
@@ -1969,6 +1969,18 @@ impl<C: CldrCalendar, FSet: DateTimeNamesMarker> FixedCalendarDateTimeNames<C, F
         DateTimePatternFormatter::new(pattern.as_borrowed(), self.inner.as_borrowed())
     }
 
+    /// Associates this [`FixedCalendarDateTimeNames`] with a pattern for parsing.
+    ///
+    /// Names that are needed for parsing but not loaded result in an error
+    /// when parsing. For an example, see [`DateTimePatternParser`].
+    #[inline]
+    pub fn parser_with_pattern<'l>(
+        &'l self,
+        pattern: &'l DateTimePattern,
+    ) -> DateTimePatternParser<'l, C, FSet> {
+        DateTimePatternParser::new(pattern.as_borrowed(), self.inner.as_borrowed())
+    }
+
     /// Associates this [`FixedCalendarDateTimeNames`] with a datetime pattern
     /// and loads all data required for that pattern.
     ///
@@ -2894,12 +2906,7 @@ impl RawDateTimeNamesBorrowed<'_> {
         field_length: FieldLength,
         code: MonthCode,
     ) -> Result<MonthPlaceholderValue, GetNameForMonthError> {
-        let month_name_length = MonthNameLength::from_field(field_symbol, field_length)
-            .ok_or(GetNameForMonthError::InvalidFieldLength)?;
-        let month_names = self
-            .month_names
-            .get_with_variables(month_name_length)
-            .ok_or(GetNameForMonthError::NotLoaded)?;
+        let month_names = self.get_month_names(field_symbol, field_length)?;
         let Some((month_number, is_leap)) = code.parsed() else {
             return Err(GetNameForMonthError::InvalidMonthCode);
         };
@@ -2945,13 +2952,7 @@ impl RawDateTimeNamesBorrowed<'_> {
         field_length: FieldLength,
         day: input::IsoWeekday,
     ) -> Result<&str, GetNameForWeekdayError> {
-        let weekday_name_length = WeekdayNameLength::from_field(field_symbol, field_length)
-            .ok_or(GetNameForWeekdayError::InvalidFieldLength)?;
-        let weekday_names = self
-            .weekday_names
-            .get_with_variables(weekday_name_length)
-            .ok_or(GetNameForWeekdayError::NotLoaded)?;
-        weekday_names
+        self.get_weekday_names(field_symbol, field_length)?
             .names
             .get((day as usize) % 7)
             // TODO: make weekday_names length 7 in the type system
@@ -2967,14 +2968,7 @@ impl RawDateTimeNamesBorrowed<'_> {
         field_length: FieldLength,
         era: FormattingEra,
     ) -> Result<&str, GetNameForEraError> {
-        let year_name_length = YearNameLength::from_field_length(field_length)
-            .ok_or(GetNameForEraError::InvalidFieldLength)?;
-        let year_names = self
-            .year_names
-            .get_with_variables(year_name_length)
-            .ok_or(GetNameForEraError::NotLoaded)?;
-
-        match (year_names, era) {
+        match (self.get_year_names(field_length)?, era) {
             (YearNames::VariableEras(era_names), FormattingEra::Code(era_code)) => {
                 crate::provider::neo::get_year_name_from_map(era_names, era_code.0.as_str().into())
                     .ok_or(GetNameForEraError::InvalidEraCode)
@@ -3017,12 +3011,7 @@ impl RawDateTimeNamesBorrowed<'_> {
         is_top_of_hour: bool,
     ) -> Result<&str, GetNameForDayPeriodError> {
         use fields::DayPeriod::NoonMidnight;
-        let day_period_name_length = DayPeriodNameLength::from_field(field_symbol, field_length)
-            .ok_or(GetNameForDayPeriodError::InvalidFieldLength)?;
-        let dayperiod_names = self
-            .dayperiod_names
-            .get_with_variables(day_period_name_length)
-            .ok_or(GetNameForDayPeriodError::NotLoaded)?;
+        let dayperiod_names = self.get_day_period_names(field_symbol, field_length)?;
         let option_value: Option<&str> = match (field_symbol, u8::from(hour), is_top_of_hour) {
             (NoonMidnight, 00, true) => dayperiod_names.midnight().or_else(|| dayperiod_names.am()),
             (NoonMidnight, 12, true) => dayperiod_names.noon().or_else(|| dayperiod_names.pm()),
@@ -3059,6 +3048,53 @@ pub(crate) struct TimeZoneDataPayloadsBorrowed<'a> {
 }
 
 impl<'data> RawDateTimeNamesBorrowed<'data> {
+    pub(crate) fn get_year_names(
+        &self,
+        field_length: FieldLength,
+    ) -> Result<&'data YearNames<'data>, GetNameForEraError> {
+        let year_name_length = YearNameLength::from_field_length(field_length)
+            .ok_or(GetNameForEraError::InvalidFieldLength)?;
+        self.year_names
+            .get_with_variables(year_name_length)
+            .ok_or(GetNameForEraError::NotLoaded)
+    }
+
+    pub(crate) fn get_month_names(
+        &self,
+        field_symbol: fields::Month,
+        field_length: FieldLength,
+    ) -> Result<&'data MonthNames<'data>, GetNameForMonthError> {
+        let month_name_length = MonthNameLength::from_field(field_symbol, field_length)
+            .ok_or(GetNameForMonthError::InvalidFieldLength)?;
+        self.month_names
+            .get_with_variables(month_name_length)
+            .ok_or(GetNameForMonthError::NotLoaded)
+    }
+
+    pub(crate) fn get_weekday_names(
+        &self,
+        field_symbol: fields::Weekday,
+        field_length: FieldLength,
+    ) -> Result<&'data LinearNames<'data>, GetNameForWeekdayError> {
+        let weekday_name_length = WeekdayNameLength::from_field(field_symbol, field_length)
+            .ok_or(GetNameForWeekdayError::InvalidFieldLength)?;
+        self.weekday_names
+            .get_with_variables(weekday_name_length)
+            .ok_or(GetNameForWeekdayError::NotLoaded)
+    }
+
+    pub(crate) fn get_day_period_names(
+        &self,
+        field_symbol: fields::DayPeriod,
+        field_length: FieldLength,
+    ) -> Result<&'data LinearNames<'data>, GetNameForDayPeriodError> {
+        let day_period_name_length = DayPeriodNameLength::from_field(field_symbol, field_length)
+            .ok_or(GetNameForDayPeriodError::InvalidFieldLength)?;
+        self.dayperiod_names
+            .get_with_variables(day_period_name_length)
+            .ok_or(GetNameForDayPeriodError::NotLoaded)
+    }
+
     pub(crate) fn get_payloads(&self) -> TimeZoneDataPayloadsBorrowed<'data> {
         TimeZoneDataPayloadsBorrowed {
             essentials: self.zone_essentials.get_option(),
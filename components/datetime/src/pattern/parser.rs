@@ -0,0 +1,1205 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::names::RawDateTimeNamesBorrowed;
+use super::pattern::DateTimePatternBorrowed;
+use super::{
+    GetNameForDayPeriodError, GetNameForEraError, GetNameForMonthError, GetNameForWeekdayError,
+};
+use crate::error::ErrorField;
+use crate::provider::fields::{self, Field, FieldLength, FieldSymbol};
+use crate::provider::neo::{MonthNames, YearNames};
+use crate::provider::pattern::PatternItem;
+use crate::scaffold::CldrCalendar;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use fixed_decimal::SignedFixedDecimal;
+use icu_calendar::types::{Era, FormattingEra, IsoWeekday, MonthCode};
+use icu_calendar::{AsCalendar, Calendar, Date, DateError};
+use icu_decimal::DecimalFormatter;
+use icu_time::{DateTime, Time};
+use tinystr::TinyAsciiStr;
+use writeable::Writeable;
+
+/// An error from parsing a datetime with a [`DateTimePatternParser`].
+///
+/// All offsets are byte offsets into the input string.
+#[derive(Debug, Copy, Clone, PartialEq, displaydoc::Display)]
+#[non_exhaustive]
+pub enum DateTimeParseError {
+    /// The input does not match a literal of the pattern.
+    #[displaydoc("The input does not match the pattern at offset {0}")]
+    InvalidLiteral(usize),
+    /// No value for the field could be read from the input.
+    #[displaydoc("No value for the field {0:?} could be read at offset {1}")]
+    InvalidField(ErrorField, usize),
+    /// The value read for the field is out of range.
+    ///
+    /// This includes dates that do not exist in the calendar, such as February 30,
+    /// in which case the day field is reported.
+    #[displaydoc("The value of the field {0:?} at offset {1} is out of range")]
+    OutOfRange(ErrorField, usize),
+    /// The value read for the field contradicts the value of another field,
+    /// for example a weekday that does not match the date.
+    #[displaydoc("The value of the field {0:?} at offset {1} contradicts another field")]
+    Inconsistent(ErrorField, usize),
+    /// The names needed to parse the field have not been loaded.
+    #[displaydoc("Names for the field {0:?} at offset {1} are not loaded")]
+    NamesNotLoaded(ErrorField, usize),
+    /// The field is not supported by the parser.
+    #[displaydoc("The field {0:?} at offset {1} is not supported")]
+    UnsupportedField(ErrorField, usize),
+    /// The input continues after the end of the pattern.
+    #[displaydoc("Unexpected input after the end of the pattern at offset {0}")]
+    TrailingInput(usize),
+    /// The pattern does not contain a field that is required for the result,
+    /// such as the year when parsing a date.
+    #[displaydoc("The pattern does not contain the required field {0:?}")]
+    MissingField(ErrorField),
+}
+
+impl core::error::Error for DateTimeParseError {}
+
+impl DateTimeParseError {
+    /// Returns the byte offset into the input at which the error occurred,
+    /// or `None` if the error is not tied to a position in the input.
+    pub fn offset(&self) -> Option<usize> {
+        match *self {
+            Self::InvalidLiteral(offset)
+            | Self::InvalidField(_, offset)
+            | Self::OutOfRange(_, offset)
+            | Self::Inconsistent(_, offset)
+            | Self::NamesNotLoaded(_, offset)
+            | Self::UnsupportedField(_, offset)
+            | Self::TrailingInput(offset) => Some(offset),
+            Self::MissingField(_) => None,
+        }
+    }
+}
+
+/// A parser for datetimes written in a specific [`DateTimePattern`].
+///
+/// ❗ This type forgoes most internationalization functionality of the datetime crate.
+/// It assumes that the pattern is already localized for the customer's locale.
+///
+/// The parser reads the fields of the pattern using the names loaded into the
+/// [`FixedCalendarDateTimeNames`] it was created from. Matching is lenient:
+///
+/// - Names are matched case-insensitively, and unambiguous prefixes of names
+///   (such as "Sept" or "Sept." for "September") are accepted.
+/// - Month name fields also accept month numbers.
+/// - Numbers may use ASCII digits or the digits of the locale's numbering system,
+///   if the decimal formatter is loaded.
+/// - Whitespace in the pattern matches any amount of whitespace, including none.
+///
+/// Two-digit years in `yy` fields are interpreted in the range 1950–2049.
+///
+/// The following fields are not supported: cyclic and related ISO years, day of year,
+/// day of week in month, numeric weekdays, flexible day periods, milliseconds in day,
+/// and time zones.
+///
+/// Create one of these via [`FixedCalendarDateTimeNames::parser_with_pattern`].
+///
+/// There is no constructor that takes a field set. To parse input written in the locale's
+/// pattern for a field set, take the pattern from a value formatted by a
+/// [`FixedCalendarDateTimeFormatter`], and the names from the formatter via
+/// [`FixedCalendarDateTimeNames::from_formatter`]. Since the locale's pattern can depend on
+/// the value, for example on whether the era is displayed, the value should be
+/// representative of the input.
+///
+/// # Examples
+///
+/// ```
+/// use icu::calendar::{Date, Gregorian};
+/// use icu::datetime::pattern::DateTimePattern;
+/// use icu::datetime::pattern::FixedCalendarDateTimeNames;
+/// use icu::locale::locale;
+///
+/// let mut names =
+///     FixedCalendarDateTimeNames::<Gregorian>::try_new(locale!("de").into()).unwrap();
+/// let pattern: DateTimePattern = "d. MMMM y".parse().unwrap();
+/// names.include_for_pattern(&pattern).unwrap();
+///
+/// let parser = names.parser_with_pattern(&pattern);
+/// let expected = Date::try_new_gregorian(2025, 3, 4).unwrap();
+///
+/// assert_eq!(parser.parse_date("4. März 2025", Gregorian), Ok(expected));
+/// assert_eq!(parser.parse_date("4. MÄRZ 2025", Gregorian), Ok(expected));
+/// assert_eq!(parser.parse_date("4. Mär. 2025", Gregorian), Ok(expected));
+///
+/// // Errors report the byte offset of the failure:
+/// assert_eq!(
+///     parser
+///         .parse_date("4. Marz 2025", Gregorian)
+///         .unwrap_err()
+///         .offset(),
+///     Some(3)
+/// );
+/// ```
+///
+/// Parse a time with day periods:
+///
+/// ```
+/// use icu::calendar::Gregorian;
+/// use icu::datetime::input::Time;
+/// use icu::datetime::pattern::DateTimePattern;
+/// use icu::datetime::pattern::FixedCalendarDateTimeNames;
+/// use icu::locale::locale;
+///
+/// let mut names =
+///     FixedCalendarDateTimeNames::<Gregorian>::try_new(locale!("ja").into()).unwrap();
+/// let pattern: DateTimePattern = "aK時".parse().unwrap();
+/// names.include_for_pattern(&pattern).unwrap();
+///
+/// assert_eq!(
+///     names.parser_with_pattern(&pattern).parse_time("午後3時"),
+///     Ok(Time::try_new(15, 0, 0, 0).unwrap())
+/// );
+/// ```
+///
+/// Parse input written in the locale's pattern for a field set:
+///
+/// ```
+/// use icu::calendar::{Date, Gregorian};
+/// use icu::datetime::fieldsets::YMD;
+/// use icu::datetime::pattern::FixedCalendarDateTimeNames;
+/// use icu::datetime::FixedCalendarDateTimeFormatter;
+/// use icu::locale::locale;
+///
+/// let formatter = FixedCalendarDateTimeFormatter::<Gregorian, _>::try_new(
+///     locale!("en").into(),
+///     YMD::medium(),
+/// )
+/// .unwrap();
+/// let pattern = formatter
+///     .format(&Date::try_new_gregorian(2025, 1, 1).unwrap())
+///     .pattern();
+/// let names = FixedCalendarDateTimeNames::from_formatter(locale!("en").into(), formatter);
+///
+/// assert_eq!(
+///     names
+///         .parser_with_pattern(&pattern)
+///         .parse_date("Mar 4, 2025", Gregorian),
+///     Ok(Date::try_new_gregorian(2025, 3, 4).unwrap())
+/// );
+/// ```
+///
+/// [`DateTimePattern`]: super::DateTimePattern
+/// [`FixedCalendarDateTimeFormatter`]: crate::FixedCalendarDateTimeFormatter
+/// [`FixedCalendarDateTimeNames`]: super::FixedCalendarDateTimeNames
+/// [`FixedCalendarDateTimeNames::from_formatter`]: super::FixedCalendarDateTimeNames::from_formatter
+/// [`FixedCalendarDateTimeNames::parser_with_pattern`]: super::FixedCalendarDateTimeNames::parser_with_pattern
+#[derive(Debug, Copy, Clone)]
+pub struct DateTimePatternParser<'a, C: CldrCalendar, FSet> {
+    pattern: DateTimePatternBorrowed<'a>,
+    names: RawDateTimeNamesBorrowed<'a>,
+    _calendar: PhantomData<C>,
+    _marker: PhantomData<FSet>,
+}
+
+impl<'a, C: CldrCalendar, FSet> DateTimePatternParser<'a, C, FSet> {
+    pub(crate) fn new(
+        pattern: DateTimePatternBorrowed<'a>,
+        names: RawDateTimeNamesBorrowed<'a>,
+    ) -> Self {
+        Self {
+            pattern,
+            names,
+            _calendar: PhantomData,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Parses a date from a string written in the pattern.
+    ///
+    /// The pattern must contain a year, a month, and a day field. If it contains a weekday
+    /// field, the weekday must match the date.
+    ///
+    /// Era fields are supported for all eras that have names, including eras with an offset
+    /// from the calendar's extended year, such as the Ethiopian Amete Alem era.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::{Date, Gregorian};
+    /// use icu::datetime::pattern::DateTimePattern;
+    /// use icu::datetime::pattern::DateTimeParseError;
+    /// use icu::datetime::pattern::FixedCalendarDateTimeNames;
+    /// use icu::locale::locale;
+    ///
+    /// let mut names =
+    ///     FixedCalendarDateTimeNames::<Gregorian>::try_new(locale!("en").into()).unwrap();
+    /// let pattern: DateTimePattern = "MMM d, y G".parse().unwrap();
+    /// names.include_for_pattern(&pattern).unwrap();
+    /// let parser = names.parser_with_pattern(&pattern);
+    ///
+    /// assert_eq!(
+    ///     parser.parse_date("Mar 4, 2025 AD", Gregorian),
+    ///     Ok(Date::try_new_gregorian(2025, 3, 4).unwrap())
+    /// );
+    /// // Extended year -43 is year 44 BCE
+    /// assert_eq!(
+    ///     parser.parse_date("mar 15, 44 bc", Gregorian),
+    ///     Ok(Date::try_new_gregorian(-43, 3, 15).unwrap())
+    /// );
+    ///
+    /// // A weekday in the input must match the date:
+    /// let pattern: DateTimePattern = "EEE, MMM d, y".parse().unwrap();
+    /// names.include_for_pattern(&pattern).unwrap();
+    /// let parser = names.parser_with_pattern(&pattern);
+    ///
+    /// assert!(parser.parse_date("Tue, Mar 4, 2025", Gregorian).is_ok());
+    /// assert!(matches!(
+    ///     parser.parse_date("Wed, Mar 4, 2025", Gregorian),
+    ///     Err(DateTimeParseError::Inconsistent(_, 0))
+    /// ));
+    /// ```
+    pub fn parse_date<A>(&self, input: &str, calendar: A) -> Result<Date<A>, DateTimeParseError>
+    where
+        A: AsCalendar<Calendar = C>,
+        C: Calendar,
+    {
+        let fields = self.parse_fields(input)?;
+        fields.to_date(calendar)
+    }
+
+    /// Parses a time of day from a string written in the pattern.
+    ///
+    /// The pattern must contain an hour field, and a day period field if the hour field
+    /// uses a 12-hour cycle. Minutes, seconds, and fractional seconds default to zero if the
+    /// pattern does not contain them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::Gregorian;
+    /// use icu::datetime::input::Time;
+    /// use icu::datetime::pattern::DateTimePattern;
+    /// use icu::datetime::pattern::FixedCalendarDateTimeNames;
+    /// use icu::locale::locale;
+    ///
+    /// let mut names =
+    ///     FixedCalendarDateTimeNames::<Gregorian>::try_new(locale!("en").into()).unwrap();
+    /// let pattern: DateTimePattern = "h:mm:ss.SSS a".parse().unwrap();
+    /// names.include_for_pattern(&pattern).unwrap();
+    /// let parser = names.parser_with_pattern(&pattern);
+    ///
+    /// assert_eq!(
+    ///     parser.parse_time("5:43:12.345 PM"),
+    ///     Ok(Time::try_new(17, 43, 12, 345_000_000).unwrap())
+    /// );
+    /// assert_eq!(
+    ///     parser.parse_time("12:00:00.000\u{202f}am"),
+    ///     Ok(Time::try_new(0, 0, 0, 0).unwrap())
+    /// );
+    /// ```
+    pub fn parse_time(&self, input: &str) -> Result<Time, DateTimeParseError> {
+        let fields = self.parse_fields(input)?;
+        fields.to_time()
+    }
+
+    /// Parses a date and a time of day from a string written in the pattern.
+    ///
+    /// The requirements of [`Self::parse_date`] and [`Self::parse_time`] both apply.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::{Date, Gregorian};
+    /// use icu::datetime::input::{DateTime, Time};
+    /// use icu::datetime::pattern::DateTimePattern;
+    /// use icu::datetime::pattern::FixedCalendarDateTimeNames;
+    /// use icu::locale::locale;
+    ///
+    /// let mut names =
+    ///     FixedCalendarDateTimeNames::<Gregorian>::try_new(locale!("en").into()).unwrap();
+    /// let pattern: DateTimePattern = "M/d/yy, HH:mm".parse().unwrap();
+    /// names.include_for_pattern(&pattern).unwrap();
+    ///
+    /// let datetime = names
+    ///     .parser_with_pattern(&pattern)
+    ///     .parse_date_time("3/4/25, 17:43", Gregorian)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(datetime.date, Date::try_new_gregorian(2025, 3, 4).unwrap());
+    /// assert_eq!(datetime.time, Time::try_new(17, 43, 0, 0).unwrap());
+    /// ```
+    pub fn parse_date_time<A>(
+        &self,
+        input: &str,
+        calendar: A,
+    ) -> Result<DateTime<A>, DateTimeParseError>
+    where
+        A: AsCalendar<Calendar = C>,
+        C: Calendar,
+    {
+        let fields = self.parse_fields(input)?;
+        Ok(DateTime {
+            date: fields.to_date(calendar)?,
+            time: fields.to_time()?,
+        })
+    }
+
+    fn parse_fields(&self, input: &str) -> Result<ParsedFields, DateTimeParseError> {
+        let numbers = NumberSymbols::new(self.names.decimal_formatter);
+        let mut fields = ParsedFields::default();
+        let mut offset = skip_whitespace(input, 0);
+        let mut items = self.pattern.0.as_borrowed().items.iter().peekable();
+        while let Some(item) = items.next() {
+            match item {
+                PatternItem::Literal(ch) if ch.is_whitespace() => {
+                    offset = skip_whitespace(input, offset);
+                }
+                PatternItem::Literal(ch) => match input[offset..].chars().next() {
+                    Some(c) if chars_match(c, ch) => offset += c.len_utf8(),
+                    _ => return Err(DateTimeParseError::InvalidLiteral(offset)),
+                },
+                PatternItem::Field(field) => {
+                    // Numeric fields that are directly followed by another numeric field,
+                    // as in "yyyyMMdd", are read with exactly their width.
+                    let max_digits = match items.peek() {
+                        Some(PatternItem::Field(next)) if is_numeric(*next) => {
+                            match (field.symbol, field.length) {
+                                (FieldSymbol::Year(_), FieldLength::One) => 4,
+                                (_, l) => l.to_len(),
+                            }
+                        }
+                        _ => MAX_DIGITS,
+                    };
+                    offset =
+                        self.parse_field(input, offset, field, max_digits, &numbers, &mut fields)?;
+                }
+            }
+        }
+        let end = skip_whitespace(input, offset);
+        if end < input.len() {
+            return Err(DateTimeParseError::TrailingInput(end));
+        }
+        Ok(fields)
+    }
+
+    /// Parses a single field at `offset` and returns the offset after it.
+    fn parse_field(
+        &self,
+        input: &str,
+        offset: usize,
+        field: Field,
+        max_digits: usize,
+        numbers: &NumberSymbols,
+        fields: &mut ParsedFields,
+    ) -> Result<usize, DateTimeParseError> {
+        let rest = &input[offset..];
+        let error_field = ErrorField(field);
+        let invalid = DateTimeParseError::InvalidField(error_field, offset);
+        let out_of_range = DateTimeParseError::OutOfRange(error_field, offset);
+        let not_loaded = DateTimeParseError::NamesNotLoaded(error_field, offset);
+        let unsupported = DateTimeParseError::UnsupportedField(error_field, offset);
+
+        // Reads a number within the given range
+        let number = |min: u32, max: u32| {
+            let (value, len, _) = numbers.read(rest, max_digits).ok_or(invalid)?;
+            if !(min..=max).contains(&value) {
+                return Err(out_of_range);
+            }
+            Ok((value, len))
+        };
+
+        let len = match (field.symbol, field.length) {
+            (FieldSymbol::Era, l) => {
+                let year_names = self.names.get_year_names(l).map_err(|e| match e {
+                    GetNameForEraError::NotLoaded => not_loaded,
+                    _ => unsupported,
+                })?;
+                let (era, len) = match year_names {
+                    YearNames::FixedEras(names) => {
+                        match_name(rest, &names.iter().collect::<Vec<_>>())
+                            .map(|(index, len)| (ParsedEra::Index(index as u8), len))
+                    }
+                    YearNames::VariableEras(names) => {
+                        match_name(rest, &names.b().iter().collect::<Vec<_>>()).and_then(
+                            |(index, len)| {
+                                let code = names.a().get(index)?;
+                                let code = TinyAsciiStr::try_from_utf8(code.as_bytes()).ok()?;
+                                Some((ParsedEra::Code(Era(code)), len))
+                            },
+                        )
+                    }
+                    YearNames::Cyclic(_) => return Err(unsupported),
+                }
+                .ok_or(invalid)?;
+                fields.era.set(Parsed::new(era, field, offset))?;
+                len
+            }
+            (FieldSymbol::Year(fields::Year::Calendar), l) => {
+                let sign_len = match rest.chars().next() {
+                    Some(c @ ('-' | '\u{2212}')) if l != FieldLength::Two => c.len_utf8(),
+                    _ => 0,
+                };
+                let (value, len, digits) =
+                    numbers.read(&rest[sign_len..], max_digits).ok_or(invalid)?;
+                let mut year = i32::try_from(value).map_err(|_| out_of_range)?;
+                if l == FieldLength::Two && digits == 2 {
+                    year += if year < 50 { 2000 } else { 1900 };
+                }
+                if sign_len > 0 {
+                    year = -year;
+                }
+                fields.year.set(Parsed::new(year, field, offset))?;
+                sign_len + len
+            }
+            (FieldSymbol::Month(_), FieldLength::One | FieldLength::Two) => {
+                let (value, len) = number(1, 99)?;
+                fields
+                    .month
+                    .set(Parsed::new(month_code(value as u8, false), field, offset))?;
+                len
+            }
+            (FieldSymbol::Month(symbol), l) => {
+                let month_names = self.names.get_month_names(symbol, l).map_err(|e| match e {
+                    GetNameForMonthError::NotLoaded => not_loaded,
+                    _ => unsupported,
+                })?;
+                let by_name = match month_names {
+                    MonthNames::Linear(names) => {
+                        match_name(rest, &names.iter().collect::<Vec<_>>())
+                            .map(|(index, len)| (month_code(index as u8 + 1, false), len))
+                    }
+                    MonthNames::LeapLinear(names) => {
+                        let num_months = names.len() / 2;
+                        match_name(rest, &names.iter().collect::<Vec<_>>()).map(|(index, len)| {
+                            if index < num_months {
+                                (month_code(index as u8 + 1, false), len)
+                            } else {
+                                (month_code((index - num_months) as u8 + 1, true), len)
+                            }
+                        })
+                    }
+                    MonthNames::LeapNumeric(_) => None,
+                };
+                let (code, len) = match by_name {
+                    Some(result) => result,
+                    None => {
+                        number(1, 99).map(|(value, len)| (month_code(value as u8, false), len))?
+                    }
+                };
+                fields.month.set(Parsed::new(code, field, offset))?;
+                len
+            }
+            (FieldSymbol::Day(fields::Day::DayOfMonth), _) => {
+                let (value, len) = number(1, 31)?;
+                fields.day.set(Parsed::new(value as u8, field, offset))?;
+                len
+            }
+            (FieldSymbol::Weekday(symbol), l) => {
+                let weekday_names =
+                    self.names
+                        .get_weekday_names(symbol, l)
+                        .map_err(|e| match e {
+                            GetNameForWeekdayError::NotLoaded => not_loaded,
+                            GetNameForWeekdayError::InvalidFieldLength => unsupported,
+                        })?;
+                let (index, len) =
+                    match_name(rest, &weekday_names.names.iter().collect::<Vec<_>>())
+                        .ok_or(invalid)?;
+                fields
+                    .weekday
+                    .set(Parsed::new(IsoWeekday::from(index), field, offset))?;
+                len
+            }
+            (FieldSymbol::DayPeriod(symbol), l) => {
+                let day_period_names =
+                    self.names
+                        .get_day_period_names(symbol, l)
+                        .map_err(|e| match e {
+                            GetNameForDayPeriodError::NotLoaded => not_loaded,
+                            GetNameForDayPeriodError::InvalidFieldLength => unsupported,
+                        })?;
+                let mut candidates = [day_period_names.am(), day_period_names.pm(), None, None];
+                if symbol == fields::DayPeriod::NoonMidnight {
+                    candidates[2] = day_period_names.noon();
+                    candidates[3] = day_period_names.midnight();
+                }
+                let (index, len) =
+                    match_name(rest, &candidates.map(Option::unwrap_or_default)).ok_or(invalid)?;
+                let day_period = match index {
+                    0 => DayPeriod::Am,
+                    1 => DayPeriod::Pm,
+                    2 => DayPeriod::Noon,
+                    _ => DayPeriod::Midnight,
+                };
+                fields
+                    .day_period
+                    .set(Parsed::new(day_period, field, offset))?;
+                len
+            }
+            (FieldSymbol::Hour(symbol), _) => {
+                let (value, len) = match symbol {
+                    fields::Hour::H11 => number(0, 11)?,
+                    fields::Hour::H12 => number(1, 12)?,
+                    fields::Hour::H23 => number(0, 23)?,
+                    fields::Hour::H24 => number(1, 24)?,
+                };
+                fields.hour.set(Parsed::new(value as u8, field, offset))?;
+                len
+            }
+            (FieldSymbol::Minute, _) => {
+                let (value, len) = number(0, 59)?;
+                fields.minute.set(Parsed::new(value as u8, field, offset))?;
+                len
+            }
+            (FieldSymbol::Second(fields::Second::Second), _) => {
+                let (value, len) = number(0, 59)?;
+                fields.second.set(Parsed::new(value as u8, field, offset))?;
+                len
+            }
+            (FieldSymbol::DecimalSecond(_), _) => {
+                let (value, mut len) = number(0, 59)?;
+                fields.second.set(Parsed::new(value as u8, field, offset))?;
+                if let Some(separator_len) = numbers.decimal_separator_len(&rest[len..]) {
+                    let fraction_start = len + separator_len;
+                    if let Some((nanosecond, fraction_len)) =
+                        numbers.read_fraction(&rest[fraction_start..])
+                    {
+                        fields
+                            .nanosecond
+                            .set(Parsed::new(nanosecond, field, offset))?;
+                        len = fraction_start + fraction_len;
+                    }
+                }
+                len
+            }
+            _ => return Err(unsupported),
+        };
+        Ok(offset + len)
+    }
+}
+
+/// The maximum number of digits read for a numeric field.
+const MAX_DIGITS: usize = 9;
+
+fn is_numeric(field: Field) -> bool {
+    matches!(
+        (field.symbol, field.length),
+        (FieldSymbol::Year(fields::Year::Calendar), _)
+            | (FieldSymbol::Month(_), FieldLength::One | FieldLength::Two)
+            | (FieldSymbol::Day(fields::Day::DayOfMonth), _)
+            | (FieldSymbol::Hour(_), _)
+            | (FieldSymbol::Minute, _)
+            | (FieldSymbol::Second(fields::Second::Second), _)
+            | (FieldSymbol::DecimalSecond(_), _)
+    )
+}
+
+fn month_code(number: u8, is_leap: bool) -> MonthCode {
+    let bytes = [
+        b'M',
+        b'0' + number / 10 % 10,
+        b'0' + number % 10,
+        if is_leap { b'L' } else { 0 },
+    ];
+    #[allow(clippy::expect_used)] // the bytes are always ASCII
+    MonthCode(TinyAsciiStr::try_from_raw(bytes).expect("valid month code"))
+}
+
+fn skip_whitespace(input: &str, offset: usize) -> usize {
+    let rest = &input[offset..];
+    input.len() - rest.trim_start().len()
+}
+
+/// Compares two characters case-insensitively, treating all whitespace as equal.
+fn chars_match(a: char, b: char) -> bool {
+    a == b || (a.is_whitespace() && b.is_whitespace()) || a.to_lowercase().eq(b.to_lowercase())
+}
+
+/// Returns the length of the prefix of `input` that matches `name`.
+fn match_prefix(input: &str, name: &str) -> Option<usize> {
+    let mut chars = input.chars();
+    for n in name.chars() {
+        if !chars_match(chars.next()?, n) {
+            return None;
+        }
+    }
+    Some(input.len() - chars.as_str().len())
+}
+
+/// Finds the name that `input` starts with and returns its index and the
+/// length of the matched input.
+///
+/// The longest full match wins. If there is none, the letters at the start of
+/// the input, optionally followed by a period, may abbreviate a single name.
+fn match_name(input: &str, names: &[&str]) -> Option<(usize, usize)> {
+    let full_match = names
+        .iter()
+        .enumerate()
+        .filter(|(_, name)| !name.is_empty())
+        .filter_map(|(index, name)| Some((index, match_prefix(input, name)?)))
+        .fold(None, |best: Option<(usize, usize)>, candidate| match best {
+            Some(best) if best.1 >= candidate.1 => Some(best),
+            _ => Some(candidate),
+        });
+    if full_match.is_some() {
+        return full_match;
+    }
+
+    let word_len = input
+        .char_indices()
+        .find(|(_, c)| !c.is_alphabetic())
+        .map(|(i, _)| i)
+        .unwrap_or(input.len());
+    if word_len == 0 {
+        return None;
+    }
+    let word = &input[..word_len];
+    let mut candidates = names
+        .iter()
+        .enumerate()
+        .filter(|(_, name)| match_prefix(name, word).is_some())
+        .map(|(index, _)| index);
+    let index = candidates.next()?;
+    if candidates.next().is_some() {
+        // Ambiguous abbreviation
+        return None;
+    }
+    let period_len = if input[word_len..].starts_with('.') {
+        1
+    } else {
+        0
+    };
+    Some((index, word_len + period_len))
+}
+
+/// The digits and decimal separator used for reading numbers.
+struct NumberSymbols {
+    /// The native digits zero through nine, if different from ASCII digits.
+    digits: Option<[char; 10]>,
+    /// The native decimal separator, if different from the ones accepted by default.
+    decimal_separator: Option<String>,
+}
+
+impl NumberSymbols {
+    fn new(decimal_formatter: Option<&DecimalFormatter>) -> Self {
+        let mut symbols = Self {
+            digits: None,
+            decimal_separator: None,
+        };
+        let Some(decimal_formatter) = decimal_formatter else {
+            return symbols;
+        };
+        // Format a number that contains each digit once to find the native
+        // digits and decimal separator.
+        let Ok(number) = SignedFixedDecimal::try_from_str("0.123456789") else {
+            return symbols;
+        };
+        let chars: Vec<char> = decimal_formatter
+            .format(&number)
+            .write_to_string()
+            .chars()
+            .collect();
+        if let Some((&zero, rest)) = chars.split_first() {
+            if let Some(separator_end) = rest.len().checked_sub(9) {
+                let (separator, one_to_nine) = rest.split_at(separator_end);
+                let mut digits = [zero; 10];
+                digits[1..].copy_from_slice(one_to_nine);
+                if digits != ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'] {
+                    symbols.digits = Some(digits);
+                }
+                let separator: String = separator.iter().collect();
+                if !matches!(separator.as_str(), "" | "." | ",") {
+                    symbols.decimal_separator = Some(separator);
+                }
+            }
+        }
+        symbols
+    }
+
+    fn digit_value(&self, c: char) -> Option<u32> {
+        c.to_digit(10).or_else(|| {
+            self.digits?
+                .iter()
+                .position(|&d| d == c)
+                .map(|value| value as u32)
+        })
+    }
+
+    /// Reads up to `max_digits` digits, returning the value, the length
+    /// of the input read, and the number of digits.
+    fn read(&self, input: &str, max_digits: usize) -> Option<(u32, usize, usize)> {
+        let mut value = 0u32;
+        let mut len = 0;
+        let mut digits = 0;
+        for c in input.chars().take(max_digits.min(MAX_DIGITS)) {
+            let Some(digit) = self.digit_value(c) else {
+                break;
+            };
+            value = value * 10 + digit;
+            len += c.len_utf8();
+            digits += 1;
+        }
+        (digits > 0).then_some((value, len, digits))
+    }
+
+    /// Reads fractional digits as nanoseconds, returning the value and the
+    /// length of the input read. Digits beyond nanosecond precision are ignored.
+    fn read_fraction(&self, input: &str) -> Option<(u32, usize)> {
+        let mut value = 0u32;
+        let mut len = 0;
+        let mut digits = 0;
+        for c in input.chars() {
+            let Some(digit) = self.digit_value(c) else {
+                break;
+            };
+            if digits < 9 {
+                value = value * 10 + digit;
+                digits += 1;
+            }
+            len += c.len_utf8();
+        }
+        if len == 0 {
+            return None;
+        }
+        Some((value * 10u32.pow(9 - digits), len))
+    }
+
+    fn decimal_separator_len(&self, input: &str) -> Option<usize> {
+        if let Some(separator) = &self.decimal_separator {
+            if input.starts_with(separator.as_str()) {
+                return Some(separator.len());
+            }
+        }
+        input.starts_with(['.', ',']).then_some(1)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum ParsedEra {
+    /// The index of a fixed era
+    Index(u8),
+    /// The code of a variable era
+    Code(Era),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum DayPeriod {
+    Am,
+    Pm,
+    Noon,
+    Midnight,
+}
+
+/// A value read from the input, with the field and offset it was read at.
+#[derive(Debug, Copy, Clone)]
+struct Parsed<T> {
+    value: T,
+    field: Field,
+    offset: usize,
+}
+
+impl<T> Parsed<T> {
+    fn new(value: T, field: Field, offset: usize) -> Self {
+        Self {
+            value,
+            field,
+            offset,
+        }
+    }
+
+    fn error_field(&self) -> ErrorField {
+        ErrorField(self.field)
+    }
+}
+
+trait SetParsed<T> {
+    /// Stores a value, checking that it agrees with any value already read
+    /// for the same field.
+    fn set(&mut self, parsed: Parsed<T>) -> Result<(), DateTimeParseError>;
+}
+
+impl<T: PartialEq> SetParsed<T> for Option<Parsed<T>> {
+    fn set(&mut self, parsed: Parsed<T>) -> Result<(), DateTimeParseError> {
+        match self {
+            Some(existing) if existing.value != parsed.value => Err(
+                DateTimeParseError::Inconsistent(parsed.error_field(), parsed.offset),
+            ),
+            Some(_) => Ok(()),
+            None => {
+                *self = Some(parsed);
+                Ok(())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct ParsedFields {
+    era: Option<Parsed<ParsedEra>>,
+    year: Option<Parsed<i32>>,
+    month: Option<Parsed<MonthCode>>,
+    day: Option<Parsed<u8>>,
+    weekday: Option<Parsed<IsoWeekday>>,
+    day_period: Option<Parsed<DayPeriod>>,
+    /// The hour as written, in the hour cycle of its field
+    hour: Option<Parsed<u8>>,
+    minute: Option<Parsed<u8>>,
+    second: Option<Parsed<u8>>,
+    nanosecond: Option<Parsed<u32>>,
+}
+
+fn missing(symbol: FieldSymbol) -> DateTimeParseError {
+    DateTimeParseError::MissingField(ErrorField(Field {
+        symbol,
+        length: FieldLength::One,
+    }))
+}
+
+/// Returns the year to pass to the calendar without an era for the year `era_year` of the
+/// fixed era with index `era_index`.
+///
+/// Fixed eras are only known by their index, so the mapping is taken from the calendar:
+/// the first of the `candidates` that falls into the era, and a neighboring year in the same
+/// era, give the offset and the direction of the era's years.
+fn year_in_era<C: Calendar>(
+    calendar: &C,
+    era_index: u8,
+    era_year: i32,
+    candidates: [i32; 4],
+) -> Option<i32> {
+    let era_and_year = |year: i32| {
+        let inner = calendar
+            .date_from_codes(None, year, month_code(1, false), 1)
+            .ok()?;
+        let year_info = calendar.year(&inner);
+        match year_info.formatting_era()? {
+            FormattingEra::Index(index, _) => Some((index, year_info.era_year()?)),
+            _ => None,
+        }
+    };
+    candidates.into_iter().find_map(|candidate| {
+        let (index, candidate_era_year) = era_and_year(candidate)?;
+        if index != era_index {
+            return None;
+        }
+        // +1 if the era years count forward, -1 if they count backward
+        let direction = [1, -1].into_iter().find_map(|step| {
+            let (index, neighbor_era_year) = era_and_year(candidate.checked_add(step)?)?;
+            (index == era_index).then_some((neighbor_era_year - candidate_era_year) * step)
+        })?;
+        let year = candidate
+            .checked_add(direction.checked_mul(era_year.checked_sub(candidate_era_year)?)?)?;
+        (era_and_year(year)? == (era_index, era_year)).then_some(year)
+    })
+}
+
+impl ParsedFields {
+    fn to_date<A: AsCalendar>(&self, calendar: A) -> Result<Date<A>, DateTimeParseError> {
+        let year = self
+            .year
+            .ok_or(missing(FieldSymbol::Year(fields::Year::Calendar)))?;
+        let month = self
+            .month
+            .ok_or(missing(FieldSymbol::Month(fields::Month::Format)))?;
+        let day = self
+            .day
+            .ok_or(missing(FieldSymbol::Day(fields::Day::DayOfMonth)))?;
+        let era = self.era;
+
+        let map_err = |e: DateError| {
+            let (field, offset) = match (e, era) {
+                (DateError::Range { field: "year", .. }, _) => (year.field, year.offset),
+                (DateError::Range { field: "month", .. } | DateError::UnknownMonthCode(_), _) => {
+                    (month.field, month.offset)
+                }
+                (DateError::UnknownEra(_), Some(era)) => (era.field, era.offset),
+                (DateError::UnknownEra(_), None) => (year.field, year.offset),
+                _ => (day.field, day.offset),
+            };
+            DateTimeParseError::OutOfRange(ErrorField(field), offset)
+        };
+
+        let date = match era {
+            None => Date::try_new_from_codes(None, year.value, month.value, day.value, calendar),
+            Some(Parsed {
+                value: ParsedEra::Code(code),
+                ..
+            }) => {
+                Date::try_new_from_codes(Some(code), year.value, month.value, day.value, calendar)
+            }
+            Some(
+                era @ Parsed {
+                    value: ParsedEra::Index(index),
+                    ..
+                },
+            ) => {
+                let extended_year = year_in_era(
+                    calendar.as_calendar(),
+                    index,
+                    year.value,
+                    [year.value, 1 - year.value, 1, 0],
+                )
+                .ok_or(DateTimeParseError::Inconsistent(
+                    era.error_field(),
+                    era.offset,
+                ))?;
+                Date::try_new_from_codes(None, extended_year, month.value, day.value, calendar)
+            }
+        }
+        .map_err(map_err)?;
+
+        if let Some(weekday) = self.weekday {
+            if date.day_of_week() != weekday.value {
+                return Err(DateTimeParseError::Inconsistent(
+                    weekday.error_field(),
+                    weekday.offset,
+                ));
+            }
+        }
+
+        Ok(date)
+    }
+
+    fn to_time(&self) -> Result<Time, DateTimeParseError> {
+        let hour = self
+            .hour
+            .ok_or(missing(FieldSymbol::Hour(fields::Hour::H23)))?;
+        let value = match hour.field.symbol {
+            FieldSymbol::Hour(fields::Hour::H11 | fields::Hour::H12) => {
+                let value = hour.value % 12;
+                match self.day_period {
+                    None => return Err(missing(FieldSymbol::DayPeriod(fields::DayPeriod::AmPm))),
+                    Some(Parsed {
+                        value: DayPeriod::Am,
+                        ..
+                    }) => value,
+                    Some(Parsed {
+                        value: DayPeriod::Pm,
+                        ..
+                    }) => value + 12,
+                    Some(Parsed {
+                        value: DayPeriod::Noon,
+                        ..
+                    }) if value == 0 => 12,
+                    Some(Parsed {
+                        value: DayPeriod::Midnight,
+                        ..
+                    }) if value == 0 => 0,
+                    Some(day_period) => {
+                        return Err(DateTimeParseError::Inconsistent(
+                            day_period.error_field(),
+                            day_period.offset,
+                        ))
+                    }
+                }
+            }
+            _ => hour.value % 24,
+        };
+        Time::try_new(
+            value,
+            self.minute.map(|m| m.value).unwrap_or_default(),
+            self.second.map(|s| s.value).unwrap_or_default(),
+            self.nanosecond.map(|n| n.value).unwrap_or_default(),
+        )
+        .map_err(|_| DateTimeParseError::OutOfRange(hour.error_field(), hour.offset))
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "compiled_data")]
+mod tests {
+    use super::super::*;
+    use super::*;
+    use icu_calendar::Gregorian;
+    use icu_locale_core::locale;
+
+    fn parse_date(
+        locale: icu_locale_core::Locale,
+        pattern: &str,
+        input: &str,
+    ) -> Result<Date<Gregorian>, DateTimeParseError> {
+        let mut names: FixedCalendarDateTimeNames<Gregorian> =
+            FixedCalendarDateTimeNames::try_new(locale.into()).unwrap();
+        let pattern: DateTimePattern = pattern.parse().unwrap();
+        names.include_for_pattern(&pattern).unwrap();
+        names
+            .parser_with_pattern(&pattern)
+            .parse_date(input, Gregorian)
+    }
+
+    fn parse_time(
+        locale: icu_locale_core::Locale,
+        pattern: &str,
+        input: &str,
+    ) -> Result<Time, DateTimeParseError> {
+        let mut names: FixedCalendarDateTimeNames<Gregorian> =
+            FixedCalendarDateTimeNames::try_new(locale.into()).unwrap();
+        let pattern: DateTimePattern = pattern.parse().unwrap();
+        names.include_for_pattern(&pattern).unwrap();
+        names.parser_with_pattern(&pattern).parse_time(input)
+    }
+
+    #[test]
+    fn test_parse_dates() {
+        let march_4 = Ok(Date::try_new_gregorian(2025, 3, 4).unwrap());
+        let cases = [
+            (locale!("en"), "M/d/yy", "3/4/25"),
+            (locale!("en"), "M/d/yy", " 3/4/2025 "),
+            (locale!("en"), "MMMM d, y", "March 4, 2025"),
+            (locale!("en"), "MMMM d, y", "Mar. 4, 2025"),
+            (locale!("en"), "MMMM d, y", "march  4,2025"),
+            (locale!("en"), "MMM d, y", "3 4, 2025"),
+            (locale!("en"), "yyyyMMdd", "20250304"),
+            (locale!("en"), "EEEE, MMMM d, y", "Tuesday, March 4, 2025"),
+            (locale!("en"), "EEEE, MMMM d, y", "tue., March 4, 2025"),
+            (locale!("de"), "d. MMMM y", "4. März 2025"),
+            (locale!("de"), "d. MMMM y", "4.märz 2025"),
+            (locale!("ja"), "y年M月d日", "2025年3月4日"),
+            (locale!("bn"), "d/M/y", "৪/৩/২০২৫"),
+            (locale!("bn"), "d/M/y", "4/3/2025"),
+        ];
+        for (locale, pattern, input) in cases {
+            assert_eq!(
+                parse_date(locale, pattern, input),
+                march_4,
+                "{pattern}: {input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_two_digit_years() {
+        assert_eq!(
+            parse_date(locale!("en"), "M/d/yy", "3/4/49"),
+            Ok(Date::try_new_gregorian(2049, 3, 4).unwrap())
+        );
+        assert_eq!(
+            parse_date(locale!("en"), "M/d/yy", "3/4/50"),
+            Ok(Date::try_new_gregorian(1950, 3, 4).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_parse_eras() {
+        use crate::fieldsets;
+        use icu_calendar::cal::{Ethiopian, EthiopianEraStyle};
+        use writeable::TryWriteable;
+
+        assert_eq!(
+            parse_date(locale!("en"), "MMMM d, y G", "March 4, 44 BC"),
+            Ok(Date::try_new_gregorian(-43, 3, 4).unwrap())
+        );
+
+        // The Amete Alem era is offset by 5500 years from the extended year
+        let calendar = Ethiopian::new_with_era_style(EthiopianEraStyle::AmeteAlem);
+        let mut names: FixedCalendarDateTimeNames<Ethiopian, fieldsets::enums::DateFieldSet> =
+            FixedCalendarDateTimeNames::try_new(locale!("en").into()).unwrap();
+        let pattern: DateTimePattern = "MMMM d, y G".parse().unwrap();
+        names.include_for_pattern(&pattern).unwrap();
+        for year in [1, 500, 5501, 7517] {
+            let date = Date::try_new_ethiopian(EthiopianEraStyle::AmeteAlem, year, 7, 4).unwrap();
+            let input = names
+                .with_pattern_unchecked(&pattern)
+                .format(&date)
+                .try_write_to_string()
+                .unwrap()
+                .into_owned();
+            assert_eq!(
+                names
+                    .parser_with_pattern(&pattern)
+                    .parse_date(&input, calendar),
+                Ok(date),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_times() {
+        let cases = [
+            (locale!("en"), "h:mm a", "3:05 PM", (15, 5, 0, 0)),
+            (locale!("en"), "h:mm a", "3:05pm", (15, 5, 0, 0)),
+            (locale!("en"), "h:mm a", "12:30 AM", (0, 30, 0, 0)),
+            (locale!("en"), "h:mm a", "12:30 PM", (12, 30, 0, 0)),
+            (locale!("en"), "h:mm b", "12:00 noon", (12, 0, 0, 0)),
+            (locale!("en"), "HH:mm:ss", "23:59:58", (23, 59, 58, 0)),
+            (locale!("en"), "k:mm", "24:15", (0, 15, 0, 0)),
+            (
+                locale!("en"),
+                "HH:mm:ss.SS",
+                "01:02:03.5",
+                (1, 2, 3, 500_000_000),
+            ),
+            (locale!("en"), "HHmm", "0915", (9, 15, 0, 0)),
+            (locale!("ja"), "aK時", "午後3時", (15, 0, 0, 0)),
+            (locale!("ja"), "aK:mm", "午前0:30", (0, 30, 0, 0)),
+        ];
+        for (locale, pattern, input, (h, m, s, ns)) in cases {
+            assert_eq!(
+                parse_time(locale, pattern, input),
+                Ok(Time::try_new(h, m, s, ns).unwrap()),
+                "{pattern}: {input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        use DateTimeParseError::*;
+        let cases = [
+            ("M/d/y", "3-4-2025", Some(1)),
+            ("M/d/y", "13/4/2025", Some(0)),
+            ("M/d/y", "2/30/2025", Some(2)),
+            ("M/d/y", "3/4/2025 extra", Some(9)),
+            ("MMMM d, y", "Ma 4, 2025", Some(0)),
+            ("MMMM d, y", "Foo 4, 2025", Some(0)),
+            ("EEE, M/d/y", "Mon, 3/4/2025", Some(0)),
+            ("M/d", "3/4", None),
+        ];
+        for (pattern, input, offset) in cases {
+            let error = parse_date(locale!("en"), pattern, input).unwrap_err();
+            assert_eq!(error.offset(), offset, "{pattern}: {input}: {error:?}");
+        }
+
+        assert!(matches!(
+            parse_date(locale!("en"), "M/d/y", "2/30/2025"),
+            Err(OutOfRange(
+                ErrorField(Field {
+                    symbol: FieldSymbol::Day(_),
+                    ..
+                }),
+                2
+            ))
+        ));
+        assert!(matches!(
+            parse_time(locale!("en"), "h:mm a", "13:00 PM"),
+            Err(OutOfRange(_, 0))
+        ));
+        assert!(matches!(
+            parse_time(locale!("en"), "h:mm b", "3:00 noon"),
+            Err(Inconsistent(_, 5))
+        ));
+        assert!(matches!(
+            parse_time(locale!("en"), "h:mm", "3:05"),
+            Err(MissingField(ErrorField(Field {
+                symbol: FieldSymbol::DayPeriod(_),
+                ..
+            })))
+        ));
+        assert!(matches!(
+            parse_date(locale!("en"), "y D", "2025 63"),
+            Err(UnsupportedField(_, 5))
+        ));
+    }
+
+    #[test]
+    fn test_names_not_loaded() {
+        let names: FixedCalendarDateTimeNames<Gregorian> =
+            FixedCalendarDateTimeNames::try_new(locale!("en").into()).unwrap();
+        let pattern: DateTimePattern = "MMMM d, y".parse().unwrap();
+        assert!(matches!(
+            names
+                .parser_with_pattern(&pattern)
+                .parse_date("March 4, 2025", Gregorian),
+            Err(DateTimeParseError::NamesNotLoaded(_, 0))
+        ));
+    }
+}
@@ -22,6 +22,8 @@ pub(crate) mod packed_pattern;
 pub mod pattern;
 #[cfg(feature = "datagen")]
 pub mod skeleton;
+#[cfg(not(feature = "datagen"))]
+pub(crate) mod skeleton;
 pub mod time_zones;
 
 pub use packed_pattern::*;
@@ -63,6 +65,7 @@ const _: () = {
     impl_weekday_names_v1!(Baked);
     impl_day_period_names_v1!(Baked);
     impl_glue_pattern_v1!(Baked);
+    impl_date_time_interval_patterns_v1!(Baked);
     impl_time_neo_skeleton_patterns_v1!(Baked);
 
     impl_buddhist_year_names_v1!(Baked);
@@ -127,6 +130,7 @@ pub const MARKERS: &[DataMarkerInfo] = &[
     neo::WeekdayNamesV1::INFO,
     neo::DayPeriodNamesV1::INFO,
     neo::GluePatternV1::INFO,
+    neo::DateTimeIntervalPatternsV1::INFO,
     TimeNeoSkeletonPatternsV1::INFO,
     neo::BuddhistYearNamesV1::INFO,
     neo::ChineseYearNamesV1::INFO,
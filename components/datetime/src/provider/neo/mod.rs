@@ -9,10 +9,12 @@ mod adapter;
 use crate::provider::pattern::runtime::{self, PatternULE};
 use crate::size_test_macro::size_test;
 use alloc::borrow::Cow;
-use icu_pattern::SinglePlaceholderPattern;
+use icu_pattern::{DoublePlaceholderPattern, SinglePlaceholderPattern};
 use icu_provider::prelude::*;
 use potential_utf::PotentialUtf8;
-use zerovec::{ule::tuplevar::Tuple2VarULE, VarZeroCow, VarZeroSlice, VarZeroVec, ZeroMap};
+use zerovec::{
+    ule::tuplevar::Tuple2VarULE, VarZeroCow, VarZeroSlice, VarZeroVec, ZeroMap, ZeroMap2d,
+};
 
 /// Helpers involving the data marker attributes used for date names.
 ///
@@ -642,6 +644,40 @@ pub struct DateTimeSkeletons<'data> {
     pub map: ZeroMap<'data, str, PatternULE>,
 }
 
+/// Patterns for formatting intervals between two datetimes, such as "Jan 3 – 7, 2025".
+///
+/// The patterns are keyed by the skeleton of the pattern used to format a single datetime
+/// and by the pattern character of the greatest field that differs between the two
+/// datetimes, following CLDR's `intervalFormats`. They are not calendar-specific: the
+/// skeleton is computed from the pattern selected for the calendar being formatted.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(marker(DateTimeIntervalPatternsV1, "datetime/patterns/interval@1"))]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_datetime::provider::neo))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct DateTimeIntervalPatterns<'data> {
+    /// The pattern combining two fully formatted datetimes when no interval pattern
+    /// applies, such as `{0} – {1}`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            deserialize_with = "icu_pattern::deserialize_borrowed_cow::<icu_pattern::DoublePlaceholder, _>"
+        )
+    )]
+    pub fallback: Cow<'data, DoublePlaceholderPattern>,
+    /// Interval patterns by skeleton, such as `yMMMd`, and greatest difference, such as `d`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub patterns: ZeroMap2d<'data, str, char, PatternULE>,
+}
+
 /// Calendar-agnostic year name data marker
 #[derive(Debug)]
 pub struct YearNamesV1;
@@ -25,6 +25,7 @@ pub enum SkeletonError {
     #[displaydoc("symbol unimplemented {0} in skeleton")]
     SymbolUnimplemented(char),
     #[displaydoc("unimplemented field {0} in skeleton")]
+    #[cfg_attr(not(feature = "datagen"), allow(dead_code))] // only public with datagen
     UnimplementedField(char),
     #[displaydoc("skeleton has a variant subtag")]
     SkeletonHasVariant,
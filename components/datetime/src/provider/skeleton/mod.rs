@@ -8,7 +8,9 @@
 //!
 //! See [`Skeleton`](reference::Skeleton) and [`Bag`](components::Bag) for more information.
 //!
-//! ✨ *Enabled with the `datagen` Cargo feature.*
+//! ✨ *Enabled with the `datagen` Cargo feature.* Without it, only skeleton parsing is
+//! compiled, for internal use by
+//! [`FieldSetBuilder::try_from_skeleton_str`](crate::fieldsets::builder::FieldSetBuilder::try_from_skeleton_str).
 //!
 //! # Implementation status
 //!
@@ -51,16 +53,21 @@
 //! to be stable, their Rust representation might not be. Use with caution.
 //! </div>
 
-#[cfg(doc)]
+#[cfg(all(doc, any(feature = "experimental", feature = "datagen")))]
 use crate::provider::fields::components;
 
 mod error;
+#[cfg(feature = "datagen")]
 mod helpers;
+#[cfg(feature = "datagen")]
 mod plural;
 pub mod reference;
+#[cfg(feature = "datagen")]
 pub mod runtime;
-#[cfg(feature = "serde")]
+#[cfg(feature = "datagen")]
 mod serde;
 pub use error::*;
+#[cfg(feature = "datagen")]
 pub use helpers::*;
+#[cfg(feature = "datagen")]
 pub use plural::*;
@@ -33,11 +33,13 @@ impl Skeleton {
         self.0.iter()
     }
 
+    #[cfg(feature = "datagen")]
     pub(crate) fn fields_len(&self) -> usize {
         self.0.len()
     }
 
     /// Return the underlying fields as a slice.
+    #[cfg(feature = "datagen")]
     pub fn as_slice(&self) -> &[fields::Field] {
         self.0.as_slice()
     }
@@ -140,7 +142,7 @@ impl TryFrom<&str> for Skeleton {
                 if *next_ch != ch {
                     break;
                 }
-                field_length += 1;
+                field_length = field_length.saturating_add(1);
                 iter.next();
             }
 
@@ -208,6 +208,17 @@ impl DatePatternSelectionData {
         items.iter()
     }
 
+    /// Borrows each of the patterns that [`Self::select`] can return.
+    pub(crate) fn iter_patterns(&self) -> impl Iterator<Item = DatePatternDataBorrowed> + '_ {
+        let DatePatternSelectionData::SkeletonDate { options, payload } = self;
+        ALL_VARIANTS.into_iter().map(|variant| {
+            DatePatternDataBorrowed::Resolved(
+                payload.get().get(options.length, variant),
+                options.alignment,
+            )
+        })
+    }
+
     /// Borrows a resolved pattern based on the given datetime
     pub(crate) fn select(&self, input: &ExtractedInput) -> DatePatternDataBorrowed {
         match self {
@@ -312,6 +323,16 @@ impl OverlapPatternSelectionData {
         items.iter()
     }
 
+    /// Borrows each of the patterns that [`Self::select`] can return.
+    pub(crate) fn iter_patterns(&self) -> impl Iterator<Item = TimePatternDataBorrowed> + '_ {
+        let OverlapPatternSelectionData::SkeletonDateTime {
+            options,
+            prefs,
+            payload,
+        } = self;
+        iter_time_patterns(options, prefs, payload)
+    }
+
     /// Borrows a resolved pattern based on the given datetime
     pub(crate) fn select(&self, input: &ExtractedInput) -> TimePatternDataBorrowed {
         match self {
@@ -404,6 +425,16 @@ impl TimePatternSelectionData {
         items.iter()
     }
 
+    /// Borrows each of the patterns that [`Self::select`] can return.
+    pub(crate) fn iter_patterns(&self) -> impl Iterator<Item = TimePatternDataBorrowed> + '_ {
+        let TimePatternSelectionData::SkeletonTime {
+            options,
+            prefs,
+            payload,
+        } = self;
+        iter_time_patterns(options, prefs, payload)
+    }
+
     /// Borrows a resolved pattern based on the given datetime
     pub(crate) fn select(&self, input: &ExtractedInput) -> TimePatternDataBorrowed {
         match self {
@@ -426,6 +457,37 @@ impl TimePatternSelectionData {
     }
 }
 
+const ALL_VARIANTS: [PackedSkeletonVariant; 3] = [
+    PackedSkeletonVariant::Standard,
+    PackedSkeletonVariant::Variant0,
+    PackedSkeletonVariant::Variant1,
+];
+
+/// Borrows each of the time patterns that can be selected with the given options.
+fn iter_time_patterns<'a>(
+    options: &'a RawOptions,
+    prefs: &'a RawPreferences,
+    payload: &'a DataPayload<ErasedPackedPatterns>,
+) -> impl Iterator<Item = TimePatternDataBorrowed<'a>> {
+    ALL_VARIANTS.into_iter().map(|variant| {
+        // Fractional seconds are only shown with the seconds, in the last variant
+        let fractional_second_digits = match options.time_precision {
+            Some(TimePrecision::FractionalSecond(digits))
+                if matches!(variant, PackedSkeletonVariant::Variant1) =>
+            {
+                Some(digits)
+            }
+            _ => None,
+        };
+        TimePatternDataBorrowed::Resolved(
+            payload.get().get(options.length, variant),
+            options.alignment,
+            prefs.hour_cycle,
+            fractional_second_digits,
+        )
+    })
+}
+
 impl<'a> TimePatternDataBorrowed<'a> {
     pub(crate) fn items_and_options(self) -> ItemsAndOptions<'a> {
         let Self::Resolved(pattern, alignment, hour_cycle, fractional_second_digits) = self;
@@ -679,6 +741,32 @@ impl DateTimeZonePatternSelectionData {
             .chain(overlap_items)
     }
 
+    /// Borrows each of the patterns that [`Self::select`] can return for date-only, time-only,
+    /// and overlap field sets. Returns nothing for field sets whose patterns are glued together.
+    pub(crate) fn iter_patterns(
+        &self,
+    ) -> impl Iterator<Item = DateTimeZonePatternDataBorrowed> + '_ {
+        let (date, time, overlap) = match self {
+            DateTimeZonePatternSelectionData::Date(date) => (Some(date), None, None),
+            DateTimeZonePatternSelectionData::Time(time) => (None, Some(time), None),
+            DateTimeZonePatternSelectionData::Overlap(overlap) => (None, None, Some(overlap)),
+            _ => (None, None, None),
+        };
+        let date_patterns = date
+            .into_iter()
+            .flat_map(|x| x.iter_patterns())
+            .map(DateTimeZonePatternDataBorrowed::Date);
+        let time_patterns = time
+            .into_iter()
+            .flat_map(|x| x.iter_patterns())
+            .map(DateTimeZonePatternDataBorrowed::Time);
+        let overlap_patterns = overlap
+            .into_iter()
+            .flat_map(|x| x.iter_patterns())
+            .map(DateTimeZonePatternDataBorrowed::Overlap);
+        date_patterns.chain(time_patterns).chain(overlap_patterns)
+    }
+
     /// Borrows a resolved pattern based on the given datetime
     pub(crate) fn select(&self, input: &ExtractedInput) -> DateTimeZonePatternDataBorrowed {
         match self {
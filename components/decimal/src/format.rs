@@ -4,6 +4,7 @@
 
 //! Lower-level types for decimal formatting.
 
+use alloc::borrow::Cow;
 use core::fmt::Write;
 
 use crate::grouper;
@@ -20,7 +21,7 @@ use writeable::Writeable;
 /// Use [`Writeable`][Writeable] to render the formatted decimal to a string or buffer.
 #[derive(Debug, PartialEq, Clone)]
 pub struct FormattedDecimal<'l> {
-    pub(crate) value: &'l SignedFixedDecimal,
+    pub(crate) value: Cow<'l, SignedFixedDecimal>,
     pub(crate) options: &'l DecimalFormatterOptions,
     pub(crate) symbols: &'l DecimalSymbols<'l>,
     pub(crate) digits: &'l DecimalDigits,
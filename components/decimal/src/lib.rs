@@ -97,12 +97,20 @@ extern crate alloc;
 mod format;
 mod grouper;
 pub mod options;
+mod parse;
 pub mod parts;
+mod precision;
 pub mod provider;
+mod scientific;
 pub(crate) mod size_test_macro;
 
 pub use format::FormattedDecimal;
+pub use parse::DecimalParseError;
+pub use parse::DecimalParser;
+pub use scientific::FormattedScientificDecimal;
+pub use scientific::ScientificDecimalFormatter;
 
+use alloc::borrow::Cow;
 use alloc::string::String;
 use fixed_decimal::SignedFixedDecimal;
 use icu_locale_core::locale;
@@ -111,7 +119,7 @@ use icu_provider::prelude::*;
 use size_test_macro::size_test;
 use writeable::Writeable;
 
-size_test!(DecimalFormatter, decimal_formatter_size, 96);
+size_test!(DecimalFormatter, decimal_formatter_size, 104);
 
 define_preferences!(
     /// The preferences for fixed decimal formatting.
@@ -276,7 +284,16 @@ impl DecimalFormatter {
     }
 
     /// Formats a [`SignedFixedDecimal`], returning a [`FormattedDecimal`].
+    ///
+    /// The precision options are applied to a copy of `value` before formatting.
     pub fn format<'l>(&'l self, value: &'l SignedFixedDecimal) -> FormattedDecimal<'l> {
+        let value = if precision::is_needed(&self.options) {
+            let mut value = value.clone();
+            precision::apply(&mut value, &self.options);
+            Cow::Owned(value)
+        } else {
+            Cow::Borrowed(value)
+        };
         FormattedDecimal {
             value,
             options: &self.options,
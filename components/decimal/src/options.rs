@@ -4,6 +4,8 @@
 
 //! Options for [`DecimalFormatter`](crate::DecimalFormatter).
 
+use displaydoc::Display;
+
 /// A bag of options defining how numbers will be formatted by
 /// [`DecimalFormatter`](crate::DecimalFormatter).
 #[derive(Debug, Eq, PartialEq, Clone, Copy, Default, Hash)]
@@ -13,16 +15,210 @@ pub struct DecimalFormatterOptions {
     ///
     /// Default is [`GroupingStrategy::Auto`]
     pub grouping_strategy: Option<GroupingStrategy>,
+
+    /// The number of fraction digits to display, padding with zeros or rounding if necessary.
+    ///
+    /// Default is to display the fraction digits of the number unchanged.
+    pub fraction_digits: Option<FractionDigits>,
+
+    /// The number of significant digits to display, padding with zeros or rounding if necessary.
+    ///
+    /// Default is to display the significant digits of the number unchanged.
+    pub significant_digits: Option<SignificantDigits>,
+
+    /// The increment to round to at the maximum fraction digit.
+    ///
+    /// Default is [`RoundingIncrement::MultiplesOf1`](fixed_decimal::RoundingIncrement::MultiplesOf1)
+    pub rounding_increment: Option<fixed_decimal::RoundingIncrement>,
+
+    /// How to round when the number has more digits than allowed.
+    ///
+    /// Default is [`UnsignedRoundingMode::HalfExpand`](fixed_decimal::UnsignedRoundingMode::HalfExpand)
+    pub rounding_mode: Option<fixed_decimal::SignedRoundingMode>,
+
+    /// How to resolve a conflict between the fraction digit and significant digit options.
+    ///
+    /// Default is [`RoundingPriority::Auto`]
+    pub rounding_priority: Option<RoundingPriority>,
+
+    /// Whether to display trailing zeros for integers.
+    ///
+    /// Default is [`TrailingZeroDisplay::Auto`]
+    pub trailing_zero_display: Option<TrailingZeroDisplay>,
 }
 
 impl From<GroupingStrategy> for DecimalFormatterOptions {
     fn from(grouping_strategy: GroupingStrategy) -> Self {
         Self {
             grouping_strategy: Some(grouping_strategy),
+            ..Default::default()
         }
     }
 }
 
+/// A validated range of fraction digits to display.
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::options;
+/// use icu::decimal::DecimalFormatter;
+/// use writeable::assert_writeable_eq;
+///
+/// let mut options: options::DecimalFormatterOptions = Default::default();
+/// options.fraction_digits =
+///     Some(options::FractionDigits::try_new(Some(2), Some(4)).unwrap());
+/// let df = DecimalFormatter::try_new(Default::default(), options)
+///     .expect("locale should be present");
+///
+/// let short = "1.5".parse().unwrap();
+/// assert_writeable_eq!(df.format(&short), "1.50");
+///
+/// let long = "1.23456".parse().unwrap();
+/// assert_writeable_eq!(df.format(&long), "1.2346");
+///
+/// // The minimum must not exceed the maximum.
+/// assert_eq!(
+///     options::FractionDigits::try_new(Some(4), Some(2)),
+///     Err(options::DigitRangeError::MinimumAboveMaximum)
+/// );
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub struct FractionDigits {
+    min: u8,
+    max: u8,
+}
+
+impl FractionDigits {
+    /// Creates a range of fraction digits, as in the `minimumFractionDigits` and
+    /// `maximumFractionDigits` options of ECMA-402.
+    ///
+    /// A missing minimum defaults to 0 and a missing maximum to 3, adjusted so that the
+    /// range is not empty. Both values must be at most 100, and the minimum must not
+    /// exceed the maximum if both are given.
+    pub fn try_new(min: Option<u8>, max: Option<u8>) -> Result<Self, DigitRangeError> {
+        let (min, max) = resolve_digit_range(min, max, 0, 3, 0, 100)?;
+        Ok(Self { min, max })
+    }
+
+    /// The minimum number of fraction digits.
+    pub fn min(self) -> u8 {
+        self.min
+    }
+
+    /// The maximum number of fraction digits.
+    pub fn max(self) -> u8 {
+        self.max
+    }
+}
+
+impl Default for FractionDigits {
+    /// The ECMA-402 defaults of 0 to 3 fraction digits.
+    fn default() -> Self {
+        Self { min: 0, max: 3 }
+    }
+}
+
+/// A validated range of significant digits to display.
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::options;
+/// use icu::decimal::DecimalFormatter;
+/// use writeable::assert_writeable_eq;
+///
+/// let mut options: options::DecimalFormatterOptions = Default::default();
+/// options.significant_digits =
+///     Some(options::SignificantDigits::try_new(None, Some(2)).unwrap());
+/// let df = DecimalFormatter::try_new(Default::default(), options)
+///     .expect("locale should be present");
+///
+/// let value = 123456.into();
+/// assert_writeable_eq!(df.format(&value), "120,000");
+///
+/// // There are between 1 and 21 significant digits.
+/// assert_eq!(
+///     options::SignificantDigits::try_new(Some(0), None),
+///     Err(options::DigitRangeError::OutOfRange)
+/// );
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub struct SignificantDigits {
+    min: u8,
+    max: u8,
+}
+
+impl SignificantDigits {
+    /// Creates a range of significant digits, as in the `minimumSignificantDigits` and
+    /// `maximumSignificantDigits` options of ECMA-402.
+    ///
+    /// A missing minimum defaults to 1 and a missing maximum to 21. Both values must be
+    /// between 1 and 21, and the minimum must not exceed the maximum if both are given.
+    pub fn try_new(min: Option<u8>, max: Option<u8>) -> Result<Self, DigitRangeError> {
+        let (min, max) = resolve_digit_range(min, max, 1, 21, 1, 21)?;
+        Ok(Self { min, max })
+    }
+
+    /// The minimum number of significant digits.
+    pub fn min(self) -> u8 {
+        self.min
+    }
+
+    /// The maximum number of significant digits.
+    pub fn max(self) -> u8 {
+        self.max
+    }
+}
+
+impl Default for SignificantDigits {
+    /// The ECMA-402 defaults of 1 to 21 significant digits.
+    fn default() -> Self {
+        Self { min: 1, max: 21 }
+    }
+}
+
+/// An error returned when creating [`FractionDigits`] or [`SignificantDigits`]
+/// from invalid values, corresponding to a `RangeError` in ECMA-402.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Display)]
+#[non_exhaustive]
+pub enum DigitRangeError {
+    /// A digit count is outside of the allowed range.
+    #[displaydoc("Digit count out of range")]
+    OutOfRange,
+    /// The minimum digit count is greater than the maximum digit count.
+    #[displaydoc("Minimum digit count is greater than the maximum")]
+    MinimumAboveMaximum,
+}
+
+impl core::error::Error for DigitRangeError {}
+
+/// Resolves a (minimum, maximum) pair of digit options as in ECMA-402's
+/// `DefaultNumberOption` and `SetNumberFormatDigitOptions`.
+fn resolve_digit_range(
+    min: Option<u8>,
+    max: Option<u8>,
+    default_min: u8,
+    default_max: u8,
+    lower: u8,
+    upper: u8,
+) -> Result<(u8, u8), DigitRangeError> {
+    if !min
+        .into_iter()
+        .chain(max)
+        .all(|value| (lower..=upper).contains(&value))
+    {
+        return Err(DigitRangeError::OutOfRange);
+    }
+    match (min, max) {
+        (Some(min), Some(max)) if min > max => Err(DigitRangeError::MinimumAboveMaximum),
+        (Some(min), Some(max)) => Ok((min, max)),
+        (Some(min), None) => Ok((min, core::cmp::max(min, default_max))),
+        (None, Some(max)) => Ok((core::cmp::min(default_min, max), max)),
+        (None, None) => Ok((default_min, default_max)),
+    }
+}
+
 /// Configuration for how often to render grouping separators.
 ///
 /// # Examples
@@ -66,3 +262,243 @@ pub enum GroupingStrategy {
     /// grouping separators, but numbers 10,000 and above will.
     Min2,
 }
+
+/// Configuration for which of the fraction digit and significant digit options wins
+/// when both are set.
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::options;
+/// use icu::decimal::DecimalFormatter;
+/// use writeable::assert_writeable_eq;
+///
+/// let mut options: options::DecimalFormatterOptions = Default::default();
+/// options.fraction_digits = Some(options::FractionDigits::try_new(None, Some(1)).unwrap());
+/// options.significant_digits =
+///     Some(options::SignificantDigits::try_new(None, Some(3)).unwrap());
+///
+/// let value = "4.321".parse().unwrap();
+///
+/// options.rounding_priority = Some(options::RoundingPriority::Auto);
+/// let df = DecimalFormatter::try_new(Default::default(), options)
+///     .expect("locale should be present");
+/// assert_writeable_eq!(df.format(&value), "4.32");
+///
+/// options.rounding_priority = Some(options::RoundingPriority::LessPrecision);
+/// let df = DecimalFormatter::try_new(Default::default(), options)
+///     .expect("locale should be present");
+/// assert_writeable_eq!(df.format(&value), "4.3");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+pub enum RoundingPriority {
+    /// Use the significant digit options and ignore the fraction digit options.
+    #[default]
+    Auto,
+
+    /// Use whichever of the two options keeps more digits.
+    MorePrecision,
+
+    /// Use whichever of the two options keeps fewer digits.
+    LessPrecision,
+}
+
+/// Configuration for whether to display trailing zeros for integers.
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::options;
+/// use icu::decimal::DecimalFormatter;
+/// use writeable::assert_writeable_eq;
+///
+/// let mut options: options::DecimalFormatterOptions = Default::default();
+/// options.fraction_digits = Some(options::FractionDigits::try_new(Some(2), None).unwrap());
+/// options.trailing_zero_display = Some(options::TrailingZeroDisplay::StripIfInteger);
+/// let df = DecimalFormatter::try_new(Default::default(), options)
+///     .expect("locale should be present");
+///
+/// let integer = "5.000".parse().unwrap();
+/// assert_writeable_eq!(df.format(&integer), "5");
+///
+/// let fraction = "5.1".parse().unwrap();
+/// assert_writeable_eq!(df.format(&fraction), "5.10");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+pub enum TrailingZeroDisplay {
+    /// Display trailing zeros according to the fraction digit and significant digit options.
+    #[default]
+    Auto,
+
+    /// Remove the fraction digits if they are all zero.
+    StripIfInteger,
+}
+
+/// A bag of options defining how numbers will be formatted by
+/// [`ScientificDecimalFormatter`](crate::ScientificDecimalFormatter).
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default, Hash)]
+#[non_exhaustive]
+pub struct ScientificDecimalFormatterOptions {
+    /// How to choose the exponent.
+    ///
+    /// Default is [`ExponentNotation::Scientific`]
+    pub notation: Option<ExponentNotation>,
+
+    /// How to render the exponent.
+    ///
+    /// Default is [`ExponentStyle::Exponential`]
+    pub exponent_style: Option<ExponentStyle>,
+}
+
+impl From<ExponentNotation> for ScientificDecimalFormatterOptions {
+    fn from(notation: ExponentNotation) -> Self {
+        Self {
+            notation: Some(notation),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<ExponentStyle> for ScientificDecimalFormatterOptions {
+    fn from(exponent_style: ExponentStyle) -> Self {
+        Self {
+            exponent_style: Some(exponent_style),
+            ..Default::default()
+        }
+    }
+}
+
+/// Configuration for how to choose the exponent of a number in
+/// [`ScientificDecimalFormatter`](crate::ScientificDecimalFormatter).
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::options;
+/// use icu::decimal::ScientificDecimalFormatter;
+/// use icu::locale::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let sdf = ScientificDecimalFormatter::try_new(
+///     locale!("en").into(),
+///     options::ExponentNotation::Engineering.into(),
+/// )
+/// .expect("locale should be present");
+///
+/// let value = "0.0123".parse().unwrap();
+/// assert_writeable_eq!(sdf.format(&value), "12.3E-3");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+pub enum ExponentNotation {
+    /// Show exactly one nonzero digit before the decimal separator, as in `1.23E4`.
+    #[default]
+    Scientific,
+
+    /// Use an exponent that is a multiple of 3, showing one to three digits before the
+    /// decimal separator, as in `12.3E3`.
+    Engineering,
+}
+
+/// Configuration for how to render the exponent in
+/// [`ScientificDecimalFormatter`](crate::ScientificDecimalFormatter).
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::options;
+/// use icu::decimal::ScientificDecimalFormatter;
+/// use icu::locale::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let sdf = ScientificDecimalFormatter::try_new(
+///     locale!("en").into(),
+///     options::ExponentStyle::Superscript.into(),
+/// )
+/// .expect("locale should be present");
+///
+/// let value = "-0.000123".parse().unwrap();
+/// assert_writeable_eq!(sdf.format(&value), "-1.23×10⁻⁴");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+pub enum ExponentStyle {
+    /// Separate the mantissa from the exponent with the locale's exponential symbol,
+    /// as in `1.23E4`.
+    #[default]
+    Exponential,
+
+    /// Multiply the mantissa by a power of ten with a superscripted exponent, as in `1.23×10⁴`.
+    ///
+    /// Superscript digits are only available for Latin digits. Other numbering systems
+    /// use [`ExponentStyle::Exponential`] instead.
+    Superscript,
+}
+
+/// A bag of options defining how numbers will be parsed by
+/// [`DecimalParser`](crate::DecimalParser).
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default, Hash)]
+#[non_exhaustive]
+pub struct DecimalParserOptions {
+    /// How closely the input must match the locale's format.
+    ///
+    /// Default is [`ParseStrictness::Lenient`]
+    pub strictness: Option<ParseStrictness>,
+}
+
+impl From<ParseStrictness> for DecimalParserOptions {
+    fn from(strictness: ParseStrictness) -> Self {
+        Self {
+            strictness: Some(strictness),
+        }
+    }
+}
+
+/// Configuration for how closely the input of [`DecimalParser`](crate::DecimalParser)
+/// must match the output of [`DecimalFormatter`](crate::DecimalFormatter).
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::options;
+/// use icu::decimal::DecimalParseError;
+/// use icu::decimal::DecimalParser;
+/// use icu::locale::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let lenient = DecimalParser::try_new(
+///     locale!("en").into(),
+///     options::ParseStrictness::Lenient.into(),
+/// )
+/// .expect("locale should be present");
+/// let strict = DecimalParser::try_new(
+///     locale!("en").into(),
+///     options::ParseStrictness::Strict.into(),
+/// )
+/// .expect("locale should be present");
+///
+/// assert_writeable_eq!(lenient.parse("12,34").unwrap(), "1234");
+/// assert_eq!(
+///     strict.parse("12,34"),
+///     Err(DecimalParseError::InvalidGrouping { index: 2 })
+/// );
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+pub enum ParseStrictness {
+    /// Accept common variations of the locale's format:
+    ///
+    /// - Leading and trailing whitespace
+    /// - ASCII digits in addition to the digits of the numbering system
+    /// - ASCII `-`, `−`, and `+` signs, and signs without their directional marks
+    /// - Grouping separators at any position, and any kind of space or apostrophe when the
+    ///   locale's grouping separator is a space or apostrophe
+    #[default]
+    Lenient,
+
+    /// Only accept the locale's symbols and digits, with grouping separators either omitted
+    /// or in the positions where [`DecimalFormatter`](crate::DecimalFormatter) puts them.
+    Strict,
+}
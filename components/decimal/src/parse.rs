@@ -0,0 +1,573 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Parsing localized decimal numbers.
+
+use core::ops::Range;
+
+use crate::grouper;
+use crate::options::*;
+use crate::provider::*;
+use crate::size_test_macro::size_test;
+use crate::{DecimalFormatter, DecimalFormatterPreferences};
+use alloc::string::String;
+use alloc::vec::Vec;
+use displaydoc::Display;
+use fixed_decimal::SignedFixedDecimal;
+use icu_provider::prelude::*;
+
+size_test!(DecimalParser, decimal_parser_size, 112);
+
+/// Characters that are treated as the same grouping separator in lenient mode.
+const LENIENT_SPACES: [char; 3] = [' ', '\u{A0}', '\u{202F}'];
+/// Characters that are treated as the same grouping separator in lenient mode.
+const LENIENT_APOSTROPHES: [char; 2] = ['\'', '\u{2019}'];
+/// Characters that are accepted as a minus sign in lenient mode.
+const LENIENT_MINUS_SIGNS: [char; 2] = ['-', '\u{2212}'];
+/// Characters that are accepted as a plus sign in lenient mode.
+const LENIENT_PLUS_SIGNS: [char; 1] = ['+'];
+/// Directional marks that some locales put around their sign symbols; ignored in lenient mode.
+const BIDI_MARKS: [char; 3] = ['\u{200E}', '\u{200F}', '\u{061C}'];
+
+/// An error returned by [`DecimalParser`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Display)]
+#[non_exhaustive]
+pub enum DecimalParseError {
+    /// The input does not contain digits where a number was expected.
+    #[displaydoc("Expected a digit at index {index}")]
+    NoDigits {
+        /// The byte index at which a digit was expected
+        index: usize,
+    },
+    /// The input contains a character that is not part of the number.
+    #[displaydoc("Unexpected character at index {index}")]
+    UnexpectedCharacter {
+        /// The byte index of the unexpected character
+        index: usize,
+    },
+    /// The grouping separators do not match the locale's grouping sizes.
+    ///
+    /// Only returned in [`ParseStrictness::Strict`] mode.
+    #[displaydoc("Misplaced grouping separator at index {index}")]
+    InvalidGrouping {
+        /// The byte index of the first misplaced grouping separator
+        index: usize,
+    },
+    /// The number has more digits or a larger magnitude than [`SignedFixedDecimal`] supports.
+    #[displaydoc("Magnitude or number of digits exceeded")]
+    Limit,
+}
+
+impl core::error::Error for DecimalParseError {}
+
+/// A parser for localized decimal numbers, the inverse of [`DecimalFormatter`].
+///
+/// [`DecimalParser`] uses the same symbols and digits as [`DecimalFormatter`], and supports:
+///
+/// 1. Digits in the local numbering system
+/// 2. Locale-specific grouping and decimal separators
+/// 3. Locale-specific plus and minus signs
+///
+/// In [`ParseStrictness::Lenient`] mode, which is the default, the parser also accepts
+/// surrounding whitespace, ASCII digits, ASCII signs, and common look-alikes of the grouping
+/// separator. See [`ParseStrictness`] for details.
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::DecimalParser;
+/// use icu::locale::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let parser = DecimalParser::try_new(locale!("de").into(), Default::default())
+///     .expect("locale should be present");
+///
+/// assert_writeable_eq!(parser.parse("-1.234,56").unwrap(), "-1234.56");
+///
+/// let parser = DecimalParser::try_new(locale!("ar-EG").into(), Default::default())
+///     .expect("locale should be present");
+///
+/// assert_writeable_eq!(parser.parse("١٢٣٫٤").unwrap(), "123.4");
+/// ```
+///
+/// Use [`DecimalParser::parse_prefix`] to parse a number followed by other text:
+///
+/// ```
+/// use icu::decimal::DecimalParser;
+/// use icu::locale::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let parser = DecimalParser::try_new(locale!("fr").into(), Default::default())
+///     .expect("locale should be present");
+///
+/// let input = "1 234,56 €";
+/// let (value, span) = parser.parse_prefix(input).unwrap();
+/// assert_writeable_eq!(value, "1234.56");
+/// assert_eq!(&input[span.end..], " €");
+/// ```
+#[doc = decimal_parser_size!()]
+#[derive(Debug)]
+pub struct DecimalParser {
+    options: DecimalParserOptions,
+    decimal: DecimalFormatter,
+}
+
+impl AsRef<DecimalParser> for DecimalParser {
+    fn as_ref(&self) -> &DecimalParser {
+        self
+    }
+}
+
+impl DecimalParser {
+    icu_provider::gen_buffer_data_constructors!(
+        (prefs: DecimalFormatterPreferences, options: DecimalParserOptions) -> error: DataError,
+        /// Creates a new [`DecimalParser`] from compiled data and an options bag.
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<
+        D: DataProvider<DecimalSymbolsV2> + DataProvider<DecimalDigitsV1> + ?Sized,
+    >(
+        provider: &D,
+        prefs: DecimalFormatterPreferences,
+        options: DecimalParserOptions,
+    ) -> Result<Self, DataError> {
+        // The data is resolved exactly as for formatting, so that the parser accepts what the
+        // formatter produces.
+        let decimal = DecimalFormatter::try_new_unstable(provider, prefs, Default::default())?;
+        Ok(Self { options, decimal })
+    }
+
+    /// Parses `input` as a number, failing if it contains anything else.
+    ///
+    /// In lenient mode, leading and trailing whitespace is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::decimal::DecimalParseError;
+    /// use icu::decimal::DecimalParser;
+    /// use icu::locale::locale;
+    ///
+    /// let parser = DecimalParser::try_new(locale!("en").into(), Default::default())
+    ///     .expect("locale should be present");
+    ///
+    /// assert_eq!(
+    ///     parser.parse("12 apples"),
+    ///     Err(DecimalParseError::UnexpectedCharacter { index: 3 })
+    /// );
+    /// ```
+    pub fn parse(&self, input: &str) -> Result<SignedFixedDecimal, DecimalParseError> {
+        let (value, span) = self.parse_prefix(input)?;
+        let mut cursor = Cursor::new(input, span.end);
+        if self.is_lenient() {
+            cursor.skip_while(char::is_whitespace);
+        }
+        match cursor.peek() {
+            None => Ok(value),
+            Some(_) => Err(DecimalParseError::UnexpectedCharacter {
+                index: cursor.index,
+            }),
+        }
+    }
+
+    /// Parses a number at the start of `input`, returning it together with the byte range
+    /// it occupies in `input`.
+    ///
+    /// Parsing stops at the first character that cannot continue the number; the text after
+    /// the returned range is not inspected. In lenient mode, leading whitespace is skipped and
+    /// is not part of the returned range.
+    pub fn parse_prefix(
+        &self,
+        input: &str,
+    ) -> Result<(SignedFixedDecimal, Range<usize>), DecimalParseError> {
+        let symbols = self.decimal.symbols.get();
+        let lenient = self.is_lenient();
+        let mut cursor = Cursor::new(input, 0);
+        if lenient {
+            cursor.skip_while(char::is_whitespace);
+        }
+        let start = cursor.index;
+
+        let (minus_prefix, minus_suffix) = symbols.minus_sign_affixes();
+        let (plus_prefix, plus_suffix) = symbols.plus_sign_affixes();
+        let mut negative = false;
+        let mut expected_suffix = None;
+        if self.eat_sign(&mut cursor, minus_prefix, &LENIENT_MINUS_SIGNS) {
+            negative = true;
+            expected_suffix = Some(minus_suffix);
+        } else if self.eat_sign(&mut cursor, plus_prefix, &LENIENT_PLUS_SIGNS) {
+            expected_suffix = Some(plus_suffix);
+        }
+
+        // The digits are collected as ASCII and then parsed by `fixed_decimal`.
+        let mut ascii = String::new();
+        if negative {
+            ascii.push('-');
+        }
+        let integer_start = ascii.len();
+        // Byte index in `input` and number of preceding integer digits of each grouping separator.
+        let mut separators = Vec::new();
+        loop {
+            if let Some(digit) = self.eat_digit(&mut cursor) {
+                ascii.push(digit);
+                continue;
+            }
+            let index = cursor.index;
+            let digit_count = ascii.len() - integer_start;
+            if digit_count > 0 && self.eat_grouping_separator(&mut cursor) {
+                if self.peek_digit(&cursor) {
+                    separators.push((index, digit_count));
+                    continue;
+                }
+                // A trailing grouping separator is not part of the number.
+                cursor.index = index;
+            }
+            break;
+        }
+        let integer_digits = ascii.len() - integer_start;
+        if integer_digits == 0 {
+            return Err(DecimalParseError::NoDigits {
+                index: cursor.index,
+            });
+        }
+        if !lenient {
+            self.check_grouping(&separators, integer_digits)?;
+        }
+
+        let index = cursor.index;
+        if cursor.eat_str(symbols.decimal_separator()) {
+            if self.peek_digit(&cursor) {
+                ascii.push('.');
+                while let Some(digit) = self.eat_digit(&mut cursor) {
+                    ascii.push(digit);
+                }
+            } else {
+                // A trailing decimal separator is not part of the number.
+                cursor.index = index;
+            }
+        }
+
+        match expected_suffix {
+            Some(suffix) => {
+                if !self.eat_sign(&mut cursor, suffix, &[]) && !suffix.is_empty() && !lenient {
+                    return Err(DecimalParseError::UnexpectedCharacter {
+                        index: cursor.index,
+                    });
+                }
+            }
+            None => {
+                if self.eat_sign(&mut cursor, minus_suffix, &[]) {
+                    ascii.insert(0, '-');
+                } else {
+                    self.eat_sign(&mut cursor, plus_suffix, &[]);
+                }
+            }
+        }
+
+        let value = SignedFixedDecimal::try_from_str(&ascii).map_err(|e| match e {
+            fixed_decimal::ParseError::Syntax => {
+                debug_assert!(false, "invalid ASCII decimal {ascii:?}");
+                DecimalParseError::UnexpectedCharacter { index: start }
+            }
+            _ => DecimalParseError::Limit,
+        })?;
+        Ok((value, start..cursor.index))
+    }
+
+    fn is_lenient(&self) -> bool {
+        self.options.strictness.unwrap_or_default() == ParseStrictness::Lenient
+    }
+
+    /// Returns the ASCII digit for a digit of the numbering system.
+    fn to_ascii_digit(&self, c: char) -> Option<char> {
+        let digits = &self.decimal.digits.get().digits;
+        if let Some(i) = digits.iter().position(|&d| d == c) {
+            return char::from_digit(i as u32, 10);
+        }
+        if self.is_lenient() && c.is_ascii_digit() {
+            return Some(c);
+        }
+        None
+    }
+
+    fn peek_digit(&self, cursor: &Cursor) -> bool {
+        cursor.peek().and_then(|c| self.to_ascii_digit(c)).is_some()
+    }
+
+    fn eat_digit(&self, cursor: &mut Cursor) -> Option<char> {
+        let digit = self.to_ascii_digit(cursor.peek()?)?;
+        cursor.next();
+        Some(digit)
+    }
+
+    /// Consumes the grouping separator, or one of its look-alikes in lenient mode.
+    fn eat_grouping_separator(&self, cursor: &mut Cursor) -> bool {
+        let separator = self.decimal.symbols.get().grouping_separator();
+        if cursor.eat_str(separator) {
+            return true;
+        }
+        if !self.is_lenient() {
+            return false;
+        }
+        let mut chars = separator.chars();
+        let (Some(separator), None) = (chars.next(), chars.next()) else {
+            return false;
+        };
+        for equivalents in [&LENIENT_SPACES[..], &LENIENT_APOSTROPHES[..]] {
+            if equivalents.contains(&separator) {
+                return cursor.eat_char_if(|c| equivalents.contains(&c));
+            }
+        }
+        false
+    }
+
+    /// Consumes a sign affix. In lenient mode, directional marks are ignored, and
+    /// `alternatives` are accepted in place of an affix with a single non-mark character.
+    fn eat_sign(&self, cursor: &mut Cursor, affix: &str, alternatives: &[char]) -> bool {
+        if affix.is_empty() {
+            return false;
+        }
+        if cursor.eat_str(affix) {
+            return true;
+        }
+        if !self.is_lenient() {
+            return false;
+        }
+        let original = cursor.index;
+        let mut affix_chars = affix.chars().filter(|c| !BIDI_MARKS.contains(c)).peekable();
+        let single = affix_chars.clone().count() == 1;
+        cursor.skip_while(|c| BIDI_MARKS.contains(&c));
+        while let Some(expected) = affix_chars.next() {
+            let is_alternative = |c: char| single && alternatives.contains(&c);
+            if !cursor.eat_char_if(|c| c == expected || is_alternative(c)) {
+                cursor.index = original;
+                return false;
+            }
+            if affix_chars.peek().is_some() {
+                cursor.skip_while(|c| BIDI_MARKS.contains(&c));
+            }
+        }
+        // An affix that consists only of marks is never matched.
+        let matched = cursor.index > original
+            && cursor
+                .input
+                .get(original..cursor.index)
+                .is_some_and(|s| s.chars().any(|c| !BIDI_MARKS.contains(&c)));
+        if !matched {
+            cursor.index = original;
+        }
+        matched
+    }
+
+    /// Checks that the grouping separators are where [`DecimalFormatter`] would put them.
+    fn check_grouping(
+        &self,
+        separators: &[(usize, usize)],
+        integer_digits: usize,
+    ) -> Result<(), DecimalParseError> {
+        if separators.is_empty() {
+            return Ok(());
+        }
+        let sizes = self.decimal.symbols.get().grouping_sizes;
+        let upper_magnitude = i16::try_from(integer_digits - 1).unwrap_or(i16::MAX);
+        // The separator follows the digit at this magnitude.
+        let magnitude =
+            |digit_count: usize| i16::try_from(integer_digits - digit_count).unwrap_or(i16::MAX);
+        let matches = |strategy| {
+            // The formatter writes a separator after every digit for which `check` is true.
+            let expected = (1..=upper_magnitude)
+                .rev()
+                .filter(|&m| grouper::check(upper_magnitude, m, strategy, sizes));
+            expected.eq(separators.iter().map(|&(_, count)| magnitude(count)))
+        };
+        if matches(GroupingStrategy::Auto) || matches(GroupingStrategy::Min2) {
+            return Ok(());
+        }
+        let first_misplaced = separators
+            .iter()
+            .find(|&&(_, count)| {
+                !grouper::check(
+                    upper_magnitude,
+                    magnitude(count),
+                    GroupingStrategy::Auto,
+                    sizes,
+                )
+            })
+            .or(separators.first());
+        Err(DecimalParseError::InvalidGrouping {
+            index: first_misplaced.map(|&(index, _)| index).unwrap_or_default(),
+        })
+    }
+}
+
+/// A position in the input string.
+struct Cursor<'a> {
+    input: &'a str,
+    index: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(input: &'a str, index: usize) -> Self {
+        Self { input, index }
+    }
+
+    fn rest(&self) -> &'a str {
+        self.input.get(self.index..).unwrap_or_default()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.index += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat_char_if(&mut self, predicate: impl Fn(char) -> bool) -> bool {
+        match self.peek() {
+            Some(c) if predicate(c) => {
+                self.index += c.len_utf8();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn eat_str(&mut self, s: &str) -> bool {
+        if !s.is_empty() && self.rest().starts_with(s) {
+            self.index += s.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn skip_while(&mut self, predicate: impl Fn(char) -> bool) {
+        while self.eat_char_if(&predicate) {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locale_core::locale;
+    use writeable::assert_writeable_eq;
+
+    fn parser(locale: icu_locale_core::Locale, strictness: ParseStrictness) -> DecimalParser {
+        DecimalParser::try_new((&locale).into(), strictness.into()).unwrap()
+    }
+
+    #[test]
+    fn test_lenient() {
+        let cases = [
+            (locale!("en"), "1,234.56", "1234.56"),
+            (locale!("en"), "  -1,234.5 ", "-1234.5"),
+            (locale!("en"), "+12", "12"),
+            (locale!("en"), "\u{2212}12", "-12"),
+            (locale!("en"), "12,34,5", "12345"),
+            (locale!("en"), "0.000", "0.000"),
+            (locale!("de"), "1.234,56", "1234.56"),
+            (locale!("fr"), "1 234,56", "1234.56"),
+            (locale!("fr"), "1\u{202F}234,56", "1234.56"),
+            (locale!("ar-EG"), "١٢٣٫٤", "123.4"),
+            (locale!("ar-EG"), "\u{61C}-١٬٢٣٤", "-1234"),
+            (locale!("ar-EG"), "-123", "-123"),
+            (locale!("bn"), "১০,০০,০০৭", "1000007"),
+            (locale!("th-u-nu-thai"), "๑,๐๐๐", "1000"),
+        ];
+        for (locale, input, expected) in cases {
+            let parser = parser(locale.clone(), ParseStrictness::Lenient);
+            assert_writeable_eq!(parser.parse(input).unwrap(), expected, "{locale} {input:?}");
+        }
+    }
+
+    #[test]
+    fn test_strict() {
+        let cases = [
+            (locale!("en"), "1,234.56", Ok("1234.56")),
+            (locale!("en"), "1234567", Ok("1234567")),
+            (locale!("en"), "-1,234,567", Ok("-1234567")),
+            (
+                locale!("en"),
+                "12,34,567",
+                Err(DecimalParseError::InvalidGrouping { index: 2 }),
+            ),
+            (
+                locale!("en"),
+                "1234,567",
+                Err(DecimalParseError::InvalidGrouping { index: 4 }),
+            ),
+            (
+                locale!("en"),
+                " 12",
+                Err(DecimalParseError::NoDigits { index: 0 }),
+            ),
+            (
+                locale!("en"),
+                "12 ",
+                Err(DecimalParseError::UnexpectedCharacter { index: 2 }),
+            ),
+            (locale!("bn"), "১০,০০,০০৭", Ok("1000007")),
+            (
+                locale!("bn"),
+                "১,০০০,০০৭",
+                Err(DecimalParseError::InvalidGrouping { index: 3 }),
+            ),
+            (
+                locale!("ar-EG"),
+                "123",
+                Err(DecimalParseError::NoDigits { index: 0 }),
+            ),
+            (
+                locale!("fr"),
+                "1 234",
+                Err(DecimalParseError::UnexpectedCharacter { index: 1 }),
+            ),
+        ];
+        for (locale, input, expected) in cases {
+            let parser = parser(locale.clone(), ParseStrictness::Strict);
+            let actual = parser.parse(input).map(|value| value.to_string());
+            assert_eq!(
+                actual.as_deref().map_err(|e| *e),
+                expected,
+                "{locale} {input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_prefix() {
+        let parser = parser(locale!("en"), ParseStrictness::Lenient);
+        let cases = [
+            ("  12 apples", "12", 2..4),
+            ("1,234, 5", "1234", 0..5),
+            ("3.", "3", 0..1),
+            ("-0.5%", "-0.5", 0..4),
+        ];
+        for (input, expected, span) in cases {
+            let (value, actual_span) = parser.parse_prefix(input).unwrap();
+            assert_writeable_eq!(value, expected, "{input:?}");
+            assert_eq!(actual_span, span, "{input:?}");
+        }
+        assert_eq!(
+            parser.parse_prefix("-x"),
+            Err(DecimalParseError::NoDigits { index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let formatter =
+            DecimalFormatter::try_new(locale!("ar-EG").into(), Default::default()).unwrap();
+        let parser = parser(locale!("ar-EG"), ParseStrictness::Strict);
+        for input in ["-1234567.891", "0", "0.001", "100"] {
+            let value: SignedFixedDecimal = input.parse().unwrap();
+            let formatted = formatter.format_to_string(&value);
+            assert_eq!(parser.parse(&formatted), Ok(value), "{formatted}");
+        }
+    }
+}
@@ -71,3 +71,29 @@ pub const DECIMAL: Part = Part {
     category: "decimal",
     value: "decimal",
 };
+
+/// A [`Part`] used by [`FormattedScientificDecimal`](super::FormattedScientificDecimal)
+/// for the mantissa, including its sign.
+pub const MANTISSA: Part = Part {
+    category: "decimal",
+    value: "mantissa",
+};
+
+/// A [`Part`] used by [`FormattedScientificDecimal`](super::FormattedScientificDecimal)
+/// for the symbol between the mantissa and the exponent, such as `E` or `×10`.
+pub const EXPONENT_SEPARATOR: Part = Part {
+    category: "decimal",
+    value: "exponentSeparator",
+};
+
+/// A [`Part`] used by [`FormattedScientificDecimal`](super::FormattedScientificDecimal).
+pub const EXPONENT_MINUS_SIGN: Part = Part {
+    category: "decimal",
+    value: "exponentMinusSign",
+};
+
+/// A [`Part`] used by [`FormattedScientificDecimal`](super::FormattedScientificDecimal).
+pub const EXPONENT_INTEGER: Part = Part {
+    category: "decimal",
+    value: "exponentInteger",
+};
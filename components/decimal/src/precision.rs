@@ -0,0 +1,317 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Algorithms to apply the precision options to a number before formatting.
+
+use crate::options::DecimalFormatterOptions;
+use crate::options::FractionDigits;
+use crate::options::RoundingPriority;
+use crate::options::SignificantDigits;
+use crate::options::TrailingZeroDisplay;
+use fixed_decimal::RoundingIncrement;
+use fixed_decimal::SignedFixedDecimal;
+use fixed_decimal::SignedRoundingMode;
+use fixed_decimal::UnsignedRoundingMode;
+
+/// Returns whether applying the options can change the number.
+pub fn is_needed(options: &DecimalFormatterOptions) -> bool {
+    fraction_digits(options).is_some()
+        || options.significant_digits.is_some()
+        || options.trailing_zero_display == Some(TrailingZeroDisplay::StripIfInteger)
+}
+
+/// Returns the fraction digits to round to, which default to the ECMA-402 defaults when
+/// only a rounding increment is set.
+fn fraction_digits(options: &DecimalFormatterOptions) -> Option<FractionDigits> {
+    options.fraction_digits.or_else(|| {
+        options
+            .rounding_increment
+            .filter(|&increment| increment != RoundingIncrement::MultiplesOf1)
+            .map(|_| FractionDigits::default())
+    })
+}
+
+/// Rounds to a maximum number of fraction digits and pads to a minimum number of fraction
+/// digits. Returns the rounding magnitude.
+fn round_fraction(
+    value: &mut SignedFixedDecimal,
+    digits: FractionDigits,
+    increment: RoundingIncrement,
+    mode: SignedRoundingMode,
+) -> i16 {
+    let (min, max) = (i16::from(digits.min()), i16::from(digits.max()));
+    value.round_with_mode_and_increment(-max, mode, increment);
+    value.absolute.trim_end();
+    value.absolute.pad_end(-min);
+    -max
+}
+
+/// Rounds to a maximum number of significant digits and pads to a minimum number of
+/// significant digits. Returns the rounding magnitude.
+fn round_significant(
+    value: &mut SignedFixedDecimal,
+    digits: SignificantDigits,
+    mode: SignedRoundingMode,
+) -> i16 {
+    let (min, max) = (i16::from(digits.min()), i16::from(digits.max()));
+    let most_significant = |value: &SignedFixedDecimal| {
+        if value.absolute.is_zero() {
+            0
+        } else {
+            value.absolute.nonzero_magnitude_start()
+        }
+    };
+    value.round_with_mode(most_significant(value).saturating_sub(max - 1), mode);
+    value.absolute.trim_end();
+    // Rounding may have carried into a new most significant digit.
+    let start = most_significant(value);
+    value.absolute.pad_end(start.saturating_sub(min - 1));
+    start.saturating_sub(max - 1)
+}
+
+/// Applies the precision options to `value`.
+pub fn apply(value: &mut SignedFixedDecimal, options: &DecimalFormatterOptions) {
+    let mode = options
+        .rounding_mode
+        .unwrap_or(SignedRoundingMode::Unsigned(
+            UnsignedRoundingMode::HalfExpand,
+        ));
+    let increment = options.rounding_increment.unwrap_or_default();
+    let priority = options.rounding_priority.unwrap_or_default();
+    match (options.significant_digits, fraction_digits(options)) {
+        (None, None) => (),
+        (None, Some(fraction_digits)) => {
+            round_fraction(value, fraction_digits, increment, mode);
+        }
+        (Some(significant_digits), None) => {
+            round_significant(value, significant_digits, mode);
+        }
+        (Some(significant_digits), Some(_)) if priority == RoundingPriority::Auto => {
+            round_significant(value, significant_digits, mode);
+        }
+        (Some(significant_digits), Some(fraction_digits)) => {
+            let mut fraction = value.clone();
+            let fraction_magnitude =
+                round_fraction(&mut fraction, fraction_digits, increment, mode);
+            let significant_magnitude = round_significant(value, significant_digits, mode);
+            let prefer_significant = match priority {
+                RoundingPriority::LessPrecision => significant_magnitude > fraction_magnitude,
+                RoundingPriority::Auto | RoundingPriority::MorePrecision => {
+                    significant_magnitude <= fraction_magnitude
+                }
+            };
+            if !prefer_significant {
+                *value = fraction;
+            }
+        }
+    }
+    if options.trailing_zero_display == Some(TrailingZeroDisplay::StripIfInteger) {
+        value.absolute.trim_end_if_integer();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fixed_decimal::RoundingIncrement;
+    use writeable::assert_writeable_eq;
+
+    #[test]
+    fn test_precision() {
+        #[derive(Debug)]
+        struct TestCase {
+            input: &'static str,
+            options: DecimalFormatterOptions,
+            expected: &'static str,
+        }
+        let fraction = |min, max| DecimalFormatterOptions {
+            fraction_digits: Some(FractionDigits::try_new(min, max).unwrap()),
+            ..Default::default()
+        };
+        let significant = |min, max| DecimalFormatterOptions {
+            significant_digits: Some(SignificantDigits::try_new(min, max).unwrap()),
+            ..Default::default()
+        };
+        let both = |max_fraction, max_significant, priority| DecimalFormatterOptions {
+            fraction_digits: Some(FractionDigits::try_new(None, Some(max_fraction)).unwrap()),
+            significant_digits: Some(
+                SignificantDigits::try_new(None, Some(max_significant)).unwrap(),
+            ),
+            rounding_priority: Some(priority),
+            ..Default::default()
+        };
+        let cases = [
+            TestCase {
+                input: "1.23456",
+                options: Default::default(),
+                expected: "1.23456",
+            },
+            TestCase {
+                input: "1.23456",
+                options: fraction(None, Some(2)),
+                expected: "1.23",
+            },
+            TestCase {
+                input: "1.23456",
+                options: fraction(Some(0), None),
+                expected: "1.235",
+            },
+            TestCase {
+                input: "1.5",
+                options: fraction(Some(3), None),
+                expected: "1.500",
+            },
+            TestCase {
+                input: "1.50000",
+                options: fraction(Some(1), Some(3)),
+                expected: "1.5",
+            },
+            TestCase {
+                input: "-2.5",
+                options: fraction(None, Some(0)),
+                expected: "-3",
+            },
+            TestCase {
+                input: "123456",
+                options: significant(None, Some(2)),
+                expected: "120,000",
+            },
+            TestCase {
+                input: "0.0012345",
+                options: significant(None, Some(3)),
+                expected: "0.00123",
+            },
+            TestCase {
+                input: "9.99",
+                options: significant(Some(2), Some(2)),
+                expected: "10",
+            },
+            TestCase {
+                input: "5",
+                options: significant(Some(3), None),
+                expected: "5.00",
+            },
+            TestCase {
+                input: "0",
+                options: significant(Some(3), None),
+                expected: "0.00",
+            },
+            TestCase {
+                input: "1.23456",
+                options: both(3, 2, RoundingPriority::Auto),
+                expected: "1.2",
+            },
+            TestCase {
+                input: "1.23456",
+                options: both(3, 2, RoundingPriority::MorePrecision),
+                expected: "1.235",
+            },
+            TestCase {
+                input: "1.23456",
+                options: both(3, 2, RoundingPriority::LessPrecision),
+                expected: "1.2",
+            },
+            TestCase {
+                input: "1234.56",
+                options: both(1, 3, RoundingPriority::MorePrecision),
+                expected: "1,234.6",
+            },
+            TestCase {
+                input: "1234.56",
+                options: both(1, 3, RoundingPriority::LessPrecision),
+                expected: "1,230",
+            },
+            TestCase {
+                input: "1.234",
+                options: DecimalFormatterOptions {
+                    rounding_mode: Some(SignedRoundingMode::Floor),
+                    ..fraction(None, Some(1))
+                },
+                expected: "1.2",
+            },
+            TestCase {
+                input: "-1.234",
+                options: DecimalFormatterOptions {
+                    rounding_mode: Some(SignedRoundingMode::Floor),
+                    ..fraction(None, Some(1))
+                },
+                expected: "-1.3",
+            },
+            TestCase {
+                input: "1.23",
+                options: DecimalFormatterOptions {
+                    rounding_increment: Some(RoundingIncrement::MultiplesOf5),
+                    ..fraction(Some(2), Some(2))
+                },
+                expected: "1.25",
+            },
+            TestCase {
+                input: "1.30",
+                options: DecimalFormatterOptions {
+                    rounding_increment: Some(RoundingIncrement::MultiplesOf25),
+                    ..fraction(Some(2), Some(2))
+                },
+                expected: "1.25",
+            },
+            TestCase {
+                input: "5",
+                options: DecimalFormatterOptions {
+                    trailing_zero_display: Some(TrailingZeroDisplay::StripIfInteger),
+                    ..fraction(Some(2), None)
+                },
+                expected: "5",
+            },
+            TestCase {
+                input: "5.1",
+                options: DecimalFormatterOptions {
+                    trailing_zero_display: Some(TrailingZeroDisplay::StripIfInteger),
+                    ..fraction(Some(2), None)
+                },
+                expected: "5.10",
+            },
+        ];
+        for case in cases {
+            let formatter = crate::DecimalFormatter::try_new(Default::default(), case.options)
+                .expect("locale should be present");
+            let input: SignedFixedDecimal = case.input.parse().unwrap();
+            assert_writeable_eq!(formatter.format(&input), case.expected, "{case:?}");
+        }
+    }
+
+    #[test]
+    fn test_invalid_digits() {
+        use crate::options::DigitRangeError;
+
+        assert_eq!(
+            FractionDigits::try_new(Some(4), Some(2)),
+            Err(DigitRangeError::MinimumAboveMaximum)
+        );
+        assert_eq!(
+            FractionDigits::try_new(Some(101), None),
+            Err(DigitRangeError::OutOfRange)
+        );
+        assert_eq!(
+            SignificantDigits::try_new(Some(3), Some(2)),
+            Err(DigitRangeError::MinimumAboveMaximum)
+        );
+        assert_eq!(
+            SignificantDigits::try_new(Some(0), None),
+            Err(DigitRangeError::OutOfRange)
+        );
+        assert_eq!(
+            SignificantDigits::try_new(None, Some(0)),
+            Err(DigitRangeError::OutOfRange)
+        );
+        assert_eq!(
+            SignificantDigits::try_new(None, Some(22)),
+            Err(DigitRangeError::OutOfRange)
+        );
+
+        // A single bound adjusts the default of the other bound.
+        let digits = FractionDigits::try_new(Some(5), None).unwrap();
+        assert_eq!((digits.min(), digits.max()), (5, 5));
+        let digits = SignificantDigits::try_new(None, Some(21)).unwrap();
+        assert_eq!((digits.min(), digits.max()), (1, 21));
+    }
+}
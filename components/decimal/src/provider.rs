@@ -108,11 +108,16 @@ const _: () = {
     make_provider!(Baked);
     impl_decimal_symbols_v2!(Baked);
     impl_decimal_digits_v1!(Baked);
+    impl_decimal_exponent_symbols_v1!(Baked);
 };
 
 #[cfg(feature = "datagen")]
 /// The latest minimum set of markers required by this component.
-pub const MARKERS: &[DataMarkerInfo] = &[DecimalSymbolsV2::INFO, DecimalDigitsV1::INFO];
+pub const MARKERS: &[DataMarkerInfo] = &[
+    DecimalSymbolsV2::INFO,
+    DecimalDigitsV1::INFO,
+    DecimalExponentSymbolsV1::INFO,
+];
 
 /// A collection of settings expressing where to put grouping separators in a decimal number.
 /// For example, `1,000,000` has two grouping separators, positioned along every 3 digits.
@@ -244,6 +249,35 @@ pub struct DecimalDigits {
     pub digits: [char; 10],
 }
 
+/// Symbols required for formatting a number in scientific or engineering notation.
+///
+/// Like [`DecimalSymbols`], this data is keyed by locale and numbering system.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(DecimalExponentSymbolsV1 = "decimal/exponent@1")]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_decimal::provider))]
+pub struct DecimalExponentSymbols<'data> {
+    /// Separator between the mantissa and the exponent, such as `E` in `1.23E4`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub exponential: Cow<'data, str>,
+
+    /// Sign between the mantissa and a power of ten with a superscripted exponent,
+    /// such as `×` in `1.23×10⁴`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub superscripting_exponent: Cow<'data, str>,
+
+    /// Sign to apply to a negative exponent.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub minus_sign: Cow<'data, str>,
+}
+
 impl DecimalSymbols<'_> {
     /// Return (prefix, suffix) for the minus sign
     pub fn minus_sign_affixes(&self) -> (&str, &str) {
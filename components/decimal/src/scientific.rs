@@ -0,0 +1,407 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Formatting decimals in scientific and engineering notation.
+
+use core::fmt::Write;
+
+use crate::format::FormattedDecimal;
+use crate::options::*;
+use crate::parts;
+use crate::provider::*;
+use crate::size_test_macro::size_test;
+use crate::{DecimalFormatter, DecimalFormatterPreferences};
+use alloc::borrow::Cow;
+use alloc::string::String;
+use fixed_decimal::{SignedFixedDecimal, UnsignedFixedDecimal};
+use icu_provider::prelude::*;
+use writeable::Writeable;
+
+size_test!(
+    ScientificDecimalFormatter,
+    scientific_decimal_formatter_size,
+    192
+);
+
+/// Superscript forms of the Latin digits, in order.
+const SUPERSCRIPT_DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+/// A formatter for [`SignedFixedDecimal`] in scientific or engineering notation, such as
+/// `1.23E4` or `1.23×10⁴`.
+///
+/// The mantissa is rendered like [`DecimalFormatter`] would render it. The exponent symbols
+/// come from the locale's number symbols for the resolved numbering system.
+///
+/// The mantissa keeps the fraction digits that are visible in the input, but trailing zeros
+/// in the integer part of the input are not treated as significant. Round the input to
+/// control the number of digits in the mantissa.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::SignedFixedDecimal;
+/// use icu::decimal::ScientificDecimalFormatter;
+/// use icu::locale::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let sdf = ScientificDecimalFormatter::try_new(
+///     locale!("fr").into(),
+///     Default::default(),
+/// )
+/// .expect("locale should be present");
+///
+/// assert_writeable_eq!(sdf.format(&SignedFixedDecimal::from(12300)), "1,23E4");
+///
+/// let value = "0.0001230".parse().unwrap();
+/// assert_writeable_eq!(sdf.format(&value), "1,230E-4");
+/// ```
+///
+/// The numbering system and the exponent symbols follow the locale:
+///
+/// ```
+/// use fixed_decimal::SignedFixedDecimal;
+/// use icu::decimal::ScientificDecimalFormatter;
+/// use icu::locale::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let sdf = ScientificDecimalFormatter::try_new(
+///     locale!("ar-EG").into(),
+///     Default::default(),
+/// )
+/// .expect("locale should be present");
+///
+/// assert_writeable_eq!(sdf.format(&SignedFixedDecimal::from(12300)), "١٫٢٣أس٤");
+/// ```
+#[doc = scientific_decimal_formatter_size!()]
+#[derive(Debug)]
+pub struct ScientificDecimalFormatter {
+    options: ScientificDecimalFormatterOptions,
+    decimal: DecimalFormatter,
+    symbols: DataPayload<DecimalExponentSymbolsV1>,
+}
+
+impl AsRef<ScientificDecimalFormatter> for ScientificDecimalFormatter {
+    fn as_ref(&self) -> &ScientificDecimalFormatter {
+        self
+    }
+}
+
+impl ScientificDecimalFormatter {
+    icu_provider::gen_buffer_data_constructors!(
+        (prefs: DecimalFormatterPreferences, options: ScientificDecimalFormatterOptions) -> error: DataError,
+        /// Creates a new [`ScientificDecimalFormatter`] from compiled data and an options bag.
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<
+        D: DataProvider<DecimalSymbolsV2>
+            + DataProvider<DecimalDigitsV1>
+            + DataProvider<DecimalExponentSymbolsV1>
+            + ?Sized,
+    >(
+        provider: &D,
+        prefs: DecimalFormatterPreferences,
+        options: ScientificDecimalFormatterOptions,
+    ) -> Result<Self, DataError> {
+        let decimal = DecimalFormatter::try_new_unstable(provider, prefs, Default::default())?;
+
+        // Resolve the exponent symbols the same way as the decimal symbols, so that both
+        // come from the same numbering system.
+        let locale = DecimalExponentSymbolsV1::make_locale(prefs.locale_preferences);
+        let provided_nu = prefs.numbering_system.as_ref().map(|s| s.as_str());
+        let symbols = match provided_nu {
+            Some(provided_nu) => provider
+                .load(DataRequest {
+                    id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+                        DataMarkerAttributes::from_str_or_panic(provided_nu),
+                        &locale,
+                    ),
+                    ..Default::default()
+                })
+                .or_else(|_err| {
+                    provider.load(DataRequest {
+                        id: DataIdentifierBorrowed::for_locale(&locale),
+                        ..Default::default()
+                    })
+                })?,
+            None => provider.load(DataRequest {
+                id: DataIdentifierBorrowed::for_locale(&locale),
+                ..Default::default()
+            })?,
+        }
+        .payload;
+
+        Ok(Self {
+            options,
+            decimal,
+            symbols,
+        })
+    }
+
+    /// Formats a [`SignedFixedDecimal`], returning a [`FormattedScientificDecimal`].
+    pub fn format<'l>(&'l self, value: &SignedFixedDecimal) -> FormattedScientificDecimal<'l> {
+        let (mantissa, exponent) = split_exponent(value, self.options.notation.unwrap_or_default());
+        FormattedScientificDecimal {
+            mantissa,
+            exponent,
+            exponent_style: self.options.exponent_style.unwrap_or_default(),
+            options: &self.decimal.options,
+            symbols: self.decimal.symbols.get(),
+            digits: self.decimal.digits.get(),
+            exponent_symbols: self.symbols.get(),
+        }
+    }
+
+    /// Formats a [`SignedFixedDecimal`], returning a [`String`].
+    pub fn format_to_string(&self, value: &SignedFixedDecimal) -> String {
+        self.format(value).write_to_string().into_owned()
+    }
+}
+
+/// Splits `value` into a mantissa and a power of ten according to the notation.
+fn split_exponent(
+    value: &SignedFixedDecimal,
+    notation: ExponentNotation,
+) -> (SignedFixedDecimal, i16) {
+    let mut mantissa = value.clone();
+    if value.absolute.is_zero() {
+        mantissa.absolute.trim_start();
+        return (mantissa, 0);
+    }
+    let start = value.absolute.nonzero_magnitude_start();
+    let exponent = match notation {
+        ExponentNotation::Scientific => start,
+        ExponentNotation::Engineering => {
+            let exponent = i32::from(start).div_euclid(3) * 3;
+            // Next to the lower magnitude limit, use the next larger multiple of 3 instead.
+            i16::try_from(exponent).unwrap_or((exponent + 3) as i16)
+        }
+    };
+    // Shift in two steps, since the negation of `i16::MIN` does not fit into an `i16`.
+    mantissa.multiply_pow10(-(exponent / 2));
+    mantissa.multiply_pow10(-(exponent - exponent / 2));
+    mantissa.absolute.trim_start();
+    if *value.absolute.magnitude_range().start() >= 0 {
+        // Trailing zeros of an integer are not significant.
+        mantissa.absolute.trim_end();
+    }
+    (mantissa, exponent)
+}
+
+/// An intermediate structure returned by [`ScientificDecimalFormatter`].
+/// Use [`Writeable`][Writeable] to render the formatted decimal to a string or buffer.
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::parts;
+/// use icu::decimal::ScientificDecimalFormatter;
+/// use icu::locale::locale;
+/// use writeable::assert_writeable_parts_eq;
+///
+/// let sdf = ScientificDecimalFormatter::try_new(
+///     locale!("en").into(),
+///     Default::default(),
+/// )
+/// .unwrap();
+///
+/// let value = "-0.000123".parse().unwrap();
+///
+/// assert_writeable_parts_eq!(
+///     sdf.format(&value),
+///     "-1.23E-4",
+///     [
+///         (0, 5, parts::MANTISSA),
+///         (0, 1, parts::MINUS_SIGN),
+///         (1, 2, parts::INTEGER),
+///         (2, 3, parts::DECIMAL),
+///         (3, 5, parts::FRACTION),
+///         (5, 6, parts::EXPONENT_SEPARATOR),
+///         (6, 7, parts::EXPONENT_MINUS_SIGN),
+///         (7, 8, parts::EXPONENT_INTEGER),
+///     ]
+/// );
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct FormattedScientificDecimal<'l> {
+    mantissa: SignedFixedDecimal,
+    exponent: i16,
+    exponent_style: ExponentStyle,
+    options: &'l DecimalFormatterOptions,
+    symbols: &'l DecimalSymbols<'l>,
+    digits: &'l DecimalDigits,
+    exponent_symbols: &'l DecimalExponentSymbols<'l>,
+}
+
+impl FormattedScientificDecimal<'_> {
+    /// Writes the digits of the absolute value of the exponent, mapped through `digits`.
+    fn write_exponent_digits<W: Write + ?Sized>(
+        &self,
+        w: &mut W,
+        digits: &[char; 10],
+    ) -> core::fmt::Result {
+        let exponent = UnsignedFixedDecimal::from(self.exponent.unsigned_abs());
+        for m in exponent.magnitude_range().rev() {
+            #[allow(clippy::indexing_slicing)] // digit_at in 0..=9
+            w.write_char(digits[exponent.digit_at(m) as usize])?;
+        }
+        Ok(())
+    }
+}
+
+impl Writeable for FormattedScientificDecimal<'_> {
+    fn write_to_parts<W>(&self, w: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: writeable::PartsWrite + ?Sized,
+    {
+        let digits = &self.digits.digits;
+        let symbols = self.exponent_symbols;
+        w.with_part(parts::MANTISSA, |w| {
+            FormattedDecimal {
+                value: Cow::Borrowed(&self.mantissa),
+                options: self.options,
+                symbols: self.symbols,
+                digits: self.digits,
+            }
+            .write_to_parts(w)
+        })?;
+        // Unicode has superscript forms only for the Latin digits, so other
+        // numbering systems fall back to the exponential symbol.
+        let superscript = self.exponent_style == ExponentStyle::Superscript
+            && digits.iter().copied().eq('0'..='9');
+        if superscript {
+            w.with_part(parts::EXPONENT_SEPARATOR, |w| {
+                w.write_str(&symbols.superscripting_exponent)?;
+                w.write_str("10")
+            })?;
+            if self.exponent < 0 {
+                w.with_part(parts::EXPONENT_MINUS_SIGN, |w| w.write_char('⁻'))?;
+            }
+            w.with_part(parts::EXPONENT_INTEGER, |w| {
+                self.write_exponent_digits(w, &SUPERSCRIPT_DIGITS)
+            })?;
+        } else {
+            w.with_part(parts::EXPONENT_SEPARATOR, |w| {
+                w.write_str(&symbols.exponential)
+            })?;
+            if self.exponent < 0 {
+                w.with_part(parts::EXPONENT_MINUS_SIGN, |w| {
+                    w.write_str(&symbols.minus_sign)
+                })?;
+            }
+            w.with_part(parts::EXPONENT_INTEGER, |w| {
+                self.write_exponent_digits(w, digits)
+            })?;
+        }
+        Ok(())
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedScientificDecimal<'_>);
+
+#[cfg(test)]
+mod tests {
+    use icu_locale_core::locale;
+    use writeable::assert_writeable_eq;
+
+    use crate::options::*;
+    use crate::ScientificDecimalFormatter;
+
+    #[test]
+    pub fn test_notation() {
+        let sci =
+            ScientificDecimalFormatter::try_new(locale!("en").into(), Default::default()).unwrap();
+        let eng = ScientificDecimalFormatter::try_new(
+            locale!("en").into(),
+            ExponentNotation::Engineering.into(),
+        )
+        .unwrap();
+
+        #[derive(Debug)]
+        struct TestCase {
+            pub input: &'static str,
+            pub scientific: &'static str,
+            pub engineering: &'static str,
+        }
+        let cases = [
+            TestCase {
+                input: "0",
+                scientific: "0E0",
+                engineering: "0E0",
+            },
+            TestCase {
+                input: "-0.00",
+                scientific: "-0.00E0",
+                engineering: "-0.00E0",
+            },
+            TestCase {
+                input: "1",
+                scientific: "1E0",
+                engineering: "1E0",
+            },
+            TestCase {
+                input: "12300",
+                scientific: "1.23E4",
+                engineering: "12.3E3",
+            },
+            TestCase {
+                input: "12300.0",
+                scientific: "1.23000E4",
+                engineering: "12.3000E3",
+            },
+            TestCase {
+                input: "1000",
+                scientific: "1E3",
+                engineering: "1E3",
+            },
+            TestCase {
+                input: "123456789",
+                scientific: "1.23456789E8",
+                engineering: "123.456789E6",
+            },
+            TestCase {
+                input: "-0.0123",
+                scientific: "-1.23E-2",
+                engineering: "-12.3E-3",
+            },
+            TestCase {
+                input: "0.00100",
+                scientific: "1.00E-3",
+                engineering: "1.00E-3",
+            },
+            TestCase {
+                input: "1e-20",
+                scientific: "1E-20",
+                engineering: "10E-21",
+            },
+        ];
+        for cas in &cases {
+            let value = cas.input.parse().unwrap();
+            assert_writeable_eq!(sci.format(&value), cas.scientific, "{cas:?}");
+            assert_writeable_eq!(eng.format(&value), cas.engineering, "{cas:?}");
+        }
+    }
+
+    #[test]
+    pub fn test_superscript() {
+        let options = ExponentStyle::Superscript.into();
+        let en = ScientificDecimalFormatter::try_new(locale!("en").into(), options).unwrap();
+        let value = "12300".parse().unwrap();
+        assert_writeable_eq!(en.format(&value), "1.23×10⁴");
+        let value = "-0.000000000012".parse().unwrap();
+        assert_writeable_eq!(en.format(&value), "-1.2×10⁻¹¹");
+
+        // Non-Latin digits have no superscript forms.
+        let ar = ScientificDecimalFormatter::try_new(locale!("ar-EG").into(), options).unwrap();
+        let value = "0.0123".parse().unwrap();
+        assert_writeable_eq!(ar.format(&value), "١٫٢٣أس؜-٢");
+        let ar_exponential =
+            ScientificDecimalFormatter::try_new(locale!("ar-EG").into(), Default::default())
+                .unwrap();
+        assert_eq!(
+            ar.format_to_string(&value),
+            ar_exponential.format_to_string(&value)
+        );
+    }
+}
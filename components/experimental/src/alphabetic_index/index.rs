@@ -0,0 +1,476 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use icu_collator::preferences::CollationType;
+use icu_collator::provider::*;
+use icu_collator::{Collator, CollatorOptions, CollatorPreferences, Strength};
+use icu_collections::codepointinvliststringlist::CodePointInversionListAndStringList;
+use icu_locale::exemplar_chars::{ExemplarCharacters, ExemplarCharactersBorrowed};
+use icu_locale::provider::{ExemplarCharactersIndexV1, ExemplarCharactersMainV1};
+use icu_locale_core::preferences::{define_preferences, prefs_convert};
+use icu_normalizer::provider::{CanonicalDecompositionDataV2, CanonicalDecompositionTablesV1};
+use icu_properties::props::Script;
+use icu_properties::provider::ScriptV1;
+use icu_properties::CodePointMapData;
+use icu_provider::prelude::*;
+
+define_preferences!(
+    /// The preferences for an alphabetic index.
+    [Copy]
+    AlphabeticIndexPreferences,
+    {
+        /// The collation type. This corresponds to the `-u-co` BCP-47 tag.
+        collation_type: CollationType
+    }
+);
+prefs_convert!(AlphabeticIndexPreferences, CollatorPreferences, {
+    collation_type
+});
+
+/// The default label of the underflow, inflow, and overflow buckets.
+const DEFAULT_OTHER_LABEL: &str = "…";
+
+/// The default maximum number of labels, as in ICU4C.
+const DEFAULT_MAX_LABEL_COUNT: usize = 99;
+
+/// The Chinese tailorings have contractions of U+FDD0 with the index
+/// letters that sort before the first Han character of each bucket.
+const CHINESE_INDEX_BOUNDARY_PREFIX: char = '\u{FDD0}';
+
+/// The kind of an index [`Bucket`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BucketKind {
+    /// A bucket for the strings that sort at or after its label.
+    Normal,
+    /// The bucket for the strings that sort before the first label,
+    /// such as digits and punctuation.
+    Underflow,
+    /// A bucket for the strings between the labels of two different
+    /// scripts, that is, the strings in scripts without labels that sort
+    /// between them.
+    Inflow,
+    /// The bucket for the strings that sort after the last label or are in
+    /// a script without labels that sorts after it.
+    Overflow,
+}
+
+/// A bucket of an [`AlphabeticIndex`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bucket {
+    label: String,
+    kind: BucketKind,
+}
+
+impl Bucket {
+    /// The label to display for this bucket.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// The kind of this bucket.
+    pub fn kind(&self) -> BucketKind {
+        self.kind
+    }
+}
+
+/// A builder for an [`AlphabeticIndex`].
+///
+/// The builder starts out with the index characters of a locale. Labels
+/// from other locales can be added with
+/// [`AlphabeticIndexBuilder::add_index_characters()`] to create an index
+/// for a list with names in several scripts.
+///
+/// # Examples
+///
+/// ```
+/// use icu::experimental::alphabetic_index::AlphabeticIndexBuilder;
+/// use icu::locale::exemplar_chars::ExemplarCharacters;
+/// use icu::locale::locale;
+///
+/// let mut builder =
+///     AlphabeticIndexBuilder::try_new(locale!("en").into()).unwrap();
+/// builder.add_index_characters(
+///     ExemplarCharacters::try_new_index(&locale!("ru").into()).unwrap(),
+/// );
+/// let index = builder.build();
+///
+/// let labels: Vec<&str> =
+///     index.buckets().iter().map(|bucket| bucket.label()).collect();
+/// assert_eq!(labels[..4], ["…", "A", "B", "C"]);
+/// assert_eq!(labels[26..30], ["Z", "…", "А", "Б"]);
+///
+/// let bucket = |s| index.buckets()[index.bucket_index(s)].label();
+/// assert_eq!(bucket("Zoe"), "Z");
+/// assert_eq!(bucket("Ξένια"), "…");
+/// assert_eq!(bucket("Юлия"), "Ю");
+/// ```
+#[derive(Debug)]
+pub struct AlphabeticIndexBuilder {
+    collator: Collator,
+    scripts: CodePointMapData<Script>,
+    labels: Vec<String>,
+    max_label_count: usize,
+    underflow_label: String,
+    inflow_label: String,
+    overflow_label: String,
+}
+
+/// Whether `error` means that there is no data for the locale, in which
+/// case the builder falls back to other data, rather than that the data
+/// could not be loaded.
+fn is_missing_data(error: &DataError) -> bool {
+    matches!(
+        error.kind,
+        DataErrorKind::IdentifierNotFound | DataErrorKind::MarkerNotFound
+    )
+}
+
+impl AlphabeticIndexBuilder {
+    /// Creates a builder with the collation and the index characters of the
+    /// given locale, using compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(prefs: AlphabeticIndexPreferences) -> Result<Self, DataError> {
+        let collator = Collator::try_new((&prefs).into(), Self::collator_options())?;
+        let locale = ExemplarCharactersIndexV1::make_locale(prefs.locale_preferences);
+        let mut builder = Self::new_with(
+            collator.static_to_owned(),
+            CodePointMapData::<Script>::new().static_to_owned(),
+        );
+        match ExemplarCharacters::try_new_index(&locale) {
+            Ok(index) if !index.code_points().is_empty() || index.has_strings() => {
+                builder.add_index_characters(index);
+            }
+            Err(e) if !is_missing_data(&e) => return Err(e),
+            _ => match ExemplarCharacters::try_new_main(&locale) {
+                Ok(main) => {
+                    builder.add_main_characters(&main);
+                }
+                Err(e) if !is_missing_data(&e) => return Err(e),
+                Err(_) => {}
+            },
+        }
+        Ok(builder)
+    }
+
+    icu_provider::gen_buffer_data_constructors!(
+        (prefs: AlphabeticIndexPreferences) -> error: DataError,
+        functions: [
+            try_new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        prefs: AlphabeticIndexPreferences,
+    ) -> Result<Self, DataError>
+    where
+        D: DataProvider<CollationSpecialPrimariesV1>
+            + DataProvider<CollationRootV1>
+            + DataProvider<CollationTailoringV1>
+            + DataProvider<CollationDiacriticsV1>
+            + DataProvider<CollationJamoV1>
+            + DataProvider<CollationMetadataV1>
+            + DataProvider<CollationReorderingV1>
+            + DataProvider<CanonicalDecompositionDataV2>
+            + DataProvider<CanonicalDecompositionTablesV1>
+            + DataProvider<ExemplarCharactersIndexV1>
+            + DataProvider<ExemplarCharactersMainV1>
+            + DataProvider<ScriptV1>
+            + ?Sized,
+    {
+        let collator =
+            Collator::try_new_unstable(provider, (&prefs).into(), Self::collator_options())?;
+        let locale = ExemplarCharactersIndexV1::make_locale(prefs.locale_preferences);
+        let mut builder = Self::new_with(
+            collator,
+            CodePointMapData::<Script>::try_new_unstable(provider)?,
+        );
+        match ExemplarCharacters::try_new_index_unstable(provider, &locale) {
+            Ok(index)
+                if !index.as_borrowed().code_points().is_empty()
+                    || index.as_borrowed().has_strings() =>
+            {
+                builder.add_index_characters(index.as_borrowed());
+            }
+            Err(e) if !is_missing_data(&e) => return Err(e),
+            _ => match ExemplarCharacters::try_new_main_unstable(provider, &locale) {
+                Ok(main) => {
+                    builder.add_main_characters(&main.as_borrowed());
+                }
+                Err(e) if !is_missing_data(&e) => return Err(e),
+                Err(_) => {}
+            },
+        }
+        Ok(builder)
+    }
+
+    fn collator_options() -> CollatorOptions {
+        let mut options = CollatorOptions::default();
+        options.strength = Some(Strength::Primary);
+        options
+    }
+
+    fn new_with(collator: Collator, scripts: CodePointMapData<Script>) -> Self {
+        Self {
+            collator,
+            scripts,
+            labels: Vec::new(),
+            max_label_count: DEFAULT_MAX_LABEL_COUNT,
+            underflow_label: DEFAULT_OTHER_LABEL.to_owned(),
+            inflow_label: DEFAULT_OTHER_LABEL.to_owned(),
+            overflow_label: DEFAULT_OTHER_LABEL.to_owned(),
+        }
+    }
+
+    /// Adds the index exemplar characters of a locale as labels.
+    ///
+    /// Labels that are equal at the primary collation strength to a label
+    /// that was added before are ignored.
+    pub fn add_index_characters(&mut self, index: ExemplarCharactersBorrowed<'_>) -> &mut Self {
+        self.labels
+            .extend(index.code_points().iter_chars().map(String::from));
+        self.labels
+            .extend(index.strings().iter().map(ToOwned::to_owned));
+        self
+    }
+
+    /// Adds labels from a locale without index exemplar characters: the
+    /// uppercase forms of its main exemplar letters, as ICU4C does.
+    fn add_main_characters(&mut self, main: &CodePointInversionListAndStringList) {
+        self.labels.extend(
+            main.code_points()
+                .iter_chars()
+                .filter(|c| c.is_alphabetic())
+                .map(|c| c.to_uppercase().collect()),
+        );
+    }
+
+    /// Adds custom labels.
+    ///
+    /// Labels that are equal at the primary collation strength to a label
+    /// that was added before are ignored.
+    pub fn add_labels<'a>(&mut self, labels: impl IntoIterator<Item = &'a str>) -> &mut Self {
+        self.labels
+            .extend(labels.into_iter().map(ToOwned::to_owned));
+        self
+    }
+
+    /// Sets the maximum number of labels. If there are more labels, a
+    /// subset spread evenly over the labels is kept. The default is 99.
+    pub fn set_max_label_count(&mut self, max_label_count: usize) -> &mut Self {
+        self.max_label_count = max_label_count;
+        self
+    }
+
+    /// Sets the label of the underflow bucket. The default is "…".
+    pub fn set_underflow_label(&mut self, label: &str) -> &mut Self {
+        label.clone_into(&mut self.underflow_label);
+        self
+    }
+
+    /// Sets the label of the inflow buckets. The default is "…".
+    pub fn set_inflow_label(&mut self, label: &str) -> &mut Self {
+        label.clone_into(&mut self.inflow_label);
+        self
+    }
+
+    /// Sets the label of the overflow bucket. The default is "…".
+    pub fn set_overflow_label(&mut self, label: &str) -> &mut Self {
+        label.clone_into(&mut self.overflow_label);
+        self
+    }
+
+    /// Creates the index.
+    pub fn build(self) -> AlphabeticIndex {
+        let collator = self.collator.as_borrowed();
+        let scripts = self.scripts.as_borrowed();
+
+        let mut labels: Vec<Boundary> = self
+            .labels
+            .iter()
+            .filter_map(|label| {
+                let first = label.chars().next()?;
+                let mut chinese = String::from(CHINESE_INDEX_BOUNDARY_PREFIX);
+                chinese.push_str(label);
+                let (boundary, script) = if first.is_ascii_uppercase()
+                    && label.len() == 1
+                    && collator.compare(&chinese, "\u{FDD0}") == Ordering::Less
+                {
+                    (chinese, Script::Han)
+                } else {
+                    (label.clone(), script_group(scripts.get(first)))
+                };
+                Some(Boundary {
+                    label: label.clone(),
+                    boundary,
+                    script,
+                    bucket: 0,
+                })
+            })
+            // Ignorable labels would never match.
+            .filter(|label| collator.compare(&label.boundary, "") == Ordering::Greater)
+            .collect();
+        // The sort is stable, so the first label wins among equal ones.
+        labels.sort_by(|a, b| collator.compare(&a.boundary, &b.boundary));
+        labels.dedup_by(|a, b| collator.compare(&a.boundary, &b.boundary) == Ordering::Equal);
+        if labels.len() > self.max_label_count {
+            let len = labels.len();
+            let max = self.max_label_count;
+            let mut i = 0;
+            labels.retain(|_| {
+                // Keep the labels where `i * max / len` increases.
+                let keep = (i * max) % len < max;
+                i += 1;
+                keep
+            });
+        }
+
+        let mut buckets = alloc::vec![Bucket {
+            label: self.underflow_label.clone(),
+            kind: BucketKind::Underflow,
+        }];
+        let mut previous_script = None;
+        for label in labels.iter_mut() {
+            if previous_script.is_some_and(|script| script != label.script) {
+                buckets.push(Bucket {
+                    label: self.inflow_label.clone(),
+                    kind: BucketKind::Inflow,
+                });
+            }
+            previous_script = Some(label.script);
+            label.bucket = buckets.len();
+            buckets.push(Bucket {
+                label: core::mem::take(&mut label.label),
+                kind: BucketKind::Normal,
+            });
+        }
+        buckets.push(Bucket {
+            label: self.overflow_label.clone(),
+            kind: BucketKind::Overflow,
+        });
+
+        AlphabeticIndex {
+            collator: self.collator,
+            scripts: self.scripts,
+            boundaries: labels,
+            buckets,
+        }
+    }
+}
+
+/// Hiragana and Katakana share the Japanese index labels.
+fn script_group(script: Script) -> Script {
+    if script == Script::Katakana {
+        Script::Hiragana
+    } else {
+        script
+    }
+}
+
+#[derive(Debug)]
+struct Boundary {
+    /// The label, moved to the bucket once the buckets are created.
+    label: String,
+    /// The string that sorts first in the bucket.
+    boundary: String,
+    script: Script,
+    /// The index of the bucket.
+    bucket: usize,
+}
+
+/// An index that assigns strings to labeled buckets according to the
+/// collation of a locale, created with an [`AlphabeticIndexBuilder`].
+///
+/// The buckets are in collation order: the underflow bucket, the buckets
+/// for the labels (with an inflow bucket between the labels of different
+/// scripts), and the overflow bucket.
+///
+/// # Examples
+///
+/// ```
+/// use icu::experimental::alphabetic_index::AlphabeticIndexBuilder;
+/// use icu::locale::locale;
+///
+/// let index =
+///     AlphabeticIndexBuilder::try_new(locale!("ja").into()).unwrap().build();
+///
+/// let mut sections = vec![Vec::new(); index.buckets().len()];
+/// for name in ["さくら", "アキラ", "いちろう", "Emma", "かおる"] {
+///     sections[index.bucket_index(name)].push(name);
+/// }
+///
+/// let sections: Vec<_> = index
+///     .buckets()
+///     .iter()
+///     .zip(sections)
+///     .filter(|(_, names)| !names.is_empty())
+///     .map(|(bucket, names)| (bucket.label(), names))
+///     .collect();
+/// assert_eq!(
+///     sections,
+///     [
+///         ("…", vec!["Emma"]),
+///         ("あ", vec!["アキラ", "いちろう"]),
+///         ("か", vec!["かおる"]),
+///         ("さ", vec!["さくら"]),
+///     ]
+/// );
+/// ```
+#[derive(Debug)]
+pub struct AlphabeticIndex {
+    collator: Collator,
+    scripts: CodePointMapData<Script>,
+    boundaries: Vec<Boundary>,
+    buckets: Vec<Bucket>,
+}
+
+impl AlphabeticIndex {
+    /// The buckets of this index, in collation order.
+    pub fn buckets(&self) -> &[Bucket] {
+        &self.buckets
+    }
+
+    /// The index into [`AlphabeticIndex::buckets()`] of the bucket for `s`.
+    pub fn bucket_index(&self, s: &str) -> usize {
+        let collator = self.collator.as_borrowed();
+        let i = self
+            .boundaries
+            .partition_point(|b| collator.compare(&b.boundary, s) != Ordering::Greater);
+        let Some(boundary) = i.checked_sub(1).and_then(|i| self.boundaries.get(i)) else {
+            return 0;
+        };
+        let script = s
+            .chars()
+            .next()
+            .map(|c| script_group(self.scripts.as_borrowed().get(c)))
+            .unwrap_or(Script::Common);
+        if script == boundary.script || script == Script::Common || script == Script::Inherited {
+            boundary.bucket
+        } else {
+            // A string in a script without labels goes to the inflow bucket
+            // after the labels of the preceding script or to the overflow.
+            self.buckets
+                .iter()
+                .enumerate()
+                .skip(boundary.bucket)
+                .find(|(_, bucket)| bucket.kind != BucketKind::Normal)
+                .map(|(i, _)| i)
+                .unwrap_or(self.buckets.len() - 1)
+        }
+    }
+}
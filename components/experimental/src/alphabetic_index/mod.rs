@@ -0,0 +1,23 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Alphabetic index: locale-sensitive section headers for sorted lists
+//!
+//! An alphabetic index assigns strings to labeled buckets, such as the
+//! A–Z headers of a contact list, the あかさたな… headers of a Japanese
+//! list, or the ㄱㄴㄷ… headers of a Korean list. The labels come from the
+//! index exemplar characters of the locale and the bucket boundaries are
+//! determined with the collation of the locale.
+//!
+//! This is modeled after [ICU4C's `AlphabeticIndex`](https://unicode-org.github.io/icu/userguide/collation/customization/indexing.html).
+
+#![warn(missing_docs)]
+
+mod index;
+
+pub use index::AlphabeticIndex;
+pub use index::AlphabeticIndexBuilder;
+pub use index::AlphabeticIndexPreferences;
+pub use index::Bucket;
+pub use index::BucketKind;
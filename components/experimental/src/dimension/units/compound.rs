@@ -0,0 +1,571 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Composes the display names of compound units, such as `kilometer-per-hour` or `square-meter`,
+//! from the display names of their single units and the CLDR `per`, `times`, power, and SI prefix
+//! patterns. See <https://unicode.org/reports/tr35/tr35-general.html#compound-units>.
+
+use alloc::borrow::ToOwned;
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use icu_pattern::{PatternItem, SinglePlaceholderPattern};
+use icu_plurals::{PluralCategory, PluralOperands, PluralRules};
+use icu_provider::prelude::*;
+use smallvec::SmallVec;
+use writeable::{PartsWrite, Writeable};
+
+use super::options::Width;
+use crate::dimension::provider::pattern_key::{PatternKey, PowerValue};
+use crate::dimension::provider::units::{UnitsDisplayName, UnitsDisplayNameV1};
+use crate::dimension::provider::units_essentials::{
+    CompoundCount, UnitsEssentials, UnitsEssentialsV1,
+};
+use crate::measure::measureunit::MeasureUnit;
+use crate::measure::provider::si_prefix::Base;
+use crate::measure::si_prefix::get_si_prefix_name;
+use crate::units::provider::UnitsInfoV1;
+
+/// The display data of a unit.
+#[derive(Debug)]
+pub(crate) enum UnitDisplay {
+    /// A unit that has its own display name in the data, e.g. `meter` or `kilometer-per-hour`.
+    Simple(DataPayload<UnitsDisplayNameV1>),
+
+    /// A unit whose display name is composed from the display names of its single units.
+    Compound(Box<CompoundUnitDisplay>),
+}
+
+/// The display data of a compound unit, e.g. `kilowatt-per-square-second`.
+///
+/// Everything that does not depend on the number is resolved when the formatter is created:
+/// only the plural forms of the last unit of the numerator and of the `perUnitPattern` are
+/// selected when formatting.
+#[derive(Debug)]
+pub(crate) struct CompoundUnitDisplay {
+    /// The power patterns of the last unit of the numerator.
+    essentials: DataPayload<UnitsEssentialsV1>,
+
+    /// The last unit of the numerator, whose plural form agrees with the number, if any.
+    last: Option<LastUnitDisplay>,
+
+    /// The text before and after the numerator: the `per` pattern with the denominator applied,
+    /// e.g. `("", "/s²")`. Empty if there is no denominator or if `per_unit` is used.
+    per: (String, String),
+
+    /// The `perUnitPattern` of the denominator (e.g. `{0}/h`), if the denominator is a single
+    /// unit that has one. It replaces the `per` pattern.
+    per_unit: Option<DataPayload<UnitsDisplayNameV1>>,
+}
+
+/// The last unit of the numerator of a compound unit, e.g. `meter` in `newton-meter`.
+#[derive(Debug)]
+struct LastUnitDisplay {
+    /// The display name of the unit.
+    display_name: DataPayload<UnitsDisplayNameV1>,
+
+    /// The text around the name for the other units of the numerator, joined with the `times`
+    /// pattern, e.g. `("newton-", "")`.
+    times: (String, String),
+
+    /// The text around the name for the SI prefix, e.g. `("kilo", "")`.
+    prefix: (String, String),
+
+    /// The power of the unit, if its absolute value is not 1.
+    power: Option<PowerValue>,
+}
+
+/// The display data of a single unit within a compound unit, e.g. `square-kilometer`.
+#[derive(Debug)]
+struct SingleUnitDisplay {
+    /// The display name of the unit, which includes the SI prefix if the data has such a unit
+    /// (e.g. `kilometer`).
+    display_name: DataPayload<UnitsDisplayNameV1>,
+
+    /// The SI prefix pattern, if the SI prefix is not part of the display name.
+    prefix: Option<PatternKey>,
+
+    /// The power pattern, if the absolute power of the unit is not 1.
+    power: Option<PowerValue>,
+}
+
+impl UnitDisplay {
+    /// Loads the display data of `unit`.
+    ///
+    /// If the data has a display name for the whole unit, it is used as it is.
+    /// Otherwise, the display name is composed from the display names of its single units.
+    pub(crate) fn try_load<P>(
+        provider: &P,
+        locale: &DataLocale,
+        width: Width,
+        unit: &MeasureUnit,
+        plural_rules: &PluralRules,
+    ) -> Result<Self, DataError>
+    where
+        P: ?Sized
+            + DataProvider<UnitsDisplayNameV1>
+            + DataProvider<UnitsEssentialsV1>
+            + DataProvider<UnitsInfoV1>,
+    {
+        // Find the names of the single units, stopping once all of them are known.
+        let units_info = DataProvider::<UnitsInfoV1>::load(provider, Default::default())?.payload;
+        let mut names: SmallVec<[Option<String>; 8]> =
+            unit.contained_units.iter().map(|_| None).collect();
+        let mut missing = names.len();
+        for (name, id) in units_info.get().units_conversion_trie.iter() {
+            if missing == 0 {
+                break;
+            }
+            for (single_unit, slot) in unit.contained_units.iter().zip(names.iter_mut()) {
+                if slot.is_none() && usize::from(single_unit.unit_id) == id {
+                    *slot = Some(name.clone());
+                    missing -= 1;
+                }
+            }
+        }
+
+        let mut single_units = Vec::with_capacity(unit.contained_units.len());
+        for (single_unit, name) in unit.contained_units.iter().zip(names) {
+            let name = name.ok_or_else(|| DataError::custom("the unit is not valid"))?;
+            let prefix = get_si_prefix_name(single_unit.si_prefix);
+            let power = match single_unit.power.unsigned_abs() {
+                1 => None,
+                2 => Some(PowerValue::Two),
+                3 => Some(PowerValue::Three),
+                _ => {
+                    return Err(DataError::custom(
+                        "only the powers 1, 2, and 3 can be formatted",
+                    ))
+                }
+            };
+            single_units.push((single_unit, name, prefix, power));
+        }
+
+        // Reconstruct the identifier of the unit, e.g. `kilometer-per-square-second`.
+        let mut identifier = String::new();
+        for is_numerator in [true, false] {
+            let mut first = true;
+            for (single_unit, name, prefix, power) in &single_units {
+                if (single_unit.power > 0) != is_numerator {
+                    continue;
+                }
+                if !is_numerator && first {
+                    if !identifier.is_empty() {
+                        identifier.push('-');
+                    }
+                    identifier.push_str("per");
+                }
+                if !identifier.is_empty() {
+                    identifier.push('-');
+                }
+                identifier.push_str(&single_identifier(*power, prefix.as_deref(), name));
+                first = false;
+            }
+        }
+
+        if let Some(display_name) = load_display_name(provider, locale, width, &identifier)? {
+            return Ok(Self::Simple(display_name));
+        }
+
+        // A single unit in the denominator may have its own pattern, e.g. `{0}/h` for `per-hour`.
+        let mut denominators = single_units
+            .iter()
+            .filter(|(single_unit, ..)| single_unit.power < 0);
+        let per_unit = match (denominators.next(), denominators.next()) {
+            (Some((_, name, prefix, power)), None) if single_units.len() > 1 => {
+                let per_unit = single_identifier(*power, prefix.as_deref(), name);
+                load_display_name(provider, locale, width, &format!("per-{per_unit}"))?
+            }
+            _ => None,
+        };
+
+        let mut numerator = Vec::new();
+        let mut denominator = Vec::new();
+        for (single_unit, name, prefix, power) in single_units {
+            let prefixed_name = format!("{}{name}", prefix.as_deref().unwrap_or_default());
+            let (display_name, prefix) =
+                match load_display_name(provider, locale, width, &prefixed_name)? {
+                    Some(display_name) => (display_name, None),
+                    None => (
+                        load_display_name(provider, locale, width, &name)?.ok_or_else(|| {
+                            DataError::custom("no display name for the unit")
+                                .with_display_context(&name)
+                        })?,
+                        prefix.map(|_| match single_unit.si_prefix.base {
+                            Base::Decimal => PatternKey::Decimal(single_unit.si_prefix.power),
+                            // The binary prefixes are keyed by their power of 1024.
+                            Base::Binary => {
+                                PatternKey::Binary(single_unit.si_prefix.power.unsigned_abs() / 10)
+                            }
+                        }),
+                    ),
+                };
+            let display = SingleUnitDisplay {
+                display_name,
+                prefix,
+                power,
+            };
+            if single_unit.power > 0 {
+                numerator.push(display);
+            } else {
+                denominator.push(display);
+            }
+        }
+
+        let essentials = provider
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+                    DataMarkerAttributes::from_str_or_panic(width.as_str()),
+                    locale,
+                ),
+                ..Default::default()
+            })?
+            .payload;
+        validate(essentials.get(), numerator.iter().chain(&denominator))?;
+
+        let display =
+            CompoundUnitDisplay::new(essentials, numerator, &denominator, per_unit, plural_rules);
+        Ok(Self::Compound(Box::new(display)))
+    }
+}
+
+/// Returns the identifier of a single unit, e.g. `square-kilometer`.
+fn single_identifier(power: Option<PowerValue>, prefix: Option<&str>, name: &str) -> String {
+    let power = match power {
+        Some(PowerValue::Two) => "square-",
+        Some(PowerValue::Three) => "cubic-",
+        None => "",
+    };
+    format!("{power}{}{name}", prefix.unwrap_or_default())
+}
+
+/// Loads the display name of a unit, returning `None` if the data does not have it.
+fn load_display_name<P>(
+    provider: &P,
+    locale: &DataLocale,
+    width: Width,
+    unit: &str,
+) -> Result<Option<DataPayload<UnitsDisplayNameV1>>, DataError>
+where
+    P: ?Sized + DataProvider<UnitsDisplayNameV1>,
+{
+    let attribute = format!("{}-{unit}", width.as_str());
+    let Ok(attribute) = DataMarkerAttributes::try_from_str(&attribute) else {
+        return Ok(None);
+    };
+    Ok(provider
+        .load(DataRequest {
+            id: DataIdentifierBorrowed::for_marker_attributes_and_locale(attribute, locale),
+            ..Default::default()
+        })
+        .allow_identifier_not_found()?
+        .map(|response| response.payload))
+}
+
+/// Checks that the data has all the patterns needed for formatting `units`.
+fn validate<'a>(
+    essentials: &UnitsEssentials,
+    units: impl Iterator<Item = &'a SingleUnitDisplay>,
+) -> Result<(), DataError> {
+    for unit in units {
+        if let Some(prefix) = unit.prefix {
+            if essentials.prefixes.get(&prefix).is_none() {
+                return Err(DataError::custom("no pattern for the SI prefix"));
+            }
+        }
+        if let Some(power) = unit.power {
+            let key = PatternKey::Power {
+                power,
+                count: CompoundCount::Other,
+            };
+            if essentials.prefixes.get(&key).is_none() {
+                return Err(DataError::custom("no pattern for the power"));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The text around the number in a formatted unit, e.g. `("", " km/h")`.
+#[derive(Debug, PartialEq)]
+pub(crate) struct UnitText<'a> {
+    pub(crate) before: &'a str,
+    pub(crate) after: &'a str,
+}
+
+impl<'a> UnitText<'a> {
+    fn from_pattern(pattern: &'a SinglePlaceholderPattern) -> Self {
+        // A single placeholder pattern has at most one literal on each side of the number.
+        let mut text = Self {
+            before: "",
+            after: "",
+        };
+        let mut seen_placeholder = false;
+        for item in pattern.iter() {
+            match item {
+                PatternItem::Placeholder(_) => seen_placeholder = true,
+                PatternItem::Literal(s) if seen_placeholder => text.after = s,
+                PatternItem::Literal(s) => text.before = s,
+            }
+        }
+        text
+    }
+
+    /// Returns whether the name of the unit comes after the number.
+    fn is_name_after(&self) -> bool {
+        !self.after.trim().is_empty()
+    }
+
+    /// Returns the name of the unit, i.e. the text without the whitespace around the number.
+    fn name(&self) -> &'a str {
+        if self.is_name_after() {
+            self.after.trim()
+        } else {
+            self.before.trim()
+        }
+    }
+
+    /// Returns the name of the unit with its SI prefix and power applied.
+    fn decorated_name(&self, prefix: Option<&str>, power: Option<&str>) -> String {
+        let mut name = self.name().to_owned();
+        if let Some(prefix) = prefix {
+            name = apply(prefix, &name);
+        }
+        if let Some(power) = power {
+            name = apply(power, &name);
+        }
+        name
+    }
+}
+
+/// Splits `text` into its leading whitespace, the rest without whitespace, and its trailing
+/// whitespace.
+fn split_whitespace(text: &str) -> (&str, &str, &str) {
+    let trimmed_start = text.trim_start();
+    let trimmed = trimmed_start.trim_end();
+    (
+        &text[..text.len() - trimmed_start.len()],
+        trimmed,
+        &trimmed_start[trimmed.len()..],
+    )
+}
+
+/// Substitutes `{0}` in a CLDR compound pattern, e.g. `kilo{0}`.
+fn apply(pattern: &str, arg0: &str) -> String {
+    pattern.replacen("{0}", arg0, 1)
+}
+
+/// Substitutes `{0}` and `{1}` in a CLDR compound pattern, e.g. `{0}-{1}`.
+fn apply2(pattern: &str, arg0: &str, arg1: &str) -> String {
+    apply(&pattern.replacen("{1}", arg1, 1), arg0)
+}
+
+/// Splits a CLDR compound pattern around `placeholder`, e.g. `kilo{0}` into `("kilo", "")`.
+fn split_pattern<'a>(pattern: &'a str, placeholder: &str) -> (&'a str, &'a str) {
+    pattern.split_once(placeholder).unwrap_or((pattern, ""))
+}
+
+fn owned((before, after): (&str, &str)) -> (String, String) {
+    (before.to_owned(), after.to_owned())
+}
+
+/// Returns the power pattern, e.g. `{0}²`, for the plural category of a number.
+fn power_pattern<'a>(
+    essentials: &'a UnitsEssentials,
+    power: PowerValue,
+    category: PluralCategory,
+) -> Option<&'a str> {
+    let count = match category {
+        PluralCategory::Zero => CompoundCount::Zero,
+        PluralCategory::One => CompoundCount::One,
+        PluralCategory::Two => CompoundCount::Two,
+        PluralCategory::Few => CompoundCount::Few,
+        PluralCategory::Many => CompoundCount::Many,
+        PluralCategory::Other => CompoundCount::Other,
+    };
+    essentials
+        .prefixes
+        .get(&PatternKey::Power { power, count })
+        .or_else(|| {
+            essentials.prefixes.get(&PatternKey::Power {
+                power,
+                count: CompoundCount::Other,
+            })
+        })
+}
+
+impl CompoundUnitDisplay {
+    /// Resolves the parts of the compound unit that do not depend on the number.
+    fn new(
+        essentials: DataPayload<UnitsEssentialsV1>,
+        mut numerator: Vec<SingleUnitDisplay>,
+        denominator: &[SingleUnitDisplay],
+        per_unit: Option<DataPayload<UnitsDisplayNameV1>>,
+        plural_rules: &PluralRules,
+    ) -> Self {
+        let essentials_ref = essentials.get();
+        // The number agrees with the last unit of the numerator; the other units use their
+        // singular form, as in "newton-meters per second".
+        let singular_names = |units: &[SingleUnitDisplay]| {
+            units
+                .iter()
+                .map(|unit| unit.singular_name(essentials_ref, plural_rules))
+                .reduce(|acc, name| apply2(&essentials_ref.times, &acc, &name))
+        };
+
+        let last = numerator.pop().map(|last| {
+            let times = match singular_names(&numerator) {
+                Some(others) => owned(split_pattern(
+                    &essentials_ref.times.replacen("{0}", &others, 1),
+                    "{1}",
+                )),
+                None => Default::default(),
+            };
+            let prefix = last
+                .prefix
+                .and_then(|key| essentials_ref.prefixes.get(&key))
+                .map(|pattern| owned(split_pattern(pattern, "{0}")))
+                .unwrap_or_default();
+            LastUnitDisplay {
+                display_name: last.display_name,
+                times,
+                prefix,
+                power: last.power,
+            }
+        });
+
+        let per = match singular_names(denominator) {
+            Some(denominator) if per_unit.is_none() => owned(split_pattern(
+                &essentials_ref.per.replacen("{1}", &denominator, 1),
+                "{0}",
+            )),
+            _ => Default::default(),
+        };
+
+        Self {
+            essentials,
+            last,
+            per,
+            per_unit,
+        }
+    }
+
+    /// Writes the unit around `number`, which has the plural operands `operands`.
+    pub(crate) fn write_to_parts<W>(
+        &self,
+        sink: &mut W,
+        number: &impl Writeable,
+        operands: PluralOperands,
+        plural_rules: &PluralRules,
+    ) -> fmt::Result
+    where
+        W: PartsWrite + ?Sized,
+    {
+        let (per_before, per_after) = match &self.per_unit {
+            Some(per_unit) => {
+                let text =
+                    UnitText::from_pattern(per_unit.get().patterns.get(operands, plural_rules));
+                (text.before, text.after)
+            }
+            None => (self.per.0.as_str(), self.per.1.as_str()),
+        };
+        sink.write_str(per_before)?;
+        match &self.last {
+            Some(last) => {
+                let text = UnitText::from_pattern(
+                    last.display_name.get().patterns.get(operands, plural_rules),
+                );
+                let power = last
+                    .power
+                    .and_then(|power| {
+                        power_pattern(
+                            self.essentials.get(),
+                            power,
+                            plural_rules.category_for(operands),
+                        )
+                    })
+                    .map(|pattern| split_pattern(pattern, "{0}"))
+                    .unwrap_or_default();
+                let write_name = |sink: &mut W, side: &str| {
+                    let (leading, name, trailing) = split_whitespace(side);
+                    for part in [
+                        leading,
+                        &last.times.0,
+                        power.0,
+                        &last.prefix.0,
+                        name,
+                        &last.prefix.1,
+                        power.1,
+                        &last.times.1,
+                        trailing,
+                    ] {
+                        sink.write_str(part)?;
+                    }
+                    Ok(())
+                };
+                if text.is_name_after() {
+                    sink.write_str(text.before)?;
+                    number.write_to_parts(sink)?;
+                    write_name(sink, text.after)?;
+                } else {
+                    write_name(sink, text.before)?;
+                    number.write_to_parts(sink)?;
+                    sink.write_str(text.after)?;
+                }
+            }
+            None => number.write_to_parts(sink)?,
+        }
+        sink.write_str(per_after)
+    }
+}
+
+impl SingleUnitDisplay {
+    /// Returns the singular name of the unit with its SI prefix and power applied, e.g.
+    /// `square kilometer`.
+    fn singular_name(&self, essentials: &UnitsEssentials, plural_rules: &PluralRules) -> String {
+        let one = PluralOperands::from(1u8);
+        let display_name: &UnitsDisplayName = self.display_name.get();
+        UnitText::from_pattern(display_name.patterns.get(one, plural_rules)).decorated_name(
+            self.prefix.and_then(|key| essentials.prefixes.get(&key)),
+            self.power
+                .and_then(|power| power_pattern(essentials, power, plural_rules.category_for(one))),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unit_text() {
+        let pattern =
+            SinglePlaceholderPattern::try_from_str("{0} meters", Default::default()).unwrap();
+        let text = UnitText::from_pattern(&pattern);
+        assert_eq!(text.name(), "meters");
+        assert!(text.is_name_after());
+        assert_eq!(
+            text.decorated_name(Some("kilo{0}"), Some("square {0}")),
+            "square kilometers"
+        );
+
+        let pattern = SinglePlaceholderPattern::try_from_str("US${0}", Default::default()).unwrap();
+        let text = UnitText::from_pattern(&pattern);
+        assert_eq!(text.name(), "US$");
+        assert!(!text.is_name_after());
+    }
+
+    #[test]
+    fn test_patterns() {
+        assert_eq!(apply("{0}²", "km"), "km²");
+        assert_eq!(apply2("{0}-{1}", "newton", "meters"), "newton-meters");
+        assert_eq!(apply2("{0} per {1}", "{0} km", "hour"), "{0} km per hour");
+        assert_eq!(split_pattern("kilo{0}", "{0}"), ("kilo", ""));
+        assert_eq!(split_pattern("{0} per hour", "{0}"), ("", " per hour"));
+        assert_eq!(split_whitespace(" km/h "), (" ", "km/h", " "));
+        assert_eq!(split_whitespace("  "), ("  ", "", ""));
+    }
+}
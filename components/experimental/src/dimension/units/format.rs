@@ -4,7 +4,8 @@
 
 //! Experimental.
 
-use crate::dimension::provider::units::UnitsDisplayName;
+use super::compound::UnitDisplay;
+use super::formatter::MixedUnitsFormatter;
 use fixed_decimal::SignedFixedDecimal;
 use icu_decimal::DecimalFormatter;
 use icu_plurals::PluralRules;
@@ -15,7 +16,7 @@ pub struct FormattedUnit<'l> {
     // TODO: review using options and essentials.
     // pub(crate) _options: &'l UnitsFormatterOptions,
     // pub(crate) essential: &'l UnitsEssentials<'l>,
-    pub(crate) display_name: &'l UnitsDisplayName<'l>,
+    pub(crate) display: &'l UnitDisplay,
     pub(crate) decimal_formatter: &'l DecimalFormatter,
     pub(crate) plural_rules: &'l PluralRules,
 }
@@ -25,16 +26,56 @@ impl Writeable for FormattedUnit<'_> {
     where
         W: writeable::PartsWrite + ?Sized,
     {
-        self.display_name
-            .patterns
-            .get(self.value.into(), self.plural_rules)
-            .interpolate((self.decimal_formatter.format(self.value),))
-            .write_to_parts(sink)
+        match self.display {
+            UnitDisplay::Simple(display_name) => display_name
+                .get()
+                .patterns
+                .get(self.value.into(), self.plural_rules)
+                .interpolate((self.decimal_formatter.format(self.value),))
+                .write_to_parts(sink),
+            UnitDisplay::Compound(display) => display.write_to_parts(
+                sink,
+                &self.decimal_formatter.format(self.value),
+                self.value.into(),
+                self.plural_rules,
+            ),
+        }
     }
 }
 
 impl_display_with_writeable!(FormattedUnit<'_>);
 
+/// A value in mixed units, such as `5 ft, 10 in`, formatted by [`MixedUnitsFormatter`].
+pub struct FormattedMixedUnits<'l> {
+    pub(crate) values: &'l [SignedFixedDecimal],
+    pub(crate) formatter: &'l MixedUnitsFormatter,
+}
+
+impl Writeable for FormattedMixedUnits<'_> {
+    fn write_to_parts<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: writeable::PartsWrite + ?Sized,
+    {
+        let units = self
+            .formatter
+            .displays
+            .iter()
+            .zip(self.values)
+            .map(|(display, value)| FormattedUnit {
+                value,
+                display,
+                decimal_formatter: &self.formatter.decimal_formatter,
+                plural_rules: &self.formatter.plural_rules,
+            });
+        self.formatter
+            .list_formatter
+            .format(units)
+            .write_to_parts(sink)
+    }
+}
+
+impl_display_with_writeable!(FormattedMixedUnits<'_>);
+
 #[test]
 fn test_basic() {
     use icu_locale_core::locale;
@@ -96,3 +137,81 @@ fn test_basic() {
         assert_writeable_eq!(fmt.format_fixed_decimal(&value), expected);
     }
 }
+
+#[test]
+fn test_measure_unit() {
+    use icu_locale_core::locale;
+    use writeable::assert_writeable_eq;
+
+    use crate::dimension::units::formatter::UnitsFormatter;
+    use crate::dimension::units::options::{UnitsFormatterOptions, Width};
+    use crate::units::converter_factory::ConverterFactory;
+
+    let long = UnitsFormatterOptions {
+        width: Width::Long,
+        ..Default::default()
+    };
+    let test_cases = [
+        // A unit with its own display name.
+        (
+            locale!("en"),
+            "kilometer-per-hour",
+            "5",
+            Default::default(),
+            "5 km/h",
+        ),
+        // A `perUnitPattern` of the denominator.
+        (
+            locale!("en"),
+            "liter-per-hour",
+            "1",
+            Default::default(),
+            "1 L/h",
+        ),
+        (
+            locale!("en"),
+            "liter-per-hour",
+            "2",
+            long,
+            "2 liters per hour",
+        ),
+        // The `per`, power, and SI prefix patterns.
+        (
+            locale!("en"),
+            "meter-per-square-second",
+            "2",
+            long,
+            "2 meters per second squared",
+        ),
+        (
+            locale!("en"),
+            "kilowatt-per-cubic-meter",
+            "3",
+            Default::default(),
+            "3 kW/m³",
+        ),
+        // The `times` pattern.
+        (
+            locale!("en"),
+            "newton-meter-per-second",
+            "2",
+            long,
+            "2 newton-meters per second",
+        ),
+        (
+            locale!("fr"),
+            "liter-per-hour",
+            "2",
+            long,
+            "2\u{a0}litres par heure",
+        ),
+    ];
+
+    let factory = ConverterFactory::new();
+    for (locale, unit, value, options, expected) in test_cases {
+        let unit = factory.parser().try_from_str(unit).unwrap();
+        let fmt = UnitsFormatter::try_new_for_measure_unit(locale.into(), &unit, options).unwrap();
+        let value = value.parse().unwrap();
+        assert_writeable_eq!(fmt.format_fixed_decimal(&value), expected);
+    }
+}
@@ -7,17 +7,22 @@
 use fixed_decimal::SignedFixedDecimal;
 use icu_decimal::options::DecimalFormatterOptions;
 use icu_decimal::{DecimalFormatter, DecimalFormatterPreferences};
+use icu_list::{ListFormatter, ListFormatterOptions, ListFormatterPreferences, ListLength};
 use icu_locale_core::preferences::{define_preferences, prefs_convert};
 use icu_plurals::{PluralRules, PluralRulesPreferences};
-use icu_provider::DataPayload;
 
-use super::format::FormattedUnit;
+use super::compound::UnitDisplay;
+use super::format::{FormattedMixedUnits, FormattedUnit};
 use super::options::{UnitsFormatterOptions, Width};
 use crate::dimension::provider::units::UnitsDisplayNameV1;
+use crate::dimension::provider::units_essentials::UnitsEssentialsV1;
+use crate::measure::measureunit::MeasureUnit;
+use crate::units::provider::UnitsInfoV1;
 use icu_provider::prelude::*;
 use smallvec::SmallVec;
 
 extern crate alloc;
+use alloc::vec::Vec;
 
 define_preferences!(
     /// The preferences for units formatting.
@@ -34,6 +39,7 @@ prefs_convert!(UnitsFormatterPreferences, DecimalFormatterPreferences, {
     numbering_system
 });
 prefs_convert!(UnitsFormatterPreferences, PluralRulesPreferences);
+prefs_convert!(UnitsFormatterPreferences, ListFormatterPreferences);
 
 /// A formatter for measurement unit values.
 ///
@@ -47,10 +53,8 @@ pub struct UnitsFormatter {
     /// for example: width of the units.
     _options: UnitsFormatterOptions,
 
-    // /// Essential data for the units formatter.
-    // essential: DataPayload<UnitsEssentialsV1>,
-    /// Display name for the units.
-    display_name: DataPayload<UnitsDisplayNameV1>,
+    /// Display data for the units.
+    display: UnitDisplay,
 
     /// A [`DecimalFormatter`] to format the unit value.
     decimal_formatter: DecimalFormatter,
@@ -70,6 +74,16 @@ impl UnitsFormatter {
         ]
     );
 
+    icu_provider::gen_buffer_data_constructors!(
+        (prefs: UnitsFormatterPreferences, unit: &MeasureUnit, options: super::options::UnitsFormatterOptions) -> error: DataError,
+        functions: [
+            try_new_for_measure_unit: skip,
+            try_new_for_measure_unit_with_buffer_provider,
+            try_new_for_measure_unit_unstable,
+            Self
+        ]
+    );
+
     // TODO: Remove this function once we have separate markers for different widths.
     #[inline]
     fn attribute(width: Width, unit: &str) -> SmallVec<[u8; 32]> {
@@ -118,7 +132,7 @@ impl UnitsFormatter {
 
         Ok(Self {
             _options: options,
-            display_name,
+            display: UnitDisplay::Simple(display_name),
             decimal_formatter,
             plural_rules,
         })
@@ -164,7 +178,80 @@ impl UnitsFormatter {
 
         Ok(Self {
             _options: options,
-            display_name,
+            display: UnitDisplay::Simple(display_name),
+            decimal_formatter,
+            plural_rules,
+        })
+    }
+
+    /// Creates a new [`UnitsFormatter`] for any [`MeasureUnit`] from compiled locale data and an
+    /// options bag.
+    ///
+    /// Units without their own display name in the data, such as `kilometer-per-square-second`,
+    /// are displayed by composing the display names of their single units with the locale's
+    /// `per`, `times`, power, and SI prefix patterns. Only the powers 1, 2, and 3 are supported.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new_for_measure_unit(
+        prefs: UnitsFormatterPreferences,
+        unit: &MeasureUnit,
+        options: super::options::UnitsFormatterOptions,
+    ) -> Result<Self, DataError> {
+        let locale = UnitsDisplayNameV1::make_locale(prefs.locale_preferences);
+        let decimal_formatter =
+            DecimalFormatter::try_new((&prefs).into(), DecimalFormatterOptions::default())?;
+
+        let plural_rules = PluralRules::try_new_cardinal((&prefs).into())?;
+
+        let display = UnitDisplay::try_load(
+            &crate::provider::Baked,
+            &locale,
+            options.width,
+            unit,
+            &plural_rules,
+        )?;
+
+        Ok(Self {
+            _options: options,
+            display,
+            decimal_formatter,
+            plural_rules,
+        })
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new_for_measure_unit)]
+    pub fn try_new_for_measure_unit_unstable<D>(
+        provider: &D,
+        prefs: UnitsFormatterPreferences,
+        unit: &MeasureUnit,
+        options: super::options::UnitsFormatterOptions,
+    ) -> Result<Self, DataError>
+    where
+        D: ?Sized
+            + DataProvider<UnitsDisplayNameV1>
+            + DataProvider<UnitsEssentialsV1>
+            + DataProvider<UnitsInfoV1>
+            + DataProvider<icu_decimal::provider::DecimalSymbolsV2>
+            + DataProvider<icu_decimal::provider::DecimalDigitsV1>
+            + DataProvider<icu_plurals::provider::CardinalV1>,
+    {
+        let locale = UnitsDisplayNameV1::make_locale(prefs.locale_preferences);
+        let decimal_formatter = DecimalFormatter::try_new_unstable(
+            provider,
+            (&prefs).into(),
+            DecimalFormatterOptions::default(),
+        )?;
+
+        let plural_rules = PluralRules::try_new_cardinal_unstable(provider, (&prefs).into())?;
+
+        let display = UnitDisplay::try_load(provider, &locale, options.width, unit, &plural_rules)?;
+
+        Ok(Self {
+            _options: options,
+            display,
             decimal_formatter,
             plural_rules,
         })
@@ -174,9 +261,154 @@ impl UnitsFormatter {
     pub fn format_fixed_decimal<'l>(&'l self, value: &'l SignedFixedDecimal) -> FormattedUnit<'l> {
         FormattedUnit {
             value,
-            display_name: self.display_name.get(),
+            display: &self.display,
             decimal_formatter: &self.decimal_formatter,
             plural_rules: &self.plural_rules,
         }
     }
 }
+
+/// Returns the options of the list formatter that joins the components of a mixed unit.
+fn list_formatter_options(width: Width) -> ListFormatterOptions {
+    ListFormatterOptions::default().with_length(match width {
+        Width::Long => ListLength::Wide,
+        Width::Short => ListLength::Short,
+        Width::Narrow => ListLength::Narrow,
+    })
+}
+
+/// A formatter for values in mixed units, such as `5 ft, 10 in` for `foot-and-inch`.
+///
+/// Each component is formatted as by [`UnitsFormatter`], and the components are joined with the
+/// locale's unit list pattern.
+///
+/// The values are typically the output of a
+/// [`MixedUnitsConverter`](crate::units::converter::MixedUnitsConverter).
+pub struct MixedUnitsFormatter {
+    /// Display data for each of the units, from the largest to the smallest.
+    pub(crate) displays: Vec<UnitDisplay>,
+
+    /// A [`DecimalFormatter`] to format the unit values.
+    pub(crate) decimal_formatter: DecimalFormatter,
+
+    /// A [`PluralRules`] to determine the plural category of each unit.
+    pub(crate) plural_rules: PluralRules,
+
+    /// A [`ListFormatter`] to join the formatted units.
+    pub(crate) list_formatter: ListFormatter,
+}
+
+impl MixedUnitsFormatter {
+    icu_provider::gen_buffer_data_constructors!(
+        (prefs: UnitsFormatterPreferences, units: &[MeasureUnit], options: super::options::UnitsFormatterOptions) -> error: DataError,
+        functions: [
+            try_new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self
+        ]
+    );
+
+    /// Creates a new [`MixedUnitsFormatter`] from compiled locale data and an options bag.
+    ///
+    /// `units` are the components of the mixed unit, from the largest to the smallest, as
+    /// returned by [`MeasureUnitParser::try_mixed_from_str`](crate::measure::parser::MeasureUnitParser::try_mixed_from_str).
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        prefs: UnitsFormatterPreferences,
+        units: &[MeasureUnit],
+        options: super::options::UnitsFormatterOptions,
+    ) -> Result<Self, DataError> {
+        let locale = UnitsDisplayNameV1::make_locale(prefs.locale_preferences);
+        let plural_rules = PluralRules::try_new_cardinal((&prefs).into())?;
+        let displays = units
+            .iter()
+            .map(|unit| {
+                UnitDisplay::try_load(
+                    &crate::provider::Baked,
+                    &locale,
+                    options.width,
+                    unit,
+                    &plural_rules,
+                )
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            displays,
+            decimal_formatter: DecimalFormatter::try_new(
+                (&prefs).into(),
+                DecimalFormatterOptions::default(),
+            )?,
+            plural_rules,
+            list_formatter: ListFormatter::try_new_unit(
+                (&prefs).into(),
+                list_formatter_options(options.width),
+            )?,
+        })
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        prefs: UnitsFormatterPreferences,
+        units: &[MeasureUnit],
+        options: super::options::UnitsFormatterOptions,
+    ) -> Result<Self, DataError>
+    where
+        D: ?Sized
+            + DataProvider<UnitsDisplayNameV1>
+            + DataProvider<UnitsEssentialsV1>
+            + DataProvider<UnitsInfoV1>
+            + DataProvider<icu_decimal::provider::DecimalSymbolsV2>
+            + DataProvider<icu_decimal::provider::DecimalDigitsV1>
+            + DataProvider<icu_plurals::provider::CardinalV1>
+            + DataProvider<icu_list::provider::ListUnitV2>,
+    {
+        let locale = UnitsDisplayNameV1::make_locale(prefs.locale_preferences);
+        let plural_rules = PluralRules::try_new_cardinal_unstable(provider, (&prefs).into())?;
+        let displays = units
+            .iter()
+            .map(|unit| {
+                UnitDisplay::try_load(provider, &locale, options.width, unit, &plural_rules)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            displays,
+            decimal_formatter: DecimalFormatter::try_new_unstable(
+                provider,
+                (&prefs).into(),
+                DecimalFormatterOptions::default(),
+            )?,
+            plural_rules,
+            list_formatter: ListFormatter::try_new_unit_unstable(
+                provider,
+                (&prefs).into(),
+                list_formatter_options(options.width),
+            )?,
+        })
+    }
+
+    /// Formats the values of the components of the mixed unit, from the largest to the smallest.
+    ///
+    /// `values` must contain exactly one value per unit, as in the output of
+    /// [`MixedUnitsConverter::convert`](crate::units::converter::MixedUnitsConverter::convert).
+    /// This is checked in debug builds; in release builds, extra values or extra units are
+    /// ignored.
+    pub fn format<'l>(&'l self, values: &'l [SignedFixedDecimal]) -> FormattedMixedUnits<'l> {
+        debug_assert_eq!(
+            values.len(),
+            self.displays.len(),
+            "one value is needed for each unit"
+        );
+        FormattedMixedUnits {
+            values,
+            formatter: self,
+        }
+    }
+}
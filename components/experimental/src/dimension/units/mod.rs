@@ -4,6 +4,7 @@
 
 //! Experimental.
 
+mod compound;
 pub mod format;
 pub mod formatter;
 pub mod options;
@@ -44,6 +44,17 @@ pub enum Width {
     Narrow,
 }
 
+impl Width {
+    /// Returns the data marker attribute of the width, e.g. `short`.
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Width::Long => "long",
+            Width::Short => "short",
+            Width::Narrow => "narrow",
+        }
+    }
+}
+
 impl From<Width> for tinystr::TinyStr8 {
     fn from(width: Width) -> Self {
         match width {
@@ -17,6 +17,7 @@
 
 extern crate alloc;
 
+pub mod alphabetic_index;
 pub mod compactdecimal;
 pub mod dimension;
 pub mod displaynames;
@@ -88,6 +89,7 @@ pub mod provider {
         impl_short_year_relative_v1!(Baked);
         impl_transliterator_rules_v1!(Baked);
         impl_units_info_v1!(Baked);
+        impl_units_preferences_v1!(Baked);
         impl_units_trie_v1!(Baked);
     };
 
@@ -143,5 +145,6 @@ pub mod provider {
         super::relativetime::provider::ShortYearRelativeV1::INFO,
         super::transliterate::provider::TransliteratorRulesV1::INFO,
         super::units::provider::UnitsInfoV1::INFO,
+        super::units::provider::UnitsPreferencesV1::INFO,
     ];
 }
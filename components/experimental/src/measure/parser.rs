@@ -156,4 +156,18 @@ impl<'data> MeasureUnitParser<'data> {
             contained_units: measure_unit_items.into(),
         })
     }
+
+    /// Parses a CLDR mixed unit identifier and returns its units, from the first to the last one.
+    /// Examples include: `foot-and-inch`, `hour-and-minute-and-second`, and also single units such as `meter`.
+    /// Returns:
+    ///    - Ok(units) if the identifier and all of its units are valid.
+    ///    - Err(InvalidUnitError) if the identifier is invalid.
+    pub fn try_mixed_from_str(&self, s: &str) -> Result<Vec<MeasureUnit>, InvalidUnitError> {
+        s.split("-and-")
+            .map(|unit| match unit {
+                "" => Err(InvalidUnitError),
+                unit => self.try_from_str(unit),
+            })
+            .collect()
+    }
 }
@@ -2,6 +2,7 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use alloc::string::String;
 use zerotrie::ZeroTrieSimpleAscii;
 
 use super::provider::si_prefix::{Base, SiPrefix};
@@ -136,3 +137,23 @@ pub fn get_si_prefix(part: &[u8]) -> (SiPrefix, &[u8]) {
         part,
     )
 }
+
+/// Returns the name of the SI prefix (e.g. `kilo` or `kibi`), or `None` if there is no prefix.
+pub(crate) fn get_si_prefix_name(si_prefix: SiPrefix) -> Option<String> {
+    fn find(mut prefixes: impl Iterator<Item = (String, usize)>, value: usize) -> Option<String> {
+        prefixes
+            .find(|(_, prefix_value)| *prefix_value == value)
+            .map(|(name, _)| name)
+    }
+
+    if si_prefix.power == 0 {
+        return None;
+    }
+    match si_prefix.base {
+        Base::Decimal => find(
+            DECIMAL_PREFIXES_TRIE.iter(),
+            (si_prefix.power as i16 + SI_PREFIXES_OFFSET as i16) as usize,
+        ),
+        Base::Binary => find(BINARY_TRIE.iter(), si_prefix.power as usize),
+    }
+}
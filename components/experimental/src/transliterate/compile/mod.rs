@@ -45,6 +45,43 @@ impl Direction {
     }
 }
 
+/// Converts a compound transform ID, such as `"[:Latin:]; NFD; Latin-ASCII"`, to the equivalent
+/// transform rules, i.e., `"::[:Latin:]; ::NFD; ::Latin-ASCII;"`.
+pub(crate) fn compound_id_to_source(id: &str) -> String {
+    let mut source = String::new();
+    let mut element_start = 0;
+    let mut set_depth = 0usize;
+    let mut in_quote = false;
+    let mut chars = id.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            // escaped characters never separate elements
+            '\\' => {
+                chars.next();
+            }
+            '\'' => in_quote = !in_quote,
+            '[' if !in_quote => set_depth += 1,
+            ']' if !in_quote => set_depth = set_depth.saturating_sub(1),
+            ';' if !in_quote && set_depth == 0 => {
+                push_compound_element(&mut source, &id[element_start..i]);
+                element_start = i + 1;
+            }
+            _ => (),
+        }
+    }
+    push_compound_element(&mut source, &id[element_start..]);
+    source
+}
+
+fn push_compound_element(source: &mut String, element: &str) {
+    let element = element.trim();
+    if !element.is_empty() {
+        source.push_str("::");
+        source.push_str(element);
+        source.push_str(";\n");
+    }
+}
+
 #[derive(Debug, Default)]
 /// A collection of transliteration rules.
 ///
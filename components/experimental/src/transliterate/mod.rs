@@ -28,7 +28,7 @@ mod compile;
 #[allow(clippy::indexing_slicing, clippy::unwrap_used)] // TODO(#3958): Remove.
 mod transliterator;
 
-pub use transliterator::{CustomTransliterator, Transliterator};
+pub use transliterator::{CustomTransliterator, IncrementalTransliteration, Transliterator};
 
 pub use compile::RuleCollection;
 pub use compile::RuleCollectionProvider;
@@ -0,0 +1,264 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module resolves legacy transform IDs, such as `Latin-Cyrillic`, to the BCP-47 IDs that
+//! are used to load transliterators from data.
+//!
+//! Compiled data only contains registered aliases for the transliterators that reference each
+//! other, so IDs coming from users at runtime are resolved algorithmically instead, following
+//! the conventions of [UTS #35](https://unicode.org/reports/tr35/tr35-general.html#Transforms).
+
+use super::Filter;
+use alloc::format;
+use alloc::string::String;
+use icu_collections::codepointinvlist::CodePointInversionListBuilder;
+use icu_locale_core::{subtags, LanguageIdentifier, Locale};
+use icu_properties::props::Script;
+use icu_properties::provider::{ScriptNameToValueV2, ScriptV1, ScriptValueToShortNameV1};
+use icu_properties::{CodePointMapData, PropertyNamesShort, PropertyParser};
+use icu_provider::prelude::*;
+
+/// The result of resolving a legacy ID.
+#[derive(Debug)]
+pub(super) enum ResolvedId {
+    /// The transliterator with the given BCP-47 ID.
+    Bcp47(Locale),
+    /// An `Any-{Script}` transliterator, i.e., every available transliterator from another
+    /// script to the given one, each applied to the runs of its source script.
+    AnyTo(subtags::Script),
+}
+
+/// A source or target of a legacy ID, e.g., `Latin` or `de`.
+enum Name<'a> {
+    Any,
+    Script(subtags::Script),
+    Language(LanguageIdentifier),
+    Other(&'a str),
+}
+
+#[derive(Debug)]
+pub(super) struct LegacyIdResolver {
+    script_parser: PropertyParser<Script>,
+    script_names: PropertyNamesShort<Script>,
+    scripts: CodePointMapData<Script>,
+}
+
+impl LegacyIdResolver {
+    pub(super) fn try_new_unstable<P>(provider: &P) -> Result<Self, DataError>
+    where
+        P: DataProvider<ScriptNameToValueV2>
+            + DataProvider<ScriptValueToShortNameV1>
+            + DataProvider<ScriptV1>
+            + ?Sized,
+    {
+        Ok(Self {
+            script_parser: PropertyParser::try_new_unstable(provider)?,
+            script_names: PropertyNamesShort::try_new_unstable(provider)?,
+            scripts: CodePointMapData::try_new_unstable(provider)?,
+        })
+    }
+
+    /// Resolves a lowercase legacy ID of the form `source-target/variant`.
+    ///
+    /// Scripts map to script subtags, languages to language identifiers, and anything else
+    /// to the `d0` (target) and `s0` (source) transform fields. The variant becomes the `m0`
+    /// (mechanism) field. For example, `latin-ascii` resolves to `und-t-und-latn-d0-ascii`,
+    /// and `de-ascii` to `de-t-de-d0-ascii`.
+    pub(super) fn resolve(&self, id: &str) -> Option<ResolvedId> {
+        let (id, variant) = match id.split_once('/') {
+            Some((id, variant)) => (id, Some(variant)),
+            None => (id, None),
+        };
+        let (source, target) = id.split_once('-')?;
+        let source = self.classify(source);
+        let target = self.classify(target);
+
+        if let (Name::Any, Name::Script(script), None) = (&source, &target, variant) {
+            return Some(ResolvedId::AnyTo(*script));
+        }
+
+        let mut main = match &source {
+            Name::Language(language) => language.clone(),
+            _ => LanguageIdentifier::default(),
+        };
+        let mut d0 = None;
+        match target {
+            Name::Script(script) => main.script = Some(script),
+            Name::Language(language) => main = language,
+            Name::Other(other) => d0 = Some(other),
+            Name::Any => return None,
+        }
+        let mut s0 = None;
+        let tlang = match source {
+            Name::Script(script) => Some(format!("und-{script}")),
+            Name::Language(language) => Some(language.to_string()),
+            Name::Other(other) => {
+                s0 = Some(other);
+                None
+            }
+            Name::Any => None,
+        };
+
+        let mut tfields = String::new();
+        for (key, value) in [("d0", d0), ("m0", variant), ("s0", s0)] {
+            if let Some(value) = value {
+                tfields.push_str(&format!("-{key}-{value}"));
+            }
+        }
+        let bcp47 = match tlang {
+            Some(tlang) => format!("{main}-t-{tlang}{tfields}"),
+            None if !tfields.is_empty() => format!("{main}-t{tfields}"),
+            None => return None,
+        };
+        Locale::try_from_str(&bcp47).ok().map(ResolvedId::Bcp47)
+    }
+
+    fn classify<'a>(&self, name: &'a str) -> Name<'a> {
+        if name == "any" {
+            return Name::Any;
+        }
+        if let Some(script) = self
+            .script_parser
+            .as_borrowed()
+            .get_loose(name)
+            .and_then(|script| self.script_names.as_borrowed().get_locale_script(script))
+        {
+            return Name::Script(script);
+        }
+        // only consider names starting with a 2 or 3 letter language subtag, so that
+        // targets such as `ASCII` are not mistaken for languages.
+        let language_len = name.find(['_', '-']).unwrap_or(name.len());
+        if (2..=3).contains(&language_len) {
+            if let Ok(language) = LanguageIdentifier::try_from_str(&name.replace('_', "-")) {
+                return Name::Language(language);
+            }
+        }
+        Name::Other(name)
+    }
+
+    /// Returns the scripts other than `target` that transliterators to `target` can convert
+    /// from, together with the BCP-47 IDs of these transliterators.
+    pub(super) fn sources_for(
+        &self,
+        target: subtags::Script,
+    ) -> impl Iterator<Item = (Script, Locale)> + '_ {
+        Script::ALL_VALUES
+            .iter()
+            .copied()
+            .filter(|&script| {
+                !matches!(script, Script::Common | Script::Inherited | Script::Unknown)
+            })
+            .filter_map(move |script| {
+                let source = self.script_names.as_borrowed().get_locale_script(script)?;
+                if source == target {
+                    return None;
+                }
+                let locale = Locale::try_from_str(&format!("und-{target}-t-und-{source}")).ok()?;
+                Some((script, locale))
+            })
+    }
+
+    /// Returns the characters that belong to runs of the given script, which also includes
+    /// inherited characters such as combining marks.
+    pub(super) fn script_filter(&self, script: Script) -> Filter<'static> {
+        let mut builder = CodePointInversionListBuilder::new();
+        for script in [script, Script::Inherited] {
+            builder.add_set(
+                &self
+                    .scripts
+                    .as_borrowed()
+                    .get_set_for_value(script)
+                    .to_code_point_inversion_list(),
+            );
+        }
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locale_core::subtags::script;
+
+    fn resolve(id: &str) -> Option<String> {
+        let resolver = LegacyIdResolver::try_new_unstable(&icu_properties::provider::Baked)
+            .expect("compiled data");
+        resolver.resolve(id).map(|resolved| match resolved {
+            ResolvedId::Bcp47(locale) => locale.to_string(),
+            ResolvedId::AnyTo(script) => format!("any-{script}"),
+        })
+    }
+
+    #[test]
+    fn test_scripts() {
+        assert_eq!(
+            resolve("latin-cyrillic").as_deref(),
+            Some("und-Cyrl-t-und-latn")
+        );
+        // Script aliases and short names resolve to the same script subtags.
+        assert_eq!(resolve("latn-cyrl").as_deref(), Some("und-Cyrl-t-und-latn"));
+        assert_eq!(resolve("han-latin").as_deref(), Some("und-Latn-t-und-hani"));
+        assert_eq!(resolve("hani-latn").as_deref(), Some("und-Latn-t-und-hani"));
+    }
+
+    #[test]
+    fn test_inverse() {
+        assert_eq!(
+            resolve("cyrillic-latin").as_deref(),
+            Some("und-Latn-t-und-cyrl")
+        );
+        assert_eq!(
+            resolve("ascii-latin").as_deref(),
+            Some("und-Latn-t-s0-ascii")
+        );
+        assert_eq!(
+            resolve("latin-ascii").as_deref(),
+            Some("und-t-und-latn-d0-ascii")
+        );
+        assert_eq!(resolve("el-latin").as_deref(), Some("el-Latn-t-el"));
+        assert_eq!(resolve("latin-el").as_deref(), Some("el-t-und-latn"));
+    }
+
+    #[test]
+    fn test_variants() {
+        assert_eq!(
+            resolve("greek-latin/ungegn").as_deref(),
+            Some("und-Latn-t-und-grek-m0-ungegn")
+        );
+        assert_eq!(resolve("de-ascii").as_deref(), Some("de-t-de-d0-ascii"));
+        assert_eq!(
+            resolve("any-latin/bgn").as_deref(),
+            Some("und-Latn-t-m0-bgn")
+        );
+    }
+
+    #[test]
+    fn test_any() {
+        assert_eq!(resolve("any-latin").as_deref(), Some("any-Latn"));
+        assert!(matches!(
+            LegacyIdResolver::try_new_unstable(&icu_properties::provider::Baked)
+                .unwrap()
+                .resolve("any-greek"),
+            Some(ResolvedId::AnyTo(s)) if s == script!("Grek")
+        ));
+        assert_eq!(resolve("latin-any"), None);
+        assert_eq!(resolve("any-any"), None);
+    }
+
+    #[test]
+    fn test_unknown() {
+        assert_eq!(resolve("latin"), None);
+        assert_eq!(resolve(""), None);
+        // Names that are neither scripts nor languages become transform fields.
+        assert_eq!(resolve("foo-bar"), Some("bar-t-foo".into()));
+        assert_eq!(
+            resolve("fwidth-hwidth").as_deref(),
+            Some("und-t-d0-hwidth-s0-fwidth")
+        );
+        // Names that are not valid subtags need a registered alias.
+        assert_eq!(resolve("fullwidth-halfwidth"), None);
+        assert_eq!(resolve("latin-not_a_subtag"), None);
+        assert_eq!(resolve("foo-!!"), None);
+    }
+}
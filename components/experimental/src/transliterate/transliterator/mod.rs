@@ -3,12 +3,16 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 mod hardcoded;
+mod legacy_id;
 #[allow(clippy::indexing_slicing, clippy::unwrap_used)] // TODO(#3958): Remove.
 mod replaceable;
 
+use crate::transliterate::compile::compound_id_to_source;
 use crate::transliterate::provider::{FunctionCall, Rule, RuleULE, SimpleId, VarTable};
 use crate::transliterate::provider::{RuleBasedTransliterator, Segment, TransliteratorRulesV1};
 use crate::transliterate::transliterator::hardcoded::Case;
+use crate::transliterate::transliterator::legacy_id::{LegacyIdResolver, ResolvedId};
+use crate::transliterate::RuleCollection;
 use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::string::{String, ToString};
@@ -19,7 +23,8 @@ use icu_collections::codepointinvlist::CodePointInversionList;
 use icu_collections::codepointinvliststringlist::CodePointInversionListAndStringList;
 use icu_locale_core::Locale;
 use icu_normalizer::provider::*;
-use icu_normalizer::{ComposingNormalizer, DecomposingNormalizer};
+use icu_normalizer::{ComposingNormalizer, DecomposingNormalizer, StreamingNormalizer};
+use icu_properties::provider::*;
 use icu_provider::prelude::*;
 use litemap::LiteMap;
 use replaceable::*;
@@ -73,13 +78,18 @@ impl ComposingTransliterator {
         Ok(Self(inner))
     }
 
-    fn transliterate(&self, mut rep: Replaceable, _env: &Env) {
+    fn transliterate(&self, mut rep: Replaceable, _env: &Env) -> usize {
+        if rep.is_incremental() {
+            return normalize_incremental(rep, self.0.as_borrowed().streaming());
+        }
+
         // would be cool to use `normalize_to` and pass Insertable, but we need to know the
         // input string, which gets replaced by the normalized string.
 
         if let Cow::Owned(buf) = self.0.as_borrowed().normalize(rep.as_str_modifiable()) {
             rep.replace_modifiable_with_str(&buf);
         } // else the input was already normalized, so no need to modify `rep`
+        rep.frozen_suffix_len()
     }
 }
 
@@ -110,16 +120,40 @@ impl DecomposingTransliterator {
         Ok(Self(inner))
     }
 
-    fn transliterate(&self, mut rep: Replaceable, _env: &Env) {
+    fn transliterate(&self, mut rep: Replaceable, _env: &Env) -> usize {
+        if rep.is_incremental() {
+            return normalize_incremental(rep, self.0.as_borrowed().streaming());
+        }
+
         // would be cool to use `normalize_to` and pass Insertable, but we need to know the
         // input string, which gets replaced by the normalized string.
 
         if let Cow::Owned(buf) = self.0.as_borrowed().normalize(rep.as_str_modifiable()) {
             rep.replace_modifiable_with_str(&buf);
         } // else the input was already normalized, so no need to modify `rep`
+        rep.frozen_suffix_len()
     }
 }
 
+/// Normalizes the modifiable range of `rep` up to its last normalization boundary, and returns
+/// the pending length. Text after the last boundary could still change with more input.
+fn normalize_incremental(mut rep: Replaceable, mut stream: StreamingNormalizer) -> usize {
+    let mut normalized = String::new();
+    let mut boundary = 0;
+    // The streaming normalizer writes output exactly when the pushed character starts a new
+    // normalization segment, so pushing one character at a time reveals the last boundary.
+    for (i, c) in rep.as_str_modifiable().char_indices() {
+        let len = normalized.len();
+        // writing to a `String` is infallible
+        let _ = stream.push_to(c.encode_utf8(&mut [0; 4]).as_bytes(), &mut normalized);
+        if normalized.len() != len {
+            boundary = i;
+        }
+    }
+    rep.replace_modifiable_prefix_with_str(boundary, &normalized);
+    rep.pending_len()
+}
+
 #[derive(Debug)]
 enum InternalTransliterator {
     RuleBased(DataPayload<TransliteratorRulesV1>),
@@ -129,23 +163,40 @@ enum InternalTransliterator {
     Null,
     Remove,
     Dyn(Box<dyn CustomTransliterator>),
+    /// Transliterators that are each applied to the runs of their filter, in sequence. Used for
+    /// `Any-{Script}` IDs, where each filter is the set of characters of a source script.
+    Any(Vec<(Filter<'static>, InternalTransliterator)>),
 }
 
 impl InternalTransliterator {
-    fn transliterate(&self, mut rep: Replaceable, env: &Env) {
+    /// Transliterates the modifiable range of `rep`, and returns the length of the suffix of
+    /// its text that is pending. See [`Replaceable::pending_len`].
+    fn transliterate(&self, mut rep: Replaceable, env: &Env) -> usize {
+        // transliterators that do not look at context commit everything
+        let committed_all = rep.frozen_suffix_len();
         match self {
-            Self::RuleBased(rbt) => rbt.get().transliterate(rep, env),
+            Self::RuleBased(rbt) => return rbt.get().transliterate(rep, env),
             // TODO(#3910): internal hardcoded transliterators
-            Self::Composing(t) => t.transliterate(rep, env),
-            Self::Decomposing(t) => t.transliterate(rep, env),
+            Self::Composing(t) => return t.transliterate(rep, env),
+            Self::Decomposing(t) => return t.transliterate(rep, env),
             Self::Hex(t) => t.transliterate(rep),
             Self::Null => (),
             Self::Remove => rep.replace_modifiable_with_str(""),
+            // custom transliterators cannot tell us what might change, so they commit everything
             Self::Dyn(custom) => {
                 let replacement = custom.transliterate(rep.as_str(), rep.allowed_range());
                 rep.replace_modifiable_with_str(&replacement)
             }
+            Self::Any(transliterators) => {
+                let mut pending_len = committed_all;
+                for (filter, inner) in transliterators {
+                    pending_len =
+                        transliterate_filtered(rep.child_until(pending_len), filter, inner, env);
+                }
+                return pending_len;
+            }
         }
+        committed_all
     }
 }
 
@@ -413,6 +464,360 @@ impl Transliterator {
         })
     }
 
+    /// Construct a [`Transliterator`] from a compound transform ID.
+    ///
+    /// A compound ID is a `;`-separated list of transforms, each of which can have a filter, and
+    /// which can start with a global filter, e.g., `"[:Latin:]; NFD; [:Nonspacing Mark:] Remove"`.
+    /// Transforms use legacy IDs of the form `source-target/variant`, which are resolved to the
+    /// BCP-47 IDs of the data according to [UTS #35](https://unicode.org/reports/tr35/tr35-general.html#Transforms),
+    /// e.g., `Latin-ASCII` to `und-t-und-latn-d0-ascii`. An `Any-{Script}` transform, such as
+    /// `Any-Latin`, applies every available transliterator to that script to the runs of its
+    /// source script.
+    ///
+    /// See [`Transliterator::try_new_inverse_from_id`] for the inverse direction.
+    ///
+    /// # Examples
+    /// ```
+    /// use icu::experimental::transliterate::Transliterator;
+    ///
+    /// let t =
+    ///     Transliterator::try_new_from_id("NFD; [:Nonspacing Mark:] Remove; NFC")
+    ///         .unwrap();
+    /// let output = t.transliterate("Übermäßig".to_string());
+    ///
+    /// assert_eq!(output, "Ubermaßig");
+    /// ```
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new_from_id(id: &str) -> Result<Self, DataError> {
+        Self::try_new_from_id_unstable(
+            &crate::provider::Baked,
+            &icu_normalizer::provider::Baked,
+            &icu_properties::provider::Baked,
+            id,
+        )
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new_from_id)]
+    pub fn try_new_from_id_unstable<PT, PN, PP>(
+        transliterator_provider: &PT,
+        normalizer_provider: &PN,
+        properties_provider: &PP,
+        id: &str,
+    ) -> Result<Self, DataError>
+    where
+        PT: DataProvider<TransliteratorRulesV1> + ?Sized,
+        PN: DataProvider<CanonicalDecompositionDataV2>
+            + DataProvider<CompatibilityDecompositionDataV2>
+            + DataProvider<CanonicalDecompositionTablesV1>
+            + DataProvider<CompatibilityDecompositionTablesV1>
+            + DataProvider<CanonicalCompositionsV1>
+            + ?Sized,
+        PP: ?Sized
+            + DataProvider<AsciiHexDigitV1>
+            + DataProvider<AlphabeticV1>
+            + DataProvider<BidiControlV1>
+            + DataProvider<BidiMirroredV1>
+            + DataProvider<CanonicalCombiningClassV1>
+            + DataProvider<CanonicalCombiningClassNameToValueV2>
+            + DataProvider<CaseIgnorableV1>
+            + DataProvider<CasedV1>
+            + DataProvider<ChangesWhenCasefoldedV1>
+            + DataProvider<ChangesWhenCasemappedV1>
+            + DataProvider<ChangesWhenLowercasedV1>
+            + DataProvider<ChangesWhenNfkcCasefoldedV1>
+            + DataProvider<ChangesWhenTitlecasedV1>
+            + DataProvider<ChangesWhenUppercasedV1>
+            + DataProvider<DashV1>
+            + DataProvider<DefaultIgnorableCodePointV1>
+            + DataProvider<DeprecatedV1>
+            + DataProvider<DiacriticV1>
+            + DataProvider<EmojiV1>
+            + DataProvider<EmojiComponentV1>
+            + DataProvider<EmojiModifierV1>
+            + DataProvider<EmojiModifierBaseV1>
+            + DataProvider<EmojiPresentationV1>
+            + DataProvider<ExtendedPictographicV1>
+            + DataProvider<ExtenderV1>
+            + DataProvider<GraphemeBaseV1>
+            + DataProvider<GraphemeClusterBreakV1>
+            + DataProvider<GraphemeClusterBreakNameToValueV2>
+            + DataProvider<GraphemeExtendV1>
+            + DataProvider<HexDigitV1>
+            + DataProvider<IdsBinaryOperatorV1>
+            + DataProvider<IdsTrinaryOperatorV1>
+            + DataProvider<IdContinueV1>
+            + DataProvider<IdStartV1>
+            + DataProvider<IdeographicV1>
+            + DataProvider<JoinControlV1>
+            + DataProvider<LogicalOrderExceptionV1>
+            + DataProvider<LowercaseV1>
+            + DataProvider<MathV1>
+            + DataProvider<NoncharacterCodePointV1>
+            + DataProvider<PatternSyntaxV1>
+            + DataProvider<PatternWhiteSpaceV1>
+            + DataProvider<QuotationMarkV1>
+            + DataProvider<RadicalV1>
+            + DataProvider<RegionalIndicatorV1>
+            + DataProvider<SentenceBreakV1>
+            + DataProvider<SentenceBreakNameToValueV2>
+            + DataProvider<SentenceTerminalV1>
+            + DataProvider<SoftDottedV1>
+            + DataProvider<TerminalPunctuationV1>
+            + DataProvider<UnifiedIdeographV1>
+            + DataProvider<UppercaseV1>
+            + DataProvider<VariationSelectorV1>
+            + DataProvider<WhiteSpaceV1>
+            + DataProvider<WordBreakV1>
+            + DataProvider<WordBreakNameToValueV2>
+            + DataProvider<XidContinueV1>
+            + DataProvider<GeneralCategoryMaskNameToValueV2>
+            + DataProvider<GeneralCategoryV1>
+            + DataProvider<ScriptNameToValueV2>
+            + DataProvider<ScriptV1>
+            + DataProvider<ScriptValueToShortNameV1>
+            + DataProvider<ScriptWithExtensionsPropertyV1>
+            + DataProvider<XidStartV1>,
+    {
+        Self::internal_try_new_from_id_unstable(
+            id,
+            false,
+            transliterator_provider,
+            normalizer_provider,
+            properties_provider,
+        )
+    }
+
+    /// Construct a [`Transliterator`] for the inverse of a compound transform ID.
+    ///
+    /// The inverse reverses the order of the transforms, and replaces each one by its inverse,
+    /// e.g., `Latin-Cyrillic` by `Cyrillic-Latin`, and `NFD` by `NFC`. A global filter of the
+    /// form `([set])` only applies in the inverse direction. See [`Transliterator::try_new_from_id`]
+    /// for the syntax of compound IDs.
+    ///
+    /// # Examples
+    /// ```
+    /// use icu::experimental::transliterate::Transliterator;
+    ///
+    /// let t = Transliterator::try_new_inverse_from_id("NFD").unwrap();
+    /// let output = t.transliterate("a\u{308}".to_string());
+    ///
+    /// assert_eq!(output, "ä");
+    /// ```
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new_inverse_from_id(id: &str) -> Result<Self, DataError> {
+        Self::try_new_inverse_from_id_unstable(
+            &crate::provider::Baked,
+            &icu_normalizer::provider::Baked,
+            &icu_properties::provider::Baked,
+            id,
+        )
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new_inverse_from_id)]
+    pub fn try_new_inverse_from_id_unstable<PT, PN, PP>(
+        transliterator_provider: &PT,
+        normalizer_provider: &PN,
+        properties_provider: &PP,
+        id: &str,
+    ) -> Result<Self, DataError>
+    where
+        PT: DataProvider<TransliteratorRulesV1> + ?Sized,
+        PN: DataProvider<CanonicalDecompositionDataV2>
+            + DataProvider<CompatibilityDecompositionDataV2>
+            + DataProvider<CanonicalDecompositionTablesV1>
+            + DataProvider<CompatibilityDecompositionTablesV1>
+            + DataProvider<CanonicalCompositionsV1>
+            + ?Sized,
+        PP: ?Sized
+            + DataProvider<AsciiHexDigitV1>
+            + DataProvider<AlphabeticV1>
+            + DataProvider<BidiControlV1>
+            + DataProvider<BidiMirroredV1>
+            + DataProvider<CanonicalCombiningClassV1>
+            + DataProvider<CanonicalCombiningClassNameToValueV2>
+            + DataProvider<CaseIgnorableV1>
+            + DataProvider<CasedV1>
+            + DataProvider<ChangesWhenCasefoldedV1>
+            + DataProvider<ChangesWhenCasemappedV1>
+            + DataProvider<ChangesWhenLowercasedV1>
+            + DataProvider<ChangesWhenNfkcCasefoldedV1>
+            + DataProvider<ChangesWhenTitlecasedV1>
+            + DataProvider<ChangesWhenUppercasedV1>
+            + DataProvider<DashV1>
+            + DataProvider<DefaultIgnorableCodePointV1>
+            + DataProvider<DeprecatedV1>
+            + DataProvider<DiacriticV1>
+            + DataProvider<EmojiV1>
+            + DataProvider<EmojiComponentV1>
+            + DataProvider<EmojiModifierV1>
+            + DataProvider<EmojiModifierBaseV1>
+            + DataProvider<EmojiPresentationV1>
+            + DataProvider<ExtendedPictographicV1>
+            + DataProvider<ExtenderV1>
+            + DataProvider<GraphemeBaseV1>
+            + DataProvider<GraphemeClusterBreakV1>
+            + DataProvider<GraphemeClusterBreakNameToValueV2>
+            + DataProvider<GraphemeExtendV1>
+            + DataProvider<HexDigitV1>
+            + DataProvider<IdsBinaryOperatorV1>
+            + DataProvider<IdsTrinaryOperatorV1>
+            + DataProvider<IdContinueV1>
+            + DataProvider<IdStartV1>
+            + DataProvider<IdeographicV1>
+            + DataProvider<JoinControlV1>
+            + DataProvider<LogicalOrderExceptionV1>
+            + DataProvider<LowercaseV1>
+            + DataProvider<MathV1>
+            + DataProvider<NoncharacterCodePointV1>
+            + DataProvider<PatternSyntaxV1>
+            + DataProvider<PatternWhiteSpaceV1>
+            + DataProvider<QuotationMarkV1>
+            + DataProvider<RadicalV1>
+            + DataProvider<RegionalIndicatorV1>
+            + DataProvider<SentenceBreakV1>
+            + DataProvider<SentenceBreakNameToValueV2>
+            + DataProvider<SentenceTerminalV1>
+            + DataProvider<SoftDottedV1>
+            + DataProvider<TerminalPunctuationV1>
+            + DataProvider<UnifiedIdeographV1>
+            + DataProvider<UppercaseV1>
+            + DataProvider<VariationSelectorV1>
+            + DataProvider<WhiteSpaceV1>
+            + DataProvider<WordBreakV1>
+            + DataProvider<WordBreakNameToValueV2>
+            + DataProvider<XidContinueV1>
+            + DataProvider<GeneralCategoryMaskNameToValueV2>
+            + DataProvider<GeneralCategoryV1>
+            + DataProvider<ScriptNameToValueV2>
+            + DataProvider<ScriptV1>
+            + DataProvider<ScriptValueToShortNameV1>
+            + DataProvider<ScriptWithExtensionsPropertyV1>
+            + DataProvider<XidStartV1>,
+    {
+        Self::internal_try_new_from_id_unstable(
+            id,
+            true,
+            transliterator_provider,
+            normalizer_provider,
+            properties_provider,
+        )
+    }
+
+    fn internal_try_new_from_id_unstable<PT, PN, PP>(
+        id: &str,
+        reverse: bool,
+        transliterator_provider: &PT,
+        normalizer_provider: &PN,
+        properties_provider: &PP,
+    ) -> Result<Self, DataError>
+    where
+        PT: DataProvider<TransliteratorRulesV1> + ?Sized,
+        PN: DataProvider<CanonicalDecompositionDataV2>
+            + DataProvider<CompatibilityDecompositionDataV2>
+            + DataProvider<CanonicalDecompositionTablesV1>
+            + DataProvider<CompatibilityDecompositionTablesV1>
+            + DataProvider<CanonicalCompositionsV1>
+            + ?Sized,
+        PP: ?Sized
+            + DataProvider<AsciiHexDigitV1>
+            + DataProvider<AlphabeticV1>
+            + DataProvider<BidiControlV1>
+            + DataProvider<BidiMirroredV1>
+            + DataProvider<CanonicalCombiningClassV1>
+            + DataProvider<CanonicalCombiningClassNameToValueV2>
+            + DataProvider<CaseIgnorableV1>
+            + DataProvider<CasedV1>
+            + DataProvider<ChangesWhenCasefoldedV1>
+            + DataProvider<ChangesWhenCasemappedV1>
+            + DataProvider<ChangesWhenLowercasedV1>
+            + DataProvider<ChangesWhenNfkcCasefoldedV1>
+            + DataProvider<ChangesWhenTitlecasedV1>
+            + DataProvider<ChangesWhenUppercasedV1>
+            + DataProvider<DashV1>
+            + DataProvider<DefaultIgnorableCodePointV1>
+            + DataProvider<DeprecatedV1>
+            + DataProvider<DiacriticV1>
+            + DataProvider<EmojiV1>
+            + DataProvider<EmojiComponentV1>
+            + DataProvider<EmojiModifierV1>
+            + DataProvider<EmojiModifierBaseV1>
+            + DataProvider<EmojiPresentationV1>
+            + DataProvider<ExtendedPictographicV1>
+            + DataProvider<ExtenderV1>
+            + DataProvider<GraphemeBaseV1>
+            + DataProvider<GraphemeClusterBreakV1>
+            + DataProvider<GraphemeClusterBreakNameToValueV2>
+            + DataProvider<GraphemeExtendV1>
+            + DataProvider<HexDigitV1>
+            + DataProvider<IdsBinaryOperatorV1>
+            + DataProvider<IdsTrinaryOperatorV1>
+            + DataProvider<IdContinueV1>
+            + DataProvider<IdStartV1>
+            + DataProvider<IdeographicV1>
+            + DataProvider<JoinControlV1>
+            + DataProvider<LogicalOrderExceptionV1>
+            + DataProvider<LowercaseV1>
+            + DataProvider<MathV1>
+            + DataProvider<NoncharacterCodePointV1>
+            + DataProvider<PatternSyntaxV1>
+            + DataProvider<PatternWhiteSpaceV1>
+            + DataProvider<QuotationMarkV1>
+            + DataProvider<RadicalV1>
+            + DataProvider<RegionalIndicatorV1>
+            + DataProvider<SentenceBreakV1>
+            + DataProvider<SentenceBreakNameToValueV2>
+            + DataProvider<SentenceTerminalV1>
+            + DataProvider<SoftDottedV1>
+            + DataProvider<TerminalPunctuationV1>
+            + DataProvider<UnifiedIdeographV1>
+            + DataProvider<UppercaseV1>
+            + DataProvider<VariationSelectorV1>
+            + DataProvider<WhiteSpaceV1>
+            + DataProvider<WordBreakV1>
+            + DataProvider<WordBreakNameToValueV2>
+            + DataProvider<XidContinueV1>
+            + DataProvider<GeneralCategoryMaskNameToValueV2>
+            + DataProvider<GeneralCategoryV1>
+            + DataProvider<ScriptNameToValueV2>
+            + DataProvider<ScriptV1>
+            + DataProvider<ScriptValueToShortNameV1>
+            + DataProvider<ScriptWithExtensionsPropertyV1>
+            + DataProvider<XidStartV1>,
+    {
+        // The compound ID is compiled as the rules of an otherwise empty collection, so the
+        // transforms it references are resolved when loading the dependencies below.
+        let mut collection = RuleCollection::default();
+        #[allow(clippy::unwrap_used)] // valid locale
+        let compound_id: Locale = "und-x-compound".parse().unwrap();
+        collection.register_source(&compound_id, compound_id_to_source(id), [], reverse, true);
+        let transliterator = collection
+            .as_provider_unstable(properties_provider, normalizer_provider)?
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_marker_attributes(
+                    #[allow(clippy::unwrap_used)] // valid marker attributes
+                    DataMarkerAttributes::try_from_str("und-x-compound").unwrap(),
+                ),
+                ..Default::default()
+            })?
+            .payload;
+
+        let legacy_ids = LegacyIdResolver::try_new_unstable(properties_provider)?;
+        let mut env = LiteMap::new();
+        Transliterator::load_deps(
+            &transliterator,
+            None::<&fn(&Locale) -> Option<Result<Box<dyn CustomTransliterator>, DataError>>>,
+            transliterator_provider,
+            normalizer_provider,
+            Some(&legacy_ids),
+            &mut env,
+        )?;
+
+        Ok(Transliterator {
+            transliterator,
+            env,
+        })
+    }
+
     fn load_rbt<PT, PN, F>(
         marker_attributes: &DataMarkerAttributes,
         lookup: Option<&F>,
@@ -441,6 +846,39 @@ impl Transliterator {
         }
         // Avoid recursive load
         env.insert(marker_attributes.to_string(), InternalTransliterator::Null);
+        Transliterator::load_deps(
+            &transliterator,
+            lookup,
+            transliterator_provider,
+            normalizer_provider,
+            None,
+            env,
+        )?;
+        Ok(transliterator)
+    }
+
+    /// Loads the dependencies of `transliterator` into `env`.
+    ///
+    /// If `legacy_ids` is given, dependencies on legacy IDs without a registered alias are
+    /// resolved at runtime. This is only the case for transliterators compiled from IDs.
+    fn load_deps<PT, PN, F>(
+        transliterator: &DataPayload<TransliteratorRulesV1>,
+        lookup: Option<&F>,
+        transliterator_provider: &PT,
+        normalizer_provider: &PN,
+        legacy_ids: Option<&LegacyIdResolver>,
+        env: &mut LiteMap<String, InternalTransliterator>,
+    ) -> Result<(), DataError>
+    where
+        PT: DataProvider<TransliteratorRulesV1> + ?Sized,
+        PN: DataProvider<CanonicalDecompositionDataV2>
+            + DataProvider<CompatibilityDecompositionDataV2>
+            + DataProvider<CanonicalDecompositionTablesV1>
+            + DataProvider<CompatibilityDecompositionTablesV1>
+            + DataProvider<CanonicalCompositionsV1>
+            + ?Sized,
+        F: Fn(&Locale) -> Option<Result<Box<dyn CustomTransliterator>, DataError>>,
+    {
         for dep in transliterator.get().deps() {
             if !env.contains_key(&*dep) {
                 // Load the transliterator, by checking
@@ -449,7 +887,19 @@ impl Transliterator {
                     Transliterator::load_special(&dep, normalizer_provider)
                     // b) the user-provided override
                     .or_else(|| Some(lookup?(&dep.parse().ok()?)?.map(InternalTransliterator::Dyn)))
-                    // c) the data
+                    // c) legacy IDs that are resolved at runtime
+                    .or_else(|| {
+                        Some(Transliterator::load_legacy(
+                            legacy_ids?.resolve(dep.strip_prefix("x-")?),
+                            &dep,
+                            lookup,
+                            transliterator_provider,
+                            normalizer_provider,
+                            legacy_ids?,
+                            env,
+                        ))
+                    })
+                    // d) the data
                     .unwrap_or_else(|| {
                         Transliterator::load_rbt(
                             #[allow(clippy::unwrap_used)] // infallible
@@ -464,7 +914,60 @@ impl Transliterator {
                 env.insert(dep.to_string(), internal_t);
             }
         }
-        Ok(transliterator)
+        Ok(())
+    }
+
+    fn load_legacy<PT, PN, F>(
+        resolved: Option<ResolvedId>,
+        id: &str,
+        lookup: Option<&F>,
+        transliterator_provider: &PT,
+        normalizer_provider: &PN,
+        legacy_ids: &LegacyIdResolver,
+        env: &mut LiteMap<String, InternalTransliterator>,
+    ) -> Result<InternalTransliterator, DataError>
+    where
+        PT: DataProvider<TransliteratorRulesV1> + ?Sized,
+        PN: DataProvider<CanonicalDecompositionDataV2>
+            + DataProvider<CompatibilityDecompositionDataV2>
+            + DataProvider<CanonicalDecompositionTablesV1>
+            + DataProvider<CompatibilityDecompositionTablesV1>
+            + DataProvider<CanonicalCompositionsV1>
+            + ?Sized,
+        F: Fn(&Locale) -> Option<Result<Box<dyn CustomTransliterator>, DataError>>,
+    {
+        let load = |locale: &Locale, env: &mut LiteMap<String, InternalTransliterator>| {
+            if let Some(custom) = lookup.and_then(|lookup| lookup(locale)) {
+                return custom.map(InternalTransliterator::Dyn);
+            }
+            Transliterator::load_rbt(
+                #[allow(clippy::unwrap_used)] // infallible
+                DataMarkerAttributes::try_from_str(&locale.to_string().to_ascii_lowercase())
+                    .unwrap(),
+                lookup,
+                transliterator_provider,
+                normalizer_provider,
+                false,
+                env,
+            )
+            .map(InternalTransliterator::RuleBased)
+        };
+        match resolved {
+            Some(ResolvedId::Bcp47(locale)) => load(&locale, env),
+            Some(ResolvedId::AnyTo(target)) => {
+                let mut transliterators = Vec::new();
+                for (script, locale) in legacy_ids.sources_for(target) {
+                    match load(&locale, env) {
+                        Ok(t) => transliterators.push((legacy_ids.script_filter(script), t)),
+                        // not every script has a transliterator to the target
+                        Err(e) if e.kind == DataErrorKind::IdentifierNotFound => (),
+                        Err(e) => return Err(e),
+                    }
+                }
+                Ok(InternalTransliterator::Any(transliterators))
+            }
+            None => Err(DataError::custom("unknown transliterator ID").with_display_context(id)),
+        }
     }
 
     fn load_special<P>(
@@ -533,6 +1036,122 @@ impl Transliterator {
         self.transliterator.get().transliterate(rep, &self.env);
         buffer.into_string()
     }
+
+    /// Appends `input` to the text of `state` and transliterates it incrementally.
+    ///
+    /// This is meant for transliterating text while it is being typed, such as in an input method.
+    /// Only text whose transliteration cannot change with more input is committed, the rest stays
+    /// pending in `state` until the next call. Once the input is complete, call
+    /// [`Transliterator::finish_incremental`] to commit the pending text.
+    ///
+    /// As in ICU4C/J, the pending text may already be partially transliterated by the first steps
+    /// of a compound transliterator, and the committed text is used as context for later calls.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::experimental::transliterate::{
+    ///     IncrementalTransliteration, RuleCollection, Transliterator,
+    /// };
+    ///
+    /// let mut collection = RuleCollection::default();
+    /// collection.register_source(
+    ///     &"und-t-und-x0-keys".parse().unwrap(),
+    ///     "ng > ŋ; n > ɲ; a > ɑ;".to_string(),
+    ///     [],
+    ///     false,
+    ///     true,
+    /// );
+    /// let provider = collection.as_provider();
+    /// let t = Transliterator::try_new_unstable(
+    ///     &provider,
+    ///     &provider,
+    ///     &"und-t-und-x0-keys".parse().unwrap(),
+    /// )
+    /// .unwrap();
+    ///
+    /// let mut state = IncrementalTransliteration::new();
+    ///
+    /// t.transliterate_incremental(&mut state, "an");
+    /// // "n" might still become "ŋ"
+    /// assert_eq!(state.committed(), "ɑ");
+    /// assert_eq!(state.pending(), "n");
+    ///
+    /// t.transliterate_incremental(&mut state, "a");
+    /// assert_eq!(state.committed(), "ɑɲɑ");
+    /// assert_eq!(state.pending(), "");
+    ///
+    /// t.transliterate_incremental(&mut state, "n");
+    /// t.finish_incremental(&mut state);
+    /// assert_eq!(state.committed(), "ɑɲɑɲ");
+    /// ```
+    pub fn transliterate_incremental(&self, state: &mut IncrementalTransliteration, input: &str) {
+        state.text.push_str(input);
+        self.transliterate_pending(state, true);
+    }
+
+    /// Transliterates and commits all pending text of `state`.
+    ///
+    /// See [`Transliterator::transliterate_incremental`].
+    pub fn finish_incremental(&self, state: &mut IncrementalTransliteration) {
+        self.transliterate_pending(state, false);
+    }
+
+    fn transliterate_pending(&self, state: &mut IncrementalTransliteration, incremental: bool) {
+        let mut buffer = TransliteratorBuffer::from_string(core::mem::take(&mut state.text));
+        let rep = Replaceable::new_incremental(&mut buffer, state.committed_len, incremental);
+        let pending_len = self.transliterator.get().transliterate(rep, &self.env);
+        state.text = buffer.into_string();
+        state.committed_len = state.text.len() - pending_len;
+    }
+}
+
+/// The text of an incremental transliteration with [`Transliterator::transliterate_incremental`].
+///
+/// The text consists of a committed prefix, which will not change anymore, followed by
+/// pending text, which may still change when more input is appended.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IncrementalTransliteration {
+    text: String,
+    // Invariant: a valid UTF-8 index into `text`
+    committed_len: usize,
+}
+
+impl IncrementalTransliteration {
+    /// Creates an empty [`IncrementalTransliteration`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the full text, consisting of the committed and the pending text.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the range of [`as_str`](Self::as_str) that is committed.
+    pub fn committed_range(&self) -> Range<usize> {
+        0..self.committed_len
+    }
+
+    /// Returns the range of [`as_str`](Self::as_str) that is pending.
+    pub fn pending_range(&self) -> Range<usize> {
+        self.committed_len..self.text.len()
+    }
+
+    /// Returns the committed text.
+    pub fn committed(&self) -> &str {
+        &self.text[self.committed_range()]
+    }
+
+    /// Returns the pending text.
+    pub fn pending(&self) -> &str {
+        &self.text[self.pending_range()]
+    }
+
+    /// Returns the full text, consisting of the committed and the pending text.
+    pub fn into_string(self) -> String {
+        self.text
+    }
 }
 
 impl RuleBasedTransliterator<'_> {
@@ -540,40 +1159,70 @@ impl RuleBasedTransliterator<'_> {
     ///  1. Split the input modifiable range of the Replaceable according into runs according to self.filter
     ///  2. Transliterate each run in sequence
     ///      i. Transliterate the first id_group, then the first rule_group, then the second id_group, etc.
-    fn transliterate(&self, mut rep: Replaceable, env: &Env) {
+    ///
+    /// In incremental transliteration, each step of the last run only transliterates what the
+    /// previous step committed, like in ICU4C/J.
+    fn transliterate(&self, mut rep: Replaceable, env: &Env) -> usize {
         // assumes the cursor is at the right position.
 
+        let mut pending_len = rep.frozen_suffix_len();
         rep.for_each_run(&self.filter, |run| {
             // eprintln!("got RBT filtered_run: {run:?}");
+            let mut run_pending_len = run.frozen_suffix_len();
             for (id_group, rule_group) in self.id_group_list.iter().zip(self.rule_group_list.iter())
             {
                 // first handle id_group
                 for single_id in id_group.iter() {
                     let id = SimpleId::zero_from(single_id);
-                    id.transliterate(run.child(), env);
+                    run_pending_len = id.transliterate(run.child_until(run_pending_len), env);
                 }
 
                 // then handle rule_group
                 let rule_group = RuleGroup::from(rule_group);
-                rule_group.transliterate(run.child(), &self.variable_table, env);
+                run_pending_len = rule_group.transliterate(
+                    run.child_until(run_pending_len),
+                    &self.variable_table,
+                    env,
+                );
+            }
+            if run.is_incremental() {
+                // only the last run can be incremental
+                pending_len = run_pending_len;
             }
             // eprintln!("finished RBT filtered_run transliteration: {run:?}")
         });
+        pending_len
     }
 }
 
 impl SimpleId<'_> {
-    fn transliterate(&self, mut rep: Replaceable, env: &Env) {
+    fn transliterate(&self, rep: Replaceable, env: &Env) -> usize {
         // eprintln!("transliterating SimpleId: {self:?}");
         // definitely loaded in the constructor
         let inner = env.get(self.id.as_ref()).unwrap();
-        rep.for_each_run(&self.filter, |run| {
-            // eprintln!("transliterating SimpleId run: {rep:?}");
-            inner.transliterate(run.child(), env)
-        })
+        transliterate_filtered(rep, &self.filter, inner, env)
     }
 }
 
+/// Applies `inner` to each run of `rep` as defined by `filter`, and returns the pending length.
+fn transliterate_filtered(
+    mut rep: Replaceable,
+    filter: &Filter,
+    inner: &InternalTransliterator,
+    env: &Env,
+) -> usize {
+    let mut pending_len = rep.frozen_suffix_len();
+    rep.for_each_run(filter, |run| {
+        // eprintln!("transliterating filtered run: {rep:?}");
+        let run_pending_len = inner.transliterate(run.child(), env);
+        if run.is_incremental() {
+            // only the last run can be incremental
+            pending_len = run_pending_len;
+        }
+    });
+    pending_len
+}
+
 struct RuleGroup<'a> {
     rules: &'a VarZeroSlice<RuleULE, Index32>,
 }
@@ -583,12 +1232,16 @@ impl<'a> RuleGroup<'a> {
         Self { rules }
     }
 
-    fn transliterate(&self, mut rep: Replaceable, vt: &VarTable, env: &Env) {
+    /// Applies the rules to the modifiable range of `rep`, and returns the pending length.
+    ///
+    /// In incremental transliteration, this stops at the first position where a rule
+    /// matches partially, which leaves the rest of the text pending.
+    fn transliterate(&self, mut rep: Replaceable, vt: &VarTable, env: &Env) -> usize {
         // no need to split into runs, because a RuleGroup has no filters.
 
         if self.rules.is_empty() {
             // empty rule group, nothing to do
-            return;
+            return rep.frozen_suffix_len();
         }
 
         // while the cursor has not reached the end yet, keep trying to apply each rule in order.
@@ -602,21 +1255,39 @@ impl<'a> RuleGroup<'a> {
                 let rule: Rule = Rule::zero_from(rule);
                 // eprintln!("trying rule: {rule:?}");
                 let matcher = rep.start_match();
-                if let Some((data, matcher)) = rule.matches(matcher, vt) {
-                    rule.apply(matcher.finish_match(), data, vt, env);
-                    // eprintln!("finished applying replacement: {rep:?}");
-                    // eprintln!("applied rule!");
-                    // rule application is responsible for updating the cursor
-                    continue 'main;
+                match rule.matches(matcher, vt) {
+                    RuleMatch::Match(data, matcher) => {
+                        rule.apply(matcher.finish_match(), data, vt, env);
+                        // eprintln!("finished applying replacement: {rep:?}");
+                        // eprintln!("applied rule!");
+                        // rule application is responsible for updating the cursor
+                        continue 'main;
+                    }
+                    // this rule might match once more input is available, so neither it
+                    // nor a later rule may be applied yet
+                    RuleMatch::Partial => break 'main,
+                    RuleMatch::Mismatch => (),
                 }
             }
             // eprintln!("no rule matched, moving cursor forward");
             // no rule matched, so just move the cursor forward by one code point
             rep.step_cursor();
         }
+        rep.pending_len()
     }
 }
 
+/// The result of [`Rule::matches`].
+enum RuleMatch<'r1, 'r2> {
+    /// The rule matches, and can be applied with the [`MatchData`] and [`RepMatcher`].
+    Match(MatchData, RepMatcher<'r1, 'r2, true>),
+    /// The rule does not match.
+    Mismatch,
+    /// The result of matching depends on input that is not available yet. Only occurs in
+    /// incremental transliteration.
+    Partial,
+}
+
 impl Rule<'_> {
     /// Applies this rule's replacement using the given [`MatchData`]. Updates the cursor of the
     /// current run.
@@ -633,31 +1304,37 @@ impl Rule<'_> {
         replace_str_with_specials(&self.replacer, &mut dest, &data, vt, env);
     }
 
-    /// Returns `None` if there is no match. If there is a match, returns the associated
-    /// [`MatchData`] and [`RepMatcher`].
+    /// Returns [`RuleMatch::Mismatch`] if there is no match. If there is a match, returns the
+    /// associated [`MatchData`] and [`RepMatcher`].
     // Thought: RepMatcher<true> could be "FinishedRepMatcher"? but we can still match post..
     fn matches<'r1, 'r2>(
         &self,
         mut matcher: RepMatcher<'r1, 'r2, false>,
         vt: &VarTable,
-    ) -> Option<(MatchData, RepMatcher<'r1, 'r2, true>)> {
+    ) -> RuleMatch<'r1, 'r2> {
         let mut match_data = MatchData::new();
 
         if !self.ante_matches(&mut matcher, &mut match_data, vt) {
-            return None;
+            // the ante context is never partial, as it cannot change with more input
+            return RuleMatch::Mismatch;
         }
 
         if !self.key_matches(&mut matcher, &mut match_data, vt) {
-            return None;
+            return RuleMatch::mismatch_or_partial(&matcher);
         }
 
         let mut matcher = matcher.finish_key();
 
         if !self.post_matches(&mut matcher, &mut match_data, vt) {
-            return None;
+            return RuleMatch::mismatch_or_partial(&matcher);
+        }
+
+        // e.g. a quantifier at the end of the input could still match more
+        if matcher.is_partial() {
+            return RuleMatch::Partial;
         }
 
-        Some((match_data, matcher))
+        RuleMatch::Match(match_data, matcher)
     }
 
     /// Returns whether the ante context matches or not. Fills in `match_data` if applicable.
@@ -709,6 +1386,18 @@ impl Rule<'_> {
     }
 }
 
+impl RuleMatch<'_, '_> {
+    fn mismatch_or_partial<const KEY_FINISHED: bool>(
+        matcher: &RepMatcher<'_, '_, KEY_FINISHED>,
+    ) -> Self {
+        if matcher.is_partial() {
+            Self::Partial
+        } else {
+            Self::Mismatch
+        }
+    }
+}
+
 /// Returns the index of the first special construct that is encoded as a private use char in `s`,
 /// if there is one. Returns `None` if the passed string is pure
 /// (contains no encoded special constructs).
@@ -1549,4 +2238,156 @@ mod tests {
         let output = "U+0000U+00E4U+0061U+10FFFFU+2764U+0021";
         assert_eq!(t.transliterate(input.to_string()), output);
     }
+
+    #[test]
+    fn test_incremental() {
+        let mut collection = RuleCollection::default();
+        collection.register_source(
+            &"und-x-test".parse().unwrap(),
+            "abc > Y; ab > X; a > A; x } y > Z; [:Latin:]+ } '$' > L; \\$ > D;".into(),
+            [],
+            false,
+            true,
+        );
+        let t = Transliterator::try_new_unstable(
+            &collection.as_provider(),
+            &icu_normalizer::provider::Baked,
+            &"und-x-test".parse().unwrap(),
+        )
+        .unwrap();
+
+        let cases = [
+            // (input, committed, pending)
+            ("a", "", "a"),
+            ("b", "", "ab"),
+            ("d", "X", "d"),
+            ("-", "Xd-", ""),
+            ("x", "Xd-", "x"),
+            // the quantifier might match more
+            ("y", "Xd-Z", "y"),
+            ("q", "Xd-Z", "yq"),
+            ("$", "Xd-ZLD", ""),
+        ];
+        let mut state = IncrementalTransliteration::new();
+        for (input, committed, pending) in cases {
+            t.transliterate_incremental(&mut state, input);
+            assert_eq!(state.committed(), committed, "{input}");
+            assert_eq!(state.pending(), pending, "{input}");
+        }
+
+        t.transliterate_incremental(&mut state, "ab");
+        assert_eq!(state.pending(), "ab");
+        t.finish_incremental(&mut state);
+        assert_eq!(state.committed(), "Xd-ZLDX");
+        assert_eq!(
+            state.pending_range(),
+            state.as_str().len()..state.as_str().len()
+        );
+    }
+
+    #[test]
+    fn test_incremental_normalization() {
+        let mut collection = RuleCollection::default();
+        collection.register_source(
+            &"und-x-test".parse().unwrap(),
+            "::NFD; ::[:Nonspacing Mark:] Remove; ::NFC;".into(),
+            [],
+            false,
+            true,
+        );
+        let t = Transliterator::try_new_unstable(
+            &collection.as_provider(),
+            &icu_normalizer::provider::Baked,
+            &"und-x-test".parse().unwrap(),
+        )
+        .unwrap();
+
+        let mut state = IncrementalTransliteration::new();
+        t.transliterate_incremental(&mut state, "a");
+        assert_eq!(state.committed(), "");
+        // the combining mark is only removed once it is known to be complete
+        t.transliterate_incremental(&mut state, "\u{0308}");
+        assert_eq!(state.committed(), "");
+        t.transliterate_incremental(&mut state, "äb");
+        // NFC cannot commit the second "a" before the "b" is committed by NFD
+        assert_eq!(state.committed(), "a");
+        assert_eq!(state.pending(), "ab");
+        t.finish_incremental(&mut state);
+        assert_eq!(state.into_string(), "aab");
+    }
+
+    fn script_collection() -> RuleCollection {
+        let mut collection = RuleCollection::default();
+        collection.register_source(
+            &"und-Cyrl-t-und-latn".parse().unwrap(),
+            "a <> а; b <> б;".into(),
+            [],
+            false,
+            true,
+        );
+        collection.register_source(
+            &"und-Latn-t-und-cyrl".parse().unwrap(),
+            "a <> а; b <> б;".into(),
+            [],
+            true,
+            true,
+        );
+        collection.register_source(
+            &"und-Latn-t-und-grek".parse().unwrap(),
+            "α > a; β > b;".into(),
+            [],
+            false,
+            true,
+        );
+        collection
+    }
+
+    #[test]
+    fn test_compound_id() {
+        let collection = script_collection();
+        let provider = collection.as_provider();
+        let t = |id| {
+            Transliterator::try_new_from_id_unstable(
+                &provider,
+                &icu_normalizer::provider::Baked,
+                &icu_properties::provider::Baked,
+                id,
+            )
+        };
+
+        let latin_cyrillic = t("Latin-Cyrillic").unwrap();
+        assert_eq!(latin_cyrillic.transliterate("abc".into()), "абc");
+
+        // global and per-transform filters
+        let filtered = t("[ab]; [b] Latin-Cyrillic; Latin-Cyrillic; NFD").unwrap();
+        assert_eq!(filtered.transliterate("abcä".into()), "абcä");
+
+        let any_latin = t("Any-Latin").unwrap();
+        assert_eq!(any_latin.transliterate("аб αβ".into()), "ab ab");
+
+        assert!(t("Latin-Foo").is_err());
+        assert!(t("Latin-Greek").is_err());
+    }
+
+    #[test]
+    fn test_inverse_compound_id() {
+        let collection = script_collection();
+        let provider = collection.as_provider();
+        let t = |id| {
+            Transliterator::try_new_inverse_from_id_unstable(
+                &provider,
+                &icu_normalizer::provider::Baked,
+                &icu_properties::provider::Baked,
+                id,
+            )
+            .unwrap()
+        };
+
+        assert_eq!(t("Latin-Cyrillic").transliterate("абc".into()), "abc");
+        // the inverse of NFD is NFC
+        assert_eq!(t("NFD").transliterate("a\u{0308}".into()), "ä");
+        // inverse global filters only apply to the inverse
+        let inverse = t("NFD; Latin-Cyrillic; ([б])");
+        assert_eq!(inverse.transliterate("аба\u{0308}".into()), "аbа\u{0308}");
+    }
 }
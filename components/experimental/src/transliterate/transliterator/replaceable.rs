@@ -34,6 +34,7 @@
 use super::Filter;
 use alloc::string::String;
 use alloc::vec::Vec;
+use core::cell::Cell;
 use core::fmt::{Debug, Formatter};
 use core::mem::ManuallyDrop;
 use core::ops::Range;
@@ -163,11 +164,18 @@ impl DerefMut for Hide<'_> {
 /// - `cursor` must be a valid UTF-8 index into the visible part of `content`.
 /// - `run_range()` (as defined by `freeze_pre_len` and `freeze_post_len`), must always be a valid
 ///   UTF-8 range into `content`.
+///
+/// # Incremental transliteration
+/// If `incremental` is set, the end of the modifiable range is not the end of the input, as more
+/// input may be appended later. Transliteration then stops at the first position where a rule
+/// could still match differently with more input, and everything from the cursor onwards stays
+/// pending. See [`Replaceable::pending_len`].
 pub(crate) struct Replaceable<'a> {
     content: Hide<'a>,
     freeze_pre_len: usize,
     freeze_post_len: usize,
     cursor: usize,
+    incremental: bool,
 }
 
 impl<'a> Replaceable<'a> {
@@ -176,6 +184,25 @@ impl<'a> Replaceable<'a> {
         unsafe { Replaceable::from_hide(Hide::new(&mut buf.0)) }
     }
 
+    /// Creates a `Replaceable` whose modifiable range starts after the first `committed_len`
+    /// bytes of the buffer, which are only used as context.
+    ///
+    /// `committed_len` must be a valid UTF-8 index into the buffer.
+    pub(crate) fn new_incremental(
+        buf: &'a mut TransliteratorBuffer,
+        committed_len: usize,
+        incremental: bool,
+    ) -> Self {
+        // SAFETY: we have exclusive access to the buffer, so it must contain valid UTF-8
+        let mut rep = unsafe { Replaceable::from_hide(Hide::new(&mut buf.0)) };
+        assert!(rep.as_str().is_char_boundary(committed_len));
+        // SAFETY: committed_len is a valid UTF-8 index
+        rep.freeze_pre_len = committed_len;
+        rep.cursor = committed_len;
+        rep.incremental = incremental;
+        rep
+    }
+
     /// # Safety
     /// The caller must ensure the visible portion of `content` is valid UTF-8.
     unsafe fn from_hide(content: Hide<'a>) -> Self {
@@ -186,6 +213,7 @@ impl<'a> Replaceable<'a> {
             freeze_pre_len: 0,
             freeze_post_len: 0,
             cursor: 0,
+            incremental: false,
         }
     }
 
@@ -245,6 +273,40 @@ impl<'a> Replaceable<'a> {
         self.cursor = cursor;
     }
 
+    /// Returns whether more input may be appended after the modifiable range.
+    pub(crate) fn is_incremental(&self) -> bool {
+        self.incremental
+    }
+
+    /// Returns the length of the internal text from the cursor to the end.
+    ///
+    /// After an incremental transliteration, this is the suffix that has not been committed, and
+    /// needs to be transliterated again once more input is available.
+    pub(crate) fn pending_len(&self) -> usize {
+        self.content.len() - self.cursor
+    }
+
+    /// Returns the length of the internal text after the modifiable range.
+    ///
+    /// This is the [`pending_len`](Replaceable::pending_len) of a transliteration that commits all
+    /// of the modifiable range.
+    pub(crate) fn frozen_suffix_len(&self) -> usize {
+        self.freeze_post_len
+    }
+
+    /// Replaces the first `len` bytes of the modifiable range with `s`, and moves the cursor
+    /// to the end of the replacement.
+    ///
+    /// `len` must be a valid UTF-8 length into the modifiable range.
+    pub(crate) fn replace_modifiable_prefix_with_str(&mut self, len: usize, s: &str) {
+        let start = self.freeze_pre_len;
+        assert!(self.as_str_modifiable().is_char_boundary(len));
+        // SAFETY: start..start + len is a valid UTF-8 range, and `s.bytes()` contains only valid UTF-8
+        self.content.splice(start..start + len, s.bytes());
+        // SAFETY: the end of the valid UTF-8 replacement is a valid UTF-8 index
+        self.cursor = start + s.len();
+    }
+
     /// Returns true if the cursor is at the end of the modifiable range.
     pub(crate) fn is_finished(&self) -> bool {
         // the cursor should never be > the upper bound
@@ -261,6 +323,26 @@ impl<'a> Replaceable<'a> {
             freeze_pre_len: self.freeze_pre_len,
             freeze_post_len: self.freeze_post_len,
             cursor: self.cursor,
+            incremental: self.incremental,
+        }
+    }
+
+    /// Returns a `Replaceable` like [`child`](Replaceable::child), except that its modifiable
+    /// range ends before the last `pending_len` bytes of the internal text.
+    ///
+    /// This is used to only transliterate what a previous incremental transliteration committed.
+    /// `pending_len` must be the result of [`pending_len`](Replaceable::pending_len) on a child.
+    pub(crate) fn child_until(&mut self, pending_len: usize) -> Replaceable {
+        debug_assert!(pending_len >= self.freeze_post_len);
+        debug_assert!(self.content.len() - pending_len >= self.cursor);
+        Replaceable {
+            content: self.content.child(),
+            // SAFETY: the end of a committed range is a valid UTF-8 index that is not before
+            // the cursor
+            freeze_pre_len: self.freeze_pre_len,
+            freeze_post_len: pending_len,
+            cursor: self.cursor,
+            incremental: self.incremental,
         }
     }
 
@@ -288,6 +370,7 @@ impl<'a> Replaceable<'a> {
             forward_cursor: cursor,
             ante_match_len: 0,
             post_match_len: 0,
+            partial: Cell::new(false),
         }
     }
 
@@ -326,6 +409,8 @@ impl<'a> Replaceable<'a> {
         // eprintln!("computing filtered run for rep: {self:?}, start: {start}, run_start: {run_start}, run_end: {run_end}");
 
         let freeze_post_len = self.content.len() - run_end;
+        // a run that is followed by a character outside the filter cannot grow with more input
+        let incremental = self.incremental && run_end == self.allowed_upper_bound();
 
         Some(Replaceable {
             content: self.content.child(),
@@ -333,6 +418,7 @@ impl<'a> Replaceable<'a> {
             freeze_pre_len: run_start,
             freeze_post_len,
             cursor: run_start,
+            incremental,
         })
     }
 
@@ -400,6 +486,11 @@ impl Debug for Replaceable<'_> {
 /// the `Replaceable`'s internal text.
 ///
 /// The `RepMatcher` does not modify the contained `Replaceable`.
+///
+/// # Incremental matching
+/// If the `Replaceable` is incremental, the `RepMatcher` records whenever a forward match
+/// needed to look past the end of the available input. Such a match is *partial*, as its result
+/// could change once more input is appended. See [`RepMatcher::is_partial`].
 #[derive(Debug)]
 pub(super) struct RepMatcher<'a, 'b, const KEY_FINISHED: bool> {
     rep: &'b mut Replaceable<'a>,
@@ -407,6 +498,7 @@ pub(super) struct RepMatcher<'a, 'b, const KEY_FINISHED: bool> {
     ante_match_len: usize, // relative to rep.cursor
     post_match_len: usize, // relative to rep.cursor + key_match_len
     forward_cursor: usize, // absolute
+    partial: Cell<bool>,
 }
 
 // we can only finish a KEY_FINISHED = true matcher
@@ -437,11 +529,26 @@ impl<'a, 'b> RepMatcher<'a, 'b, false> {
             ante_match_len: self.ante_match_len,
             post_match_len: self.post_match_len,
             forward_cursor: self.forward_cursor,
+            partial: self.partial,
         }
     }
 }
 
 impl<const KEY_FINISHED: bool> RepMatcher<'_, '_, KEY_FINISHED> {
+    /// Returns whether some part of the match needed more input than is available, which
+    /// means the outcome of the match is not final. This is never the case for
+    /// non-incremental `Replaceable`s.
+    pub(super) fn is_partial(&self) -> bool {
+        self.partial.get()
+    }
+
+    /// Records that a forward match ran into the end of the available input.
+    fn hit_limit(&self) {
+        if self.rep.incremental {
+            self.partial.set(true);
+        }
+    }
+
     fn remaining(&self) -> usize {
         if KEY_FINISHED {
             self.rep.content.len() - self.forward_cursor
@@ -479,11 +586,23 @@ impl<const KEY_FINISHED: bool> Utf8Matcher<Forward> for RepMatcher<'_, '_, KEY_F
     }
 
     fn is_empty(&self) -> bool {
-        self.remaining() == 0
+        let is_empty = self.remaining() == 0;
+        if is_empty {
+            self.hit_limit();
+        }
+        is_empty
     }
 
     fn match_str(&self, s: &str) -> bool {
-        self.remaining_forward_slice().starts_with(s)
+        let remaining = self.remaining_forward_slice();
+        if remaining.starts_with(s) {
+            return true;
+        }
+        if s.starts_with(remaining) {
+            // `s` could still match if more input is appended
+            self.hit_limit();
+        }
+        false
     }
 
     fn match_start_anchor(&self) -> bool {
@@ -492,7 +611,12 @@ impl<const KEY_FINISHED: bool> Utf8Matcher<Forward> for RepMatcher<'_, '_, KEY_F
 
     fn match_end_anchor(&self) -> bool {
         // no matter if we're matching key or post, we must be completely at the end of the string
-        self.forward_cursor == self.rep.content.len()
+        let at_end = self.forward_cursor == self.rep.content.len();
+        if at_end {
+            // the anchor would not match if more input is appended
+            self.hit_limit();
+        }
+        at_end
     }
 
     fn consume(&mut self, len: usize) -> bool {
@@ -512,7 +636,11 @@ impl<const KEY_FINISHED: bool> Utf8Matcher<Forward> for RepMatcher<'_, '_, KEY_F
     }
 
     fn next_char(&self) -> Option<char> {
-        self.remaining_forward_slice().chars().next()
+        let next = self.remaining_forward_slice().chars().next();
+        if next.is_none() {
+            self.hit_limit();
+        }
+        next
     }
 }
 
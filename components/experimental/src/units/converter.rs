@@ -2,7 +2,11 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use alloc::vec::Vec;
+use num_traits::{One, Zero};
+
 use crate::units::convertible::Convertible;
+use crate::units::ratio::IcuRatio;
 
 /// A converter for converting between two single or compound units.
 /// For example:
@@ -12,7 +16,7 @@ use crate::units::convertible::Convertible;
 ///
 /// NOTE:
 ///     This converter does not support conversions between mixed units,
-///     for example, from "meter" to "foot-and-inch".
+///     for example, from "meter" to "foot-and-inch". Use [`MixedUnitsConverter`] instead.
 #[derive(Debug, Clone)]
 pub struct UnitsConverter<N>(pub(crate) UnitsConverterInner<N>)
 where
@@ -28,6 +32,68 @@ where
     }
 }
 
+/// A converter for converting from a single or compound unit to a mixed unit.
+/// For example:
+///     1 - `meter` to `foot-and-inch`.
+///     2 - `kilogram` to `stone-and-pound`.
+///     3 - `second` to `hour-and-minute-and-second`.
+///
+/// The value is split into one component per output unit, from the largest to the smallest unit.
+/// All components but the last one are integers, and the last one is rounded to a fixed number of
+/// fraction digits, carrying into the larger units if needed.
+/// For example, 1.8 meters are 5 feet and 10.87 inches with two fraction digits.
+///
+/// NOTE:
+///     The computation is exact: it uses [`IcuRatio`] for all the intermediate values.
+#[derive(Debug, Clone)]
+pub struct MixedUnitsConverter {
+    /// Converts the input unit to the first (largest) output unit.
+    pub(crate) first: UnitsConverter<IcuRatio>,
+
+    /// The number of each output unit in one of the previous output unit,
+    /// e.g. 12 for `foot-and-inch`.
+    pub(crate) rates: Vec<IcuRatio>,
+
+    /// The number of fraction digits of the last component.
+    pub(crate) trailing_fraction_digits: u8,
+}
+
+impl MixedUnitsConverter {
+    /// Converts the given value from the input unit to the components of the mixed output unit.
+    ///
+    /// If the value is negative, only the first non-zero component is negative.
+    pub fn convert(&self, value: &IcuRatio) -> Vec<IcuRatio> {
+        let converted = self.first.convert(value);
+        let is_negative = converted.is_negative();
+        let mut remainder = converted.abs();
+
+        let mut components = Vec::with_capacity(self.rates.len() + 1);
+        for rate in self.rates.iter() {
+            let integer = remainder.trunc();
+            remainder = (remainder - integer.clone()) * rate.clone();
+            components.push(integer);
+        }
+        components.push(remainder.round_to_fraction_digits(self.trailing_fraction_digits));
+
+        // Rounding the last component may reach the size of the previous unit, e.g. 5 feet and
+        // 11.999 inches round to 5 feet and 12 inches, which should be 6 feet and 0 inches.
+        for (i, rate) in self.rates.iter().enumerate().rev() {
+            if components[i + 1] < *rate {
+                break;
+            }
+            components[i + 1] -= rate.clone();
+            components[i] += IcuRatio::one();
+        }
+
+        if is_negative {
+            if let Some(component) = components.iter_mut().find(|component| !component.is_zero()) {
+                *component = IcuRatio::zero() - component.clone();
+            }
+        }
+        components
+    }
+}
+
 /// Enum containing all the of converters: Proportional, Reciprocal, and Offset converters as follows:
 ///    1 - Proportional: Converts between two units that are proportionally related (e.g. `meter` to `foot`).
 ///    2 - Reciprocal: Converts between two units that are reciprocal (e.g. `mile-per-gallon` to `liter-per-100-kilometer`).
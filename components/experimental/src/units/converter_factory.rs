@@ -9,12 +9,13 @@ use crate::units::provider;
 use crate::units::ratio::IcuRatio;
 use crate::units::{
     converter::{
-        OffsetConverter, ProportionalConverter, ReciprocalConverter, UnitsConverter,
-        UnitsConverterInner,
+        MixedUnitsConverter, OffsetConverter, ProportionalConverter, ReciprocalConverter,
+        UnitsConverter, UnitsConverterInner,
     },
     provider::Sign,
 };
 
+use alloc::vec::Vec;
 use icu_provider::prelude::*;
 use icu_provider::DataError;
 use litemap::LiteMap;
@@ -270,7 +271,7 @@ impl ConverterFactory {
     ///
     /// NOTE:
     ///    This converter does not support conversions between mixed units,
-    ///    such as, from "meter" to "foot-and-inch".
+    ///    such as, from "meter" to "foot-and-inch". Use [`Self::mixed_converter`] instead.
     pub fn converter<T: Convertible>(
         &self,
         input_unit: &MeasureUnit,
@@ -322,4 +323,43 @@ impl ConverterFactory {
             )))
         }
     }
+
+    /// Creates a converter for converting from a single or compound unit to a mixed unit,
+    /// given as its units from the largest to the smallest one.
+    /// For example:
+    ///    1 - `meter` to [`foot`, `inch`]
+    ///    2 - `second` to [`hour`, `minute`, `second`]
+    ///
+    /// The last component of the result is rounded to `trailing_fraction_digits` fraction digits.
+    ///
+    /// Returns `None` if the input unit is not convertible to the first output unit,
+    /// if two consecutive output units are not proportionally related,
+    /// or if the output units are not ordered from the largest to the smallest one.
+    pub fn mixed_converter(
+        &self,
+        input_unit: &MeasureUnit,
+        output_units: &[MeasureUnit],
+        trailing_fraction_digits: u8,
+    ) -> Option<MixedUnitsConverter> {
+        let first = self.converter(input_unit, output_units.first()?)?;
+
+        let mut rates = Vec::with_capacity(output_units.len() - 1);
+        for pair in output_units.windows(2) {
+            let UnitsConverter(UnitsConverterInner::Proportional(converter)) =
+                self.converter::<IcuRatio>(&pair[0], &pair[1])?
+            else {
+                return None;
+            };
+            if converter.conversion_rate <= IcuRatio::one() {
+                return None;
+            }
+            rates.push(converter.conversion_rate);
+        }
+
+        Some(MixedUnitsConverter {
+            first,
+            rates,
+            trailing_fraction_digits,
+        })
+    }
 }
@@ -2,6 +2,7 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use crate::units::ratio::IcuRatio;
 use num_bigint::BigInt;
 use num_rational::Ratio;
 use num_traits::ToPrimitive;
@@ -40,6 +41,24 @@ impl Convertible for Ratio<BigInt> {
     }
 }
 
+impl Convertible for IcuRatio {
+    fn mul_refs(&self, other: &Self) -> Self {
+        self * other
+    }
+
+    fn add_refs(&self, other: &Self) -> Self {
+        self + other
+    }
+
+    fn from_ratio_bigint(ratio: Ratio<BigInt>) -> Option<Self> {
+        Some(ratio.into())
+    }
+
+    fn reciprocal(&self) -> Self {
+        self.recip()
+    }
+}
+
 impl Convertible for f64 {
     fn mul_refs(&self, other: &Self) -> Self {
         self * other
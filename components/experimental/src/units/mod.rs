@@ -9,6 +9,19 @@ pub mod converter_factory;
 pub mod convertible;
 pub mod provider;
 pub mod ratio;
+pub mod router;
+
+/// Locale preferences used by this module
+pub mod preferences {
+    #[doc(inline)]
+    /// **This is a reexport of a type in [`icu::locale`](icu_locale_core::preferences::extensions::unicode::keywords)**.
+    #[doc = "\n"] // prevent autoformatting
+    pub use icu_locale_core::preferences::extensions::unicode::keywords::MeasurementSystem;
+    #[doc(inline)]
+    /// **This is a reexport of a type in [`icu::locale`](icu_locale_core::preferences::extensions::unicode::keywords)**.
+    #[doc = "\n"] // prevent autoformatting
+    pub use icu_locale_core::preferences::extensions::unicode::keywords::MeasurementUnitOverride;
+}
 
 #[derive(Display, Debug, Copy, Clone, PartialEq)]
 #[displaydoc("The unit is not valid.")]
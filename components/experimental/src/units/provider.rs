@@ -9,6 +9,7 @@
 //!
 //! Read more about data providers: [`icu_provider`]
 
+use alloc::borrow::Cow;
 use icu_provider::prelude::*;
 use num_bigint::BigInt;
 use zerotrie::ZeroTrieSimpleAscii;
@@ -51,6 +52,69 @@ pub struct UnitsInfo<'data> {
     pub convert_infos: VarZeroVec<'data, ConversionInfoULE>,
 }
 
+/// This type contains the preferred units for each category, usage, and region,
+/// as defined in CLDR's `unitPreferenceData`.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(marker(UnitsPreferencesV1, "units/preferences@1", singleton))]
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_experimental::units::provider))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct UnitsPreferences<'data> {
+    /// Maps from `{category}/{usage}/{region}` (e.g. `length/person-height/US`) to the index of
+    /// the first preference in `preferences`.
+    /// The region `001` contains the preferences for all the regions that are not listed.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub index: ZeroTrieSimpleAscii<ZeroVec<'data, u8>>,
+
+    /// Contains the preferences, grouped by category, usage, and region.
+    /// Each group is ordered from the largest to the smallest unit, and ends with the first
+    /// preference that has a zero threshold.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub preferences: VarZeroVec<'data, UnitPreferenceULE>,
+}
+
+/// Represents a preferred unit, which is used for values that are at least the threshold.
+/// For example, `mile` with a threshold of 0.5 is used for road distances of at least 0.5 miles.
+#[zerovec::make_varule(UnitPreferenceULE)]
+#[derive(Clone, Debug, PartialOrd, Ord, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "datagen", derive(databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_experimental::units::provider))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize),
+    zerovec::derive(Serialize)
+)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    zerovec::derive(Deserialize)
+)]
+#[zerovec::derive(Debug)]
+pub struct UnitPreference<'data> {
+    /// Represents the numerator of the threshold, in the preferred unit.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub geq_num: ZeroVec<'data, u8>,
+
+    /// Represents the denominator of the threshold, in the preferred unit.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub geq_den: ZeroVec<'data, u8>,
+
+    /// The identifier of the preferred unit, which may be a mixed unit (e.g. `foot-and-inch`).
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub unit: Cow<'data, str>,
+
+    /// The CLDR number skeleton for values in the preferred unit (e.g. `precision-increment/50`),
+    /// or empty if the preference has none.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub skeleton: Cow<'data, str>,
+}
+
 /// Represents the conversion information for a unit.
 /// Which includes the base unit (the unit which the unit is converted to), the conversion factor, and the offset.
 #[zerovec::make_varule(ConversionInfoULE)]
@@ -146,3 +210,13 @@ impl ConversionInfoULE {
         )
     }
 }
+
+impl UnitPreferenceULE {
+    /// Extracts the threshold as [`super::ratio::IcuRatio`].
+    pub(crate) fn geq_as_ratio(&self) -> IcuRatio {
+        IcuRatio::from_big_ints(
+            BigInt::from_bytes_le(num_bigint::Sign::Plus, self.geq_num().as_ule_slice()),
+            BigInt::from_bytes_le(num_bigint::Sign::Plus, self.geq_den().as_ule_slice()),
+        )
+    }
+}
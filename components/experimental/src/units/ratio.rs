@@ -99,6 +99,19 @@ impl IcuRatio {
     pub fn is_negative(&self) -> bool {
         self.0.is_negative()
     }
+
+    /// Returns the integer part of the ratio, rounding towards zero.
+    /// For example, the integer part of 7/2 is 3, and of -7/2 is -3.
+    pub fn trunc(&self) -> Self {
+        Self(self.0.trunc())
+    }
+
+    /// Rounds the ratio to the given number of fraction digits, rounding half away from zero.
+    /// For example, 7/8 rounded to 2 fraction digits is 88/100.
+    pub fn round_to_fraction_digits(&self, fraction_digits: u8) -> Self {
+        let scale = Ratio::from_integer(BigInt::from(10).pow(u32::from(fraction_digits)));
+        Self((&self.0 * &scale).round() / scale)
+    }
 }
 
 impl Mul for IcuRatio {
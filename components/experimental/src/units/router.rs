@@ -0,0 +1,278 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Routes a value to the unit that is preferred for it in a given locale.
+
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use icu_locale_core::preferences::define_preferences;
+use icu_provider::prelude::*;
+use num_traits::Zero;
+
+use super::converter::MixedUnitsConverter;
+use super::converter_factory::ConverterFactory;
+use super::preferences::{MeasurementSystem, MeasurementUnitOverride};
+use super::provider::{UnitsInfoV1, UnitsPreferencesV1};
+use super::ratio::IcuRatio;
+
+define_preferences!(
+    /// The preferences for units routing.
+    [Copy]
+    UnitsRouterPreferences,
+    {
+        /// The user's preferred measurement system.
+        ///
+        /// Corresponds to the `-u-ms` in Unicode Locale Identifier.
+        measurement_system: MeasurementSystem,
+        /// The user's preferred temperature unit.
+        ///
+        /// Corresponds to the `-u-mu` in Unicode Locale Identifier.
+        measurement_unit_override: MeasurementUnitOverride
+    }
+);
+
+/// A preferred unit, along with a converter from the input unit to it.
+#[derive(Debug, Clone)]
+pub struct UnitsRoute {
+    /// The minimum value, in the first component of the preferred unit, for which this route is used.
+    geq: IcuRatio,
+
+    /// The identifier of the preferred unit, e.g. `foot-and-inch`.
+    unit: String,
+
+    /// The CLDR number skeleton for values in the preferred unit, e.g. `precision-increment/50`.
+    skeleton: Option<String>,
+
+    /// Converts from the input unit to the preferred unit.
+    converter: MixedUnitsConverter,
+}
+
+impl UnitsRoute {
+    /// Returns the identifier of the preferred unit, e.g. `foot-and-inch`.
+    pub fn unit(&self) -> &str {
+        &self.unit
+    }
+
+    /// Returns the CLDR number skeleton for values in the preferred unit, if any.
+    ///
+    /// For example, road distances in the US below half a mile use `foot` with
+    /// `precision-increment/50` above 500 feet, and `precision-increment/10` below.
+    pub fn skeleton(&self) -> Option<&str> {
+        self.skeleton.as_deref()
+    }
+
+    /// Returns the converter from the input unit to the preferred unit.
+    pub fn converter(&self) -> &MixedUnitsConverter {
+        &self.converter
+    }
+}
+
+/// Picks the preferred unit for a value, based on the CLDR unit preferences for a category,
+/// a usage, and the user's region and measurement preferences.
+///
+/// The region is taken from the `-u-ms` keyword if present (`metric` uses the preferences of
+/// `001`, `ussystem` of `US`, and `uksystem` of `GB`), and from the locale otherwise.
+/// Locales without a region use the preferences of `001`; use `LocaleExpander` to add the likely
+/// region first.
+///
+/// # Example
+///
+/// ```
+/// use icu::experimental::units::ratio::IcuRatio;
+/// use icu::experimental::units::router::UnitsRouter;
+/// use icu::locale::locale;
+///
+/// let router = UnitsRouter::try_new(
+///     locale!("en-US").into(),
+///     "length",
+///     "person-height",
+///     "meter",
+///     0,
+/// )
+/// .expect("preferences should be present");
+///
+/// let route = router.route(&IcuRatio::from(2u32));
+/// assert_eq!(route.unit(), "foot-and-inch");
+/// assert_eq!(
+///     route.converter().convert(&IcuRatio::from(2u32)),
+///     vec![IcuRatio::from(6u32), IcuRatio::from(7u32)]
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct UnitsRouter {
+    /// The routes, ordered from the largest to the smallest threshold.
+    routes: Vec<UnitsRoute>,
+}
+
+impl UnitsRouter {
+    icu_provider::gen_buffer_data_constructors!(
+        (
+            prefs: UnitsRouterPreferences,
+            category: &str,
+            usage: &str,
+            input_unit: &str,
+            trailing_fraction_digits: u8
+        ) -> error: DataError,
+        /// Creates a new [`UnitsRouter`] from compiled data.
+        ///
+        /// `category` and `usage` are CLDR unit preference identifiers, e.g. `length` and
+        /// `person-height`. Unknown usages fall back to `default`.
+        /// The last component of each converted value is rounded to `trailing_fraction_digits`
+        /// fraction digits.
+        functions: [
+            try_new,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        prefs: UnitsRouterPreferences,
+        category: &str,
+        usage: &str,
+        input_unit: &str,
+        trailing_fraction_digits: u8,
+    ) -> Result<Self, DataError>
+    where
+        D: ?Sized + DataProvider<UnitsPreferencesV1> + DataProvider<UnitsInfoV1>,
+    {
+        let preferences =
+            DataProvider::<UnitsPreferencesV1>::load(provider, Default::default())?.payload;
+        let preferences = preferences.get();
+        let factory = ConverterFactory::try_new_unstable(provider)?;
+        let parser = factory.parser();
+
+        let input_unit = parser
+            .try_from_str(input_unit)
+            .map_err(|_| DataError::custom("the input unit is not valid"))?;
+
+        let region = match prefs.measurement_system {
+            Some(MeasurementSystem::Metric) => "001".to_owned(),
+            Some(MeasurementSystem::USSystem) => "US".to_owned(),
+            Some(MeasurementSystem::UKSystem) => "GB".to_owned(),
+            _ => prefs
+                .locale_preferences
+                .to_data_locale_region_priority()
+                .region
+                .map(|region| region.as_str().to_owned())
+                .unwrap_or_else(|| "001".to_owned()),
+        };
+
+        let temperature_override = match prefs.measurement_unit_override {
+            Some(MeasurementUnitOverride::Celsius) => Some("celsius"),
+            Some(MeasurementUnitOverride::Kelvin) => Some("kelvin"),
+            Some(MeasurementUnitOverride::Fahrenheit) => Some("fahrenheit"),
+            _ => None,
+        };
+
+        let mut routes = Vec::new();
+        if let (Some(unit), "temperature") = (temperature_override, category) {
+            routes.push(Self::make_route(
+                &factory,
+                &input_unit,
+                IcuRatio::zero(),
+                unit,
+                None,
+                trailing_fraction_digits,
+            )?);
+            return Ok(Self { routes });
+        }
+
+        let start = Self::find_group(&preferences.index, category, usage, &region)?;
+        for preference in preferences.preferences.iter().skip(start) {
+            let geq = preference.geq_as_ratio();
+            let is_last = geq.is_zero();
+            routes.push(Self::make_route(
+                &factory,
+                &input_unit,
+                geq,
+                preference.unit(),
+                Some(preference.skeleton()).filter(|skeleton| !skeleton.is_empty()),
+                trailing_fraction_digits,
+            )?);
+            if is_last {
+                break;
+            }
+        }
+        if routes.is_empty() {
+            return Err(DataError::custom("no unit preferences found"));
+        }
+
+        Ok(Self { routes })
+    }
+
+    /// Returns the index of the first preference for the given category, usage, and region,
+    /// falling back to the `default` usage and the `001` region.
+    fn find_group(
+        index: &zerotrie::ZeroTrieSimpleAscii<zerovec::ZeroVec<u8>>,
+        category: &str,
+        usage: &str,
+        region: &str,
+    ) -> Result<usize, DataError> {
+        let mut usage = usage;
+        loop {
+            for region in [region, "001"] {
+                if let Some(start) = index.get(format!("{category}/{usage}/{region}")) {
+                    return Ok(start);
+                }
+            }
+            usage = match usage.rfind('-') {
+                Some(i) => &usage[..i],
+                None if usage != "default" => "default",
+                None => {
+                    return Err(DataError::custom("unknown unit preference category")
+                        .with_display_context(category))
+                }
+            };
+        }
+    }
+
+    fn make_route(
+        factory: &ConverterFactory,
+        input_unit: &crate::measure::measureunit::MeasureUnit,
+        geq: IcuRatio,
+        unit: &str,
+        skeleton: Option<&str>,
+        trailing_fraction_digits: u8,
+    ) -> Result<UnitsRoute, DataError> {
+        let output_units = factory.parser().try_mixed_from_str(unit).map_err(|_| {
+            DataError::custom("the preferred unit is not valid").with_display_context(unit)
+        })?;
+        let converter = factory
+            .mixed_converter(input_unit, &output_units, trailing_fraction_digits)
+            .ok_or_else(|| {
+                DataError::custom("the input unit is not convertible to the preferred unit")
+                    .with_display_context(unit)
+            })?;
+        Ok(UnitsRoute {
+            geq,
+            unit: unit.to_owned(),
+            skeleton: skeleton.map(ToOwned::to_owned),
+            converter,
+        })
+    }
+
+    /// Returns the route for `value`, given in the input unit.
+    ///
+    /// This is the first route for which the absolute value, converted to the first component
+    /// of the preferred unit, is at least the threshold of the route.
+    pub fn route(&self, value: &IcuRatio) -> &UnitsRoute {
+        #[allow(clippy::indexing_slicing)] // routes is never empty
+        let last = &self.routes[self.routes.len() - 1];
+        self.routes
+            .iter()
+            .find(|route| route.converter.first.convert(value).abs() >= route.geq)
+            .unwrap_or(last)
+    }
+
+    /// Returns all the routes, ordered from the largest to the smallest threshold.
+    pub fn routes(&self) -> &[UnitsRoute] {
+        &self.routes
+    }
+}
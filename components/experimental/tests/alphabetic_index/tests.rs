@@ -0,0 +1,193 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_collator::provider::*;
+use icu_experimental::alphabetic_index::{AlphabeticIndex, AlphabeticIndexBuilder, BucketKind};
+use icu_locale::exemplar_chars::ExemplarCharacters;
+use icu_locale::provider::{ExemplarCharactersIndexV1, ExemplarCharactersMainV1};
+use icu_locale_core::locale;
+use icu_normalizer::provider::{CanonicalDecompositionDataV2, CanonicalDecompositionTablesV1};
+use icu_properties::provider::ScriptV1;
+use icu_provider::prelude::*;
+
+fn bucket<'a>(index: &'a AlphabeticIndex, s: &str) -> &'a str {
+    index.buckets()[index.bucket_index(s)].label()
+}
+
+fn labels(index: &AlphabeticIndex) -> Vec<&str> {
+    index.buckets().iter().map(|b| b.label()).collect()
+}
+
+#[test]
+fn test_english() {
+    let index = AlphabeticIndexBuilder::try_new(locale!("en").into())
+        .unwrap()
+        .build();
+    let labels = labels(&index);
+    assert_eq!(labels.len(), 28);
+    assert_eq!(labels[..3], ["…", "A", "B"]);
+    assert_eq!(labels[25..], ["Y", "Z", "…"]);
+    assert_eq!(index.buckets()[0].kind(), BucketKind::Underflow);
+    assert_eq!(index.buckets()[1].kind(), BucketKind::Normal);
+    assert_eq!(index.buckets()[27].kind(), BucketKind::Overflow);
+
+    assert_eq!(bucket(&index, "apple"), "A");
+    assert_eq!(bucket(&index, "Ångström"), "A");
+    assert_eq!(bucket(&index, "zebra"), "Z");
+    assert_eq!(index.bucket_index("123"), 0);
+    assert_eq!(index.bucket_index(""), 0);
+    assert_eq!(index.bucket_index("中文"), 27);
+    assert_eq!(index.bucket_index("Ωmega"), 27);
+}
+
+#[test]
+fn test_multiple_locales() {
+    let mut builder = AlphabeticIndexBuilder::try_new(locale!("en").into()).unwrap();
+    builder.add_index_characters(ExemplarCharacters::try_new_index(&locale!("ru").into()).unwrap());
+    let index = builder.build();
+    let inflow = index
+        .buckets()
+        .iter()
+        .position(|b| b.kind() == BucketKind::Inflow)
+        .unwrap();
+    assert_eq!(index.buckets()[inflow - 1].label(), "Z");
+    assert_eq!(index.buckets()[inflow + 1].label(), "А");
+
+    assert_eq!(bucket(&index, "Zoe"), "Z");
+    assert_eq!(index.bucket_index("Ξένια"), inflow);
+    assert_eq!(bucket(&index, "Борис"), "Б");
+    assert_eq!(bucket(&index, "Юлия"), "Ю");
+    assert_eq!(index.bucket_index("中文"), index.buckets().len() - 1);
+}
+
+#[test]
+fn test_japanese() {
+    let index = AlphabeticIndexBuilder::try_new(locale!("ja").into())
+        .unwrap()
+        .build();
+    assert_eq!(
+        labels(&index)[1..11],
+        ["あ", "か", "さ", "た", "な", "は", "ま", "や", "ら", "わ"]
+    );
+    assert_eq!(bucket(&index, "いちろう"), "あ");
+    assert_eq!(bucket(&index, "アキラ"), "あ");
+    assert_eq!(bucket(&index, "ぎん"), "か");
+    assert_eq!(bucket(&index, "ヲ"), "わ");
+}
+
+#[test]
+fn test_korean() {
+    let index = AlphabeticIndexBuilder::try_new(locale!("ko").into())
+        .unwrap()
+        .build();
+    assert_eq!(labels(&index)[1..4], ["ㄱ", "ㄴ", "ㄷ"]);
+    assert_eq!(bucket(&index, "가나다"), "ㄱ");
+    assert_eq!(bucket(&index, "까치"), "ㄱ");
+    assert_eq!(bucket(&index, "한국"), "ㅎ");
+}
+
+#[test]
+fn test_tailored_labels() {
+    let index = AlphabeticIndexBuilder::try_new(locale!("cs").into())
+        .unwrap()
+        .build();
+    assert_eq!(bucket(&index, "cukr"), "C");
+    assert_eq!(bucket(&index, "Čapek"), "Č");
+    assert_eq!(bucket(&index, "hrad"), "H");
+    assert_eq!(bucket(&index, "chata"), "CH");
+    assert_eq!(bucket(&index, "ihned"), "I");
+}
+
+#[test]
+fn test_chinese_pinyin() {
+    let index = AlphabeticIndexBuilder::try_new(locale!("zh").into())
+        .unwrap()
+        .build();
+    assert_eq!(bucket(&index, "阿"), "A");
+    assert_eq!(bucket(&index, "八"), "B");
+    assert_eq!(bucket(&index, "擦"), "C");
+    assert_eq!(bucket(&index, "中文"), "Z");
+}
+
+#[test]
+fn test_options() {
+    let mut builder = AlphabeticIndexBuilder::try_new(locale!("en").into()).unwrap();
+    builder
+        .set_max_label_count(5)
+        .set_underflow_label("#")
+        .set_overflow_label("?");
+    let index = builder.build();
+    assert_eq!(labels(&index), ["#", "A", "G", "L", "Q", "V", "?"]);
+    assert_eq!(bucket(&index, "Jim"), "G");
+    assert_eq!(bucket(&index, "42"), "#");
+
+    let mut builder = AlphabeticIndexBuilder::try_new(locale!("en").into()).unwrap();
+    // Primary duplicates of existing labels are ignored.
+    builder.add_labels(["Ä", "Sch", "a"]);
+    let index = builder.build();
+    assert_eq!(labels(&index).len(), 29);
+    assert_eq!(bucket(&index, "Schmidt"), "Sch");
+    assert_eq!(bucket(&index, "Sally"), "S");
+    assert_eq!(bucket(&index, "Ärger"), "A");
+}
+
+/// Serves compiled data, except for an error loading the index exemplar characters.
+struct IndexErrorProvider(DataErrorKind);
+
+macro_rules! delegate_to_baked {
+    ($($baked:path => [$($marker:ty),*]),*) => {
+        $($(
+            impl DataProvider<$marker> for IndexErrorProvider {
+                fn load(&self, req: DataRequest) -> Result<DataResponse<$marker>, DataError> {
+                    $baked.load(req)
+                }
+            }
+        )*)*
+    };
+}
+
+delegate_to_baked!(
+    icu_collator::provider::Baked => [
+        CollationSpecialPrimariesV1,
+        CollationRootV1,
+        CollationTailoringV1,
+        CollationDiacriticsV1,
+        CollationJamoV1,
+        CollationMetadataV1,
+        CollationReorderingV1
+    ],
+    icu_normalizer::provider::Baked => [
+        CanonicalDecompositionDataV2,
+        CanonicalDecompositionTablesV1
+    ],
+    icu_locale::provider::Baked => [ExemplarCharactersMainV1],
+    icu_properties::provider::Baked => [ScriptV1]
+);
+
+impl DataProvider<ExemplarCharactersIndexV1> for IndexErrorProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<ExemplarCharactersIndexV1>, DataError> {
+        Err(self.0.with_req(ExemplarCharactersIndexV1::INFO, req))
+    }
+}
+
+#[test]
+fn test_index_data_errors() {
+    // Missing index characters fall back to the main exemplar characters.
+    let index = AlphabeticIndexBuilder::try_new_unstable(
+        &IndexErrorProvider(DataErrorKind::MarkerNotFound),
+        locale!("en").into(),
+    )
+    .unwrap()
+    .build();
+    assert_eq!(bucket(&index, "apple"), "A");
+    assert_eq!(bucket(&index, "zebra"), "Z");
+
+    // Other errors are returned.
+    let error = AlphabeticIndexBuilder::try_new_unstable(
+        &IndexErrorProvider(DataErrorKind::Deserialize),
+        locale!("en").into(),
+    )
+    .unwrap_err();
+    assert_eq!(error.kind, DataErrorKind::Deserialize);
+}
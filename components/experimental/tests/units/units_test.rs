@@ -296,3 +296,351 @@ fn test_unparsable_units() {
         );
     });
 }
+
+#[test]
+fn test_mixed_units() {
+    struct MixedUnitsTest {
+        input_unit: &'static str,
+        output_unit: &'static str,
+        trailing_fraction_digits: u8,
+        input: &'static str,
+        expected: &'static [&'static str],
+    }
+
+    let tests = [
+        MixedUnitsTest {
+            input_unit: "meter",
+            output_unit: "foot-and-inch",
+            trailing_fraction_digits: 2,
+            input: "1.8",
+            expected: &["5", "10.87"],
+        },
+        MixedUnitsTest {
+            input_unit: "meter",
+            output_unit: "foot-and-inch",
+            trailing_fraction_digits: 0,
+            input: "1.8",
+            expected: &["5", "11"],
+        },
+        MixedUnitsTest {
+            input_unit: "meter",
+            output_unit: "foot-and-inch",
+            trailing_fraction_digits: 0,
+            input: "-1.8",
+            expected: &["-5", "11"],
+        },
+        MixedUnitsTest {
+            input_unit: "meter",
+            output_unit: "foot-and-inch",
+            trailing_fraction_digits: 0,
+            input: "-0.1",
+            expected: &["0", "-4"],
+        },
+        MixedUnitsTest {
+            // 1.8287 meters are 5 feet and 11.996 inches, which round up to 6 feet.
+            input_unit: "meter",
+            output_unit: "foot-and-inch",
+            trailing_fraction_digits: 1,
+            input: "1.8287",
+            expected: &["6", "0"],
+        },
+        MixedUnitsTest {
+            input_unit: "kilogram",
+            output_unit: "stone-and-pound",
+            trailing_fraction_digits: 0,
+            input: "70",
+            expected: &["11", "0"],
+        },
+        MixedUnitsTest {
+            // 3599.9 seconds round up twice to 1 hour.
+            input_unit: "second",
+            output_unit: "hour-and-minute-and-second",
+            trailing_fraction_digits: 0,
+            input: "3599.9",
+            expected: &["1", "0", "0"],
+        },
+        MixedUnitsTest {
+            input_unit: "kilometer-per-hour",
+            output_unit: "meter-per-second",
+            trailing_fraction_digits: 2,
+            input: "100",
+            expected: &["27.78"],
+        },
+    ];
+
+    let converter_factory = ConverterFactory::new();
+    let parser = converter_factory.parser();
+
+    for test in tests {
+        let input_unit = parser
+            .try_from_str(test.input_unit)
+            .expect("Failed to parse input unit");
+        let output_units = parser
+            .try_mixed_from_str(test.output_unit)
+            .expect("Failed to parse output unit");
+        let converter = converter_factory
+            .mixed_converter(&input_unit, &output_units, test.trailing_fraction_digits)
+            .expect("Failed to create converter");
+
+        let result = converter.convert(&IcuRatio::from_str(test.input).unwrap());
+        let expected: Vec<IcuRatio> = test
+            .expected
+            .iter()
+            .map(|component| IcuRatio::from_str(component).unwrap())
+            .collect();
+        assert_eq!(
+            result, expected,
+            "Failed test: Input Unit: {:?}, Output Unit: {:?}, Input: {:?}",
+            test.input_unit, test.output_unit, test.input
+        );
+    }
+}
+
+#[test]
+fn test_mixed_units_non_convertible() {
+    let converter_factory = ConverterFactory::new();
+    let parser = converter_factory.parser();
+
+    let input_unit = parser.try_from_str("meter").unwrap();
+    for output_unit in [
+        // Not convertible from the input unit.
+        "pound-and-ounce",
+        // Not ordered from the largest to the smallest unit.
+        "inch-and-foot",
+        // Units that are not proportionally related.
+        "meter-and-meter",
+    ] {
+        let output_units = parser.try_mixed_from_str(output_unit).unwrap();
+        assert!(
+            converter_factory
+                .mixed_converter(&input_unit, &output_units, 0)
+                .is_none(),
+            "Output unit '{}' should not be convertible.",
+            output_unit
+        );
+    }
+
+    assert!(parser.try_mixed_from_str("foot-and-").is_err());
+    assert!(parser.try_mixed_from_str("foot-and-and-inch").is_err());
+}
+
+#[test]
+fn test_units_router() {
+    use icu_experimental::units::router::UnitsRouter;
+    use icu_locale_core::Locale;
+
+    struct UnitsRouterTest {
+        locale: &'static str,
+        category: &'static str,
+        usage: &'static str,
+        input_unit: &'static str,
+        input: &'static str,
+        expected_unit: &'static str,
+        expected: &'static [&'static str],
+    }
+
+    let tests = [
+        UnitsRouterTest {
+            locale: "en-US",
+            category: "length",
+            usage: "person-height",
+            input_unit: "meter",
+            input: "1.8",
+            expected_unit: "foot-and-inch",
+            expected: &["5", "10.87"],
+        },
+        UnitsRouterTest {
+            // Unknown regions fall back to 001.
+            locale: "fr-FR",
+            category: "length",
+            usage: "person-height",
+            input_unit: "meter",
+            input: "1.8",
+            expected_unit: "centimeter",
+            expected: &["180"],
+        },
+        UnitsRouterTest {
+            // Locales without a region use the preferences of 001.
+            locale: "en",
+            category: "length",
+            usage: "person-height",
+            input_unit: "meter",
+            input: "1.8",
+            expected_unit: "centimeter",
+            expected: &["180"],
+        },
+        UnitsRouterTest {
+            locale: "en-US-u-ms-metric",
+            category: "length",
+            usage: "person-height",
+            input_unit: "meter",
+            input: "1.8",
+            expected_unit: "centimeter",
+            expected: &["180"],
+        },
+        UnitsRouterTest {
+            locale: "de-DE-u-ms-uksystem",
+            category: "mass",
+            usage: "person",
+            input_unit: "kilogram",
+            input: "70",
+            expected_unit: "stone-and-pound",
+            expected: &["11", "0.32"],
+        },
+        UnitsRouterTest {
+            // 1000 meters are 0.62 miles, which is above the threshold of 0.5 miles.
+            locale: "en-GB",
+            category: "length",
+            usage: "road",
+            input_unit: "meter",
+            input: "1000",
+            expected_unit: "mile",
+            expected: &["0.62"],
+        },
+        UnitsRouterTest {
+            // 200 meters are 656 feet, which is below 0.5 miles but above 500 feet.
+            locale: "en-US",
+            category: "length",
+            usage: "road",
+            input_unit: "meter",
+            input: "200",
+            expected_unit: "foot",
+            expected: &["656.17"],
+        },
+        UnitsRouterTest {
+            locale: "en-GB",
+            category: "length",
+            usage: "road",
+            input_unit: "meter",
+            input: "-50",
+            expected_unit: "yard",
+            expected: &["-54.68"],
+        },
+        UnitsRouterTest {
+            // Unknown usages fall back to the parent usage.
+            locale: "en-US",
+            category: "length",
+            usage: "person-height-informal",
+            input_unit: "centimeter",
+            input: "180",
+            expected_unit: "foot-and-inch",
+            expected: &["5", "10.87"],
+        },
+        UnitsRouterTest {
+            // Unknown usages fall back to the default usage.
+            locale: "en-US",
+            category: "length",
+            usage: "unknown",
+            input_unit: "meter",
+            input: "0.5",
+            expected_unit: "foot",
+            expected: &["1.64"],
+        },
+        UnitsRouterTest {
+            locale: "en-PR",
+            category: "temperature",
+            usage: "weather",
+            input_unit: "celsius",
+            input: "20",
+            expected_unit: "fahrenheit",
+            expected: &["68"],
+        },
+        UnitsRouterTest {
+            locale: "en-US-u-mu-celsius",
+            category: "temperature",
+            usage: "weather",
+            input_unit: "fahrenheit",
+            input: "68",
+            expected_unit: "celsius",
+            expected: &["20"],
+        },
+        UnitsRouterTest {
+            // The temperature override does not apply to other categories.
+            locale: "en-US-u-mu-celsius",
+            category: "mass",
+            usage: "person",
+            input_unit: "kilogram",
+            input: "70",
+            expected_unit: "pound",
+            expected: &["154.32"],
+        },
+    ];
+
+    for test in tests {
+        let locale: Locale = test.locale.parse().unwrap();
+        let router = UnitsRouter::try_new(
+            (&locale).into(),
+            test.category,
+            test.usage,
+            test.input_unit,
+            2,
+        )
+        .expect("Failed to create router");
+
+        let input = IcuRatio::from_str(test.input).unwrap();
+        let route = router.route(&input);
+        let expected: Vec<IcuRatio> = test
+            .expected
+            .iter()
+            .map(|component| IcuRatio::from_str(component).unwrap())
+            .collect();
+        assert_eq!(
+            (route.unit(), route.converter().convert(&input)),
+            (test.expected_unit, expected),
+            "Failed test: Locale: {:?}, Category: {:?}, Usage: {:?}, Input: {:?}",
+            test.locale,
+            test.category,
+            test.usage,
+            test.input
+        );
+    }
+}
+
+#[test]
+fn test_units_router_skeletons() {
+    use icu_experimental::units::router::UnitsRouter;
+    use icu_locale_core::locale;
+
+    let router = UnitsRouter::try_new((&locale!("en-US")).into(), "length", "road", "meter", 2)
+        .expect("Failed to create router");
+    let routes: Vec<_> = router
+        .routes()
+        .iter()
+        .map(|route| (route.unit(), route.skeleton()))
+        .collect();
+    assert_eq!(
+        routes,
+        [
+            ("mile", None),
+            ("foot", Some("precision-increment/50")),
+            ("foot", Some("precision-increment/10")),
+        ]
+    );
+
+    // 100 meters are 328 feet, which is below the threshold of 500 feet.
+    let route = router.route(&IcuRatio::from(100u32));
+    assert_eq!(route.skeleton(), Some("precision-increment/10"));
+}
+
+#[test]
+fn test_units_router_errors() {
+    use icu_experimental::units::router::UnitsRouter;
+    use icu_locale_core::locale;
+
+    for (category, usage, input_unit) in [
+        // Unknown category.
+        ("volume", "default", "liter"),
+        // Invalid input unit.
+        ("length", "default", "metre"),
+        // Input unit not convertible to the preferred units.
+        ("length", "default", "kilogram"),
+    ] {
+        assert!(
+            UnitsRouter::try_new((&locale!("en-US")).into(), category, usage, input_unit, 0)
+                .is_err(),
+            "Category '{}' with input unit '{}' should fail.",
+            category,
+            input_unit
+        );
+    }
+}
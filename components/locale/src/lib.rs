@@ -92,6 +92,7 @@ mod directionality;
 pub mod exemplar_chars;
 mod expander;
 pub mod fallback;
+pub mod matcher;
 pub mod provider;
 
 pub use icu_locale_core::*;
@@ -101,6 +102,8 @@ pub use directionality::{Direction, LocaleDirectionality};
 pub use expander::LocaleExpander;
 #[doc(inline)]
 pub use fallback::LocaleFallbacker;
+#[doc(inline)]
+pub use matcher::LocaleMatcher;
 
 /// Used to track the result of a transformation operation that potentially modifies its argument in place.
 #[derive(Debug, PartialEq)]
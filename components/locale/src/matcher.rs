@@ -0,0 +1,583 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Tools for negotiating the best supported locale for a list of desired locales.
+//!
+//! The distance between two locales follows the CLDR [language matching] data: the locales are
+//! maximized with likely subtags, and the distances between their languages, scripts, and regions
+//! are added up. Regions are compared by partitions, so that for example `es-MX` is closer to
+//! `es-419` than to `es-ES`.
+//!
+//! Read more about the algorithm in [`LocaleMatcher`].
+//!
+//! [language matching]: https://www.unicode.org/reports/tr35/tr35.html#EnhancedLanguageMatching
+
+use crate::provider::*;
+use crate::LocaleExpander;
+use alloc::vec::Vec;
+use icu_locale_core::subtags::{language, region, script, Language, Region, Script};
+use icu_locale_core::LanguageIdentifier;
+use icu_provider::prelude::*;
+use zerovec::ZeroSlice;
+
+/// The distance of locales that do not match at all.
+const MAX_DISTANCE: u8 = 100;
+
+/// How the desired locales after the first one are penalized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum Demotion {
+    /// Each desired locale is demoted by slightly more than a region difference, relative to
+    /// the one before it. A later desired locale therefore only wins if it is a much better match,
+    /// e.g. if the earlier one only matches a different language.
+    #[default]
+    Region,
+    /// The desired locales are not demoted; the best match of any desired locale wins.
+    None,
+}
+
+/// A list of options set by the developer to adjust the behavior of the [`LocaleMatcher`].
+///
+/// # Examples
+///
+/// ```
+/// use icu::locale::matcher::{Demotion, LocaleMatcherOptions};
+///
+/// let options = LocaleMatcherOptions::default()
+///     .with_demotion(Demotion::None)
+///     .with_threshold(30);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct LocaleMatcherOptions {
+    /// How the desired locales after the first one are penalized.
+    ///
+    /// Default is [`Demotion::Region`].
+    pub demotion: Option<Demotion>,
+    /// The distance from which locales are not considered a match.
+    ///
+    /// Default is the distance between locales with different scripts, which is 50 in CLDR.
+    pub threshold: Option<u8>,
+}
+
+impl Default for LocaleMatcherOptions {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl LocaleMatcherOptions {
+    /// Constructs a new [`LocaleMatcherOptions`] struct.
+    pub const fn default() -> Self {
+        Self {
+            demotion: None,
+            threshold: None,
+        }
+    }
+
+    /// Auguments the struct with the set [`Demotion`].
+    pub const fn with_demotion(mut self, demotion: Demotion) -> Self {
+        self.demotion = Some(demotion);
+        self
+    }
+
+    /// Auguments the struct with the set threshold.
+    pub const fn with_threshold(mut self, threshold: u8) -> Self {
+        self.threshold = Some(threshold);
+        self
+    }
+}
+
+/// The result of [`LocaleMatcher::best_match`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct LocaleMatch<'a> {
+    /// The best supported locale.
+    pub supported: &'a LanguageIdentifier,
+    /// The index of the best supported locale in the supported locales.
+    pub supported_index: usize,
+    /// The index of the desired locale that it matches in the desired locales.
+    pub desired_index: usize,
+    /// The quality of the match, as the distance between the desired and the supported locale,
+    /// from 0 (equivalent locales) to 100 (unrelated locales).
+    ///
+    /// The distance does not include the demotion of the desired locale.
+    pub distance: u8,
+}
+
+/// A maximized locale, without variants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Lsr {
+    language: Language,
+    script: Option<Script>,
+    region: Option<Region>,
+}
+
+/// Picks the best supported locale for a list of desired locales, such as the languages in an
+/// HTTP `Accept-Language` header, following the CLDR [language matching] algorithm.
+///
+/// The desired and supported locales are maximized with likely subtags, and their distance is the
+/// sum of the distances between their languages, scripts, and regions, given by the first matching
+/// CLDR rule of each level. For example, Norwegian Nynorsk is close to Norwegian Bokmål,
+/// a Swiss German speaker usually also reads German, and `en-IN` is closer to `en-GB` than to
+/// `en-US`. Among regional variants at the same distance, the CLDR paradigm locales (such as
+/// `en-GB` and `es-419`) are preferred.
+///
+/// Supported locales at or above the threshold distance are not considered. Later desired locales
+/// are demoted, so that they only win if they are a much better match than earlier ones; see
+/// [`LocaleMatcherOptions`].
+///
+/// Locales that the [`LocaleExpander`] cannot maximize are compared without their missing
+/// subtags, and are therefore far from most other locales. Use
+/// [`new_extended`](Self::new_extended) to also maximize locales with less than *Basic* CLDR
+/// coverage, such as Swiss German.
+///
+/// The `Expander` generic parameter wraps a [`LocaleExpander`].
+///
+/// # Examples
+///
+/// ```
+/// use icu::locale::langid;
+/// use icu::locale::matcher::{LocaleMatcher, LocaleMatcherOptions};
+///
+/// let matcher = LocaleMatcher::new_common(LocaleMatcherOptions::default());
+///
+/// let supported = [
+///     langid!("en"),
+///     langid!("en-GB"),
+///     langid!("es"),
+///     langid!("es-419"),
+///     langid!("fr"),
+/// ];
+///
+/// // Latin American Spanish is preferred for Mexican Spanish.
+/// let best = matcher
+///     .best_match([&langid!("es-MX"), &langid!("en")], &supported)
+///     .unwrap();
+/// assert_eq!(best.supported, &langid!("es-419"));
+/// assert_eq!(best.desired_index, 0);
+///
+/// // British English is preferred for Indian English.
+/// let best = matcher.best_match([&langid!("en-IN")], &supported).unwrap();
+/// assert_eq!(best.supported, &langid!("en-GB"));
+///
+/// // A language that is not supported falls through to the next desired locale.
+/// let best = matcher
+///     .best_match([&langid!("ja"), &langid!("fr-CA")], &supported)
+///     .unwrap();
+/// assert_eq!(best.supported, &langid!("fr"));
+/// assert_eq!(best.desired_index, 1);
+///
+/// // Nothing matches.
+/// assert_eq!(matcher.best_match([&langid!("ja")], &supported), None);
+/// ```
+///
+/// [language matching]: https://www.unicode.org/reports/tr35/tr35.html#EnhancedLanguageMatching
+#[derive(Debug)]
+pub struct LocaleMatcher<Expander = LocaleExpander> {
+    matching: DataPayload<LanguageMatchingV1>,
+    expander: Expander,
+    options: LocaleMatcherOptions,
+}
+
+impl LocaleMatcher<LocaleExpander> {
+    /// Creates a [`LocaleMatcher`] from compiled data, using [`LocaleExpander`]
+    /// data for common locales.
+    ///
+    /// This includes limited likely subtags data, see [`LocaleExpander::new_common()`].
+    #[cfg(feature = "compiled_data")]
+    pub const fn new_common(options: LocaleMatcherOptions) -> Self {
+        Self::new_with_expander(LocaleExpander::new_common(), options)
+    }
+
+    // Note: This is a custom impl because the bounds on `try_new_unstable` don't suffice
+    #[doc = icu_provider::gen_buffer_unstable_docs!(BUFFER, Self::new_common)]
+    #[cfg(feature = "serde")]
+    pub fn try_new_common_with_buffer_provider(
+        provider: &(impl BufferProvider + ?Sized),
+        options: LocaleMatcherOptions,
+    ) -> Result<Self, DataError> {
+        let expander = LocaleExpander::try_new_common_with_buffer_provider(provider)?;
+        Self::try_new_with_expander_unstable(&provider.as_deserializing(), expander, options)
+    }
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new_common)]
+    pub fn try_new_common_unstable<P>(
+        provider: &P,
+        options: LocaleMatcherOptions,
+    ) -> Result<LocaleMatcher, DataError>
+    where
+        P: DataProvider<LanguageMatchingV1>
+            + DataProvider<LikelySubtagsForLanguageV1>
+            + DataProvider<LikelySubtagsForScriptRegionV1>
+            + ?Sized,
+    {
+        let expander = LocaleExpander::try_new_common_unstable(provider)?;
+        Self::try_new_with_expander_unstable(provider, expander, options)
+    }
+
+    /// Creates a [`LocaleMatcher`] from compiled data, using [`LocaleExpander`]
+    /// data for all locales.
+    ///
+    /// This includes all likely subtags data, see [`LocaleExpander::new_extended()`].
+    #[cfg(feature = "compiled_data")]
+    pub const fn new_extended(options: LocaleMatcherOptions) -> Self {
+        Self::new_with_expander(LocaleExpander::new_extended(), options)
+    }
+
+    // Note: This is a custom impl because the bounds on `try_new_unstable` don't suffice
+    #[doc = icu_provider::gen_buffer_unstable_docs!(BUFFER, Self::new_extended)]
+    #[cfg(feature = "serde")]
+    pub fn try_new_extended_with_buffer_provider(
+        provider: &(impl BufferProvider + ?Sized),
+        options: LocaleMatcherOptions,
+    ) -> Result<Self, DataError> {
+        let expander = LocaleExpander::try_new_extended_with_buffer_provider(provider)?;
+        Self::try_new_with_expander_unstable(&provider.as_deserializing(), expander, options)
+    }
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new_extended)]
+    pub fn try_new_extended_unstable<P>(
+        provider: &P,
+        options: LocaleMatcherOptions,
+    ) -> Result<LocaleMatcher, DataError>
+    where
+        P: DataProvider<LanguageMatchingV1>
+            + DataProvider<LikelySubtagsForLanguageV1>
+            + DataProvider<LikelySubtagsForScriptRegionV1>
+            + DataProvider<LikelySubtagsExtendedV1>
+            + ?Sized,
+    {
+        let expander = LocaleExpander::try_new_extended_unstable(provider)?;
+        Self::try_new_with_expander_unstable(provider, expander, options)
+    }
+}
+
+impl<Expander: AsRef<LocaleExpander>> LocaleMatcher<Expander> {
+    /// Creates a [`LocaleMatcher`] with a custom [`LocaleExpander`] and compiled data.
+    ///
+    /// This allows using [`LocaleExpander::new_extended()`] with data for all locales.
+    #[cfg(feature = "compiled_data")]
+    pub const fn new_with_expander(expander: Expander, options: LocaleMatcherOptions) -> Self {
+        LocaleMatcher {
+            matching: DataPayload::from_static_ref(
+                crate::provider::Baked::SINGLETON_LANGUAGE_MATCHING_V1,
+            ),
+            expander,
+            options,
+        }
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new_with_expander)]
+    pub fn try_new_with_expander_unstable<P>(
+        provider: &P,
+        expander: Expander,
+        options: LocaleMatcherOptions,
+    ) -> Result<Self, DataError>
+    where
+        P: DataProvider<LanguageMatchingV1> + ?Sized,
+    {
+        let matching = provider.load(Default::default())?.payload;
+
+        Ok(LocaleMatcher {
+            matching,
+            expander,
+            options,
+        })
+    }
+
+    /// Returns the best supported locale for the desired locales, which are given in order of
+    /// preference, or `None` if no supported locale is below the threshold distance for any of
+    /// them.
+    ///
+    /// Ties are broken in favor of the earlier supported locale. Variants and extensions are
+    /// ignored.
+    ///
+    /// See [`LocaleMatcher`] for an example.
+    pub fn best_match<'a, 'b>(
+        &self,
+        desired: impl IntoIterator<Item = &'b LanguageIdentifier>,
+        supported: &'a [LanguageIdentifier],
+    ) -> Option<LocaleMatch<'a>> {
+        let threshold = self
+            .options
+            .threshold
+            .unwrap_or_else(|| self.default_threshold());
+        let demotion = match self.options.demotion.unwrap_or_default() {
+            Demotion::Region => u32::from(self.default_demotion()),
+            Demotion::None => 0,
+        };
+
+        let supported_lsrs = supported
+            .iter()
+            .map(|langid| self.maximize(langid))
+            .collect::<Vec<_>>();
+
+        let mut best: Option<LocaleMatch<'a>> = None;
+        let mut best_score = u32::MAX;
+        for (desired_index, desired) in desired.into_iter().enumerate() {
+            let penalty = demotion.saturating_mul(desired_index as u32);
+            if penalty >= best_score {
+                break;
+            }
+            let desired_lsr = self.maximize(desired);
+            for (supported_index, (supported, supported_lsr)) in
+                supported.iter().zip(&supported_lsrs).enumerate()
+            {
+                let distance = self.lsr_distance(desired_lsr, *supported_lsr);
+                if distance >= threshold {
+                    continue;
+                }
+                let score = u32::from(distance) + penalty;
+                if score < best_score {
+                    best_score = score;
+                    best = Some(LocaleMatch {
+                        supported,
+                        supported_index,
+                        desired_index,
+                        distance,
+                    });
+                }
+            }
+        }
+        best
+    }
+
+    /// Returns the distance between a desired and a supported locale, from 0 (equivalent locales)
+    /// to 100 (unrelated locales).
+    ///
+    /// The distance is not necessarily symmetric: for example, a Swiss German speaker usually
+    /// reads German, but not the other way around.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locale::langid;
+    /// use icu::locale::matcher::{LocaleMatcher, LocaleMatcherOptions};
+    ///
+    /// let matcher = LocaleMatcher::new_extended(LocaleMatcherOptions::default());
+    ///
+    /// assert_eq!(matcher.distance(&langid!("en"), &langid!("en-Latn-US")), 0);
+    /// assert!(
+    ///     matcher.distance(&langid!("en-IN"), &langid!("en-GB"))
+    ///         < matcher.distance(&langid!("en-IN"), &langid!("en-US"))
+    /// );
+    /// assert!(
+    ///     matcher.distance(&langid!("gsw"), &langid!("de"))
+    ///         < matcher.distance(&langid!("de"), &langid!("gsw"))
+    /// );
+    /// assert_eq!(matcher.distance(&langid!("en"), &langid!("ja")), 100);
+    /// ```
+    pub fn distance(&self, desired: &LanguageIdentifier, supported: &LanguageIdentifier) -> u8 {
+        self.lsr_distance(self.maximize(desired), self.maximize(supported))
+    }
+
+    fn maximize(&self, langid: &LanguageIdentifier) -> Lsr {
+        let mut maximized = LanguageIdentifier {
+            language: langid.language,
+            script: langid.script,
+            region: langid.region,
+            variants: Default::default(),
+        };
+        self.expander.as_ref().maximize(&mut maximized);
+        Lsr {
+            language: maximized.language,
+            script: maximized.script,
+            region: maximized.region,
+        }
+    }
+
+    fn lsr_distance(&self, desired: Lsr, supported: Lsr) -> u8 {
+        let matching = self.matching.get();
+        let mut distance = 0u8;
+        if desired.language != supported.language {
+            distance = distance.saturating_add(self.rule_distance(
+                &matching.language_rules,
+                desired,
+                supported,
+            ));
+        }
+        if distance < MAX_DISTANCE && desired.script != supported.script {
+            distance = distance.saturating_add(self.rule_distance(
+                &matching.script_rules,
+                desired,
+                supported,
+            ));
+        }
+        if distance < MAX_DISTANCE && desired.region != supported.region {
+            let mut region_distance =
+                self.rule_distance(&matching.region_rules, desired, supported);
+            if self.is_paradigm(supported) && !self.is_paradigm(desired) {
+                region_distance = region_distance.saturating_sub(1);
+            }
+            distance = distance.saturating_add(region_distance);
+        }
+        distance.min(MAX_DISTANCE)
+    }
+
+    /// Returns the distance of the first rule that matches the locales, in either direction
+    /// unless the rule is one-way.
+    fn rule_distance(
+        &self,
+        rules: &ZeroSlice<LanguageMatchRule>,
+        desired: Lsr,
+        supported: Lsr,
+    ) -> u8 {
+        rules
+            .iter()
+            .find(|rule| {
+                (self.matches(&rule.desired, desired) && self.matches(&rule.supported, supported))
+                    || (!rule.oneway
+                        && self.matches(&rule.desired, supported)
+                        && self.matches(&rule.supported, desired))
+            })
+            .map(|rule| rule.distance)
+            .unwrap_or(MAX_DISTANCE)
+    }
+
+    fn matches(&self, pattern: &LanguageMatchPattern, lsr: Lsr) -> bool {
+        if pattern.language.is_some_and(|l| l != lsr.language) {
+            return false;
+        }
+        if pattern.script.is_some() && pattern.script != lsr.script {
+            return false;
+        }
+        if pattern.region.is_some() && pattern.region != lsr.region {
+            return false;
+        }
+        if let Some((index, negated)) = pattern.region_set {
+            let in_set = self
+                .matching
+                .get()
+                .region_sets
+                .get(usize::from(index))
+                .zip(lsr.region)
+                .is_some_and(|(set, region)| set.iter().any(|r| r == region));
+            if in_set == negated {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn is_paradigm(&self, lsr: Lsr) -> bool {
+        let (Some(script), Some(region)) = (lsr.script, lsr.region) else {
+            return false;
+        };
+        self.matching
+            .get()
+            .paradigm_locales
+            .iter()
+            .any(|paradigm| paradigm == (lsr.language, script, region))
+    }
+
+    /// The distance between locales with different scripts.
+    fn default_threshold(&self) -> u8 {
+        let und = Lsr {
+            language: Language::UND,
+            script: None,
+            region: None,
+        };
+        self.rule_distance(
+            &self.matching.get().script_rules,
+            Lsr {
+                script: Some(script!("Zzzz")),
+                ..und
+            },
+            Lsr {
+                script: Some(script!("Zyyy")),
+                ..und
+            },
+        )
+    }
+
+    /// Slightly more than the distance between regional variants.
+    fn default_demotion(&self) -> u8 {
+        let en_us = Lsr {
+            language: language!("en"),
+            script: Some(script!("Latn")),
+            region: Some(region!("US")),
+        };
+        let en_gb = Lsr {
+            region: Some(region!("GB")),
+            ..en_us
+        };
+        self.lsr_distance(en_us, en_gb).saturating_add(1)
+    }
+}
+
+#[cfg(feature = "compiled_data")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locale_core::langid;
+
+    #[test]
+    fn test_demotion() {
+        let supported = [langid!("fr"), langid!("en-GB")];
+        let desired = [langid!("fr-CA"), langid!("en-GB")];
+
+        let matcher = LocaleMatcher::new_common(LocaleMatcherOptions::default());
+        let best = matcher.best_match(&desired, &supported).unwrap();
+        assert_eq!(best.supported, &langid!("fr"));
+        assert_eq!(best.desired_index, 0);
+        assert_ne!(best.distance, 0);
+
+        // Without demotion, the exact match of the second desired locale wins.
+        let matcher = LocaleMatcher::new_common(
+            LocaleMatcherOptions::default().with_demotion(Demotion::None),
+        );
+        let best = matcher.best_match(&desired, &supported).unwrap();
+        assert_eq!(best.supported, &langid!("en-GB"));
+        assert_eq!(best.supported_index, 1);
+        assert_eq!(best.desired_index, 1);
+        assert_eq!(best.distance, 0);
+    }
+
+    #[test]
+    fn test_threshold() {
+        let supported = [langid!("en"), langid!("de")];
+
+        // Swiss German falls back to German.
+        let matcher = LocaleMatcher::new_extended(LocaleMatcherOptions::default());
+        let best = matcher.best_match([&langid!("gsw")], &supported).unwrap();
+        assert_eq!(best.supported, &langid!("de"));
+
+        // Traditional Chinese does not fall back to English.
+        assert_eq!(matcher.best_match([&langid!("zh-TW")], &supported), None);
+
+        // A stricter threshold only accepts regional variants.
+        let matcher =
+            LocaleMatcher::new_extended(LocaleMatcherOptions::default().with_threshold(4));
+        assert_eq!(matcher.best_match([&langid!("gsw")], &supported), None);
+        assert_eq!(
+            matcher
+                .best_match([&langid!("en-CA")], &supported)
+                .map(|m| m.supported),
+            Some(&langid!("en"))
+        );
+    }
+
+    #[test]
+    fn test_distance() {
+        let matcher = LocaleMatcher::new_extended(LocaleMatcherOptions::default());
+
+        // Same region partition.
+        assert_eq!(matcher.distance(&langid!("en-US"), &langid!("en-CA")), 4);
+        assert_eq!(matcher.distance(&langid!("en-CA"), &langid!("en-US")), 3);
+        // Different region partitions.
+        assert_eq!(matcher.distance(&langid!("es-MX"), &langid!("es-GQ")), 5);
+        // Paradigm locales are preferred within a partition.
+        assert_eq!(matcher.distance(&langid!("es-MX"), &langid!("es-AR")), 4);
+        assert_eq!(matcher.distance(&langid!("es-MX"), &langid!("es-419")), 3);
+        // Scripts.
+        assert_eq!(matcher.distance(&langid!("zh-MO"), &langid!("zh-HK")), 4);
+        assert_eq!(matcher.distance(&langid!("zh-HK"), &langid!("zh-TW")), 5);
+        assert!(matcher.distance(&langid!("zh-TW"), &langid!("zh-CN")) < 50);
+        assert_eq!(matcher.distance(&langid!("sr-Latn"), &langid!("sr")), 5);
+        // Closely related languages.
+        assert_eq!(matcher.distance(&langid!("nb"), &langid!("no")), 1);
+        assert_eq!(matcher.distance(&langid!("nn"), &langid!("nb")), 20);
+        // Unrelated languages.
+        assert_eq!(matcher.distance(&langid!("fr"), &langid!("de")), 84);
+    }
+}
@@ -39,6 +39,7 @@ const _: () = {
     impl_likely_subtags_extended_v1!(Baked);
     impl_likely_subtags_for_language_v1!(Baked);
     impl_likely_subtags_for_script_region_v1!(Baked);
+    impl_language_matching_v1!(Baked);
     impl_parents_v1!(Baked);
     impl_script_direction_v1!(Baked);
 
@@ -58,6 +59,7 @@ pub const MARKERS: &[DataMarkerInfo] = &[
     ExemplarCharactersMainV1::INFO,
     ExemplarCharactersNumbersV1::INFO,
     ExemplarCharactersPunctuationV1::INFO,
+    LanguageMatchingV1::INFO,
     LikelySubtagsExtendedV1::INFO,
     LikelySubtagsForLanguageV1::INFO,
     LikelySubtagsForScriptRegionV1::INFO,
@@ -375,6 +377,89 @@ pub struct ScriptDirection<'data> {
     pub ltr: ZeroVec<'data, UnvalidatedScript>,
 }
 
+/// A pattern for a maximized locale in a [`LanguageMatchRule`], such as `en_*_$!enUS`.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(LanguageMatchPatternULE)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Debug)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_locale::provider))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct LanguageMatchPattern {
+    /// The language, or `None` for any language (`*`).
+    pub language: Option<Language>,
+    /// The script, or `None` for any script (`*`).
+    pub script: Option<Script>,
+    /// The region, or `None` for any region (`*`) or a region set.
+    pub region: Option<Region>,
+    /// The index of a region set in [`LanguageMatching::region_sets`], and whether the pattern
+    /// matches the regions outside of the set (`$!name`) instead of inside it (`$name`).
+    pub region_set: Option<(u8, bool)>,
+}
+
+/// A rule giving the distance between a desired and a supported locale.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(LanguageMatchRuleULE)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Debug)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_locale::provider))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct LanguageMatchRule {
+    /// The pattern for the desired locale.
+    pub desired: LanguageMatchPattern,
+    /// The pattern for the supported locale.
+    pub supported: LanguageMatchPattern,
+    /// The distance between the locales.
+    pub distance: u8,
+    /// Whether the rule only applies in the given direction, and not with the desired and
+    /// supported locales swapped.
+    pub oneway: bool,
+}
+
+#[icu_provider::data_struct(marker(LanguageMatchingV1, "locale/language_matching@1", singleton))]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_locale::provider))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+/// This language matching data is used to compute the distance between locales, following
+/// the CLDR `languageMatching` data.
+///
+/// The rules of each level are ordered, and the first matching rule applies.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[yoke(prove_covariance_manually)]
+pub struct LanguageMatching<'data> {
+    /// The maximized paradigm locales, which are preferred over other regional variants.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub paradigm_locales: ZeroVec<'data, (Language, Script, Region)>,
+    /// The region sets used by the region rules (`$enUS`, `$americas`, ...), with containment
+    /// resolved.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub region_sets: VarZeroVec<'data, ZeroSlice<Region>>,
+    /// The rules for locales with different languages.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub language_rules: ZeroVec<'data, LanguageMatchRule>,
+    /// The rules for locales with different scripts.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub script_rules: ZeroVec<'data, LanguageMatchRule>,
+    /// The rules for locales with different regions.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub region_rules: ZeroVec<'data, LanguageMatchRule>,
+}
+
 /// A set of characters and strings which share a particular property value.
 ///
 /// <div class="stab unstable">
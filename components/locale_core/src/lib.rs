@@ -87,6 +87,7 @@ pub mod extensions;
 #[macro_use]
 pub mod subtags;
 pub mod preferences;
+pub mod priority_list;
 pub mod zerovec;
 
 #[cfg(feature = "serde")]
@@ -64,6 +64,19 @@ pub enum ParseError {
     /// ```
     #[displaydoc("Duplicated extension")]
     DuplicatedExtension,
+
+    /// Invalid quality value in a locale priority list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locale::priority_list::Quality;
+    /// use icu::locale::ParseError;
+    ///
+    /// assert_eq!("1.5".parse::<Quality>(), Err(ParseError::InvalidQuality));
+    /// ```
+    #[displaydoc("Invalid quality value")]
+    InvalidQuality,
 }
 
 impl core::error::Error for ParseError {}
@@ -0,0 +1,621 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Parsing and serializing weighted locale priority lists, such as the value of an HTTP
+//! [`Accept-Language`] header.
+//!
+//! The main type is [`LocalePriorityList`], which parses a header into [`Locale`]s ordered by
+//! descending [`Quality`]. The underlying [`PriorityListIter`] does not allocate, and can be
+//! used directly by clients that want to process the entries themselves.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::locale::locale;
+//! use icu::locale::priority_list::LocalePriorityList;
+//! use writeable::assert_writeable_eq;
+//!
+//! let list =
+//!     LocalePriorityList::try_from_str("fr;q=0.8, en-US, *;q=0.1, de;q=0")
+//!         .expect("valid header");
+//!
+//! // Entries are ordered by quality, and `*` and `q=0` entries are skipped.
+//! assert_eq!(
+//!     list.locales().collect::<Vec<_>>(),
+//!     [&locale!("en-US"), &locale!("fr")]
+//! );
+//!
+//! assert_writeable_eq!(list, "en-US, fr;q=0.8, *;q=0.1, de;q=0");
+//! ```
+//!
+//! [`Accept-Language`]: https://www.rfc-editor.org/rfc/rfc9110#name-accept-language
+
+use crate::ParseError;
+#[cfg(feature = "alloc")]
+use crate::{LanguageIdentifier, Locale};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::str::FromStr;
+use writeable::{LengthHint, Writeable};
+
+/// The weight of an entry in a [`LocalePriorityList`], between `0` and `1`
+/// with a precision of three decimal digits.
+///
+/// A quality of `0` marks the entry as not acceptable.
+///
+/// # Examples
+///
+/// ```
+/// use icu::locale::priority_list::Quality;
+/// use writeable::assert_writeable_eq;
+///
+/// let q = Quality::try_from_str("0.85").expect("valid quality");
+/// assert_eq!(q.to_thousandths(), 850);
+/// assert_writeable_eq!(q, "0.85");
+///
+/// assert!(Quality::try_from_str("1.5").is_err());
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct Quality(u16);
+
+impl Quality {
+    /// The lowest quality, `q=0`.
+    pub const ZERO: Self = Self(0);
+
+    /// The highest quality, `q=1`, which is used for entries without an explicit weight.
+    pub const ONE: Self = Self(1000);
+
+    /// Creates a [`Quality`] from a number of thousandths, between `0` and `1000`.
+    pub const fn try_from_thousandths(thousandths: u16) -> Result<Self, ParseError> {
+        if thousandths <= Self::ONE.0 {
+            Ok(Self(thousandths))
+        } else {
+            Err(ParseError::InvalidQuality)
+        }
+    }
+
+    /// Returns this quality as a number of thousandths, between `0` and `1000`.
+    pub const fn to_thousandths(self) -> u16 {
+        self.0
+    }
+
+    /// A constructor which takes a str slice such as `"0.8"`, parses it and
+    /// produces a [`Quality`].
+    #[inline]
+    pub const fn try_from_str(s: &str) -> Result<Self, ParseError> {
+        Self::try_from_utf8(s.as_bytes())
+    }
+
+    /// See [`Self::try_from_str`]
+    pub const fn try_from_utf8(code_units: &[u8]) -> Result<Self, ParseError> {
+        // qvalue = ( "0" [ "." 0*3DIGIT ] ) / ( "1" [ "." 0*3("0") ] )
+        let (integer, fraction) = match code_units.split_first() {
+            Some((&b'0', rest)) => (0, rest),
+            Some((&b'1', rest)) => (1000, rest),
+            _ => return Err(ParseError::InvalidQuality),
+        };
+        let digits = match fraction.split_first() {
+            None => fraction,
+            Some((&b'.', digits)) if digits.len() <= 3 => digits,
+            _ => return Err(ParseError::InvalidQuality),
+        };
+        let mut value = 0;
+        let mut scale = 100;
+        let mut i = 0;
+        #[allow(clippy::indexing_slicing)] // i < digits.len()
+        while i < digits.len() {
+            let digit = digits[i];
+            if !digit.is_ascii_digit() {
+                return Err(ParseError::InvalidQuality);
+            }
+            value += (digit - b'0') as u16 * scale;
+            scale /= 10;
+            i += 1;
+        }
+        Self::try_from_thousandths(integer + value)
+    }
+}
+
+impl Default for Quality {
+    fn default() -> Self {
+        Self::ONE
+    }
+}
+
+impl Writeable for Quality {
+    fn write_to<W: core::fmt::Write + ?Sized>(&self, sink: &mut W) -> core::fmt::Result {
+        if *self == Self::ONE {
+            return sink.write_char('1');
+        }
+        sink.write_str("0")?;
+        let mut fraction = self.0;
+        if fraction == 0 {
+            return Ok(());
+        }
+        sink.write_char('.')?;
+        let mut scale = 100;
+        while fraction > 0 {
+            let digit = fraction / scale;
+            sink.write_char(char::from(b'0' + digit as u8))?;
+            fraction %= scale;
+            scale /= 10;
+        }
+        Ok(())
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        LengthHint::between(1, 5)
+    }
+}
+
+writeable::impl_display_with_writeable!(Quality);
+
+impl FromStr for Quality {
+    type Err = ParseError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s)
+    }
+}
+
+/// A single entry of a priority list, as returned by [`PriorityListIter`].
+///
+/// The language range is not validated beyond its syntax, so that it can be
+/// inspected without allocating.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct PriorityListItem<'a> {
+    range: &'a [u8],
+    quality: Quality,
+}
+
+impl<'a> PriorityListItem<'a> {
+    /// Returns the language range of this entry, such as `b"en-US"` or `b"*"`.
+    ///
+    /// Legacy `_` separators are returned unchanged.
+    pub fn range(&self) -> &'a [u8] {
+        self.range
+    }
+
+    /// Returns the quality of this entry, which is [`Quality::ONE`] if not specified.
+    pub fn quality(&self) -> Quality {
+        self.quality
+    }
+
+    /// Whether this entry is the `*` wildcard, which matches any locale.
+    pub fn is_wildcard(&self) -> bool {
+        self.range == b"*"
+    }
+
+    /// Parses the language range of this entry into a [`Locale`], returning `None`
+    /// for the `*` wildcard.
+    ///
+    /// Legacy `_` separators are accepted in place of `-`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locale::locale;
+    /// use icu::locale::priority_list::PriorityListIter;
+    ///
+    /// let mut iter = PriorityListIter::new(b"en_GB;q=0.5, *");
+    ///
+    /// let item = iter.next().unwrap().unwrap();
+    /// assert_eq!(item.range(), b"en_GB");
+    /// assert_eq!(item.try_to_locale(), Ok(Some(locale!("en-GB"))));
+    ///
+    /// let item = iter.next().unwrap().unwrap();
+    /// assert_eq!(item.try_to_locale(), Ok(None));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn try_to_locale(&self) -> Result<Option<Locale>, ParseError> {
+        if self.is_wildcard() {
+            return Ok(None);
+        }
+        if self.range.contains(&b'_') {
+            let normalized = self
+                .range
+                .iter()
+                .map(|&b| if b == b'_' { b'-' } else { b })
+                .collect::<Vec<_>>();
+            Locale::try_from_utf8(&normalized).map(Some)
+        } else {
+            Locale::try_from_utf8(self.range).map(Some)
+        }
+    }
+}
+
+/// A non-allocating iterator over the entries of an [`Accept-Language`]-style priority list,
+/// in input order.
+///
+/// Empty list elements and optional whitespace are skipped. Each malformed entry produces
+/// an error, after which iteration continues with the next entry, so that clients can choose
+/// to ignore invalid entries.
+///
+/// # Examples
+///
+/// ```
+/// use icu::locale::priority_list::{PriorityListIter, Quality};
+/// use icu::locale::ParseError;
+///
+/// let mut iter = PriorityListIter::new(b"de-CH, de;q=0.9,, fr;q=2, en;q=0.5");
+///
+/// let item = iter.next().unwrap().unwrap();
+/// assert_eq!(item.range(), b"de-CH");
+/// assert_eq!(item.quality(), Quality::ONE);
+///
+/// let item = iter.next().unwrap().unwrap();
+/// assert_eq!(item.range(), b"de");
+/// assert_eq!(item.quality().to_thousandths(), 900);
+///
+/// assert_eq!(iter.next(), Some(Err(ParseError::InvalidQuality)));
+///
+/// let item = iter.next().unwrap().unwrap();
+/// assert_eq!(item.range(), b"en");
+///
+/// assert_eq!(iter.next(), None);
+/// ```
+///
+/// [`Accept-Language`]: https://www.rfc-editor.org/rfc/rfc9110#name-accept-language
+#[derive(Debug, Clone)]
+pub struct PriorityListIter<'a> {
+    remaining: &'a [u8],
+}
+
+impl<'a> PriorityListIter<'a> {
+    /// Creates an iterator over the entries of the given priority list.
+    pub fn new(code_units: &'a [u8]) -> Self {
+        Self {
+            remaining: code_units,
+        }
+    }
+}
+
+impl<'a> Iterator for PriorityListIter<'a> {
+    type Item = Result<PriorityListItem<'a>, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.remaining.is_empty() {
+                return None;
+            }
+            let mut elements = self.remaining.splitn(2, |&b| b == b',');
+            let element = elements.next().unwrap_or_default();
+            self.remaining = elements.next().unwrap_or_default();
+
+            let element = trim_ows(element);
+            if element.is_empty() {
+                continue;
+            }
+            return Some(parse_element(element));
+        }
+    }
+}
+
+/// Parses a single `language-range [ weight ]` element.
+fn parse_element(element: &[u8]) -> Result<PriorityListItem, ParseError> {
+    let mut parts = element.split(|&b| b == b';');
+    let range = trim_ows(parts.next().unwrap_or_default());
+    let is_valid_range = range == b"*"
+        || (!range.is_empty()
+            && range
+                .iter()
+                .all(|&b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_'));
+    if !is_valid_range {
+        return Err(ParseError::InvalidLanguage);
+    }
+
+    let mut quality = None;
+    for parameter in parts {
+        let value = match trim_ows(parameter) {
+            [b'q' | b'Q', b'=', value @ ..] if quality.is_none() => value,
+            _ => return Err(ParseError::InvalidQuality),
+        };
+        quality = Some(Quality::try_from_utf8(value)?);
+    }
+
+    Ok(PriorityListItem {
+        range,
+        quality: quality.unwrap_or_default(),
+    })
+}
+
+/// Trims optional whitespace (spaces and horizontal tabs) from both ends.
+fn trim_ows(mut bytes: &[u8]) -> &[u8] {
+    while let [b' ' | b'\t', rest @ ..] = bytes {
+        bytes = rest;
+    }
+    while let [rest @ .., b' ' | b'\t'] = bytes {
+        bytes = rest;
+    }
+    bytes
+}
+
+/// A list of [`Locale`]s with weights, ordered from the most to the least preferred,
+/// such as the value of an HTTP [`Accept-Language`] header.
+///
+/// Entries with the same [`Quality`] keep their relative order from the input. The `*`
+/// wildcard is kept as an entry without a locale, and can be queried with
+/// [`LocalePriorityList::wildcard_quality`].
+///
+/// Parsing accepts legacy `_` separators, and the list serializes to its canonical form
+/// via [`Writeable`].
+///
+/// # Examples
+///
+/// The list can be used as the desired locales of a matching API:
+///
+/// ```
+/// use icu::locale::priority_list::LocalePriorityList;
+/// use icu::locale::{langid, locale, LanguageIdentifier};
+///
+/// let list: LocalePriorityList =
+///     "es_419;q=0.9, pt-BR, *;q=0.5".parse().expect("valid header");
+///
+/// let desired = list
+///     .locales()
+///     .map(|l| &l.id)
+///     .collect::<Vec<&LanguageIdentifier>>();
+/// assert_eq!(desired, [&langid!("pt-BR"), &langid!("es-419")]);
+/// ```
+///
+/// Building a list programmatically:
+///
+/// ```
+/// use icu::locale::locale;
+/// use icu::locale::priority_list::{LocalePriorityList, Quality};
+/// use writeable::assert_writeable_eq;
+///
+/// let mut list = LocalePriorityList::new();
+/// list.push(None, Quality::try_from_thousandths(100).unwrap());
+/// list.push(Some(locale!("sr-Latn")), Quality::ONE);
+/// list.push(Some(locale!("hr")), Quality::try_from_str("0.7").unwrap());
+///
+/// assert_writeable_eq!(list, "sr-Latn, hr;q=0.7, *;q=0.1");
+/// ```
+///
+/// [`Accept-Language`]: https://www.rfc-editor.org/rfc/rfc9110#name-accept-language
+#[cfg(feature = "alloc")]
+#[derive(Debug, Default, PartialEq, Eq, Clone, Hash)]
+pub struct LocalePriorityList {
+    // Invariant: sorted by descending quality
+    entries: Vec<(Option<Locale>, Quality)>,
+}
+
+#[cfg(feature = "alloc")]
+impl LocalePriorityList {
+    /// Creates an empty [`LocalePriorityList`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A constructor which takes a str slice, parses it and
+    /// produces a [`LocalePriorityList`].
+    ///
+    /// Fails on the first malformed entry. Use [`PriorityListIter`] to skip invalid entries instead.
+    #[inline]
+    pub fn try_from_str(s: &str) -> Result<Self, ParseError> {
+        Self::try_from_utf8(s.as_bytes())
+    }
+
+    /// See [`Self::try_from_str`]
+    pub fn try_from_utf8(code_units: &[u8]) -> Result<Self, ParseError> {
+        let mut list = Self::new();
+        for item in PriorityListIter::new(code_units) {
+            let item = item?;
+            list.push(item.try_to_locale()?, item.quality());
+        }
+        Ok(list)
+    }
+
+    /// Adds an entry to the list, after all entries with the same or a higher quality.
+    ///
+    /// `None` stands for the `*` wildcard.
+    pub fn push(&mut self, locale: Option<Locale>, quality: Quality) {
+        let index = self.entries.partition_point(|&(_, q)| q >= quality);
+        self.entries.insert(index, (locale, quality));
+    }
+
+    /// Returns the number of entries in the list, including the wildcard and entries with a
+    /// quality of zero.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the list has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns all entries in priority order. `None` stands for the `*` wildcard.
+    pub fn iter(&self) -> impl Iterator<Item = (Option<&Locale>, Quality)> + '_ {
+        self.entries.iter().map(|(l, q)| (l.as_ref(), *q))
+    }
+
+    /// Returns the acceptable locales in priority order, skipping the `*` wildcard and
+    /// entries with a quality of zero.
+    pub fn locales(&self) -> impl Iterator<Item = &Locale> + '_ {
+        self.entries
+            .iter()
+            .filter(|(_, q)| *q > Quality::ZERO)
+            .filter_map(|(l, _)| l.as_ref())
+    }
+
+    /// Returns the quality of the `*` wildcard, if it is present.
+    pub fn wildcard_quality(&self) -> Option<Quality> {
+        self.entries
+            .iter()
+            .find(|(l, _)| l.is_none())
+            .map(|(_, q)| *q)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromStr for LocalePriorityList {
+    type Err = ParseError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromIterator<LanguageIdentifier> for LocalePriorityList {
+    /// Creates a list with all the given language identifiers at [`Quality::ONE`].
+    fn from_iter<I: IntoIterator<Item = LanguageIdentifier>>(iter: I) -> Self {
+        Self {
+            entries: iter
+                .into_iter()
+                .map(|id| (Some(Locale::from(id)), Quality::ONE))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromIterator<Locale> for LocalePriorityList {
+    /// Creates a list with all the given locales at [`Quality::ONE`].
+    fn from_iter<I: IntoIterator<Item = Locale>>(iter: I) -> Self {
+        Self {
+            entries: iter.into_iter().map(|l| (Some(l), Quality::ONE)).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Writeable for LocalePriorityList {
+    fn write_to<W: core::fmt::Write + ?Sized>(&self, sink: &mut W) -> core::fmt::Result {
+        for (i, (locale, quality)) in self.entries.iter().enumerate() {
+            if i != 0 {
+                sink.write_str(", ")?;
+            }
+            match locale {
+                Some(locale) => locale.write_to(sink)?,
+                None => sink.write_char('*')?,
+            }
+            if *quality != Quality::ONE {
+                sink.write_str(";q=")?;
+                quality.write_to(sink)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        let mut result = LengthHint::exact(self.entries.len().saturating_sub(1) * 2);
+        for (locale, quality) in &self.entries {
+            result += match locale {
+                Some(locale) => locale.writeable_length_hint(),
+                None => LengthHint::exact(1),
+            };
+            if *quality != Quality::ONE {
+                result += quality.writeable_length_hint() + 3;
+            }
+        }
+        result
+    }
+}
+
+#[cfg(feature = "alloc")]
+writeable::impl_display_with_writeable!(LocalePriorityList);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use writeable::assert_writeable_eq;
+
+    #[test]
+    fn test_quality() {
+        for (input, thousandths, output) in [
+            ("0", 0, "0"),
+            ("0.", 0, "0"),
+            ("0.000", 0, "0"),
+            ("0.5", 500, "0.5"),
+            ("0.05", 50, "0.05"),
+            ("0.123", 123, "0.123"),
+            ("0.900", 900, "0.9"),
+            ("1", 1000, "1"),
+            ("1.000", 1000, "1"),
+        ] {
+            let q = Quality::try_from_str(input).unwrap();
+            assert_eq!(q.to_thousandths(), thousandths, "{input}");
+            assert_writeable_eq!(q, output, "{input}");
+        }
+
+        for input in ["", ".5", "1.001", "2", "0.1234", "0,5", "-0", "0.5x", "1e0"] {
+            assert_eq!(
+                Quality::try_from_str(input),
+                Err(ParseError::InvalidQuality),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_iter() {
+        let items = PriorityListIter::new(b" en-US ,fr ; q=0.5,\t,de;Q=0 ,*")
+            .map(|r| r.map(|i| (i.range(), i.quality().to_thousandths())))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            items,
+            [
+                Ok((&b"en-US"[..], 1000)),
+                Ok((&b"fr"[..], 500)),
+                Ok((&b"de"[..], 0)),
+                Ok((&b"*"[..], 1000)),
+            ]
+        );
+
+        for input in [
+            "en;",
+            "en;q",
+            "en;q=",
+            "en;level=1",
+            "en;q=0.5;q=0.5",
+            ";q=0.5",
+        ] {
+            let mut iter = PriorityListIter::new(input.as_bytes());
+            assert!(iter.next().unwrap().is_err(), "{input}");
+            assert_eq!(iter.next(), None, "{input}");
+        }
+
+        assert_eq!(
+            PriorityListIter::new(b"en US").next(),
+            Some(Err(ParseError::InvalidLanguage))
+        );
+        assert_eq!(PriorityListIter::new(b" , ,").next(), None);
+    }
+
+    #[test]
+    fn test_list() {
+        let list = LocalePriorityList::try_from_str(
+            "da, en-gb;q=0.8, en;q=0.7, *;q=0.1, zh_hant_tw;q=0.8",
+        )
+        .unwrap();
+        assert_eq!(list.len(), 5);
+        assert_eq!(
+            list.wildcard_quality(),
+            Quality::try_from_thousandths(100).ok()
+        );
+        assert_writeable_eq!(list, "da, en-GB;q=0.8, zh-Hant-TW;q=0.8, en;q=0.7, *;q=0.1");
+
+        let list = LocalePriorityList::try_from_str("").unwrap();
+        assert!(list.is_empty());
+        assert_writeable_eq!(list, "");
+
+        assert_eq!(
+            LocalePriorityList::try_from_str("en, 12"),
+            Err(ParseError::InvalidLanguage)
+        );
+        assert_eq!(
+            LocalePriorityList::try_from_str("en-*"),
+            Err(ParseError::InvalidLanguage)
+        );
+        assert_eq!(
+            LocalePriorityList::try_from_str("en-US-x"),
+            Err(ParseError::InvalidExtension)
+        );
+    }
+}
@@ -29,22 +29,27 @@
 //! The top level of the crate provides normalization of input into the four normalization forms defined in [UAX #15: Unicode
 //! Normalization Forms](https://www.unicode.org/reports/tr15/): NFC, NFD, NFKC, and NFKD.
 //!
+//! It also provides the FCC form (“Fast C Contiguous”) from [UTN #5: Canonical Equivalence in
+//! Applications](https://www.unicode.org/notes/tn5/) and an FCD check via [`FcdCheckerBorrowed`].
+//!
 //! Three kinds of contiguous inputs are supported: known-well-formed UTF-8 (`&str`), potentially-not-well-formed UTF-8,
-//! and potentially-not-well-formed UTF-16. Additionally, an iterator over `char` can be wrapped in a normalizing iterator.
+//! and potentially-not-well-formed UTF-16. Additionally, an iterator over `char` can be wrapped in a normalizing iterator,
+//! and potentially-not-well-formed UTF-8 that arrives in chunks can be normalized incrementally with a [`StreamingNormalizer`].
 //!
 //! The `uts46` module provides the combination of mapping and normalization operations for [UTS #46: Unicode IDNA
 //! Compatibility Processing](https://www.unicode.org/reports/tr46/). This functionality is not meant to be used by
 //! applications directly. Instead, it is meant as a building block for a full implementation of UTS #46, such as the
-//! [`idna`](https://docs.rs/idna/latest/idna/) crate.
+//! [`idna`](https://docs.rs/idna/latest/idna/) crate. With the `icu_properties` Cargo feature, the module also provides
+//! such a full implementation of the ToASCII and ToUnicode operations.
 //!
 //! The `properties` module provides the non-recursive canonical decomposition operation on a per `char` basis and
 //! the canonical compositon operation given two `char`s. It also provides access to the Canonical Combining Class
 //! property. These operations are primarily meant for [HarfBuzz](https://harfbuzz.github.io/) via the
 //! [`icu_harfbuzz`](https://docs.rs/icu_harfbuzz/latest/icu_harfbuzz/) crate.
 //!
-//! Notably, this normalizer does _not_ provide the normalization “quick check” that can result in “maybe” in
-//! addition to “yes” and “no”. The normalization checks provided by this crate always give a definitive
-//! non-“maybe” answer.
+//! In addition to the definitive `is_normalized` checks, the normalizers provide the normalization “quick check”,
+//! which can result in “maybe” in addition to “yes” and “no” (see [`IsNormalized`]), both for strings and for
+//! individual characters.
 //!
 //! # Examples
 //!
@@ -978,6 +983,8 @@ where
     /// 2. Decomposition starts with a non-starter
     /// 3. Is not a backward-combining starter
     composition_passthrough_bound: u32,
+    /// Whether only contiguous combining characters are composed (FCC).
+    only_contiguous: bool,
 }
 
 impl<'data, I> Composition<'data, I>
@@ -988,12 +995,14 @@ where
         decomposition: Decomposition<'data, I>,
         canonical_compositions: Char16Trie<'data>,
         composition_passthrough_bound: u16,
+        only_contiguous: bool,
     ) -> Self {
         Self {
             decomposition,
             canonical_compositions,
             unprocessed_starter: None,
             composition_passthrough_bound: u32::from(composition_passthrough_bound),
+            only_contiguous,
         }
     }
 
@@ -1147,7 +1156,7 @@ where
                         return Some(starter);
                     }
                     debug_assert!(ccc >= most_recent_skipped_ccc);
-                    if ccc != most_recent_skipped_ccc {
+                    if !self.only_contiguous && ccc != most_recent_skipped_ccc {
                         // Using the non-Hangul version as a micro-optimization, since
                         // we already rejected the case where `second` is a starter
                         // above, and conjoining jamo are starters.
@@ -1326,7 +1335,7 @@ macro_rules! composing_normalize_to {
                                 continue 'bufferloop;
                             }
                             debug_assert!(ccc >= most_recent_skipped_ccc);
-                            if ccc != most_recent_skipped_ccc {
+                            if !$composition.only_contiguous && ccc != most_recent_skipped_ccc {
                                 // Using the non-Hangul version as a micro-optimization, since
                                 // we already rejected the case where `second` is a starter
                                 // above, and conjoining jamo are starters.
@@ -1485,6 +1494,14 @@ macro_rules! normalizer_methods {
             self.is_normalized_up_to(text) == text.len()
         }
 
+        /// Quick-checks whether a string slice is normalized.
+        ///
+        /// Unlike [`Self::is_normalized`], this may answer [`IsNormalized::Maybe`],
+        /// but it never needs to decompose or compose the input.
+        pub fn quick_check(&self, text: &str) -> IsNormalized {
+            self.quick_check_iter(text.chars())
+        }
+
         /// Normalize a slice of potentially-invalid UTF-16 into a `Cow<'a, [u16]>`.
         ///
         /// Unpaired surrogates are mapped to the REPLACEMENT CHARACTER
@@ -1538,6 +1555,16 @@ macro_rules! normalizer_methods {
             self.is_normalized_utf16_up_to(text) == text.len()
         }
 
+        /// Quick-checks whether a slice of potentially-invalid UTF-16 is normalized.
+        ///
+        /// Unpaired surrogates are treated as the REPLACEMENT CHARACTER.
+        ///
+        /// ✨ *Enabled with the `utf16_iter` Cargo feature.*
+        #[cfg(feature = "utf16_iter")]
+        pub fn quick_check_utf16(&self, text: &[u16]) -> IsNormalized {
+            self.quick_check_iter(text.chars())
+        }
+
         /// Normalize a slice of potentially-invalid UTF-8 into a `Cow<'a, str>`.
         ///
         /// Ill-formed byte sequences are mapped to the REPLACEMENT CHARACTER
@@ -1595,9 +1622,36 @@ macro_rules! normalizer_methods {
         pub fn is_normalized_utf8(&self, text: &[u8]) -> bool {
             self.is_normalized_utf8_up_to(text) == text.len()
         }
+
+        /// Quick-checks whether a slice of potentially-invalid UTF-8 is normalized.
+        ///
+        /// Ill-formed byte sequences are mapped to the REPLACEMENT CHARACTER
+        /// according to the WHATWG Encoding Standard before checking.
+        ///
+        /// ✨ *Enabled with the `utf8_iter` Cargo feature.*
+        #[cfg(feature = "utf8_iter")]
+        pub fn quick_check_utf8(&self, text: &[u8]) -> IsNormalized {
+            self.quick_check_iter(text.chars())
+        }
     };
 }
 
+/// The result of a normalization quick check.
+///
+/// These are the values of the NFD_QC, NFKD_QC, NFC_QC, and NFKC_QC properties
+/// defined in [UAX #15](https://www.unicode.org/reports/tr15/#Detecting_Normalization_Forms).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[allow(clippy::exhaustive_enums)] // this type is stable
+pub enum IsNormalized {
+    /// The input is normalized.
+    Yes,
+    /// The input is not normalized.
+    No,
+    /// The input may or may not be normalized. A full normalization
+    /// check, such as `is_normalized`, is needed to find out.
+    Maybe,
+}
+
 /// Borrowed version of a normalizer for performing decomposing normalization.
 #[derive(Debug)]
 pub struct DecomposingNormalizerBorrowed<'a> {
@@ -1783,6 +1837,79 @@ impl DecomposingNormalizerBorrowed<'_> {
 
     normalizer_methods!();
 
+    /// Returns a push-based normalizer for UTF-8 input that arrives in chunks.
+    ///
+    /// See [`StreamingNormalizer`].
+    pub fn streaming(&self) -> StreamingNormalizer<'_> {
+        StreamingNormalizer::new(StreamingNormalizerInner::Decomposing(self))
+    }
+
+    /// Returns the canonical combining classes of the first and the last
+    /// character of the canonical decomposition of `c`.
+    fn lead_and_trail_ccc(&self, c: char) -> (u8, u8) {
+        let trie = &self.decompositions.trie;
+        let trie_val = trie.get(c);
+        if starter_and_decomposes_to_self_impl(trie_val)
+            || in_inclusive_range(c, '\u{AC00}', '\u{D7A3}')
+        {
+            // Starters, including Hangul syllables, which decompose to jamo
+            return (0, 0);
+        }
+        if trie_value_has_ccc(trie_val)
+            && !trie_value_indicates_special_non_starter_decomposition(trie_val)
+        {
+            // Non-starter that decomposes to itself
+            let ccc = ccc_from_trie_value(trie_val).to_icu4c_value();
+            return (ccc, ccc);
+        }
+        let ccc = |c: char| ccc_from_trie_value(trie.get(c)).to_icu4c_value();
+        let mut decomposition = self.normalize_iter(core::iter::once(c));
+        let first = decomposition.next().unwrap_or(c);
+        let last = decomposition.last().unwrap_or(first);
+        (ccc(first), ccc(last))
+    }
+
+    /// Returns the NFD_QC or NFKD_QC property value of a character.
+    ///
+    /// This is [`IsNormalized::No`] if the character decomposes and
+    /// [`IsNormalized::Yes`] otherwise. Decomposing normalizations never
+    /// answer [`IsNormalized::Maybe`].
+    pub fn quick_check_char(&self, c: char) -> IsNormalized {
+        let trie_val = self.decompositions.trie.get(c);
+        if starter_and_decomposes_to_self_impl(trie_val)
+            || (trie_value_has_ccc(trie_val)
+                && !trie_value_indicates_special_non_starter_decomposition(trie_val))
+        {
+            IsNormalized::Yes
+        } else {
+            IsNormalized::No
+        }
+    }
+
+    /// Quick check per UAX #15: every character must decompose to itself
+    /// and non-starters must be in canonical order.
+    fn quick_check_iter(&self, iter: impl Iterator<Item = char>) -> IsNormalized {
+        let mut prev_ccc = 0;
+        for c in iter {
+            let trie_val = self.decompositions.trie.get(c);
+            if starter_and_decomposes_to_self_impl(trie_val) {
+                prev_ccc = 0;
+                continue;
+            }
+            if !trie_value_has_ccc(trie_val)
+                || trie_value_indicates_special_non_starter_decomposition(trie_val)
+            {
+                return IsNormalized::No;
+            }
+            let ccc = ccc_from_trie_value(trie_val).to_icu4c_value();
+            if ccc < prev_ccc {
+                return IsNormalized::No;
+            }
+            prev_ccc = ccc;
+        }
+        IsNormalized::Yes
+    }
+
     decomposing_normalize_to!(
         /// Normalize a string slice into a `Write` sink.
         ,
@@ -2275,6 +2402,8 @@ impl DecomposingNormalizer {
 pub struct ComposingNormalizerBorrowed<'a> {
     decomposing_normalizer: DecomposingNormalizerBorrowed<'a>,
     canonical_compositions: &'a CanonicalCompositions<'a>,
+    /// Whether only contiguous combining characters are composed (FCC).
+    only_contiguous: bool,
 }
 
 impl ComposingNormalizerBorrowed<'static> {
@@ -2286,6 +2415,7 @@ impl ComposingNormalizerBorrowed<'static> {
         ComposingNormalizer {
             decomposing_normalizer: self.decomposing_normalizer.static_to_owned(),
             canonical_compositions: DataPayload::from_static_ref(self.canonical_compositions),
+            only_contiguous: self.only_contiguous,
         }
     }
 
@@ -2299,6 +2429,27 @@ impl ComposingNormalizerBorrowed<'static> {
         ComposingNormalizerBorrowed {
             decomposing_normalizer: DecomposingNormalizerBorrowed::new_nfd(),
             canonical_compositions: crate::provider::Baked::SINGLETON_CANONICAL_COMPOSITIONS_V1,
+            only_contiguous: false,
+        }
+    }
+
+    /// FCC constructor using compiled data.
+    ///
+    /// FCC ("Fast C Contiguous") is like NFC except that a combining character
+    /// only composes with the preceding starter if nothing remains between
+    /// them. FCC text is FCD, so it can be processed by algorithms that
+    /// accept FCD input, such as collation, without further normalization.
+    /// See [Unicode Technical Note #5](https://www.unicode.org/notes/tn5/#FCC).
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub const fn new_fcc() -> Self {
+        ComposingNormalizerBorrowed {
+            decomposing_normalizer: DecomposingNormalizerBorrowed::new_nfd(),
+            canonical_compositions: crate::provider::Baked::SINGLETON_CANONICAL_COMPOSITIONS_V1,
+            only_contiguous: true,
         }
     }
 
@@ -2312,6 +2463,7 @@ impl ComposingNormalizerBorrowed<'static> {
         ComposingNormalizerBorrowed {
             decomposing_normalizer: DecomposingNormalizerBorrowed::new_nfkd(),
             canonical_compositions: crate::provider::Baked::SINGLETON_CANONICAL_COMPOSITIONS_V1,
+            only_contiguous: false,
         }
     }
 
@@ -2329,6 +2481,7 @@ impl ComposingNormalizerBorrowed<'static> {
         ComposingNormalizerBorrowed {
             decomposing_normalizer: DecomposingNormalizerBorrowed::new_uts46_decomposed(),
             canonical_compositions: crate::provider::Baked::SINGLETON_CANONICAL_COMPOSITIONS_V1,
+            only_contiguous: false,
         }
     }
 }
@@ -2356,11 +2509,88 @@ impl ComposingNormalizerBorrowed<'_> {
             ),
             self.canonical_compositions.canonical_compositions.clone(),
             self.decomposing_normalizer.composition_passthrough_bound,
+            self.only_contiguous,
         )
     }
 
     normalizer_methods!();
 
+    /// Returns a push-based normalizer for UTF-8 input that arrives in chunks.
+    ///
+    /// See [`StreamingNormalizer`].
+    pub fn streaming(&self) -> StreamingNormalizer<'_> {
+        StreamingNormalizer::new(StreamingNormalizerInner::Composing(self))
+    }
+
+    /// Returns the NFC_QC or NFKC_QC property value of a character.
+    ///
+    /// This is [`IsNormalized::No`] if the character cannot occur in normalized
+    /// text, [`IsNormalized::Maybe`] if it can compose with a preceding character,
+    /// and [`IsNormalized::Yes`] otherwise. FCC has no quick check property of its
+    /// own, so an FCC normalizer returns the NFC_QC value.
+    pub fn quick_check_char(&self, c: char) -> IsNormalized {
+        self.quick_check_trie_value(c, self.decomposing_normalizer.decompositions.trie.get(c))
+    }
+
+    /// See trie-value-format.md
+    fn quick_check_trie_value(&self, c: char, trie_val: u32) -> IsNormalized {
+        if starter_and_decomposes_to_self_impl(trie_val) {
+            if c != REPLACEMENT_CHARACTER && (trie_val & BACKWARD_COMBINING_MARKER) != 0 {
+                IsNormalized::Maybe
+            } else {
+                IsNormalized::Yes
+            }
+        } else if trie_value_has_ccc(trie_val)
+            && !trie_value_indicates_special_non_starter_decomposition(trie_val)
+        {
+            // Non-starters that decompose to themselves always have
+            // `BACKWARD_COMBINING_MARKER` set, so look at the compositions.
+            if matches!(
+                self.canonical_compositions
+                    .canonical_compositions
+                    .iter()
+                    .next(c),
+                TrieResult::NoMatch
+            ) {
+                IsNormalized::Yes
+            } else {
+                IsNormalized::Maybe
+            }
+        } else if (trie_val & NON_ROUND_TRIP_MARKER) != 0 {
+            IsNormalized::No
+        } else if (trie_val & BACKWARD_COMBINING_MARKER) != 0 {
+            IsNormalized::Maybe
+        } else {
+            IsNormalized::Yes
+        }
+    }
+
+    /// Quick check per UAX #15. For FCC, the canonical ordering check
+    /// is replaced with the FCD check on the decompositions.
+    fn quick_check_iter(&self, iter: impl Iterator<Item = char>) -> IsNormalized {
+        let mut result = IsNormalized::Yes;
+        let mut prev_trail_ccc = 0;
+        for c in iter {
+            let trie_val = self.decomposing_normalizer.decompositions.trie.get(c);
+            match self.quick_check_trie_value(c, trie_val) {
+                IsNormalized::No => return IsNormalized::No,
+                IsNormalized::Maybe => result = IsNormalized::Maybe,
+                IsNormalized::Yes => {}
+            }
+            let (lead_ccc, trail_ccc) = if self.only_contiguous {
+                self.decomposing_normalizer.lead_and_trail_ccc(c)
+            } else {
+                let ccc = ccc_from_trie_value(trie_val).to_icu4c_value();
+                (ccc, ccc)
+            };
+            if lead_ccc != 0 && lead_ccc < prev_trail_ccc {
+                return IsNormalized::No;
+            }
+            prev_trail_ccc = trail_ccc;
+        }
+        result
+    }
+
     composing_normalize_to!(
         /// Normalize a string slice into a `Write` sink.
         ,
@@ -2636,6 +2866,8 @@ impl ComposingNormalizerBorrowed<'_> {
 pub struct ComposingNormalizer {
     decomposing_normalizer: DecomposingNormalizer,
     canonical_compositions: DataPayload<CanonicalCompositionsV1>,
+    /// Whether only contiguous combining characters are composed (FCC).
+    only_contiguous: bool,
 }
 
 impl ComposingNormalizer {
@@ -2644,6 +2876,7 @@ impl ComposingNormalizer {
         ComposingNormalizerBorrowed {
             decomposing_normalizer: self.decomposing_normalizer.as_borrowed(),
             canonical_compositions: self.canonical_compositions.get(),
+            only_contiguous: self.only_contiguous,
         }
     }
 
@@ -2683,6 +2916,43 @@ impl ComposingNormalizer {
         Ok(ComposingNormalizer {
             decomposing_normalizer,
             canonical_compositions,
+            only_contiguous: false,
+        })
+    }
+
+    /// FCC constructor using compiled data.
+    ///
+    /// See [`ComposingNormalizerBorrowed::new_fcc`].
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub const fn new_fcc() -> ComposingNormalizerBorrowed<'static> {
+        ComposingNormalizerBorrowed::new_fcc()
+    }
+
+    icu_provider::gen_buffer_data_constructors!(
+        () -> error: DataError,
+        functions: [
+            new_fcc: skip,
+            try_new_fcc_with_buffer_provider,
+            try_new_fcc_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new_fcc)]
+    pub fn try_new_fcc_unstable<D>(provider: &D) -> Result<Self, DataError>
+    where
+        D: DataProvider<CanonicalDecompositionDataV2>
+            + DataProvider<CanonicalDecompositionTablesV1>
+            + DataProvider<CanonicalCompositionsV1>
+            + ?Sized,
+    {
+        Ok(ComposingNormalizer {
+            only_contiguous: true,
+            ..Self::try_new_nfc_unstable(provider)?
         })
     }
 
@@ -2723,6 +2993,7 @@ impl ComposingNormalizer {
         Ok(ComposingNormalizer {
             decomposing_normalizer,
             canonical_compositions,
+            only_contiguous: false,
         })
     }
 
@@ -2745,10 +3016,387 @@ impl ComposingNormalizer {
         Ok(ComposingNormalizer {
             decomposing_normalizer,
             canonical_compositions,
+            only_contiguous: false,
+        })
+    }
+}
+
+/// Borrowed version of a checker for the FCD ("Fast C or D") condition.
+///
+/// Text is FCD if decomposing each character separately results in canonically
+/// ordered text, i.e. if the canonical combining class of the first character of
+/// each character's canonical decomposition is zero or not lower than that of the
+/// last character of the preceding character's decomposition. Algorithms that
+/// accept FCD input, such as collation, can skip normalization for such text.
+/// See [Unicode Technical Note #5](https://www.unicode.org/notes/tn5/#FCD).
+///
+/// NFD text is FCD, and so is the output of [`ComposingNormalizerBorrowed::new_fcc`].
+/// NFC text is usually FCD, but not always.
+///
+/// # Examples
+///
+/// ```
+/// let fcd = icu_normalizer::FcdCheckerBorrowed::new();
+///
+/// // Not NFC, but FCD
+/// assert!(fcd.is_fcd("a\u{0308}"));
+/// // Precomposed character followed by a mark with a lower combining class
+/// assert!(!fcd.is_fcd("\u{00E4}\u{0323}"));
+/// assert_eq!(fcd.split_fcd("ab\u{00E4}\u{0323}"), ("ab\u{00E4}", "\u{0323}"));
+/// ```
+#[derive(Debug)]
+pub struct FcdCheckerBorrowed<'a> {
+    decomposing_normalizer: DecomposingNormalizerBorrowed<'a>,
+}
+
+#[cfg(feature = "compiled_data")]
+impl Default for FcdCheckerBorrowed<'static> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FcdCheckerBorrowed<'static> {
+    /// Cheaply converts a [`FcdCheckerBorrowed<'static>`] into a [`FcdChecker`].
+    ///
+    /// Note: Due to branching and indirection, using [`FcdChecker`] might inhibit some
+    /// compile-time optimizations that are possible with [`FcdCheckerBorrowed`].
+    pub const fn static_to_owned(self) -> FcdChecker {
+        FcdChecker {
+            decomposing_normalizer: self.decomposing_normalizer.static_to_owned(),
+        }
+    }
+
+    /// Constructs using compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub const fn new() -> Self {
+        FcdCheckerBorrowed {
+            decomposing_normalizer: DecomposingNormalizerBorrowed::new_nfd(),
+        }
+    }
+}
+
+impl FcdCheckerBorrowed<'_> {
+    /// Returns the length in code units of the longest FCD prefix.
+    fn fcd_up_to(&self, chars: impl Iterator<Item = char>, len_utf: fn(char) -> usize) -> usize {
+        let mut prev_trail_ccc = 0;
+        let mut up_to = 0;
+        for c in chars {
+            let (lead_ccc, trail_ccc) = self.decomposing_normalizer.lead_and_trail_ccc(c);
+            if lead_ccc != 0 && lead_ccc < prev_trail_ccc {
+                break;
+            }
+            prev_trail_ccc = trail_ccc;
+            up_to += len_utf(c);
+        }
+        up_to
+    }
+
+    /// Checks whether a string slice is FCD.
+    pub fn is_fcd(&self, text: &str) -> bool {
+        self.split_fcd(text).1.is_empty()
+    }
+
+    /// Splits a string slice into its longest FCD prefix and the rest.
+    pub fn split_fcd<'a>(&self, text: &'a str) -> (&'a str, &'a str) {
+        let up_to = self.fcd_up_to(text.chars(), char::len_utf8);
+        text.split_at_checked(up_to).unwrap_or_else(|| {
+            // Internal bug, not even GIGO, never supposed to happen
+            debug_assert!(false);
+            ("", text)
+        })
+    }
+
+    /// Checks whether a slice of potentially-invalid UTF-16 is FCD.
+    ///
+    /// Unpaired surrogates are treated as the REPLACEMENT CHARACTER.
+    ///
+    /// ✨ *Enabled with the `utf16_iter` Cargo feature.*
+    #[cfg(feature = "utf16_iter")]
+    pub fn is_fcd_utf16(&self, text: &[u16]) -> bool {
+        self.fcd_up_to(text.chars(), char::len_utf16) == text.len()
+    }
+
+    /// Checks whether a slice of potentially-invalid UTF-8 is FCD.
+    ///
+    /// Ill-formed byte sequences are treated as the REPLACEMENT CHARACTER
+    /// according to the WHATWG Encoding Standard.
+    ///
+    /// ✨ *Enabled with the `utf8_iter` Cargo feature.*
+    #[cfg(feature = "utf8_iter")]
+    pub fn is_fcd_utf8(&self, text: &[u8]) -> bool {
+        // The lengths of the REPLACEMENT CHARACTERs do not match the
+        // ill-formed input, but only whether the whole input passes matters.
+        text.chars().all({
+            let mut prev_trail_ccc = 0;
+            move |c| {
+                let (lead_ccc, trail_ccc) = self.decomposing_normalizer.lead_and_trail_ccc(c);
+                let ok = lead_ccc == 0 || lead_ccc >= prev_trail_ccc;
+                prev_trail_ccc = trail_ccc;
+                ok
+            }
         })
     }
 }
 
+/// A checker for the FCD ("Fast C or D") condition.
+///
+/// See [`FcdCheckerBorrowed`].
+#[derive(Debug)]
+pub struct FcdChecker {
+    decomposing_normalizer: DecomposingNormalizer,
+}
+
+#[cfg(feature = "compiled_data")]
+impl Default for FcdChecker {
+    fn default() -> Self {
+        Self::new().static_to_owned()
+    }
+}
+
+impl FcdChecker {
+    /// Constructs a borrowed version of this type for more efficient querying.
+    pub fn as_borrowed(&self) -> FcdCheckerBorrowed<'_> {
+        FcdCheckerBorrowed {
+            decomposing_normalizer: self.decomposing_normalizer.as_borrowed(),
+        }
+    }
+
+    /// Constructs using compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    #[allow(clippy::new_ret_no_self)]
+    pub const fn new() -> FcdCheckerBorrowed<'static> {
+        FcdCheckerBorrowed::new()
+    }
+
+    icu_provider::gen_buffer_data_constructors!(
+        () -> error: DataError,
+        functions: [
+            new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new)]
+    pub fn try_new_unstable<D>(provider: &D) -> Result<Self, DataError>
+    where
+        D: DataProvider<CanonicalDecompositionDataV2>
+            + DataProvider<CanonicalDecompositionTablesV1>
+            + ?Sized,
+    {
+        Ok(FcdChecker {
+            decomposing_normalizer: DecomposingNormalizer::try_new_nfd_unstable(provider)?,
+        })
+    }
+}
+
+#[derive(Debug)]
+enum StreamingNormalizerInner<'a> {
+    Decomposing(&'a DecomposingNormalizerBorrowed<'a>),
+    Composing(&'a ComposingNormalizerBorrowed<'a>),
+}
+
+/// A push-based normalizer for UTF-8 input that arrives in chunks, such as
+/// from the network.
+///
+/// Chunks may split the input at arbitrary byte positions, even in the middle
+/// of a UTF-8 sequence. Normalized output is written as soon as the end of the
+/// input seen so far can no longer affect it, so only the text since the last
+/// normalization boundary is buffered.
+///
+/// Ill-formed byte sequences are mapped to the REPLACEMENT CHARACTER
+/// according to the WHATWG Encoding Standard, like in `normalize_utf8`.
+///
+/// Created with [`DecomposingNormalizerBorrowed::streaming`] or
+/// [`ComposingNormalizerBorrowed::streaming`].
+///
+/// # Examples
+///
+/// ```
+/// let nfc = icu_normalizer::ComposingNormalizerBorrowed::new_nfc();
+/// let mut stream = nfc.streaming();
+/// let mut output = String::new();
+///
+/// // "é" as "e" followed by U+0301, split in the middle of U+0301
+/// stream.push_to(b"caf", &mut output).unwrap();
+/// stream.push_to(b"e\xCC", &mut output).unwrap();
+/// assert_eq!(output, "caf");
+/// // The last character is held back in case a combining mark follows.
+/// stream.push_to(b"\x81 au lait", &mut output).unwrap();
+/// assert_eq!(output, "café au lai");
+/// stream.finish_to(&mut output).unwrap();
+/// assert_eq!(output, "café au lait");
+/// ```
+#[derive(Debug)]
+pub struct StreamingNormalizer<'a> {
+    normalizer: StreamingNormalizerInner<'a>,
+    /// Decoded text after the last normalization boundary.
+    pending: String,
+    /// A truncated but so far valid UTF-8 sequence at the end of the
+    /// previous chunk.
+    incomplete: [u8; 4],
+    incomplete_len: usize,
+}
+
+impl<'a> StreamingNormalizer<'a> {
+    fn new(normalizer: StreamingNormalizerInner<'a>) -> Self {
+        Self {
+            normalizer,
+            pending: String::new(),
+            incomplete: [0; 4],
+            incomplete_len: 0,
+        }
+    }
+
+    /// Adds a chunk of UTF-8 input and writes the normalization of the input
+    /// up to the last normalization boundary into the sink.
+    pub fn push_to<W: core::fmt::Write + ?Sized>(
+        &mut self,
+        chunk: &[u8],
+        sink: &mut W,
+    ) -> core::fmt::Result {
+        // Only the newly added text can contain a boundary: the text
+        // that was retained from previous chunks contains none except
+        // at its start.
+        let old_len = self.pending.len();
+        self.decode(chunk);
+        let boundary = self
+            .pending
+            .char_indices()
+            .rev()
+            .take_while(|&(i, _)| i >= old_len && i > 0)
+            .find(|&(_, c)| self.has_boundary_before(c))
+            .map_or(0, |(i, _)| i);
+        if boundary == 0 {
+            return Ok(());
+        }
+        let result = match self.pending.get(..boundary) {
+            Some(head) => self.normalize_to(head, sink),
+            None => {
+                // Internal bug, not even GIGO, never supposed to happen
+                debug_assert!(false);
+                Ok(())
+            }
+        };
+        self.pending.drain(..boundary);
+        result
+    }
+
+    /// Signals the end of the input and writes the normalization of the
+    /// remaining buffered input into the sink.
+    ///
+    /// A truncated UTF-8 sequence at the end of the input is mapped to
+    /// the REPLACEMENT CHARACTER.
+    pub fn finish_to<W: core::fmt::Write + ?Sized>(mut self, sink: &mut W) -> core::fmt::Result {
+        if self.incomplete_len != 0 {
+            self.pending.push(REPLACEMENT_CHARACTER);
+        }
+        self.normalize_to(&self.pending, sink)
+    }
+
+    fn normalize_to<W: core::fmt::Write + ?Sized>(
+        &self,
+        text: &str,
+        sink: &mut W,
+    ) -> core::fmt::Result {
+        match self.normalizer {
+            StreamingNormalizerInner::Decomposing(n) => n.normalize_to(text, sink),
+            StreamingNormalizerInner::Composing(n) => n.normalize_to(text, sink),
+        }
+    }
+
+    /// Whether the normalization of text that ends right before `c`
+    /// is unaffected by `c` and whatever follows it.
+    fn has_boundary_before(&self, c: char) -> bool {
+        if in_inclusive_range(c, '\u{AC00}', '\u{D7A3}') {
+            // Hangul syllables neither decompose to a non-starter nor
+            // combine backwards.
+            return true;
+        }
+        let (decompositions, composing) = match self.normalizer {
+            StreamingNormalizerInner::Decomposing(n) => (n.decompositions, false),
+            StreamingNormalizerInner::Composing(n) => {
+                (n.decomposing_normalizer.decompositions, true)
+            }
+        };
+        let c_and_trie_val = CharacterAndTrieValue::new(c, decompositions.trie.get(c));
+        c_and_trie_val.starter_and_decomposes_to_self()
+            && !(composing && c_and_trie_val.can_combine_backwards())
+    }
+
+    /// Appends the chunk to `pending`, carrying over a truncated UTF-8
+    /// sequence at its end to the next chunk.
+    fn decode(&mut self, mut chunk: &[u8]) {
+        while self.incomplete_len != 0 {
+            let Some((&b, rest)) = chunk.split_first() else {
+                return;
+            };
+            let Some(slot) = self.incomplete.get_mut(self.incomplete_len) else {
+                // A UTF-8 sequence is never longer than four bytes.
+                debug_assert!(false);
+                return;
+            };
+            *slot = b;
+            let bytes = self
+                .incomplete
+                .get(..=self.incomplete_len)
+                .unwrap_or_default();
+            match core::str::from_utf8(bytes) {
+                Ok(s) => {
+                    self.pending.push_str(s);
+                    self.incomplete_len = 0;
+                    chunk = rest;
+                }
+                Err(e) if e.error_len().is_none() => {
+                    self.incomplete_len += 1;
+                    chunk = rest;
+                }
+                Err(_) => {
+                    // The carried bytes are a maximal subpart; `b` is
+                    // decoded again as the start of the rest.
+                    self.pending.push(REPLACEMENT_CHARACTER);
+                    self.incomplete_len = 0;
+                }
+            }
+        }
+        loop {
+            match core::str::from_utf8(chunk) {
+                Ok(s) => {
+                    self.pending.push_str(s);
+                    return;
+                }
+                Err(e) => {
+                    let (valid, rest) = chunk.split_at(e.valid_up_to());
+                    // SAFETY: `from_utf8` checked the bytes up to `valid_up_to`.
+                    self.pending
+                        .push_str(unsafe { core::str::from_utf8_unchecked(valid) });
+                    if let Some(len) = e.error_len() {
+                        self.pending.push(REPLACEMENT_CHARACTER);
+                        chunk = rest.get(len..).unwrap_or_default();
+                    } else {
+                        // At most three bytes remain.
+                        for (slot, &b) in self.incomplete.iter_mut().zip(rest) {
+                            *slot = b;
+                        }
+                        self.incomplete_len = rest.len();
+                        return;
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[cfg(feature = "utf16_iter")]
 struct IsNormalizedSinkUtf16<'a> {
     expect: &'a [u16],
@@ -3,10 +3,13 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 //! Bundles the part of UTS 46 that makes sense to implement as a
-//! normalization.
+//! normalization, and, with the `icu_properties` Cargo feature, the
+//! complete UTS 46 processing on top of it.
 //!
-//! This is meant to be used as a building block of an UTS 46
-//! implementation, such as the `idna` crate.
+//! [`Uts46Mapper`] is meant to be used as a building block of an UTS 46
+//! implementation, such as the `idna` crate. `Uts46Processor` is such an
+//! implementation: it provides the ToASCII and ToUnicode operations including
+//! Punycode, hyphen, CONTEXTJ, and Bidi rule checks.
 
 use crate::CanonicalCompositionsV1;
 use crate::CanonicalDecompositionTablesV1;
@@ -17,6 +20,16 @@ use crate::Uts46DecompositionDataV2;
 use icu_provider::DataError;
 use icu_provider::DataProvider;
 
+#[cfg(feature = "icu_properties")]
+mod processor;
+#[cfg(feature = "icu_properties")]
+mod punycode;
+
+#[cfg(feature = "icu_properties")]
+pub use processor::{
+    Uts46Error, Uts46Errors, Uts46LabelError, Uts46Options, Uts46Processor, Uts46ProcessorBorrowed,
+};
+
 // Implementation note: Despite merely wrapping a `ComposingNormalizer`,
 // having a `Uts46Mapper` serves two purposes:
 //
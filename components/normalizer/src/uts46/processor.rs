@@ -0,0 +1,626 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::punycode;
+use super::Uts46Mapper;
+use super::Uts46MapperBorrowed;
+use crate::properties::CanonicalCombiningClassMap;
+use crate::properties::CanonicalCombiningClassMapBorrowed;
+use crate::provider::CanonicalDecompositionDataV2;
+use crate::CanonicalCompositionsV1;
+use crate::CanonicalDecompositionTablesV1;
+use crate::CompatibilityDecompositionTablesV1;
+use crate::Uts46DecompositionDataV2;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use displaydoc::Display;
+use icu_properties::props::BidiClass;
+use icu_properties::props::CanonicalCombiningClass;
+use icu_properties::props::GeneralCategory;
+use icu_properties::props::GeneralCategoryGroup;
+use icu_properties::props::JoiningType;
+use icu_properties::provider::BidiClassV1;
+use icu_properties::provider::GeneralCategoryV1;
+use icu_properties::provider::JoiningTypeV1;
+use icu_properties::CodePointMapData;
+use icu_properties::CodePointMapDataBorrowed;
+use icu_provider::DataError;
+use icu_provider::DataProvider;
+
+const ZWNJ: char = '\u{200C}';
+const ZWJ: char = '\u{200D}';
+
+/// Options for [`Uts46ProcessorBorrowed::to_ascii`] and [`Uts46ProcessorBorrowed::to_unicode`].
+///
+/// The default values match the flags that the
+/// [UTS 46 conformance tests](https://www.unicode.org/Public/idna/latest/IdnaTestV2.txt)
+/// use for Nontransitional Processing. Notably, they are stricter than the
+/// [WHATWG URL Standard](https://url.spec.whatwg.org/#idna), which turns off
+/// `check_hyphens` and `use_std3_ascii_rules`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Uts46Options {
+    /// Whether to use the deprecated Transitional Processing, which maps
+    /// ß to ss and ς to σ and removes ZWJ and ZWNJ.
+    ///
+    /// Default is `false`.
+    pub transitional: bool,
+    /// The CheckHyphens flag: whether to reject labels with hyphens in
+    /// the third and fourth positions or at the start or end.
+    ///
+    /// Default is `true`.
+    pub check_hyphens: bool,
+    /// The CheckBidi flag: whether to apply the Bidi rule of
+    /// [RFC 5893](https://www.rfc-editor.org/rfc/rfc5893#section-2) to
+    /// Bidi domain names.
+    ///
+    /// Default is `true`.
+    pub check_bidi: bool,
+    /// The CheckJoiners flag: whether to apply the CONTEXTJ rules of
+    /// [RFC 5892](https://www.rfc-editor.org/rfc/rfc5892#appendix-A).
+    ///
+    /// Default is `true`.
+    pub check_joiners: bool,
+    /// The UseSTD3ASCIIRules flag: whether to reject ASCII characters other
+    /// than lowercase letters, digits, and hyphen.
+    ///
+    /// Default is `true`.
+    pub use_std3_ascii_rules: bool,
+    /// The VerifyDnsLength flag: whether [`Uts46ProcessorBorrowed::to_ascii`]
+    /// rejects empty labels and domain names as well as labels longer than
+    /// 63 and domain names longer than 253 bytes.
+    ///
+    /// Ignored by [`Uts46ProcessorBorrowed::to_unicode`]. Default is `true`.
+    pub verify_dns_length: bool,
+}
+
+impl Default for Uts46Options {
+    fn default() -> Self {
+        Self {
+            transitional: false,
+            check_hyphens: true,
+            check_bidi: true,
+            check_joiners: true,
+            use_std3_ascii_rules: true,
+            verify_dns_length: true,
+        }
+    }
+}
+
+/// A UTS 46 processing error.
+///
+/// The codes in parentheses refer to the error codes of the
+/// [UTS 46 conformance tests](https://www.unicode.org/Public/idna/latest/IdnaTestV2.txt).
+#[derive(Display, Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Uts46Error {
+    /// The label contains a disallowed code point or, after Punycode decoding,
+    /// is not in NFC or contains a code point that is not valid (P1, V1, V7).
+    #[displaydoc("Label contains a disallowed code point or is not normalized")]
+    Disallowed,
+    /// The label has hyphens in both the third and the fourth position (V2).
+    #[displaydoc("Label has hyphens in the third and fourth positions")]
+    HyphenAt3And4,
+    /// The label starts or ends with a hyphen (V3).
+    #[displaydoc("Label starts or ends with a hyphen")]
+    LeadingOrTrailingHyphen,
+    /// CheckHyphens is off and the decoded label starts with `xn--` (V4).
+    #[displaydoc("Label starts with xn--")]
+    XnPrefix,
+    /// The label starts with a combining mark (V6).
+    #[displaydoc("Label starts with a combining mark")]
+    LeadingCombiningMark,
+    /// UseSTD3ASCIIRules is on and the label contains an ASCII character other
+    /// than a lowercase letter, a digit, or a hyphen (U1).
+    #[displaydoc("Label contains an ASCII character not allowed by STD3")]
+    Std3Ascii,
+    /// The label starts with `xn--` but is not valid Punycode, decodes to an
+    /// empty or all-ASCII string, or could not be encoded (P4, A3).
+    #[displaydoc("Invalid Punycode")]
+    Punycode,
+    /// The label violates the CONTEXTJ rules for ZWNJ or ZWJ (C1, C2).
+    #[displaydoc("Label violates the CONTEXTJ rules")]
+    ContextJ,
+    /// The label violates the Bidi rule of RFC 5893 (B1–B6).
+    #[displaydoc("Label violates the Bidi rule")]
+    Bidi,
+    /// The ASCII form of the label is empty or longer than 63 bytes (A4_2).
+    #[displaydoc("Label is empty or too long")]
+    LabelLength,
+    /// The ASCII form of the domain name is empty or longer than 253 bytes (A4_1).
+    #[displaydoc("Domain name is empty or too long")]
+    DomainLength,
+}
+
+impl core::error::Error for Uts46Error {}
+
+/// A [`Uts46Error`] together with the label it occurred in.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Uts46LabelError {
+    /// The zero-based index of the label, or `None` if the error concerns
+    /// the domain name as a whole.
+    pub label: Option<usize>,
+    /// The error.
+    pub error: Uts46Error,
+}
+
+/// The errors that occurred while processing a domain name, ordered by label.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Uts46Errors(Vec<Uts46LabelError>);
+
+impl Uts46Errors {
+    /// Returns an iterator over the errors.
+    pub fn iter(&self) -> impl Iterator<Item = &Uts46LabelError> + '_ {
+        self.0.iter()
+    }
+
+    /// Returns whether `error` occurred in any label.
+    pub fn contains(&self, error: Uts46Error) -> bool {
+        self.0.iter().any(|e| e.error == error)
+    }
+
+    fn into_result(mut errors: Vec<Uts46LabelError>) -> Result<(), Self> {
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            errors.sort_by_key(|e| e.label.unwrap_or(usize::MAX));
+            Err(Self(errors))
+        }
+    }
+}
+
+impl fmt::Display for Uts46Errors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, e) in self.0.iter().enumerate() {
+            if i != 0 {
+                f.write_str("; ")?;
+            }
+            match e.label {
+                Some(label) => write!(f, "label {label}: {}", e.error)?,
+                None => write!(f, "{}", e.error)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl core::error::Error for Uts46Errors {}
+
+/// A borrowed version of a processor that implements the ToASCII and
+/// ToUnicode operations of [UTS 46](https://www.unicode.org/reports/tr46/).
+///
+/// ✨ *Enabled with the `icu_properties` Cargo feature.*
+///
+/// # Examples
+///
+/// ```
+/// use icu_normalizer::uts46::{Uts46Error, Uts46Options, Uts46ProcessorBorrowed};
+///
+/// let processor = Uts46ProcessorBorrowed::new();
+///
+/// assert_eq!(
+///     processor.to_ascii("Bücher.example", Uts46Options::default()),
+///     Ok("xn--bcher-kva.example".into())
+/// );
+///
+/// let (unicode, result) =
+///     processor.to_unicode("xn--bcher-kva.example", Uts46Options::default());
+/// assert_eq!(unicode, "bücher.example");
+/// assert!(result.is_ok());
+///
+/// let errors = processor
+///     .to_ascii("-ab.example", Uts46Options::default())
+///     .unwrap_err();
+/// assert!(errors.contains(Uts46Error::LeadingOrTrailingHyphen));
+/// ```
+#[derive(Debug)]
+pub struct Uts46ProcessorBorrowed<'a> {
+    mapper: Uts46MapperBorrowed<'a>,
+    ccc: CanonicalCombiningClassMapBorrowed<'a>,
+    bidi_class: CodePointMapDataBorrowed<'a, BidiClass>,
+    joining_type: CodePointMapDataBorrowed<'a, JoiningType>,
+    general_category: CodePointMapDataBorrowed<'a, GeneralCategory>,
+}
+
+#[cfg(feature = "compiled_data")]
+impl Default for Uts46ProcessorBorrowed<'static> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Uts46ProcessorBorrowed<'static> {
+    /// Cheaply converts a [`Uts46ProcessorBorrowed<'static>`] into a [`Uts46Processor`].
+    ///
+    /// Note: Due to branching and indirection, using [`Uts46Processor`] might inhibit some
+    /// compile-time optimizations that are possible with [`Uts46ProcessorBorrowed`].
+    pub const fn static_to_owned(self) -> Uts46Processor {
+        Uts46Processor {
+            mapper: self.mapper.static_to_owned(),
+            ccc: self.ccc.static_to_owned(),
+            bidi_class: self.bidi_class.static_to_owned(),
+            joining_type: self.joining_type.static_to_owned(),
+            general_category: self.general_category.static_to_owned(),
+        }
+    }
+
+    /// Construct with compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub const fn new() -> Self {
+        Self {
+            mapper: Uts46MapperBorrowed::new(),
+            ccc: CanonicalCombiningClassMapBorrowed::new(),
+            bidi_class: CodePointMapDataBorrowed::new(),
+            joining_type: CodePointMapDataBorrowed::new(),
+            general_category: CodePointMapDataBorrowed::new(),
+        }
+    }
+}
+
+impl Uts46ProcessorBorrowed<'_> {
+    /// Performs the UTS 46 ToUnicode operation.
+    ///
+    /// Returns the processed domain name together with the errors that were
+    /// found. The domain name is returned even in the presence of errors:
+    /// disallowed characters are replaced with U+FFFD and labels that are not
+    /// valid Punycode are left as they are.
+    ///
+    /// `options.verify_dns_length` is ignored.
+    pub fn to_unicode(
+        &self,
+        domain: &str,
+        options: Uts46Options,
+    ) -> (String, Result<(), Uts46Errors>) {
+        let mut errors = Vec::new();
+        let labels = self.process(domain, options, &mut errors);
+        (labels.join("."), Uts46Errors::into_result(errors))
+    }
+
+    /// Performs the UTS 46 ToASCII operation.
+    ///
+    /// Labels that contain non-ASCII characters after processing are
+    /// Punycode-encoded with the `xn--` prefix.
+    pub fn to_ascii(&self, domain: &str, options: Uts46Options) -> Result<String, Uts46Errors> {
+        let mut errors = Vec::new();
+        let labels = self.process(domain, options, &mut errors);
+        let mut output = String::with_capacity(domain.len());
+        for (i, label) in labels.iter().enumerate() {
+            if i != 0 {
+                output.push('.');
+            }
+            let start = output.len();
+            if label.is_ascii() {
+                output.push_str(label);
+            } else {
+                output.push_str("xn--");
+                if punycode::encode(label, &mut output).is_none() {
+                    errors.push(Uts46LabelError {
+                        label: Some(i),
+                        error: Uts46Error::Punycode,
+                    });
+                }
+            }
+            // The empty label after a trailing dot denotes the root.
+            let is_root = i != 0 && i == labels.len() - 1 && label.is_empty();
+            if options.verify_dns_length && !is_root && !(1..=63).contains(&(output.len() - start))
+            {
+                errors.push(Uts46LabelError {
+                    label: Some(i),
+                    error: Uts46Error::LabelLength,
+                });
+            }
+        }
+        if options.verify_dns_length {
+            let len = output.strip_suffix('.').unwrap_or(&output).len();
+            if !(1..=253).contains(&len) {
+                errors.push(Uts46LabelError {
+                    label: None,
+                    error: Uts46Error::DomainLength,
+                });
+            }
+        }
+        Uts46Errors::into_result(errors).map(|()| output)
+    }
+
+    /// Runs the Processing steps of UTS 46 and returns the Unicode labels.
+    fn process(
+        &self,
+        domain: &str,
+        options: Uts46Options,
+        errors: &mut Vec<Uts46LabelError>,
+    ) -> Vec<String> {
+        // Step 1 and 2: Map and Normalize
+        let mapped: String = if options.transitional {
+            self.mapper
+                .map_normalize(domain.chars().flat_map(transitional_map).flatten())
+                .collect()
+        } else {
+            self.mapper.map_normalize(domain.chars()).collect()
+        };
+
+        // Step 3 and 4: Break and Convert/Validate
+        let labels: Vec<String> = mapped
+            .split('.')
+            .enumerate()
+            .map(|(i, label)| {
+                let mut error = |error| {
+                    errors.push(Uts46LabelError {
+                        label: Some(i),
+                        error,
+                    })
+                };
+                let Some(encoded) = label.strip_prefix("xn--") else {
+                    if label.contains('\u{FFFD}') {
+                        error(Uts46Error::Disallowed);
+                    }
+                    self.validate(label, options, &mut error);
+                    return label.into();
+                };
+                match punycode::decode(encoded) {
+                    Some(decoded) if !decoded.is_ascii() => {
+                        if !self
+                            .mapper
+                            .normalize_validate(decoded.chars())
+                            .eq(decoded.chars())
+                        {
+                            error(Uts46Error::Disallowed);
+                        }
+                        self.validate(&decoded, options, &mut error);
+                        decoded
+                    }
+                    _ => {
+                        error(Uts46Error::Punycode);
+                        label.into()
+                    }
+                }
+            })
+            .collect();
+
+        // Step 4, validity criterion 9: the Bidi rule
+        if options.check_bidi
+            && labels.iter().flat_map(|l| l.chars()).any(|c| {
+                matches!(
+                    self.bidi_class.get(c),
+                    BidiClass::RightToLeft | BidiClass::ArabicLetter | BidiClass::ArabicNumber
+                )
+            })
+        {
+            for (i, label) in labels.iter().enumerate() {
+                if !self.satisfies_bidi_rule(label) {
+                    errors.push(Uts46LabelError {
+                        label: Some(i),
+                        error: Uts46Error::Bidi,
+                    });
+                }
+            }
+        }
+
+        labels
+    }
+
+    /// Checks the validity criteria of UTS 46 other than the NFC and status
+    /// checks and the Bidi rule.
+    fn validate(&self, label: &str, options: Uts46Options, error: &mut impl FnMut(Uts46Error)) {
+        if options.check_hyphens {
+            if label.get(2..4) == Some("--") {
+                error(Uts46Error::HyphenAt3And4);
+            }
+            if label.starts_with('-') || label.ends_with('-') {
+                error(Uts46Error::LeadingOrTrailingHyphen);
+            }
+        } else if label.starts_with("xn--") {
+            error(Uts46Error::XnPrefix);
+        }
+        if label
+            .chars()
+            .next()
+            .is_some_and(|c| GeneralCategoryGroup::Mark.contains(self.general_category.get(c)))
+        {
+            error(Uts46Error::LeadingCombiningMark);
+        }
+        if options.use_std3_ascii_rules
+            && label
+                .bytes()
+                .any(|b| b.is_ascii() && !matches!(b, b'a'..=b'z' | b'0'..=b'9' | b'-'))
+        {
+            error(Uts46Error::Std3Ascii);
+        }
+        if options.check_joiners && !self.satisfies_context_j(label) {
+            error(Uts46Error::ContextJ);
+        }
+    }
+
+    /// Checks the CONTEXTJ rules of RFC 5892, Appendix A.1 and A.2.
+    fn satisfies_context_j(&self, label: &str) -> bool {
+        label.char_indices().all(|(i, c)| {
+            if c != ZWNJ && c != ZWJ {
+                return true;
+            }
+            let before = label.get(..i).unwrap_or_default();
+            if before
+                .chars()
+                .next_back()
+                .is_some_and(|b| self.ccc.get(b) == CanonicalCombiningClass::Virama)
+            {
+                return true;
+            }
+            if c == ZWJ {
+                return false;
+            }
+            // (Joining_Type:{L,D})(Joining_Type:T)*‌(Joining_Type:T)*(Joining_Type:{R,D})
+            let after = label.get(i + c.len_utf8()..).unwrap_or_default();
+            let non_transparent = |c: &char| self.joining_type.get(*c) != JoiningType::Transparent;
+            matches!(
+                before
+                    .chars()
+                    .rev()
+                    .find(non_transparent)
+                    .map(|c| self.joining_type.get(c)),
+                Some(JoiningType::LeftJoining | JoiningType::DualJoining)
+            ) && matches!(
+                after
+                    .chars()
+                    .find(non_transparent)
+                    .map(|c| self.joining_type.get(c)),
+                Some(JoiningType::RightJoining | JoiningType::DualJoining)
+            )
+        })
+    }
+
+    /// Checks the Bidi rule of RFC 5893, Section 2.
+    fn satisfies_bidi_rule(&self, label: &str) -> bool {
+        use BidiClass as B;
+        let mut classes = label.chars().map(|c| self.bidi_class.get(c));
+        let Some(first) = classes.next() else {
+            return true;
+        };
+        let last = label
+            .chars()
+            .rev()
+            .map(|c| self.bidi_class.get(c))
+            .find(|&b| b != B::NonspacingMark);
+        match first {
+            // RTL label
+            B::RightToLeft | B::ArabicLetter => {
+                let mut has_en = false;
+                let mut has_an = false;
+                // Rule 2
+                let allowed = classes.all(|b| {
+                    has_en |= b == B::EuropeanNumber;
+                    has_an |= b == B::ArabicNumber;
+                    matches!(
+                        b,
+                        B::RightToLeft
+                            | B::ArabicLetter
+                            | B::ArabicNumber
+                            | B::EuropeanNumber
+                            | B::EuropeanSeparator
+                            | B::CommonSeparator
+                            | B::EuropeanTerminator
+                            | B::OtherNeutral
+                            | B::BoundaryNeutral
+                            | B::NonspacingMark
+                    )
+                });
+                // Rule 3 and 4
+                allowed
+                    && matches!(
+                        last,
+                        Some(
+                            B::RightToLeft | B::ArabicLetter | B::EuropeanNumber | B::ArabicNumber
+                        )
+                    )
+                    && !(has_en && has_an)
+            }
+            // LTR label: rule 5 and 6
+            B::LeftToRight => {
+                classes.all(|b| {
+                    matches!(
+                        b,
+                        B::LeftToRight
+                            | B::EuropeanNumber
+                            | B::EuropeanSeparator
+                            | B::CommonSeparator
+                            | B::EuropeanTerminator
+                            | B::OtherNeutral
+                            | B::BoundaryNeutral
+                            | B::NonspacingMark
+                    )
+                }) && matches!(last, Some(B::LeftToRight | B::EuropeanNumber))
+            }
+            // Rule 1
+            _ => false,
+        }
+    }
+}
+
+/// The mapping of the deviation characters in Transitional Processing.
+fn transitional_map(c: char) -> [Option<char>; 2] {
+    match c {
+        'ß' => [Some('s'), Some('s')],
+        'ς' => [Some('σ'), None],
+        ZWNJ | ZWJ => [None, None],
+        c => [Some(c), None],
+    }
+}
+
+/// A processor that implements the ToASCII and ToUnicode operations of
+/// [UTS 46](https://www.unicode.org/reports/tr46/).
+///
+/// ✨ *Enabled with the `icu_properties` Cargo feature.*
+#[derive(Debug)]
+pub struct Uts46Processor {
+    mapper: Uts46Mapper,
+    ccc: CanonicalCombiningClassMap,
+    bidi_class: CodePointMapData<BidiClass>,
+    joining_type: CodePointMapData<JoiningType>,
+    general_category: CodePointMapData<GeneralCategory>,
+}
+
+#[cfg(feature = "compiled_data")]
+impl Default for Uts46Processor {
+    fn default() -> Self {
+        Self::new().static_to_owned()
+    }
+}
+
+impl Uts46Processor {
+    /// Constructs a borrowed version of this type for more efficient querying.
+    pub fn as_borrowed(&self) -> Uts46ProcessorBorrowed<'_> {
+        Uts46ProcessorBorrowed {
+            mapper: self.mapper.as_borrowed(),
+            ccc: self.ccc.as_borrowed(),
+            bidi_class: self.bidi_class.as_borrowed(),
+            joining_type: self.joining_type.as_borrowed(),
+            general_category: self.general_category.as_borrowed(),
+        }
+    }
+
+    /// Construct with compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    #[allow(clippy::new_ret_no_self)]
+    pub const fn new() -> Uts46ProcessorBorrowed<'static> {
+        Uts46ProcessorBorrowed::new()
+    }
+
+    icu_provider::gen_buffer_data_constructors!(() -> error: DataError,
+        functions: [
+            new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+    ]);
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new)]
+    pub fn try_new_unstable<D>(provider: &D) -> Result<Self, DataError>
+    where
+        D: DataProvider<Uts46DecompositionDataV2>
+            + DataProvider<CanonicalDecompositionTablesV1>
+            + DataProvider<CompatibilityDecompositionTablesV1>
+            + DataProvider<CanonicalCompositionsV1>
+            + DataProvider<CanonicalDecompositionDataV2>
+            + DataProvider<BidiClassV1>
+            + DataProvider<JoiningTypeV1>
+            + DataProvider<GeneralCategoryV1>
+            + ?Sized,
+    {
+        Ok(Self {
+            mapper: Uts46Mapper::try_new(provider)?,
+            ccc: CanonicalCombiningClassMap::try_new_unstable(provider)?,
+            bidi_class: CodePointMapData::try_new_unstable(provider)?,
+            joining_type: CodePointMapData::try_new_unstable(provider)?,
+            general_category: CodePointMapData::try_new_unstable(provider)?,
+        })
+    }
+}
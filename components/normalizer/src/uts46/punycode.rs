@@ -0,0 +1,162 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Punycode as specified in [RFC 3492](https://www.rfc-editor.org/rfc/rfc3492).
+//!
+//! Only the parts needed for IDNA are implemented: there is no support
+//! for mixed-case annotations.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+const BASE: u32 = 36;
+const T_MIN: u32 = 1;
+const T_MAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 0x80;
+const DELIMITER: char = '-';
+
+fn adapt(mut delta: u32, num_points: u32, first_time: bool) -> u32 {
+    delta /= if first_time { DAMP } else { 2 };
+    delta += delta / num_points;
+    let mut k = 0;
+    while delta > ((BASE - T_MIN) * T_MAX) / 2 {
+        delta /= BASE - T_MIN;
+        k += BASE;
+    }
+    k + (((BASE - T_MIN + 1) * delta) / (delta + SKEW))
+}
+
+fn threshold(k: u32, bias: u32) -> u32 {
+    if k <= bias {
+        T_MIN
+    } else if k >= bias + T_MAX {
+        T_MAX
+    } else {
+        k - bias
+    }
+}
+
+fn decode_digit(b: u8) -> Option<u32> {
+    match b {
+        b'a'..=b'z' => Some(u32::from(b - b'a')),
+        b'A'..=b'Z' => Some(u32::from(b - b'A')),
+        b'0'..=b'9' => Some(u32::from(b - b'0') + 26),
+        _ => None,
+    }
+}
+
+fn encode_digit(d: u32) -> char {
+    debug_assert!(d < BASE);
+    // `d < 36`, so the casts are lossless.
+    if d < 26 {
+        char::from(b'a' + d as u8)
+    } else {
+        char::from(b'0' + (d - 26) as u8)
+    }
+}
+
+/// Decodes the Punycode `input` (without the `xn--` prefix).
+///
+/// Returns `None` if `input` is not valid Punycode.
+pub(crate) fn decode(input: &str) -> Option<String> {
+    if !input.is_ascii() {
+        return None;
+    }
+    let (mut output, extended): (Vec<char>, &[u8]) = match input.rfind(DELIMITER) {
+        // If no code points precede the last delimiter, the delimiter
+        // is not consumed and then fails to decode as a digit.
+        Some(pos) if pos > 0 => (
+            input.get(..pos)?.chars().collect(),
+            input.as_bytes().get(pos + 1..)?,
+        ),
+        _ => (Vec::new(), input.as_bytes()),
+    };
+
+    let mut n = INITIAL_N;
+    let mut i: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+    let mut bytes = extended.iter();
+    while bytes.len() != 0 {
+        let old_i = i;
+        let mut w: u32 = 1;
+        let mut k = BASE;
+        loop {
+            let digit = decode_digit(*bytes.next()?)?;
+            i = i.checked_add(digit.checked_mul(w)?)?;
+            let t = threshold(k, bias);
+            if digit < t {
+                break;
+            }
+            w = w.checked_mul(BASE - t)?;
+            k += BASE;
+        }
+        let len = u32::try_from(output.len()).ok()?.checked_add(1)?;
+        bias = adapt(i - old_i, len, old_i == 0);
+        n = n.checked_add(i / len)?;
+        i %= len;
+        let c = char::from_u32(n)?;
+        // `i < len`, which is at most `output.len() + 1`.
+        output.insert(i as usize, c);
+        i += 1;
+    }
+    Some(output.into_iter().collect())
+}
+
+/// Appends the Punycode encoding of `input` (without the `xn--` prefix)
+/// to `output`.
+///
+/// Returns `None` on arithmetic overflow, which can only happen for inputs
+/// far longer than a DNS label.
+pub(crate) fn encode(input: &str, output: &mut String) -> Option<()> {
+    let mut basic_len: u32 = 0;
+    let mut len: u32 = 0;
+    for c in input.chars() {
+        if c.is_ascii() {
+            output.push(c);
+            basic_len += 1;
+        }
+        len = len.checked_add(1)?;
+    }
+    if basic_len > 0 {
+        output.push(DELIMITER);
+    }
+
+    let mut n = INITIAL_N;
+    let mut delta: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+    let mut handled = basic_len;
+    while handled < len {
+        let m = input.chars().map(u32::from).filter(|&c| c >= n).min()?;
+        delta = delta.checked_add((m - n).checked_mul(handled + 1)?)?;
+        n = m;
+        for c in input.chars().map(u32::from) {
+            if c < n {
+                delta = delta.checked_add(1)?;
+            }
+            if c == n {
+                let mut q = delta;
+                let mut k = BASE;
+                loop {
+                    let t = threshold(k, bias);
+                    if q < t {
+                        break;
+                    }
+                    output.push(encode_digit(t + (q - t) % (BASE - t)));
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+                output.push(encode_digit(q));
+                bias = adapt(delta, handled + 1, handled == basic_len);
+                delta = 0;
+                handled += 1;
+            }
+        }
+        delta = delta.checked_add(1)?;
+        n += 1;
+    }
+    Some(())
+}
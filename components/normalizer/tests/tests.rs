@@ -11,10 +11,15 @@ use icu_normalizer::properties::CanonicalDecompositionBorrowed;
 use icu_normalizer::properties::Decomposed;
 use icu_normalizer::uts46::Uts46Mapper;
 use icu_normalizer::uts46::Uts46MapperBorrowed;
+#[cfg(feature = "icu_properties")]
+use icu_normalizer::uts46::{Uts46Error, Uts46Options, Uts46ProcessorBorrowed};
 use icu_normalizer::ComposingNormalizer;
 use icu_normalizer::ComposingNormalizerBorrowed;
 use icu_normalizer::DecomposingNormalizer;
 use icu_normalizer::DecomposingNormalizerBorrowed;
+use icu_normalizer::FcdChecker;
+use icu_normalizer::FcdCheckerBorrowed;
+use icu_normalizer::IsNormalized;
 
 #[test]
 fn test_nfd_basic() {
@@ -433,6 +438,245 @@ fn test_uts46_normalize_validate() {
 
 type StackString = arraystring::ArrayString<arraystring::typenum::U48>;
 
+#[cfg(feature = "icu_properties")]
+fn uts46_errors(domain: &str, options: Uts46Options) -> Vec<(Option<usize>, Uts46Error)> {
+    let processor = Uts46ProcessorBorrowed::new();
+    processor
+        .to_ascii(domain, options)
+        .unwrap_err()
+        .iter()
+        .map(|e| (e.label, e.error))
+        .collect()
+}
+
+#[test]
+#[cfg(feature = "icu_properties")]
+fn test_uts46_to_ascii() {
+    let processor = Uts46ProcessorBorrowed::new();
+    let nontransitional = Uts46Options::default();
+    let mut transitional = Uts46Options::default();
+    transitional.transitional = true;
+
+    let cases = [
+        // (input, nontransitional, transitional)
+        (
+            "Bücher.example",
+            "xn--bcher-kva.example",
+            "xn--bcher-kva.example",
+        ),
+        (
+            "例え。テスト",
+            "xn--r8jz45g.xn--zckzah",
+            "xn--r8jz45g.xn--zckzah",
+        ),
+        ("EXAMPLE.com.", "example.com.", "example.com."),
+        ("xn--TDA.com", "xn--tda.com", "xn--tda.com"),
+        ("faß.de", "xn--fa-hia.de", "fass.de"),
+        ("βόλος.com", "xn--nxasmm1c.com", "xn--nxasmq6b.com"),
+        // ZWJ after a virama
+        ("ශ්\u{200D}රී.com", "xn--10cl1a0b660p.com", "xn--10cl1a0b.com"),
+        // ZWNJ between a dual-joining and a right-joining letter
+        (
+            "نامه\u{200C}ای.com",
+            "xn--mgba3gch31f060k.com",
+            "xn--mgba3gch31f.com",
+        ),
+    ];
+    for (input, expected, expected_transitional) in cases {
+        assert_eq!(
+            processor.to_ascii(input, nontransitional).as_deref(),
+            Ok(expected),
+            "{input}"
+        );
+        assert_eq!(
+            processor.to_ascii(input, transitional).as_deref(),
+            Ok(expected_transitional),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "icu_properties")]
+fn test_uts46_to_unicode() {
+    let processor = Uts46ProcessorBorrowed::new();
+    let options = Uts46Options::default();
+
+    let (unicode, result) = processor.to_unicode("xn--bcher-kva.example", options);
+    assert_eq!(unicode, "bücher.example");
+    assert!(result.is_ok());
+
+    let (unicode, result) = processor.to_unicode("xn--r8jz45g.XN--ZCKZAH", options);
+    assert_eq!(unicode, "例え.テスト");
+    assert!(result.is_ok());
+
+    let (unicode, result) = processor.to_unicode("faß.xn--fa-hia", options);
+    assert_eq!(unicode, "faß.faß");
+    assert!(result.is_ok());
+
+    // Errors still produce output
+    let (unicode, result) = processor.to_unicode("a\u{061C}b.xn--", options);
+    assert_eq!(unicode, "a\u{FFFD}b.xn--");
+    let errors = result.unwrap_err();
+    assert_eq!(
+        errors
+            .iter()
+            .map(|e| (e.label, e.error))
+            .collect::<Vec<_>>(),
+        [
+            (Some(0), Uts46Error::Disallowed),
+            (Some(1), Uts46Error::Punycode)
+        ]
+    );
+
+    // DNS length is not checked
+    let long = "a".repeat(64);
+    assert_eq!(processor.to_unicode(&long, options), (long, Ok(())));
+    assert_eq!(processor.to_unicode("", options), (String::new(), Ok(())));
+}
+
+#[test]
+#[cfg(feature = "icu_properties")]
+fn test_uts46_errors() {
+    let options = Uts46Options::default();
+
+    // Hyphens
+    assert_eq!(
+        uts46_errors("-ab.cd--e", options),
+        [
+            (Some(0), Uts46Error::LeadingOrTrailingHyphen),
+            (Some(1), Uts46Error::HyphenAt3And4)
+        ]
+    );
+    assert_eq!(
+        uts46_errors("xn--xn---3ra", options),
+        [(Some(0), Uts46Error::HyphenAt3And4)]
+    );
+
+    // Punycode
+    assert_eq!(
+        uts46_errors("xn--.xn--ü.xn--a.xn--99999999999a", options),
+        [
+            (Some(0), Uts46Error::Punycode),
+            (Some(1), Uts46Error::Punycode),
+            (Some(2), Uts46Error::Disallowed),
+            (Some(3), Uts46Error::Punycode)
+        ]
+    );
+
+    // Leading combining mark
+    assert_eq!(
+        uts46_errors("\u{0301}a.com", options),
+        [(Some(0), Uts46Error::LeadingCombiningMark)]
+    );
+
+    // STD3 rules, including characters that map to disallowed ASCII
+    assert_eq!(
+        uts46_errors("a_b.⑵", options),
+        [
+            (Some(0), Uts46Error::Std3Ascii),
+            (Some(1), Uts46Error::Std3Ascii)
+        ]
+    );
+
+    // CONTEXTJ
+    assert_eq!(
+        uts46_errors("a\u{200D}b.a\u{200C}b", options),
+        [
+            (Some(0), Uts46Error::ContextJ),
+            (Some(1), Uts46Error::ContextJ)
+        ]
+    );
+
+    // Bidi: an LTR label must not start with a digit in a Bidi domain name,
+    // an RTL label must not contain L, and EN and AN must not be mixed
+    assert_eq!(
+        uts46_errors("0a.\u{05D0}a.\u{05D0}1\u{0661}.\u{05D0}\u{05D1}", options),
+        [
+            (Some(0), Uts46Error::Bidi),
+            (Some(1), Uts46Error::Bidi),
+            (Some(2), Uts46Error::Bidi)
+        ]
+    );
+    assert!(Uts46ProcessorBorrowed::new()
+        .to_ascii("0a.example", options)
+        .is_ok());
+
+    // DNS length
+    let label_63 = "a".repeat(63);
+    let label_64 = "a".repeat(64);
+    assert_eq!(
+        uts46_errors(&format!("{label_64}.a..b"), options),
+        [
+            (Some(0), Uts46Error::LabelLength),
+            (Some(2), Uts46Error::LabelLength)
+        ]
+    );
+    assert_eq!(
+        uts46_errors(&[label_63.as_str(); 4].join("."), options),
+        [(None, Uts46Error::DomainLength)]
+    );
+    assert_eq!(
+        uts46_errors("", options),
+        [
+            (Some(0), Uts46Error::LabelLength),
+            (None, Uts46Error::DomainLength)
+        ]
+    );
+
+    let errors = Uts46ProcessorBorrowed::new()
+        .to_ascii("-ab.cd--e", options)
+        .unwrap_err();
+    assert_eq!(
+        errors.to_string(),
+        "label 0: Label starts or ends with a hyphen; label 1: Label has hyphens in the third and fourth positions"
+    );
+}
+
+#[test]
+#[cfg(feature = "icu_properties")]
+fn test_uts46_options() {
+    let processor = Uts46ProcessorBorrowed::new();
+    let mut options = Uts46Options::default();
+    options.check_hyphens = false;
+    options.check_bidi = false;
+    options.check_joiners = false;
+    options.use_std3_ascii_rules = false;
+    options.verify_dns_length = false;
+
+    assert_eq!(
+        processor.to_ascii("-ab.cd--e", options).as_deref(),
+        Ok("-ab.cd--e")
+    );
+    assert_eq!(
+        uts46_errors("xn--xn---3ra", options),
+        [(Some(0), Uts46Error::XnPrefix)]
+    );
+    assert_eq!(
+        processor.to_ascii("0a.\u{05D0}a", options).as_deref(),
+        Ok("0a.xn--a-zhc")
+    );
+    assert_eq!(
+        processor.to_ascii("a\u{200D}b", options).as_deref(),
+        Ok("xn--ab-m1t")
+    );
+    assert_eq!(
+        processor.to_ascii("a_b.⑵", options).as_deref(),
+        Ok("a_b.(2)")
+    );
+    assert_eq!(processor.to_ascii("a..b", options).as_deref(), Ok("a..b"));
+
+    // Transitional processing removes the joiners before checking them
+    let mut options = Uts46Options::default();
+    options.transitional = true;
+    assert_eq!(
+        processor
+            .to_ascii("a\u{200D}b.a\u{200C}b", options)
+            .as_deref(),
+        Ok("ab.ab")
+    );
+}
+
 #[test]
 fn test_nfd_str_to() {
     let normalizer = DecomposingNormalizerBorrowed::new_nfd();
@@ -2081,3 +2325,265 @@ fn test_is_normalized_up_to() {
         0
     );
 }
+
+#[test]
+fn test_streaming() {
+    let nfd = DecomposingNormalizerBorrowed::new_nfd();
+    let nfkd = DecomposingNormalizerBorrowed::new_nfkd();
+    let nfc = ComposingNormalizerBorrowed::new_nfc();
+    let nfkc = ComposingNormalizerBorrowed::new_nfkc();
+
+    let inputs: [&[u8]; 12] = [
+        "".as_bytes(),
+        "Hello, World!".as_bytes(),
+        "cafe\u{0301} au lait".as_bytes(),
+        "A\u{0307}\u{0323}\u{0323}\u{0307}x".as_bytes(),
+        "Ệ ệ ﬁ ㈎ \u{FDFA} 𝅗𝅥".as_bytes(),
+        // Hangul LV syllable + trailing jamo and conjoining jamo
+        "가\u{11A8}\u{1100}\u{1161}\u{11A8}한국어".as_bytes(),
+        "\u{0345}\u{0301}a\u{0345}\u{0308}".as_bytes(),
+        "Ω\u{212B}\u{0958}\u{0F73}".as_bytes(),
+        b"a\xE0\x80b",
+        b"\xC3\xA4\xFFe\xCC\x81",
+        b"x\xF0\x9F\x98",
+        b"\xF0\x9F\x98\x80\xCC\x81\xED\xA0\x80",
+    ];
+
+    fn stream_all(mut stream: icu_normalizer::StreamingNormalizer, chunks: &[&[u8]]) -> String {
+        let mut output = String::new();
+        for chunk in chunks {
+            stream.push_to(chunk, &mut output).unwrap();
+        }
+        stream.finish_to(&mut output).unwrap();
+        output
+    }
+
+    for input in inputs {
+        // Ill-formed sequences become U+FFFD like in `String::from_utf8_lossy`
+        let lossy = String::from_utf8_lossy(input);
+        let byte_chunks = input.chunks(1).collect::<Vec<_>>();
+        for (expected, stream) in [
+            (nfd.normalize(&lossy), nfd.streaming()),
+            (nfkd.normalize(&lossy), nfkd.streaming()),
+            (nfc.normalize(&lossy), nfc.streaming()),
+            (nfkc.normalize(&lossy), nfkc.streaming()),
+        ] {
+            assert_eq!(stream_all(stream, &[input]), expected, "{input:?}");
+        }
+        for (expected, stream) in [
+            (nfd.normalize(&lossy), nfd.streaming()),
+            (nfc.normalize(&lossy), nfc.streaming()),
+        ] {
+            assert_eq!(stream_all(stream, &byte_chunks), expected, "{input:?}");
+        }
+        for split in 0..=input.len() {
+            let (head, tail) = input.split_at(split);
+            for (expected, stream) in [
+                (nfkd.normalize(&lossy), nfkd.streaming()),
+                (nfkc.normalize(&lossy), nfkc.streaming()),
+            ] {
+                assert_eq!(
+                    stream_all(stream, &[head, tail]),
+                    expected,
+                    "{input:?} split at {split}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_streaming_incremental_output() {
+    let nfc = ComposingNormalizerBorrowed::new_nfc();
+    let mut stream = nfc.streaming();
+    let mut output = String::new();
+
+    stream.push_to(b"ab", &mut output).unwrap();
+    assert_eq!(output, "a");
+    // U+0308 split across chunks
+    stream.push_to(b"\xCC", &mut output).unwrap();
+    assert_eq!(output, "a");
+    stream.push_to(b"\x88", &mut output).unwrap();
+    assert_eq!(output, "a");
+    stream.push_to(b"\xCC\xA3c", &mut output).unwrap();
+    assert_eq!(output, "aḅ\u{0308}");
+    // Held back until it is known that no combining mark follows
+    stream.push_to(b"\xF0\x9F", &mut output).unwrap();
+    assert_eq!(output, "aḅ\u{0308}");
+    stream.finish_to(&mut output).unwrap();
+    assert_eq!(output, "aḅ\u{0308}c\u{FFFD}");
+
+    let nfd = DecomposingNormalizerBorrowed::new_nfd();
+    let mut stream = nfd.streaming();
+    let mut output = String::new();
+    stream.push_to("한국".as_bytes(), &mut output).unwrap();
+    assert_eq!(output, "\u{1112}\u{1161}\u{11AB}");
+    stream.finish_to(&mut output).unwrap();
+    assert_eq!(output, "\u{1112}\u{1161}\u{11AB}\u{1100}\u{116E}\u{11A8}");
+}
+
+#[test]
+fn test_fcc() {
+    let nfc = ComposingNormalizerBorrowed::new_nfc();
+    let fcc = ComposingNormalizerBorrowed::new_fcc();
+
+    // Same as NFC when the combining characters are contiguous
+    assert_eq!(fcc.normalize("a\u{0308}"), "ä");
+    assert_eq!(fcc.normalize("a\u{0323}\u{0308}"), "ạ\u{0308}");
+    assert_eq!(fcc.normalize("\u{00E4}\u{0323}"), "ạ\u{0308}");
+    assert_eq!(fcc.normalize("\u{1100}\u{1161}\u{11A8}"), "각");
+    assert_eq!(fcc.normalize("\u{212B}"), "Å");
+
+    // U+0316 does not compose with a and would be skipped over by NFC
+    assert_eq!(nfc.normalize("a\u{0316}\u{0308}"), "ä\u{0316}");
+    assert_eq!(fcc.normalize("a\u{0316}\u{0308}"), "a\u{0316}\u{0308}");
+    assert_eq!(fcc.normalize("ä\u{0316}"), "a\u{0316}\u{0308}");
+    assert!(fcc.is_normalized("a\u{0316}\u{0308}"));
+    assert!(!fcc.is_normalized("ä\u{0316}"));
+    assert!(fcc
+        .normalize_iter("ä\u{0316}x".chars())
+        .eq("a\u{0316}\u{0308}x".chars()));
+    assert_eq!(
+        fcc.normalize_utf16(&[0xE4, 0x316]).as_ref(),
+        [0x61, 0x316, 0x308]
+    );
+
+    let owned =
+        icu_normalizer::ComposingNormalizer::try_new_fcc_unstable(&icu_normalizer::provider::Baked)
+            .unwrap();
+    assert_eq!(
+        owned.as_borrowed().normalize("ä\u{0316}"),
+        "a\u{0316}\u{0308}"
+    );
+}
+
+#[test]
+fn test_fcd() {
+    let fcd = FcdCheckerBorrowed::new();
+    let nfc = ComposingNormalizerBorrowed::new_nfc();
+    let nfd = DecomposingNormalizerBorrowed::new_nfd();
+    let fcc = ComposingNormalizerBorrowed::new_fcc();
+
+    assert!(fcd.is_fcd(""));
+    assert!(fcd.is_fcd("abc"));
+    assert!(fcd.is_fcd("ä\u{0308}"));
+    assert!(fcd.is_fcd("a\u{0323}\u{0308}"));
+    assert!(fcd.is_fcd("한국어"));
+    assert!(!fcd.is_fcd("a\u{0308}\u{0323}"));
+    assert!(!fcd.is_fcd("ä\u{0323}"));
+    // U+0F73 decomposes to U+0F71 U+0F72 (ccc 129 and 130)
+    assert!(fcd.is_fcd("\u{0F73}\u{0F72}"));
+    assert!(!fcd.is_fcd("\u{0F73}\u{0F71}"));
+    // U+0344 decomposes to U+0308 U+0301 (both ccc 230)
+    assert!(!fcd.is_fcd("\u{0344}\u{0323}"));
+
+    assert_eq!(fcd.split_fcd("abä\u{0323}c"), ("abä", "\u{0323}c"));
+    assert_eq!(fcd.split_fcd("abc"), ("abc", ""));
+
+    assert!(fcd.is_fcd_utf16(&[0x61, 0xD800, 0x0308]));
+    assert!(!fcd.is_fcd_utf16(&[0xE4, 0x0323]));
+    assert!(fcd.is_fcd_utf8(b"a\xFF\xCC\x88"));
+    assert!(!fcd.is_fcd_utf8("ä\u{0323}".as_bytes()));
+
+    for text in [
+        "ä\u{0323}",
+        "a\u{0308}\u{0323}",
+        "\u{0F73}\u{0F71}",
+        "ä\u{0316}ǖ\u{0323}",
+    ] {
+        assert!(fcd.is_fcd(&nfd.normalize(text)), "{text}");
+        assert!(fcd.is_fcd(&fcc.normalize(text)), "{text}");
+    }
+
+    // NFC is not necessarily FCD
+    assert_eq!(nfc.normalize("a\u{0316}\u{0308}"), "ä\u{0316}");
+    assert!(!fcd.is_fcd("ä\u{0316}"));
+
+    let owned = FcdChecker::try_new_unstable(&icu_normalizer::provider::Baked).unwrap();
+    assert!(!owned.as_borrowed().is_fcd("ä\u{0323}"));
+}
+
+#[test]
+fn test_quick_check_char() {
+    let nfd = DecomposingNormalizerBorrowed::new_nfd();
+    let nfkd = DecomposingNormalizerBorrowed::new_nfkd();
+    let nfc = ComposingNormalizerBorrowed::new_nfc();
+    let nfkc = ComposingNormalizerBorrowed::new_nfkc();
+
+    // (character, NFD_QC, NFKD_QC, NFC_QC, NFKC_QC)
+    let cases = [
+        ('a', "Y", "Y", "Y", "Y"),
+        ('ä', "N", "N", "Y", "Y"),
+        ('\u{0301}', "Y", "Y", "M", "M"),
+        ('\u{0316}', "Y", "Y", "Y", "Y"),
+        ('\u{0340}', "N", "N", "N", "N"),
+        ('\u{0344}', "N", "N", "N", "N"),
+        ('\u{212B}', "N", "N", "N", "N"),
+        ('\u{FB01}', "Y", "N", "Y", "N"),
+        ('\u{AC00}', "N", "N", "Y", "Y"),
+        ('\u{1161}', "Y", "Y", "M", "M"),
+        ('\u{11A8}', "Y", "Y", "M", "M"),
+        ('\u{0B3E}', "Y", "Y", "M", "M"),
+        ('\u{16D68}', "N", "N", "M", "M"),
+        ('\u{FFFD}', "Y", "Y", "Y", "Y"),
+    ];
+    let parse = |s: &str| match s {
+        "Y" => IsNormalized::Yes,
+        "N" => IsNormalized::No,
+        _ => IsNormalized::Maybe,
+    };
+    for (c, d, kd, c_, kc) in cases {
+        assert_eq!(nfd.quick_check_char(c), parse(d), "NFD {c:?}");
+        assert_eq!(nfkd.quick_check_char(c), parse(kd), "NFKD {c:?}");
+        assert_eq!(nfc.quick_check_char(c), parse(c_), "NFC {c:?}");
+        assert_eq!(nfkc.quick_check_char(c), parse(kc), "NFKC {c:?}");
+    }
+}
+
+#[test]
+fn test_quick_check() {
+    let nfd = DecomposingNormalizerBorrowed::new_nfd();
+    let nfc = ComposingNormalizerBorrowed::new_nfc();
+    let fcc = ComposingNormalizerBorrowed::new_fcc();
+
+    assert_eq!(nfd.quick_check("a\u{0316}\u{0308}"), IsNormalized::Yes);
+    assert_eq!(nfd.quick_check("a\u{0308}\u{0316}"), IsNormalized::No);
+    assert_eq!(nfd.quick_check("ä"), IsNormalized::No);
+
+    assert_eq!(nfc.quick_check("äbc"), IsNormalized::Yes);
+    assert_eq!(nfc.quick_check("ä\u{0316}"), IsNormalized::Yes);
+    assert_eq!(nfc.quick_check("a\u{0308}"), IsNormalized::Maybe);
+    assert_eq!(nfc.quick_check("q\u{0308}"), IsNormalized::Maybe);
+    assert!(nfc.is_normalized("q\u{0308}"));
+    assert_eq!(nfc.quick_check("\u{0308}\u{0316}"), IsNormalized::No);
+    assert_eq!(nfc.quick_check("\u{212B}"), IsNormalized::No);
+
+    // Unlike NFC, FCC requires the decompositions to be in canonical order
+    assert_eq!(fcc.quick_check("ä\u{0316}"), IsNormalized::No);
+    assert_eq!(fcc.quick_check("a\u{0316}\u{0308}"), IsNormalized::Maybe);
+    assert!(fcc.is_normalized("a\u{0316}\u{0308}"));
+
+    let text = "a\u{0308}\u{0316}";
+    let utf16: Vec<u16> = text.encode_utf16().collect();
+    assert_eq!(nfd.quick_check_utf8(text.as_bytes()), IsNormalized::No);
+    assert_eq!(nfd.quick_check_utf16(&utf16), IsNormalized::No);
+    assert_eq!(nfc.quick_check_utf8(text.as_bytes()), IsNormalized::No);
+    assert_eq!(nfc.quick_check_utf16(&utf16), IsNormalized::No);
+    assert_eq!(nfc.quick_check_utf8(b"a\xCC\x88"), IsNormalized::Maybe);
+
+    // A quick check never contradicts the full check
+    let nfkc = ComposingNormalizerBorrowed::new_nfkc();
+    for text in [
+        "\u{FB01}",
+        "ｱ\u{3099}",
+        "\u{1100}\u{1161}",
+        "\u{0F73}",
+        "\u{16D63}\u{16D68}",
+    ] {
+        match nfkc.quick_check(text) {
+            IsNormalized::Yes => assert!(nfkc.is_normalized(text), "{text:?}"),
+            IsNormalized::No => assert!(!nfkc.is_normalized(text), "{text:?}"),
+            IsNormalized::Maybe => {}
+        }
+    }
+}
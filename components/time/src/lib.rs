@@ -33,6 +33,7 @@ mod time_zone;
 mod types;
 mod windows_tz;
 mod zone_offset;
+mod zone_transitions;
 
 pub use error::InvalidOffsetError;
 #[cfg(feature = "ixdtf")]
@@ -129,6 +130,9 @@ pub mod zone {
     pub use crate::types::UtcOffset;
     pub use crate::zone_offset::UtcOffsetCalculator;
     pub use crate::zone_offset::UtcOffsets;
+    pub use crate::zone_transitions::Disambiguation;
+    pub use crate::zone_transitions::LocalTimeError;
+    pub use crate::zone_transitions::ZoneTransitionCalculator;
 
     pub use iana::IanaParser;
     pub use windows::WindowsTimeZoneMapper;
@@ -20,7 +20,7 @@ use core::ops::Deref;
 use icu_provider::prelude::*;
 use tinystr::TinyAsciiStr;
 use zerovec::ule::{AsULE, ULE};
-use zerovec::{ZeroMap2d, ZeroSlice, ZeroVec};
+use zerovec::{ZeroMap, ZeroMap2d, ZeroSlice, ZeroVec};
 
 pub mod names;
 pub mod windows;
@@ -48,6 +48,7 @@ const _: () = {
     impl_iana_to_bcp47_map_v3!(Baked);
     impl_windows_zones_to_bcp47_map_v1!(Baked);
     impl_zone_offset_period_v1!(Baked);
+    impl_zone_transitions_v1!(Baked);
 };
 
 #[cfg(feature = "datagen")]
@@ -57,6 +58,7 @@ pub const MARKERS: &[DataMarkerInfo] = &[
     names::IanaToBcp47MapV3::INFO,
     windows::WindowsZonesToBcp47MapV1::INFO,
     ZoneOffsetPeriodV1::INFO,
+    ZoneTransitionsV1::INFO,
 ];
 
 /// TimeZone ID in BCP47 format
@@ -143,3 +145,110 @@ pub struct ZoneOffsetPeriod<'data>(
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub ZeroMap2d<'data, TimeZone, MinutesSinceEpoch, (EighthsOfHourOffset, EighthsOfHourOffset)>,
 );
+
+/// An ICU4X mapping to the exact UTC offset transitions of each time zone.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(marker(ZoneTransitionsV1, "time_zone/transitions@1", singleton))]
+#[derive(PartialEq, Debug, Clone, Default)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_time::provider))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct ZoneTransitions<'data> {
+    /// The range of the transitions of each time zone in [`Self::transitions`]. Time zones
+    /// with the same transitions share a range.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub zones: ZeroMap<'data, TimeZone, (u32, u32)>,
+    /// The offset transitions of the time zones. Each transition is the instant of the
+    /// transition, in seconds since the UNIX epoch, and the UTC offset in seconds and the
+    /// zone variant that are in effect from that instant.
+    ///
+    /// The transitions of a time zone are sorted by instant, and the first one is at
+    /// [`i64::MIN`]. Transitions before 1970 are not included, so the first transition has
+    /// the offset that was in effect at the start of 1970.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub transitions: ZeroVec<'data, (i64, i32, crate::zone::TimeZoneVariant)>,
+    /// The rule that produces all transitions after the last transition of a time zone in
+    /// [`Self::transitions`], for time zones that currently observe daylight saving time.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub rules: ZeroMap<'data, TimeZone, ZoneTransitionRule>,
+}
+
+/// A yearly pair of transitions between standard and daylight time, like a POSIX `TZ` rule.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(ZoneTransitionRuleULE)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Debug)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_time::provider))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct ZoneTransitionRule {
+    /// The standard UTC offset, in seconds
+    pub standard_offset: i32,
+    /// The daylight UTC offset, in seconds
+    pub daylight_offset: i32,
+    /// The transition from standard to daylight time
+    pub start: TransitionDate,
+    /// The transition from daylight to standard time
+    pub end: TransitionDate,
+}
+
+/// The local date and time at which a [`ZoneTransitionRule`] transitions in each year.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(TransitionDateULE)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Debug)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_time::provider))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct TransitionDate {
+    /// The month, from 1 to 12
+    pub month: u8,
+    /// The day of the month, from 1 to 31. Days past the end of the month refer to the
+    /// last day of the month.
+    pub day: u8,
+    /// If set, the transition happens on this ISO weekday (1 for Monday to 7 for Sunday),
+    /// on or after [`Self::day`], or on or before it if [`Self::on_or_before`] is set.
+    pub weekday: Option<u8>,
+    /// Whether [`Self::weekday`] is searched for on or before [`Self::day`]
+    pub on_or_before: bool,
+    /// The time of the transition, in seconds since the start of the day
+    pub time: i32,
+    /// How [`Self::time`] is measured
+    pub time_mode: TransitionTimeMode,
+}
+
+/// How the time of a [`TransitionDate`] is measured.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(TransitionTimeModeULE)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Debug)]
+#[repr(u8)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_time::provider))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum TransitionTimeMode {
+    /// The local time in effect before the transition
+    Wall = 0,
+    /// The local standard time
+    Standard = 1,
+    /// UTC
+    Utc = 2,
+}
@@ -3,8 +3,12 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::error::InvalidOffsetError;
+use crate::provider::EPOCH;
+use crate::zone::{models, Disambiguation, LocalTimeError, ZoneTransitionCalculator};
+use crate::zone_transitions::LocalInstants;
+use crate::{TimeZone, TimeZoneInfo};
 use core::str::FromStr;
-use icu_calendar::{AsCalendar, Date, RangeError};
+use icu_calendar::{AsCalendar, Date, Iso, RangeError};
 
 /// This macro defines a struct for 0-based date fields: hours, minutes, seconds
 /// and fractional seconds. Each unit is bounded by a range. The traits implemented
@@ -189,6 +193,252 @@ pub struct ZonedDateTime<A: AsCalendar, Z> {
     pub zone: Z,
 }
 
+const MILLISECONDS_PER_DAY: i64 = 24 * 60 * 60 * 1000;
+
+/// Converts milliseconds since the local epoch into a local date and time.
+fn iso_from_local_milliseconds(local_milliseconds: i64) -> (Date<Iso>, Time) {
+    let days = local_milliseconds.div_euclid(MILLISECONDS_PER_DAY);
+    let milliseconds_of_day = local_milliseconds.rem_euclid(MILLISECONDS_PER_DAY);
+    // The year of any i64 number of milliseconds fits in an i32.
+    #[allow(clippy::unwrap_used)]
+    let (year, month, day) = calendrical_calculations::iso::iso_from_fixed(EPOCH + days).unwrap();
+    #[allow(clippy::unwrap_used)] // valid day and month
+    let date = Date::try_new_iso(year, month, day).unwrap();
+    let time = Time {
+        hour: Hour((milliseconds_of_day / 3_600_000) as u8),
+        minute: Minute((milliseconds_of_day / 60_000 % 60) as u8),
+        second: Second((milliseconds_of_day / 1000 % 60) as u8),
+        subsecond: Nanosecond((milliseconds_of_day % 1000) as u32 * 1_000_000),
+    };
+    (date, time)
+}
+
+/// Converts a local date and time into milliseconds since the local epoch.
+///
+/// Returns `None` if the result does not fit in an `i64`.
+fn iso_to_local_milliseconds(date: Date<Iso>, time: Time) -> Option<i64> {
+    (date.to_fixed() - EPOCH)
+        .checked_mul(MILLISECONDS_PER_DAY)?
+        .checked_add(
+            time.hour.number() as i64 * 3_600_000
+                + time.minute.number() as i64 * 60_000
+                + time.second.number() as i64 * 1000
+                + (time.subsecond.number() / 1_000_000) as i64,
+        )
+}
+
+impl ZonedDateTime<Iso, UtcOffset> {
+    /// Creates a [`ZonedDateTime`] from an absolute time, in milliseconds since the UNIX epoch,
+    /// and a UTC offset.
+    ///
+    /// Returns `None` if the local time does not fit in an `i64` number of milliseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::Date;
+    /// use icu::time::zone::UtcOffset;
+    /// use icu::time::Time;
+    /// use icu::time::ZonedDateTime;
+    ///
+    /// let zoned_date_time = ZonedDateTime::from_epoch_milliseconds_and_utc_offset(
+    ///     1_735_732_800_123,
+    ///     UtcOffset::try_from_str("-05:00").unwrap(),
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(zoned_date_time.date, Date::try_new_iso(2025, 1, 1).unwrap());
+    /// assert_eq!(zoned_date_time.time, Time::try_new(7, 0, 0, 123_000_000).unwrap());
+    /// assert_eq!(zoned_date_time.to_epoch_milliseconds(), Some(1_735_732_800_123));
+    ///
+    /// assert_eq!(
+    ///     ZonedDateTime::from_epoch_milliseconds_and_utc_offset(
+    ///         i64::MAX,
+    ///         UtcOffset::try_from_str("+01:00").unwrap(),
+    ///     ),
+    ///     None
+    /// );
+    /// ```
+    pub fn from_epoch_milliseconds_and_utc_offset(
+        epoch_milliseconds: i64,
+        utc_offset: UtcOffset,
+    ) -> Option<Self> {
+        let (date, time) = iso_from_local_milliseconds(
+            epoch_milliseconds.checked_add(utc_offset.to_seconds() as i64 * 1000)?,
+        );
+        Some(Self {
+            date,
+            time,
+            zone: utc_offset,
+        })
+    }
+
+    /// Returns the absolute time, in milliseconds since the UNIX epoch, or `None` if it does
+    /// not fit in an `i64`.
+    ///
+    /// Sub-millisecond precision is truncated.
+    pub fn to_epoch_milliseconds(&self) -> Option<i64> {
+        iso_to_local_milliseconds(self.date, self.time)?
+            .checked_sub(self.zone.to_seconds() as i64 * 1000)
+    }
+}
+
+impl ZonedDateTime<Iso, TimeZoneInfo<models::Full>> {
+    /// Creates a [`ZonedDateTime`] from an absolute time, in milliseconds since the UNIX epoch,
+    /// and a time zone.
+    ///
+    /// The UTC offset and zone variant in effect at that time are calculated with a
+    /// [`ZoneTransitionCalculator`]. Returns `None` if the time zone is unknown, or if the local
+    /// time does not fit in an `i64` number of milliseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::Date;
+    /// use icu::time::zone::{TimeZoneVariant, UtcOffset, ZoneTransitionCalculator};
+    /// use icu::time::{Time, TimeZone, ZonedDateTime};
+    /// use tinystr::tinystr;
+    ///
+    /// // America/Denver
+    /// let zoned_date_time = ZonedDateTime::from_epoch_milliseconds_and_time_zone(
+    ///     1_720_000_000_000,
+    ///     TimeZone(tinystr!(8, "usden")),
+    ///     &ZoneTransitionCalculator::new(),
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(zoned_date_time.date, Date::try_new_iso(2024, 7, 3).unwrap());
+    /// assert_eq!(zoned_date_time.time, Time::try_new(3, 46, 40, 0).unwrap());
+    /// assert_eq!(
+    ///     zoned_date_time.zone.offset(),
+    ///     Some(UtcOffset::try_from_str("-06:00").unwrap())
+    /// );
+    /// assert_eq!(zoned_date_time.zone.zone_variant(), TimeZoneVariant::Daylight);
+    /// ```
+    pub fn from_epoch_milliseconds_and_time_zone(
+        epoch_milliseconds: i64,
+        time_zone: TimeZone,
+        calculator: &ZoneTransitionCalculator,
+    ) -> Option<Self> {
+        let (offset, zone_variant) =
+            calculator.offset_at_epoch_seconds(time_zone, epoch_milliseconds.div_euclid(1000))?;
+        let ZonedDateTime { date, time, .. } =
+            ZonedDateTime::from_epoch_milliseconds_and_utc_offset(epoch_milliseconds, offset)?;
+        Some(Self {
+            date,
+            time,
+            zone: time_zone
+                .with_offset(Some(offset))
+                .at_time((date, time))
+                .with_zone_variant(zone_variant),
+        })
+    }
+
+    /// Creates a [`ZonedDateTime`] from a local date and time in a time zone.
+    ///
+    /// The UTC offset and zone variant in effect are calculated with a [`ZoneTransitionCalculator`].
+    /// If the local time is skipped or repeated in the time zone, it is resolved according to
+    /// the [`Disambiguation`]. A skipped local time resolves to the local time that is the same
+    /// distance from the transition, so the date and time of the result may differ from the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::Date;
+    /// use icu::time::zone::{Disambiguation, LocalTimeError, ZoneTransitionCalculator};
+    /// use icu::time::{Time, TimeZone, ZonedDateTime};
+    /// use tinystr::tinystr;
+    ///
+    /// let calculator = ZoneTransitionCalculator::new();
+    /// let denver = TimeZone(tinystr!(8, "usden"));
+    ///
+    /// let zoned_date_time = ZonedDateTime::try_from_local_time_and_time_zone(
+    ///     Date::try_new_iso(2025, 1, 1).unwrap(),
+    ///     Time::try_new(5, 0, 0, 0).unwrap(),
+    ///     denver,
+    ///     &calculator,
+    ///     Disambiguation::Reject,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(zoned_date_time.to_epoch_milliseconds(), Some(1_735_732_800_000));
+    ///
+    /// // 02:30 is skipped when daylight saving time starts
+    /// let date = Date::try_new_iso(2024, 3, 10).unwrap();
+    /// let time = Time::try_new(2, 30, 0, 0).unwrap();
+    ///
+    /// assert_eq!(
+    ///     ZonedDateTime::try_from_local_time_and_time_zone(
+    ///         date,
+    ///         time,
+    ///         denver,
+    ///         &calculator,
+    ///         Disambiguation::Reject
+    ///     ),
+    ///     Err(LocalTimeError::Skipped)
+    /// );
+    ///
+    /// let later = ZonedDateTime::try_from_local_time_and_time_zone(
+    ///     date,
+    ///     time,
+    ///     denver,
+    ///     &calculator,
+    ///     Disambiguation::Later,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(later.time, Time::try_new(3, 30, 0, 0).unwrap());
+    /// ```
+    pub fn try_from_local_time_and_time_zone(
+        date: Date<Iso>,
+        time: Time,
+        time_zone: TimeZone,
+        calculator: &ZoneTransitionCalculator,
+        disambiguation: Disambiguation,
+    ) -> Result<Self, LocalTimeError> {
+        let local_milliseconds =
+            iso_to_local_milliseconds(date, time).ok_or(LocalTimeError::OutOfRange)?;
+        let epoch_seconds = match calculator
+            .instants_for_local_seconds(time_zone, local_milliseconds.div_euclid(1000))
+            .ok_or(LocalTimeError::UnknownOffset)?
+        {
+            LocalInstants::Unique(instant) => instant,
+            LocalInstants::Repeated(earlier, later) => match disambiguation {
+                Disambiguation::Earlier => earlier,
+                Disambiguation::Later => later,
+                Disambiguation::Reject => return Err(LocalTimeError::Repeated),
+            },
+            LocalInstants::Skipped(earlier, later) => match disambiguation {
+                Disambiguation::Earlier => earlier,
+                Disambiguation::Later => later,
+                Disambiguation::Reject => return Err(LocalTimeError::Skipped),
+            },
+        };
+        let epoch_milliseconds = epoch_seconds
+            .checked_mul(1000)
+            .and_then(|milliseconds| milliseconds.checked_add(local_milliseconds.rem_euclid(1000)))
+            .ok_or(LocalTimeError::OutOfRange)?;
+        let mut zoned_date_time =
+            Self::from_epoch_milliseconds_and_time_zone(epoch_milliseconds, time_zone, calculator)
+                .ok_or(LocalTimeError::UnknownOffset)?;
+        // Keep sub-millisecond precision.
+        zoned_date_time.time.subsecond = Nanosecond(
+            zoned_date_time.time.subsecond.number() / 1_000_000 * 1_000_000
+                + time.subsecond.number() % 1_000_000,
+        );
+        Ok(zoned_date_time)
+    }
+
+    /// Returns the absolute time, in milliseconds since the UNIX epoch, or `None` if the UTC
+    /// offset is not known or the result does not fit in an `i64`.
+    ///
+    /// Sub-millisecond precision is truncated.
+    pub fn to_epoch_milliseconds(&self) -> Option<i64> {
+        iso_to_local_milliseconds(self.date, self.time)?
+            .checked_sub(self.zone.offset()?.to_seconds() as i64 * 1000)
+    }
+}
+
 /// An offset from Coordinated Universal Time (UTC)
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default, PartialOrd, Ord)]
 pub struct UtcOffset(i32);
@@ -359,9 +609,6 @@ impl FromStr for UtcOffset {
     }
 }
 
-#[cfg(doc)]
-use crate::TimeZoneInfo;
-
 /// A time zone variant, such as Standard Time, or Daylight/Summer Time.
 ///
 /// This should not generally be constructed by client code. Instead, use
@@ -0,0 +1,641 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::provider::{
+    TransitionDate, TransitionTimeMode, ZoneTransitionRule, ZoneTransitionsV1, EPOCH,
+};
+use crate::{
+    zone::{TimeZoneVariant, UtcOffset},
+    TimeZone,
+};
+use calendrical_calculations::iso;
+use displaydoc::Display;
+use icu_provider::prelude::*;
+use zerovec::ule::AsULE;
+use zerovec::ZeroSlice;
+
+/// [`ZoneTransitionCalculator`] uses data from the [data provider] to calculate the exact
+/// UTC offset of a time zone at any instant.
+///
+/// Unlike [`UtcOffsetCalculator`](crate::zone::UtcOffsetCalculator), which only knows which
+/// offsets a time zone uses around a local time, this contains every offset transition in the
+/// time zone database since 1970, plus a yearly rule for the future transitions of zones that
+/// currently observe daylight saving time. Instants before 1970 use the offset that was in
+/// effect at the start of 1970.
+///
+/// [data provider]: icu_provider
+#[derive(Debug)]
+pub struct ZoneTransitionCalculator {
+    transitions: DataPayload<ZoneTransitionsV1>,
+}
+
+#[cfg(feature = "compiled_data")]
+impl Default for ZoneTransitionCalculator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ZoneTransitionCalculator {
+    /// Constructs a `ZoneTransitionCalculator` using compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    #[inline]
+    pub const fn new() -> Self {
+        ZoneTransitionCalculator {
+            transitions: DataPayload::from_static_ref(
+                crate::provider::Baked::SINGLETON_ZONE_TRANSITIONS_V1,
+            ),
+        }
+    }
+
+    icu_provider::gen_buffer_data_constructors!(() -> error: DataError,
+        functions: [
+            new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new)]
+    pub fn try_new_unstable(
+        provider: &(impl DataProvider<ZoneTransitionsV1> + ?Sized),
+    ) -> Result<Self, DataError> {
+        let transitions = provider.load(Default::default())?.payload;
+        Ok(Self { transitions })
+    }
+
+    /// Calculates the UTC offset and zone variant in effect in a time zone at an instant,
+    /// given in milliseconds since the UNIX epoch.
+    ///
+    /// Returns `None` if the time zone is unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::time::zone::{TimeZoneVariant, UtcOffset, ZoneTransitionCalculator};
+    /// use icu::time::TimeZone;
+    /// use tinystr::tinystr;
+    ///
+    /// let calculator = ZoneTransitionCalculator::new();
+    ///
+    /// // America/Denver, 2024-03-10T08:59:59Z
+    /// assert_eq!(
+    ///     calculator.compute_offset_at_epoch_milliseconds(
+    ///         TimeZone(tinystr!(8, "usden")),
+    ///         1_710_061_199_000,
+    ///     ),
+    ///     Some((
+    ///         UtcOffset::try_from_str("-07:00").unwrap(),
+    ///         TimeZoneVariant::Standard
+    ///     ))
+    /// );
+    ///
+    /// // One second later, daylight saving time starts
+    /// assert_eq!(
+    ///     calculator.compute_offset_at_epoch_milliseconds(
+    ///         TimeZone(tinystr!(8, "usden")),
+    ///         1_710_061_200_000,
+    ///     ),
+    ///     Some((
+    ///         UtcOffset::try_from_str("-06:00").unwrap(),
+    ///         TimeZoneVariant::Daylight
+    ///     ))
+    /// );
+    /// ```
+    pub fn compute_offset_at_epoch_milliseconds(
+        &self,
+        time_zone_id: TimeZone,
+        epoch_milliseconds: i64,
+    ) -> Option<(UtcOffset, TimeZoneVariant)> {
+        self.offset_at_epoch_seconds(time_zone_id, epoch_milliseconds.div_euclid(1000))
+    }
+
+    /// Returns the sorted transitions of a time zone.
+    fn transitions_of(
+        &self,
+        time_zone_id: TimeZone,
+    ) -> Option<&ZeroSlice<(i64, i32, TimeZoneVariant)>> {
+        let data = self.transitions.get();
+        let (start, end) = data.zones.get_copied(&time_zone_id)?;
+        data.transitions
+            .get_subslice(usize::try_from(start).ok()?..usize::try_from(end).ok()?)
+    }
+
+    /// Calculates the UTC offset and zone variant in effect at an instant, given in seconds
+    /// since the UNIX epoch.
+    pub(crate) fn offset_at_epoch_seconds(
+        &self,
+        time_zone_id: TimeZone,
+        epoch_seconds: i64,
+    ) -> Option<(UtcOffset, TimeZoneVariant)> {
+        let transitions = self.transitions_of(time_zone_id)?;
+        // The first transition is at `i64::MIN`, so this only fails for empty data
+        let index = transitions
+            .as_ule_slice()
+            .partition_point(|t| i64::from_unaligned(t.0) <= epoch_seconds)
+            .checked_sub(1)?;
+        let (transition, offset, zone_variant) = transitions.get(index)?;
+        let (offset, zone_variant) = self
+            .transitions
+            .get()
+            .rules
+            .get_copied(&time_zone_id)
+            // The rule only applies after the last transition
+            .filter(|_| index + 1 == transitions.len())
+            .and_then(|rule| offset_from_rule(rule, transition, epoch_seconds))
+            .unwrap_or((offset, zone_variant));
+        Some((UtcOffset::try_from_seconds(offset).ok()?, zone_variant))
+    }
+
+    /// Calculates the instants, in seconds since the UNIX epoch, at which a local time occurs.
+    pub(crate) fn instants_for_local_seconds(
+        &self,
+        time_zone_id: TimeZone,
+        local_seconds: i64,
+    ) -> Option<LocalInstants> {
+        // Offsets are less than a day, so the local time can only occur within a day of it.
+        // The instants below cannot overflow once these do not.
+        let from = local_seconds.checked_sub(86400)?;
+        let to = local_seconds.checked_add(86400)?;
+        let mut offset = self
+            .offset_at_epoch_seconds(time_zone_id, from)?
+            .0
+            .to_seconds() as i64;
+        let mut start = from;
+        let mut instants = None;
+        let mut skipped = None;
+        let mut transitions = self.transitions_between(time_zone_id, from, to)?;
+        // Check in which periods between the transitions the local time occurs, and whether
+        // it falls in the gap of a transition
+        loop {
+            let next = transitions.next();
+            let instant = local_seconds - offset;
+            if start <= instant && next.map_or(true, |(transition, _)| instant < transition) {
+                instants = Some(match instants {
+                    Some((earliest, _)) => (earliest, instant),
+                    None => (instant, instant),
+                });
+            }
+            let Some((transition, next_offset)) = next else {
+                break;
+            };
+            let next_offset = next_offset as i64;
+            if transition + offset <= local_seconds && local_seconds < transition + next_offset {
+                skipped = Some((local_seconds - next_offset, local_seconds - offset));
+            }
+            start = transition;
+            offset = next_offset;
+        }
+        match (instants, skipped) {
+            (Some((earliest, latest)), _) if earliest == latest => {
+                Some(LocalInstants::Unique(earliest))
+            }
+            (Some((earliest, latest)), _) => Some(LocalInstants::Repeated(earliest, latest)),
+            (None, Some((earlier, later))) => Some(LocalInstants::Skipped(earlier, later)),
+            (None, None) => None,
+        }
+    }
+
+    /// Returns the transitions of a time zone after `from` and at or before `to`, in order,
+    /// with the UTC offset in seconds that is in effect from each of them.
+    fn transitions_between(
+        &self,
+        time_zone_id: TimeZone,
+        from: i64,
+        to: i64,
+    ) -> Option<impl Iterator<Item = (i64, i32)> + '_> {
+        let transitions = self.transitions_of(time_zone_id)?;
+        let (last, ..) = transitions.last()?;
+        let start = transitions
+            .as_ule_slice()
+            .partition_point(|t| i64::from_unaligned(t.0) <= from);
+        let stored = transitions
+            .get_subslice(start..transitions.len())?
+            .iter()
+            .take_while(move |&(transition, ..)| transition <= to)
+            .map(|(transition, offset, _)| (transition, offset));
+        // The rule only applies after the last transition
+        let from_rule = self
+            .transitions
+            .get()
+            .rules
+            .get_copied(&time_zone_id)
+            .and_then(|rule| rule_transitions(rule, from, to))
+            .into_iter()
+            .flatten()
+            .filter(move |&(transition, _)| last.max(from) < transition && transition <= to)
+            .map(|(transition, (offset, _))| (transition, offset));
+        Some(stored.chain(from_rule))
+    }
+}
+
+/// Calculates the offset in effect at an instant from the last transition of a zone at or
+/// before `since` and the yearly rule that follows it.
+///
+/// Returns `None` if the rule has no transition after `since` and at or before the instant.
+fn offset_from_rule(
+    rule: ZoneTransitionRule,
+    since: i64,
+    epoch_seconds: i64,
+) -> Option<(i32, TimeZoneVariant)> {
+    rule_transitions(rule, epoch_seconds, epoch_seconds)?
+        .filter(|&(transition, _)| since < transition && transition <= epoch_seconds)
+        .max_by_key(|&(transition, _)| transition)
+        .map(|(_, offsets)| offsets)
+}
+
+/// Returns the transitions of a rule in the years around the instants `from` to `to`, in
+/// order, with the UTC offset in seconds and the zone variant in effect from each of them.
+fn rule_transitions(
+    rule: ZoneTransitionRule,
+    from: i64,
+    to: i64,
+) -> Option<impl Iterator<Item = (i64, (i32, TimeZoneVariant))>> {
+    let year = |epoch_seconds: i64| {
+        let local_days = epoch_seconds
+            .saturating_add(rule.standard_offset as i64)
+            .div_euclid(86400);
+        iso::iso_from_fixed(EPOCH + local_days)
+            .ok()
+            .map(|(year, _, _)| year)
+    };
+    Some(
+        (year(from)?.checked_sub(1)?..=year(to)?.checked_add(1)?).flat_map(move |year| {
+            let start = (
+                transition_seconds(year, rule.start, rule.standard_offset, rule),
+                (rule.daylight_offset, TimeZoneVariant::Daylight),
+            );
+            let end = (
+                transition_seconds(year, rule.end, rule.daylight_offset, rule),
+                (rule.standard_offset, TimeZoneVariant::Standard),
+            );
+            // Daylight saving time starts after it ends in the southern hemisphere
+            if start.0 <= end.0 {
+                [start, end]
+            } else {
+                [end, start]
+            }
+        }),
+    )
+}
+
+/// Calculates the instant, in seconds since the UNIX epoch, at which a rule transitions in
+/// a year, given the offset in effect before the transition.
+fn transition_seconds(
+    year: i32,
+    date: TransitionDate,
+    offset_before: i32,
+    rule: ZoneTransitionRule,
+) -> i64 {
+    let days_in_month = match date.month {
+        2 if iso::is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    };
+    let mut day = iso::fixed_from_iso(year, date.month, date.day.min(days_in_month));
+    if let Some(weekday) = date.weekday {
+        // R.D. 1 is a Monday
+        let current = (day.to_i64_date() - 1).rem_euclid(7) + 1;
+        if date.on_or_before {
+            day -= (current - weekday as i64).rem_euclid(7);
+        } else {
+            day += (weekday as i64 - current).rem_euclid(7);
+        }
+    }
+    (day - EPOCH) * 86400 + date.time as i64
+        - match date.time_mode {
+            TransitionTimeMode::Wall => offset_before,
+            TransitionTimeMode::Standard => rule.standard_offset,
+            TransitionTimeMode::Utc => 0,
+        } as i64
+}
+
+/// The instants at which a local time occurs in a time zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LocalInstants {
+    /// The local time occurs exactly once.
+    Unique(i64),
+    /// The local time occurs twice, as the offset decreases.
+    Repeated(i64, i64),
+    /// The local time is skipped, as the offset increases. These are the instants
+    /// obtained by interpreting the local time in the offsets after and before the skip.
+    Skipped(i64, i64),
+}
+
+/// How to resolve a local time that is skipped or repeated in a time zone.
+///
+/// A local time is skipped when clocks are moved forward, such as at the start of daylight
+/// saving time, and repeated when clocks are moved back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Disambiguation {
+    /// Choose the earlier of the two candidate instants.
+    ///
+    /// For a skipped local time, this interprets the local time in the offset that applies
+    /// after the transition, resulting in a local time before the transition.
+    Earlier,
+    /// Choose the later of the two candidate instants.
+    ///
+    /// For a skipped local time, this interprets the local time in the offset that applies
+    /// before the transition, resulting in a local time after the transition.
+    Later,
+    /// Return an error if the local time is skipped or repeated.
+    Reject,
+}
+
+/// An error returned when converting a local time in a time zone into an instant.
+#[derive(Display, Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LocalTimeError {
+    /// The local time does not occur in the time zone
+    #[displaydoc("The local time is skipped in this time zone")]
+    Skipped,
+    /// The local time occurs twice in the time zone
+    #[displaydoc("The local time is repeated in this time zone")]
+    Repeated,
+    /// The UTC offset of the time zone around the local time is not known
+    #[displaydoc("The UTC offset at this local time is unknown")]
+    UnknownOffset,
+    /// The local time is too far from the UNIX epoch to be represented as an instant
+    #[displaydoc("The local time is out of range")]
+    OutOfRange,
+}
+
+impl core::error::Error for LocalTimeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Time, ZonedDateTime};
+    use icu_calendar::Date;
+    use tinystr::tinystr;
+
+    #[test]
+    fn test_epoch_milliseconds_round_trip() {
+        let offset = UtcOffset::try_from_str("+05:30").unwrap();
+        for epoch_milliseconds in [
+            0,
+            -1,
+            1_735_732_800_123,
+            -62_135_596_800_000,
+            253_402_300_799_999,
+        ] {
+            let zoned_date_time =
+                ZonedDateTime::from_epoch_milliseconds_and_utc_offset(epoch_milliseconds, offset)
+                    .unwrap();
+            assert_eq!(
+                zoned_date_time.to_epoch_milliseconds(),
+                Some(epoch_milliseconds),
+                "{zoned_date_time:?}"
+            );
+        }
+
+        let zoned_date_time =
+            ZonedDateTime::from_epoch_milliseconds_and_utc_offset(-1, offset).unwrap();
+        assert_eq!(zoned_date_time.date, Date::try_new_iso(1970, 1, 1).unwrap());
+        assert_eq!(
+            zoned_date_time.time,
+            Time::try_new(5, 29, 59, 999_000_000).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_extreme_years() {
+        let calculator = ZoneTransitionCalculator::new();
+        let denver = TimeZone(tinystr!(8, "usden"));
+        let time = Time::try_new(12, 0, 0, 0).unwrap();
+        for year in [i32::MIN, i32::MAX] {
+            let date = Date::try_new_iso(year, 1, 1).unwrap();
+            let zoned_date_time = ZonedDateTime {
+                date,
+                time,
+                zone: UtcOffset::zero(),
+            };
+            assert_eq!(zoned_date_time.to_epoch_milliseconds(), None, "{year}");
+            assert_eq!(
+                ZonedDateTime::try_from_local_time_and_time_zone(
+                    date,
+                    time,
+                    denver,
+                    &calculator,
+                    Disambiguation::Reject,
+                ),
+                Err(LocalTimeError::OutOfRange),
+                "{year}"
+            );
+        }
+
+        // Instants close to the limits of an i64 round-trip
+        for epoch_milliseconds in [i64::MIN + 86_400_000, i64::MAX] {
+            let zoned_date_time = ZonedDateTime::from_epoch_milliseconds_and_utc_offset(
+                epoch_milliseconds,
+                UtcOffset::zero(),
+            )
+            .unwrap();
+            assert_eq!(
+                zoned_date_time.to_epoch_milliseconds(),
+                Some(epoch_milliseconds)
+            );
+        }
+
+        // The local time of instants at the limits does not fit in an i64
+        for (epoch_milliseconds, offset) in [(i64::MIN, "-01:00"), (i64::MAX, "+01:00")] {
+            assert_eq!(
+                ZonedDateTime::from_epoch_milliseconds_and_utc_offset(
+                    epoch_milliseconds,
+                    UtcOffset::try_from_str(offset).unwrap(),
+                ),
+                None
+            );
+        }
+    }
+
+    #[test]
+    fn test_offsets() {
+        let calculator = ZoneTransitionCalculator::new();
+        let offset = |tz: &str, epoch_seconds: i64| {
+            calculator
+                .compute_offset_at_epoch_milliseconds(
+                    TimeZone(tz.parse().unwrap()),
+                    epoch_seconds * 1000,
+                )
+                .map(|(offset, zone_variant)| (offset.to_seconds() / 60, zone_variant))
+        };
+        use TimeZoneVariant::*;
+
+        // Transitions in the data
+        assert_eq!(offset("usnyc", -2_208_988_800), Some((-300, Standard)));
+        assert_eq!(offset("usden", 1_730_619_000), Some((-360, Daylight)));
+        assert_eq!(offset("usden", 1_730_622_600), Some((-420, Standard)));
+        assert_eq!(offset("usden", 1_173_603_599), Some((-420, Standard)));
+        assert_eq!(offset("usden", 1_173_603_600), Some((-360, Daylight)));
+
+        // Instants before 1970 use the offset at the start of 1970, when Europe/London
+        // observed British Standard Time all year
+        assert_eq!(offset("gblon", -1), Some((60, Standard)));
+        assert_eq!(offset("gblon", -2_208_988_800), Some((60, Standard)));
+
+        // Transitions calculated from the rules, including in the southern hemisphere
+        assert_eq!(offset("usden", 5_681_491_200), Some((-420, Standard)));
+        assert_eq!(offset("usden", 5_697_129_600), Some((-360, Daylight)));
+        assert_eq!(offset("gblon", 5_681_491_200), Some((0, Standard)));
+        assert_eq!(offset("gblon", 5_697_129_600), Some((60, Daylight)));
+        assert_eq!(offset("ausyd", 5_681_491_200), Some((660, Daylight)));
+        assert_eq!(offset("ausyd", 5_697_129_600), Some((600, Standard)));
+
+        // America/Phoenix does not observe DST
+        assert_eq!(offset("usphx", 5_697_129_600), Some((-420, Standard)));
+
+        assert_eq!(offset("unk", 0), None);
+    }
+
+    #[test]
+    fn test_time_zone_conversions() {
+        let calculator = ZoneTransitionCalculator::new();
+        let denver = TimeZone(tinystr!(8, "usden"));
+
+        let zoned_date_time = ZonedDateTime::from_epoch_milliseconds_and_time_zone(
+            1_720_000_000_000,
+            denver,
+            &calculator,
+        )
+        .unwrap();
+        assert_eq!(zoned_date_time.date, Date::try_new_iso(2024, 7, 3).unwrap());
+        assert_eq!(zoned_date_time.time, Time::try_new(3, 46, 40, 0).unwrap());
+        assert_eq!(
+            zoned_date_time.zone.offset(),
+            Some(UtcOffset::try_from_seconds(-6 * 3600).unwrap())
+        );
+        let round_trip = ZonedDateTime::try_from_local_time_and_time_zone(
+            zoned_date_time.date,
+            Time::try_new(3, 46, 40, 123_456_789).unwrap(),
+            denver,
+            &calculator,
+            Disambiguation::Reject,
+        )
+        .unwrap();
+        assert_eq!(round_trip.to_epoch_milliseconds(), Some(1_720_000_000_123));
+        assert_eq!(round_trip.time.subsecond.number(), 123_456_789);
+
+        // 01:30 is repeated when daylight saving time ends
+        let convert = |date, time, disambiguation| {
+            ZonedDateTime::try_from_local_time_and_time_zone(
+                date,
+                time,
+                denver,
+                &calculator,
+                disambiguation,
+            )
+            .map(|zoned_date_time| {
+                (
+                    zoned_date_time.time,
+                    zoned_date_time.to_epoch_milliseconds(),
+                )
+            })
+        };
+        let date = Date::try_new_iso(2024, 11, 3).unwrap();
+        let time = Time::try_new(1, 30, 0, 0).unwrap();
+        assert_eq!(
+            convert(date, time, Disambiguation::Earlier),
+            Ok((time, Some(1_730_619_000_000)))
+        );
+        assert_eq!(
+            convert(date, time, Disambiguation::Later),
+            Ok((time, Some(1_730_622_600_000)))
+        );
+        assert_eq!(
+            convert(date, time, Disambiguation::Reject),
+            Err(LocalTimeError::Repeated)
+        );
+
+        // 02:30 is skipped when daylight saving time starts
+        let date = Date::try_new_iso(2024, 3, 10).unwrap();
+        let time = Time::try_new(2, 30, 0, 0).unwrap();
+        assert_eq!(
+            convert(date, time, Disambiguation::Earlier),
+            Ok((Time::try_new(1, 30, 0, 0).unwrap(), Some(1_710_059_400_000)))
+        );
+        assert_eq!(
+            convert(date, time, Disambiguation::Later),
+            Ok((Time::try_new(3, 30, 0, 0).unwrap(), Some(1_710_063_000_000)))
+        );
+        assert_eq!(
+            convert(date, time, Disambiguation::Reject),
+            Err(LocalTimeError::Skipped)
+        );
+
+        assert_eq!(
+            ZonedDateTime::from_epoch_milliseconds_and_time_zone(
+                1_720_000_000_000,
+                TimeZone::unknown(),
+                &calculator
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_close_transitions() {
+        // On 1992-09-27, Europe/Lisbon moved from +01:00 to +02:00 at 01:00 local time, and
+        // back to +01:00 an hour later
+        let calculator = ZoneTransitionCalculator::new();
+        let convert = |time, disambiguation| {
+            ZonedDateTime::try_from_local_time_and_time_zone(
+                Date::try_new_iso(1992, 9, 27).unwrap(),
+                time,
+                TimeZone(tinystr!(8, "ptlis")),
+                &calculator,
+                disambiguation,
+            )
+            .map(|zoned_date_time| {
+                (
+                    zoned_date_time.time,
+                    zoned_date_time.to_epoch_milliseconds(),
+                )
+            })
+        };
+
+        // 01:30 is skipped
+        let time = Time::try_new(1, 30, 0, 0).unwrap();
+        assert_eq!(
+            convert(time, Disambiguation::Earlier),
+            Ok((Time::try_new(0, 30, 0, 0).unwrap(), Some(717_550_200_000)))
+        );
+        assert_eq!(
+            convert(time, Disambiguation::Later),
+            Ok((Time::try_new(2, 30, 0, 0).unwrap(), Some(717_553_800_000)))
+        );
+        assert_eq!(
+            convert(time, Disambiguation::Reject),
+            Err(LocalTimeError::Skipped)
+        );
+
+        // 02:30 is repeated
+        let time = Time::try_new(2, 30, 0, 0).unwrap();
+        assert_eq!(
+            convert(time, Disambiguation::Earlier),
+            Ok((time, Some(717_553_800_000)))
+        );
+        assert_eq!(
+            convert(time, Disambiguation::Later),
+            Ok((time, Some(717_557_400_000)))
+        );
+        assert_eq!(
+            convert(time, Disambiguation::Reject),
+            Err(LocalTimeError::Repeated)
+        );
+
+        // 03:30 is unique
+        assert_eq!(
+            convert(Time::try_new(3, 30, 0, 0).unwrap(), Disambiguation::Reject),
+            Ok((Time::try_new(3, 30, 0, 0).unwrap(), Some(717_561_000_000)))
+        );
+    }
+}
@@ -20,6 +20,7 @@ include!("weekday_names_v1.rs.data");
 include!("dangi_month_names_v1.rs.data");
 include!("buddhist_date_neo_skeleton_patterns_v1.rs.data");
 include!("glue_pattern_v1.rs.data");
+include!("date_time_interval_patterns_v1.rs.data");
 include!("metazone_period_v1.rs.data");
 include!("japanese_date_neo_skeleton_patterns_v1.rs.data");
 include!("buddhist_month_names_v1.rs.data");
@@ -102,6 +103,7 @@ macro_rules! impl_data_provider {
         impl_dangi_month_names_v1!($provider);
         impl_buddhist_date_neo_skeleton_patterns_v1!($provider);
         impl_glue_pattern_v1!($provider);
+        impl_date_time_interval_patterns_v1!($provider);
         impl_metazone_period_v1!($provider);
         impl_japanese_date_neo_skeleton_patterns_v1!($provider);
         impl_buddhist_month_names_v1!($provider);
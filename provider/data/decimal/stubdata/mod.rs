@@ -1,6 +1,7 @@
 // @generated
 include!("decimal_symbols_v2.rs.data");
 include!("decimal_digits_v1.rs.data");
+include!("decimal_exponent_symbols_v1.rs.data");
 /// Marks a type as a data provider. You can then use macros like
 /// `impl_core_helloworld_v1` to add implementations.
 ///
@@ -32,5 +33,6 @@ macro_rules! impl_data_provider {
         make_provider!($provider);
         impl_decimal_symbols_v2!($provider);
         impl_decimal_digits_v1!($provider);
+        impl_decimal_exponent_symbols_v1!($provider);
     };
 }
@@ -42,6 +42,7 @@ include!("script_display_names_v1.rs.data");
 include!("narrow_minute_relative_v1.rs.data");
 include!("digital_duration_data_v1.rs.data");
 include!("units_info_v1.rs.data");
+include!("units_preferences_v1.rs.data");
 include!("narrow_year_relative_v1.rs.data");
 /// Marks a type as a data provider. You can then use macros like
 /// `impl_core_helloworld_v1` to add implementations.
@@ -115,6 +116,7 @@ macro_rules! impl_data_provider {
         impl_narrow_minute_relative_v1!($provider);
         impl_digital_duration_data_v1!($provider);
         impl_units_info_v1!($provider);
+        impl_units_preferences_v1!($provider);
         impl_narrow_year_relative_v1!($provider);
     };
 }
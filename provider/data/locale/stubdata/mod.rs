@@ -8,6 +8,7 @@ include!("exemplar_characters_auxiliary_v1.rs.data");
 include!("aliases_v2.rs.data");
 include!("exemplar_characters_index_v1.rs.data");
 include!("likely_subtags_extended_v1.rs.data");
+include!("language_matching_v1.rs.data");
 include!("parents_v1.rs.data");
 include!("exemplar_characters_numbers_v1.rs.data");
 /// Marks a type as a data provider. You can then use macros like
@@ -48,6 +49,7 @@ macro_rules! impl_data_provider {
         impl_aliases_v2!($provider);
         impl_exemplar_characters_index_v1!($provider);
         impl_likely_subtags_extended_v1!($provider);
+        impl_language_matching_v1!($provider);
         impl_parents_v1!($provider);
         impl_exemplar_characters_numbers_v1!($provider);
     };
@@ -1,5 +1,6 @@
 // @generated
 include!("zone_offset_period_v1.rs.data");
+include!("zone_transitions_v1.rs.data");
 include!("windows_zones_to_bcp47_map_v1.rs.data");
 include!("iana_to_bcp47_map_v3.rs.data");
 include!("bcp47_to_iana_map_v1.rs.data");
@@ -33,6 +34,7 @@ macro_rules! impl_data_provider {
     ($ provider : ty) => {
         make_provider!($provider);
         impl_zone_offset_period_v1!($provider);
+        impl_zone_transitions_v1!($provider);
         impl_windows_zones_to_bcp47_map_v1!($provider);
         impl_iana_to_bcp47_map_v3!($provider);
         impl_bcp47_to_iana_map_v1!($provider);
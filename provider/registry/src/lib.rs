@@ -48,8 +48,10 @@ macro_rules! registry(
             icu::datetime::provider::time_zones::MetazoneSpecificNamesShortV1: MetazoneSpecificNamesShortV1,
             icu::datetime::provider::time_zones::TimeZoneEssentialsV1: TimeZoneEssentialsV1,
             icu::time::provider::ZoneOffsetPeriodV1: ZoneOffsetPeriodV1,
+            icu::time::provider::ZoneTransitionsV1: ZoneTransitionsV1,
             icu::decimal::provider::DecimalDigitsV1: DecimalDigitsV1,
             icu::decimal::provider::DecimalSymbolsV2: DecimalSymbolsV2,
+            icu::decimal::provider::DecimalExponentSymbolsV1: DecimalExponentSymbolsV1,
             icu::list::provider::ListAndV2: ListAndV2,
             icu::list::provider::ListOrV2: ListOrV2,
             icu::list::provider::ListUnitV2: ListUnitV2,
@@ -59,6 +61,7 @@ macro_rules! registry(
             icu::locale::provider::ExemplarCharactersMainV1: ExemplarCharactersMainV1,
             icu::locale::provider::ExemplarCharactersNumbersV1: ExemplarCharactersNumbersV1,
             icu::locale::provider::ExemplarCharactersPunctuationV1: ExemplarCharactersPunctuationV1,
+            icu::locale::provider::LanguageMatchingV1: LanguageMatchingV1,
             icu::locale::provider::LikelySubtagsExtendedV1: LikelySubtagsExtendedV1,
             icu::locale::provider::LikelySubtagsForLanguageV1: LikelySubtagsForLanguageV1,
             icu::locale::provider::LikelySubtagsForScriptRegionV1: LikelySubtagsForScriptRegionV1,
@@ -205,6 +208,7 @@ macro_rules! registry(
             icu::datetime::provider::neo::WeekdayNamesV1: WeekdayNamesV1,
             icu::datetime::provider::neo::DayPeriodNamesV1: DayPeriodNamesV1,
             icu::datetime::provider::neo::GluePatternV1: GluePatternV1,
+            icu::datetime::provider::neo::DateTimeIntervalPatternsV1: DateTimeIntervalPatternsV1,
             icu::datetime::provider::neo::BuddhistYearNamesV1: BuddhistYearNamesV1,
             icu::datetime::provider::neo::ChineseYearNamesV1: ChineseYearNamesV1,
             icu::datetime::provider::neo::CopticYearNamesV1: CopticYearNamesV1,
@@ -290,6 +294,7 @@ macro_rules! registry(
             icu::experimental::personnames::provider::PersonNamesFormatV1: PersonNamesFormatV1,
             icu::experimental::transliterate::provider::TransliteratorRulesV1: TransliteratorRulesV1,
             icu::experimental::units::provider::UnitsInfoV1: UnitsInfoV1,
+            icu::experimental::units::provider::UnitsPreferencesV1: UnitsPreferencesV1,
             icu::plurals::provider::PluralRangesV1: PluralRangesV1,
         );
     }
@@ -0,0 +1,78 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON languageMatching.json and territoryContainment.json files.
+//!
+//! Sample files:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/languageMatching.json>
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/territoryContainment.json>
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct ParadigmLocales {
+    /// A space-separated list of locales.
+    #[serde(rename = "_locales")]
+    pub(crate) locales: String,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct LanguageMatch {
+    #[serde(rename = "_desired")]
+    pub(crate) desired: String,
+    #[serde(rename = "_supported")]
+    pub(crate) supported: String,
+    #[serde(rename = "_distance")]
+    pub(crate) distance: String,
+    #[serde(rename = "_oneway", default)]
+    pub(crate) oneway: Option<String>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) enum LanguageMatchingItem {
+    #[serde(rename = "paradigmLocales")]
+    ParadigmLocales(ParadigmLocales),
+    /// Maps from a variable name (e.g. `$enUS`) to a `+`-separated list of regions.
+    #[serde(rename = "matchVariable")]
+    MatchVariable(BTreeMap<String, String>),
+    #[serde(rename = "languageMatch")]
+    LanguageMatch(LanguageMatch),
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct LanguageMatching {
+    #[serde(rename = "written_new")]
+    pub(crate) written_new: Vec<LanguageMatchingItem>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct LanguageMatchingSupplemental {
+    #[serde(rename = "languageMatching")]
+    pub(crate) language_matching: LanguageMatching,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct LanguageMatchingResource {
+    pub(crate) supplemental: LanguageMatchingSupplemental,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct Containment {
+    #[serde(rename = "_contains", default)]
+    pub(crate) contains: Vec<String>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct ContainmentSupplemental {
+    /// Maps from a region, optionally with a status suffix (e.g. `419-status-grouping`),
+    /// to the regions it contains.
+    #[serde(rename = "territoryContainment")]
+    pub(crate) territory_containment: BTreeMap<String, Containment>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct ContainmentResource {
+    pub(crate) supplemental: ContainmentSupplemental,
+}
@@ -19,6 +19,7 @@ pub(crate) mod directionality;
 pub(crate) mod displaynames;
 pub(crate) mod exemplar_chars;
 pub(crate) mod japanese;
+pub(crate) mod language_matching;
 pub(crate) mod likely_subtags;
 pub(crate) mod list_patterns;
 pub(crate) mod locale_resource;
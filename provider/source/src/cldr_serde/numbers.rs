@@ -19,6 +19,7 @@ pub(crate) struct Symbols {
     #[serde(rename = "approximatelySign")]
     pub(crate) approximately_sign: String,
     pub(crate) decimal: String,
+    pub(crate) exponential: String,
     pub(crate) group: String,
     #[serde(rename = "minusSign")]
     pub(crate) minus_sign: String,
@@ -26,6 +27,8 @@ pub(crate) struct Symbols {
     pub(crate) plus_sign: String,
     #[serde(rename = "percentSign")]
     pub(crate) percent_sign: String,
+    #[serde(rename = "superscriptingExponent")]
+    pub(crate) superscripting_exponent: String,
 }
 
 #[derive(PartialEq, Debug, Deserialize)]
@@ -41,6 +41,9 @@ pub(crate) struct Patterns {
     #[serde(rename = "unitPattern-count-other")]
     pub(crate) other: Option<PatternString<SinglePlaceholder>>,
 
+    #[serde(rename = "perUnitPattern")]
+    pub(crate) per_unit: Option<PatternString<SinglePlaceholder>>,
+
     #[serde(rename = "compoundUnitPattern")]
     pub(crate) compound_unit_pattern: Option<String>,
 
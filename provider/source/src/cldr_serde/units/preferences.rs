@@ -0,0 +1,38 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON unitPreferenceData.json file.
+//!
+//! The file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/unitPreferenceData.json>
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct UnitPreference {
+    pub(crate) unit: String,
+
+    pub(crate) geq: Option<String>,
+
+    pub(crate) skeleton: Option<String>,
+}
+
+/// Maps from a space-separated list of regions to the preferences for these regions.
+pub(crate) type RegionPreferences = BTreeMap<String, Vec<UnitPreference>>;
+
+/// Maps from a usage (e.g. `person-height`) to the preferences for each region.
+pub(crate) type UsagePreferences = BTreeMap<String, RegionPreferences>;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct Supplemental {
+    /// Maps from a category (e.g. `length`) to the preferences for each usage.
+    #[serde(rename = "unitPreferenceData")]
+    pub(crate) unit_preference_data: BTreeMap<String, UsagePreferences>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct Resource {
+    pub(crate) supplemental: Supplemental,
+}
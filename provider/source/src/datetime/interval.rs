@@ -0,0 +1,176 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::IterableDataProviderCached;
+use crate::SourceDataProvider;
+use either::Either;
+use icu::datetime::provider::calendar::{DateSkeletonPatterns, SkeletonData};
+use icu::datetime::provider::neo::*;
+use icu::datetime::provider::pattern::runtime;
+use icu::datetime::provider::skeleton::{
+    get_best_available_format_pattern, BestSkeleton, PatternPlurals,
+};
+use icu_pattern::DoublePlaceholderPattern;
+use icu_provider::prelude::*;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashSet};
+use zerovec::ule::encode_varule_to_box;
+
+impl DataProvider<DateTimeIntervalPatternsV1> for SourceDataProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<DateTimeIntervalPatternsV1>, DataError> {
+        self.check_req::<DateTimeIntervalPatternsV1>(req)?;
+
+        // Interval formats are keyed by skeleton, which makes them applicable to all
+        // calendars; use the Gregorian ones, which are the most complete.
+        let data = self.get_datetime_resources(req.id.locale, Either::Right("gregorian"))?;
+        let interval_formats =
+            data.datetime_formats
+                .interval_formats
+                .as_ref()
+                .ok_or_else(|| {
+                    DataError::custom("Missing intervalFormats")
+                        .with_req(DateTimeIntervalPatternsV1::INFO, req)
+                })?;
+
+        let fallback =
+            DoublePlaceholderPattern::try_from_str(&interval_formats.fallback, Default::default())
+                .map_err(|e| {
+                    DataError::custom("Could not parse intervalFormatFallback")
+                        .with_display_context(&e)
+                })?;
+
+        let mut patterns = BTreeMap::new();
+        let mut skeletons = BTreeMap::<char, DateSkeletonPatterns>::new();
+        for (skeleton, by_difference) in &interval_formats.patterns {
+            // Flexible day periods are not supported by the pattern parser
+            if skeleton.contains('B') {
+                continue;
+            }
+            for (difference, pattern) in by_difference {
+                let mut chars = difference.chars();
+                let (Some(difference), None) = (chars.next(), chars.next()) else {
+                    return Err(DataError::custom("Invalid greatest difference")
+                        .with_display_context(difference));
+                };
+                let pattern = pattern.parse::<runtime::Pattern>().map_err(|e| {
+                    DataError::custom("Could not parse interval pattern").with_display_context(&e)
+                })?;
+                if let Ok(parsed) = SkeletonData::try_from(skeleton.as_str()) {
+                    skeletons
+                        .entry(difference)
+                        .or_default()
+                        .0
+                        .insert(parsed, PatternPlurals::SinglePattern(pattern.clone()));
+                }
+                patterns.insert((skeleton.clone(), difference), pattern);
+            }
+        }
+
+        // CLDR has few interval patterns for wide months. Match the wide month skeletons
+        // against the available ones, which adjusts the width of the month in the pattern.
+        for (skeleton, difference) in patterns.keys().cloned().collect::<Vec<_>>() {
+            let Some(wide_skeleton) = wide_month_skeleton(&skeleton) else {
+                continue;
+            };
+            if patterns.contains_key(&(wide_skeleton.clone(), difference)) {
+                continue;
+            }
+            let Some(available) = skeletons.get(&difference) else {
+                continue;
+            };
+            let Ok(SkeletonData(fields)) = SkeletonData::try_from(wide_skeleton.as_str()) else {
+                continue;
+            };
+            if let BestSkeleton::AllFieldsMatch(pattern) =
+                get_best_available_format_pattern(available, fields.as_slice(), false)
+            {
+                patterns.insert(
+                    (wide_skeleton, difference),
+                    pattern.expect_pattern("interval patterns do not have plural variants"),
+                );
+            }
+        }
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(DateTimeIntervalPatterns {
+                fallback: Cow::Owned(fallback),
+                patterns: patterns
+                    .iter()
+                    .map(|((skeleton, difference), pattern)| {
+                        (skeleton.as_str(), difference, encode_varule_to_box(pattern))
+                    })
+                    .collect(),
+            }),
+        })
+    }
+}
+
+/// Returns the skeleton with the abbreviated month widened, e.g. `yMMMMd` for `yMMMd`.
+fn wide_month_skeleton(skeleton: &str) -> Option<String> {
+    let start = skeleton.find('M')?;
+    let len = skeleton[start..]
+        .find(|c| c != 'M')
+        .unwrap_or(skeleton.len() - start);
+    (len == 3).then(|| format!("{}M{}", &skeleton[..start + len], &skeleton[start + len..]))
+}
+
+impl IterableDataProviderCached<DateTimeIntervalPatternsV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        Ok(self
+            .cldr()?
+            .dates("gregorian")
+            .list_locales()?
+            .map(DataIdentifierCow::from_locale)
+            .collect())
+    }
+}
+
+#[test]
+fn test_interval_patterns() {
+    use icu::locale::langid;
+    use zerofrom::ZeroFrom;
+
+    let provider = SourceDataProvider::new_testing();
+
+    let data: DataResponse<DateTimeIntervalPatternsV1> = provider
+        .load(DataRequest {
+            id: DataIdentifierBorrowed::for_locale(&langid!("en").into()),
+            ..Default::default()
+        })
+        .unwrap();
+
+    assert_eq!(
+        writeable::Writeable::write_to_string(&data.payload.get().fallback.interpolate(("A", "B"))),
+        "A\u{2009}–\u{2009}B"
+    );
+    let pattern = data
+        .payload
+        .get()
+        .patterns
+        .get_2d("yMMMd", &'d')
+        .map(runtime::Pattern::zero_from);
+    assert_eq!(
+        pattern.map(|p| p.to_string()).as_deref(),
+        Some("MMM d\u{2009}–\u{2009}d, y")
+    );
+
+    // Matched against `yMMMd`
+    let pattern = data
+        .payload
+        .get()
+        .patterns
+        .get_2d("yMMMMd", &'d')
+        .map(runtime::Pattern::zero_from);
+    assert_eq!(
+        pattern.map(|p| p.to_string()).as_deref(),
+        Some("MMMM d\u{2009}–\u{2009}d, y")
+    );
+    assert_eq!(wide_month_skeleton("yMMMEd").as_deref(), Some("yMMMMEd"));
+    assert_eq!(wide_month_skeleton("yMMMM"), None);
+    assert_eq!(wide_month_skeleton("yMd"), None);
+}
@@ -14,6 +14,7 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::OnceLock;
 
+mod interval;
 mod neo;
 mod neo_skeleton;
 mod patterns;
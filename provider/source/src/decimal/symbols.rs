@@ -47,6 +47,45 @@ impl IterableDataProviderCached<DecimalSymbolsV2> for SourceDataProvider {
     }
 }
 
+impl DataProvider<DecimalExponentSymbolsV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<DecimalExponentSymbolsV1>, DataError> {
+        self.check_req::<DecimalExponentSymbolsV1>(req)?;
+
+        let resource: &cldr_serde::numbers::Resource = self
+            .cldr()?
+            .numbers()
+            .read_and_parse(req.id.locale, "numbers.json")?;
+
+        let numbers = &resource.main.value.numbers;
+
+        let nsname = if !req.id.marker_attributes.is_empty() {
+            req.id.marker_attributes.as_str()
+        } else {
+            &numbers.default_numbering_system
+        };
+
+        let symbols = numbers.numsys_data.symbols.get(nsname).ok_or_else(|| {
+            DataError::custom("Could not find symbols for numbering system")
+                .with_display_context(nsname)
+        })?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(DecimalExponentSymbols {
+                exponential: Cow::Owned(symbols.exponential.clone()),
+                superscripting_exponent: Cow::Owned(symbols.superscripting_exponent.clone()),
+                minus_sign: Cow::Owned(symbols.minus_sign.clone()),
+            }),
+        })
+    }
+}
+
+impl IterableDataProviderCached<DecimalExponentSymbolsV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        self.iter_ids_for_numbers_with_locales()
+    }
+}
+
 #[derive(Debug)]
 struct NumbersWithNumsys<'a>(
     pub(crate) &'a cldr_serde::numbers::Numbers,
@@ -115,3 +154,30 @@ fn test_basic() {
     assert_eq!(ar_decimal.payload.get().decimal_separator(), "٫");
     assert_eq!(ar_decimal.payload.get().numsys(), "arab");
 }
+
+#[test]
+fn test_exponent_symbols() {
+    use icu::locale::langid;
+
+    let provider = SourceDataProvider::new_testing();
+
+    let ar_exponent: DataResponse<DecimalExponentSymbolsV1> = provider
+        .load(DataRequest {
+            id: DataIdentifierCow::from_locale(langid!("ar-EG").into()).as_borrowed(),
+            ..Default::default()
+        })
+        .unwrap();
+    assert_eq!(ar_exponent.payload.get().exponential, "أس");
+    assert_eq!(ar_exponent.payload.get().superscripting_exponent, "×");
+
+    let ar_latn_exponent: DataResponse<DecimalExponentSymbolsV1> = provider
+        .load(DataRequest {
+            id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+                DataMarkerAttributes::from_str_or_panic("latn"),
+                &langid!("ar-EG").into(),
+            ),
+            ..Default::default()
+        })
+        .unwrap();
+    assert_eq!(ar_latn_exponent.payload.get().exponential, "E");
+}
@@ -0,0 +1,244 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::cldr_serde;
+use crate::cldr_serde::language_matching::LanguageMatchingItem;
+use crate::SourceDataProvider;
+
+use icu::locale::provider::*;
+use icu::locale::subtags::{Language, Region, Script};
+use icu::locale::LanguageIdentifier;
+use icu_provider::prelude::*;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use zerovec::{VarZeroVec, ZeroVec};
+
+impl DataProvider<LanguageMatchingV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<LanguageMatchingV1>, DataError> {
+        self.check_req::<LanguageMatchingV1>(req)?;
+        let matching: &cldr_serde::language_matching::LanguageMatchingResource = self
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/languageMatching.json")?;
+        let containment: &cldr_serde::language_matching::ContainmentResource = self
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/territoryContainment.json")?;
+
+        let containment = containment_map(containment)?;
+
+        let mut paradigm_locales = Vec::new();
+        let mut region_set_names = Vec::<&str>::new();
+        let mut region_sets = Vec::<Vec<Region>>::new();
+        let mut language_rules = Vec::new();
+        let mut script_rules = Vec::new();
+        let mut region_rules = Vec::new();
+
+        for item in &matching.supplemental.language_matching.written_new {
+            match item {
+                LanguageMatchingItem::ParadigmLocales(locales) => {
+                    for locale in locales.locales.split_whitespace() {
+                        let mut langid = locale.parse::<LanguageIdentifier>().map_err(|_| {
+                            DataError::custom("invalid paradigm locale")
+                                .with_display_context(locale)
+                        })?;
+                        self.cldr()?
+                            .extended_locale_expander()?
+                            .maximize(&mut langid);
+                        let (Some(script), Some(region)) = (langid.script, langid.region) else {
+                            return Err(DataError::custom("paradigm locale cannot be maximized")
+                                .with_display_context(locale));
+                        };
+                        paradigm_locales.push((langid.language, script, region));
+                    }
+                }
+                LanguageMatchingItem::MatchVariable(variables) => {
+                    for (name, value) in variables {
+                        let mut regions = BTreeSet::new();
+                        for region in value.split('+') {
+                            add_contained_regions(region, &containment, &mut regions)?;
+                        }
+                        region_set_names.push(name.trim_start_matches('$'));
+                        region_sets.push(regions.into_iter().collect());
+                    }
+                }
+                LanguageMatchingItem::LanguageMatch(rule) => {
+                    let desired = parse_pattern(&rule.desired, &region_set_names)?;
+                    let supported = parse_pattern(&rule.supported, &region_set_names)?;
+                    if desired.0 != supported.0 {
+                        return Err(DataError::custom("mismatched language match levels")
+                            .with_display_context(&rule.desired));
+                    }
+                    let rule_struct = LanguageMatchRule {
+                        desired: desired.1,
+                        supported: supported.1,
+                        distance: rule.distance.parse().map_err(|_| {
+                            DataError::custom("invalid language match distance")
+                                .with_display_context(&rule.distance)
+                        })?,
+                        oneway: rule.oneway.as_deref() == Some("true"),
+                    };
+                    match desired.0 {
+                        1 => language_rules.push(rule_struct),
+                        2 => script_rules.push(rule_struct),
+                        _ => region_rules.push(rule_struct),
+                    }
+                }
+            }
+        }
+
+        let data = LanguageMatching {
+            paradigm_locales: ZeroVec::alloc_from_slice(&paradigm_locales),
+            region_sets: VarZeroVec::from(
+                &region_sets
+                    .iter()
+                    .map(|regions| ZeroVec::alloc_from_slice(regions))
+                    .collect::<Vec<_>>(),
+            ),
+            language_rules: ZeroVec::alloc_from_slice(&language_rules),
+            script_rules: ZeroVec::alloc_from_slice(&script_rules),
+            region_rules: ZeroVec::alloc_from_slice(&region_rules),
+        };
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(data),
+        })
+    }
+}
+
+impl crate::IterableDataProviderCached<LanguageMatchingV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        Ok(HashSet::from_iter([Default::default()]))
+    }
+}
+
+/// Returns a map from each macro region to the regions it directly contains, including
+/// groupings such as `419`, and excluding deprecated containment.
+fn containment_map(
+    resource: &cldr_serde::language_matching::ContainmentResource,
+) -> Result<BTreeMap<Region, Vec<Region>>, DataError> {
+    let mut map = BTreeMap::<Region, Vec<Region>>::new();
+    for (key, containment) in &resource.supplemental.territory_containment {
+        let parent = match key.split_once("-status-") {
+            None => key.as_str(),
+            Some((parent, "grouping")) => parent,
+            Some(_) => continue,
+        };
+        let Ok(parent) = parent.parse::<Region>() else {
+            // Non-region groupings such as `EU`.
+            continue;
+        };
+        for child in &containment.contains {
+            let child = child.parse::<Region>().map_err(|_| {
+                DataError::custom("invalid contained region").with_display_context(child)
+            })?;
+            map.entry(parent).or_default().push(child);
+        }
+    }
+    Ok(map)
+}
+
+/// Adds `region` and all the regions it contains, recursively, to `regions`.
+fn add_contained_regions(
+    region: &str,
+    containment: &BTreeMap<Region, Vec<Region>>,
+    regions: &mut BTreeSet<Region>,
+) -> Result<(), DataError> {
+    let region = region
+        .parse::<Region>()
+        .map_err(|_| DataError::custom("invalid match variable").with_display_context(region))?;
+    let mut stack = vec![region];
+    while let Some(region) = stack.pop() {
+        if regions.insert(region) {
+            stack.extend(containment.get(&region).into_iter().flatten().copied());
+        }
+    }
+    Ok(())
+}
+
+/// Parses a pattern such as `en_*_$!enUS`, returning its number of subtags.
+fn parse_pattern(
+    pattern: &str,
+    region_set_names: &[&str],
+) -> Result<(usize, LanguageMatchPattern), DataError> {
+    let invalid =
+        || DataError::custom("invalid language match pattern").with_display_context(pattern);
+    let subtags = pattern.split('_').collect::<Vec<_>>();
+    let mut result = LanguageMatchPattern {
+        language: None,
+        script: None,
+        region: None,
+        region_set: None,
+    };
+    if let Some(&language) = subtags.first().filter(|&&s| s != "*") {
+        result.language = Some(language.parse::<Language>().map_err(|_| invalid())?);
+    }
+    if let Some(&script) = subtags.get(1).filter(|&&s| s != "*") {
+        result.script = Some(script.parse::<Script>().map_err(|_| invalid())?);
+    }
+    match subtags.get(2) {
+        None | Some(&"*") => (),
+        Some(region) => {
+            if let Some(name) = region.strip_prefix('$') {
+                let (name, negated) = match name.strip_prefix('!') {
+                    Some(name) => (name, true),
+                    None => (name, false),
+                };
+                let index = region_set_names
+                    .iter()
+                    .position(|&n| n == name)
+                    .ok_or_else(invalid)?;
+                result.region_set = Some((u8::try_from(index).map_err(|_| invalid())?, negated));
+            } else {
+                result.region = Some(region.parse::<Region>().map_err(|_| invalid())?);
+            }
+        }
+    }
+    if subtags.is_empty() || subtags.len() > 3 {
+        return Err(invalid());
+    }
+    Ok((subtags.len(), result))
+}
+
+#[test]
+fn test_basic() {
+    use icu::locale::subtags::{language, region, script};
+
+    let provider = SourceDataProvider::new_testing();
+
+    let data: DataResponse<LanguageMatchingV1> = provider.load(Default::default()).unwrap();
+    let data = data.payload.get();
+
+    assert!(data
+        .paradigm_locales
+        .iter()
+        .any(|l| l == (language!("es"), script!("Latn"), region!("419"))));
+
+    // `$americas` contains the Latin American grouping and its countries.
+    let americas = data
+        .region_sets
+        .iter()
+        .find(|set| set.iter().any(|r| r == region!("019")))
+        .unwrap();
+    assert!(americas.iter().any(|r| r == region!("419")));
+    assert!(americas.iter().any(|r| r == region!("MX")));
+    assert!(!americas.iter().any(|r| r == region!("ES")));
+
+    let nb_no = data.language_rules.get(0).unwrap();
+    assert_eq!(nb_no.distance, 1);
+    assert_eq!(
+        data.language_rules.last().unwrap().desired,
+        LanguageMatchPattern {
+            language: None,
+            script: None,
+            region: None,
+            region_set: None
+        }
+    );
+    assert_eq!(data.script_rules.last().unwrap().distance, 50);
+    assert!(data
+        .region_rules
+        .iter()
+        .any(|rule| rule.supported.region == Some(region!("GB")) && rule.distance == 3));
+}
@@ -71,8 +71,9 @@ fn make_testdata() {
     ])
     .with_marker_attributes_filter("units", |attrs| {
         let (_length, unit) = attrs.as_str().split_once('-').unwrap();
+        // Keep the `perUnitPattern`s of the units, e.g. `per-hour`.
         matches!(
-            unit,
+            unit.strip_prefix("per-").unwrap_or(unit),
             "meter" | "foot" | "kilogram" | "pound" | "hour" | "minute" | "second"
         )
     })
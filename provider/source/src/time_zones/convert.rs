@@ -17,8 +17,9 @@ use icu::time::zone::TimeZoneVariant;
 use icu::time::zone::UtcOffset;
 use icu::time::Time;
 use icu_provider::prelude::*;
-use parse_zoneinfo::line::Year;
+use parse_zoneinfo::line::{ChangeTime, DaySpec, TimeType, Weekday, Year};
 use parse_zoneinfo::table::Saving;
+use parse_zoneinfo::transitions::TableTransitions;
 use std::borrow::Cow;
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
@@ -483,6 +484,77 @@ impl SourceDataProvider {
             .map_err(|&e| e)
     }
 
+    fn zone_transitions(&self) -> Result<&ZoneTransitions<'static>, DataError> {
+        let tzdb = self.tzdb()?.transitions()?;
+
+        self.cldr()?
+            .tz_caches
+            .transitions
+            .get_or_init(|| {
+                let mut zones = BTreeMap::<TimeZone, (u32, u32)>::new();
+                let mut transitions = Vec::<(i64, i32, TimeZoneVariant)>::new();
+                let mut ranges = BTreeMap::<Vec<(i64, i32, TimeZoneVariant)>, (u32, u32)>::new();
+                let mut rules = BTreeMap::<TimeZone, ZoneTransitionRule>::new();
+
+                for (&bcp47, iana) in self.bcp47_to_canonical_iana_map()? {
+                    let (Some(zoneset), Some(timespans)) =
+                        (tzdb.get_zoneset(iana), tzdb.timespans(iana))
+                    else {
+                        continue;
+                    };
+
+                    let mut zone_transitions = core::iter::once((i64::MIN, &timespans.first))
+                        .chain(timespans.rest.iter().map(|(t, span)| (*t, span)))
+                        .map(|(t, span)| {
+                            (
+                                t,
+                                (span.utc_offset + span.dst_offset) as i32,
+                                if span.dst_offset != 0 {
+                                    TimeZoneVariant::Daylight
+                                } else {
+                                    TimeZoneVariant::Standard
+                                },
+                            )
+                        })
+                        .collect::<Vec<_>>();
+                    // Only keep the history since 1970, like the offset periods. The offset in
+                    // effect at the start of 1970 becomes the first transition.
+                    let since_1970 = zone_transitions.partition_point(|&(t, ..)| t <= 0);
+                    zone_transitions.drain(..since_1970.saturating_sub(1));
+                    if let Some((t, ..)) = zone_transitions.first_mut() {
+                        *t = i64::MIN;
+                    }
+                    zone_transitions
+                        .dedup_by_key(|&mut (_, offset, zone_variant)| (offset, zone_variant));
+
+                    if let Some((rule, from_year)) = tail_rule(tzdb, zoneset) {
+                        // Keep the transitions of the first year in which the rule fully applies,
+                        // all later transitions are computed from the rule.
+                        let cutoff =
+                            ChangeTime::UntilYear(Year::Number(from_year + 1)).to_timestamp();
+                        zone_transitions.retain(|&(t, ..)| t < cutoff);
+                        rules.insert(bcp47, rule);
+                    }
+
+                    // Time zones with the same transitions share them
+                    let range = *ranges.entry(zone_transitions).or_insert_with_key(|t| {
+                        let start = transitions.len();
+                        transitions.extend_from_slice(t);
+                        (start as u32, transitions.len() as u32)
+                    });
+                    zones.insert(bcp47, range);
+                }
+
+                Ok(ZoneTransitions {
+                    zones: zones.into_iter().collect(),
+                    transitions: transitions.into_iter().collect(),
+                    rules: rules.into_iter().collect(),
+                })
+            })
+            .as_ref()
+            .map_err(|&e| e)
+    }
+
     fn dedupe_group(&self, locale: DataLocale) -> Result<DataLocale, DataError> {
         let mut group = LanguageIdentifier::from((locale.language, locale.script, locale.region));
         self.cldr()?
@@ -633,6 +705,17 @@ impl DataProvider<ZoneOffsetPeriodV1> for SourceDataProvider {
     }
 }
 
+impl DataProvider<ZoneTransitionsV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<ZoneTransitionsV1>, DataError> {
+        self.check_req::<ZoneTransitionsV1>(req)?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(self.zone_transitions()?.clone()),
+        })
+    }
+}
+
 impl DataProvider<MetazoneGenericNamesLongV1> for SourceDataProvider {
     fn load(
         &self,
@@ -925,3 +1008,84 @@ fn zone_variant_convert(zone_format: &ZoneFormat) -> impl Iterator<Item = (TimeZ
             ))
         })
 }
+
+/// Returns the rule that the last zone line of a zone ends in, if it observes daylight saving
+/// time indefinitely, together with the first year in which the rule fully applies.
+fn tail_rule(
+    tzdb: &parse_zoneinfo::table::Table,
+    zoneset: &[parse_zoneinfo::table::ZoneInfo],
+) -> Option<(ZoneTransitionRule, i64)> {
+    let (last, previous) = zoneset.split_last()?;
+    let Saving::Multiple(ref name) = last.saving else {
+        return None;
+    };
+    let [start, end] = {
+        let mut ongoing = tzdb.rulesets[name]
+            .iter()
+            .filter(|rule| rule.to_year == Some(Year::Maximum))
+            .collect::<Vec<_>>();
+        ongoing.sort_by_key(|rule| rule.time_to_add == 0);
+        <[_; 2]>::try_from(ongoing).ok()?
+    };
+    if start.time_to_add == 0 || end.time_to_add != 0 {
+        return None;
+    }
+
+    fn transition_date(rule: &parse_zoneinfo::table::RuleInfo) -> TransitionDate {
+        let iso_weekday = |weekday: Weekday| match weekday {
+            Weekday::Monday => 1,
+            Weekday::Tuesday => 2,
+            Weekday::Wednesday => 3,
+            Weekday::Thursday => 4,
+            Weekday::Friday => 5,
+            Weekday::Saturday => 6,
+            Weekday::Sunday => 7,
+        };
+        let (day, weekday, on_or_before) = match rule.day {
+            DaySpec::Ordinal(day) => (day as u8, None, false),
+            DaySpec::Last(weekday) => (31, Some(iso_weekday(weekday)), true),
+            DaySpec::LastOnOrBefore(weekday, day) => (day as u8, Some(iso_weekday(weekday)), true),
+            DaySpec::FirstOnOrAfter(weekday, day) => (day as u8, Some(iso_weekday(weekday)), false),
+        };
+        TransitionDate {
+            month: rule.month as u8,
+            day,
+            weekday,
+            on_or_before,
+            time: rule.time as i32,
+            time_mode: match rule.time_type {
+                TimeType::Wall => TransitionTimeMode::Wall,
+                TimeType::Standard => TransitionTimeMode::Standard,
+                TimeType::UTC => TransitionTimeMode::Utc,
+            },
+        }
+    }
+
+    // Other rules of the set, such as predicted one-off transitions, may still apply
+    // until their last year.
+    let from_year = tzdb.rulesets[name]
+        .iter()
+        .flat_map(|rule| [Some(rule.from_year), rule.to_year])
+        .filter_map(|year| match year {
+            Some(Year::Number(year)) => Some(year),
+            _ => None,
+        })
+        .chain(
+            previous
+                .last()
+                .and_then(|zone_info| zone_info.end_time)
+                .map(|t| t.year()),
+        )
+        .max()?
+        + 1;
+
+    Some((
+        ZoneTransitionRule {
+            standard_offset: last.offset as i32,
+            daylight_offset: (last.offset + start.time_to_add) as i32,
+            start: transition_date(start),
+            end: transition_date(end),
+        },
+        from_year,
+    ))
+}
@@ -30,6 +30,7 @@ pub(crate) struct Caches {
     primary_zones: Cache<BTreeMap<TimeZone, Region>>,
     mz_period: Cache<MetazonePeriod<'static>>,
     offset_period: Cache<ZoneOffsetPeriod<'static>>,
+    transitions: Cache<ZoneTransitions<'static>>,
     reverse_metazones: Cache<BTreeMap<MetazoneId, Vec<TimeZone>>>,
 }
 
@@ -270,6 +271,12 @@ impl IterableDataProviderCached<ZoneOffsetPeriodV1> for SourceDataProvider {
     }
 }
 
+impl IterableDataProviderCached<ZoneTransitionsV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        Ok(HashSet::from_iter([Default::default()]))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use icu::time::zone::TimeZoneVariant;
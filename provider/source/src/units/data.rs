@@ -28,7 +28,7 @@ impl DataProvider<UnitsDisplayNameV1> for SourceDataProvider {
             .read_and_parse(req.id.locale, "units.json")?;
         let units_format_data = &units_format_data.main.value.units;
 
-        let unit_patterns = match length {
+        let length_patterns = match length {
             "long" => &units_format_data.long,
             "short" => &units_format_data.short,
             "narrow" => &units_format_data.narrow,
@@ -37,10 +37,28 @@ impl DataProvider<UnitsDisplayNameV1> for SourceDataProvider {
                     .into_error()
                     .with_debug_context(length))
             }
+        };
+
+        // The `per-{unit}` attributes contain the `perUnitPattern` of the unit, e.g. `{0}/h`.
+        if let Some(per_unit) = unit.strip_prefix("per-") {
+            let per_unit_pattern = length_patterns
+                .units
+                .get(per_unit)
+                .and_then(|patterns| patterns.per_unit.as_deref())
+                .ok_or_else(|| {
+                    DataErrorKind::IdentifierNotFound
+                        .into_error()
+                        .with_debug_context(unit)
+                })?;
+            return Ok(DataResponse {
+                metadata: Default::default(),
+                payload: DataPayload::from_owned(UnitsDisplayName {
+                    patterns: PluralElements::new(per_unit_pattern).into(),
+                }),
+            });
         }
-        .units
-        .get(unit)
-        .ok_or_else(|| {
+
+        let unit_patterns = length_patterns.units.get(unit).ok_or_else(|| {
             DataErrorKind::IdentifierNotFound
                 .into_error()
                 .with_debug_context(length)
@@ -92,18 +110,20 @@ impl crate::IterableDataProviderCached<UnitsDisplayNameV1> for SourceDataProvide
                 };
 
                 for (unit, patterns) in &length_patterns.units {
-                    if patterns.other.is_none() {
-                        continue;
+                    let units = [
+                        patterns.other.as_ref().map(|_| unit.clone()),
+                        patterns.per_unit.as_ref().map(|_| format!("per-{unit}")),
+                    ];
+                    for unit in units.into_iter().flatten() {
+                        data_locales.insert(DataIdentifierCow::from_owned(
+                            DataMarkerAttributes::try_from_string(format!("{length}-{unit}"))
+                                .map_err(|_| {
+                                    DataError::custom("Failed to parse the attribute")
+                                        .with_debug_context(&unit)
+                                })?,
+                            locale,
+                        ));
                     }
-                    data_locales.insert(DataIdentifierCow::from_owned(
-                        DataMarkerAttributes::try_from_string(format!("{length}-{unit}")).map_err(
-                            |_| {
-                                DataError::custom("Failed to parse the attribute")
-                                    .with_debug_context(&unit)
-                            },
-                        )?,
-                        locale,
-                    ));
                 }
             }
         }
@@ -191,3 +211,72 @@ fn test_basic() {
     let short = fr_units.patterns.get(5.into(), &fr_rules).interpolate([5]);
     assert_writeable_eq!(short, "5 m");
 }
+
+#[test]
+fn test_formatter() {
+    use icu::experimental::dimension::units::formatter::{MixedUnitsFormatter, UnitsFormatter};
+    use icu::experimental::dimension::units::options::{UnitsFormatterOptions, Width};
+    use icu::experimental::measure::parser::MeasureUnitParser;
+    use icu::experimental::units::provider::UnitsInfoV1;
+    use icu::locale::locale;
+    use writeable::assert_writeable_eq;
+
+    let provider = SourceDataProvider::new_testing();
+    let units_info: DataPayload<UnitsInfoV1> = provider.load(Default::default()).unwrap().payload;
+    let parser =
+        MeasureUnitParser::from_payload(units_info.get().units_conversion_trie.as_borrowed());
+
+    let options = |width| {
+        let mut options = UnitsFormatterOptions::default();
+        options.width = width;
+        options
+    };
+
+    for (unit, width, value, expected) in [
+        // Units with their own display name.
+        ("kilometer-per-hour", Width::Short, "5", "5 km/h"),
+        (
+            "kilometer-per-hour",
+            Width::Long,
+            "1",
+            "1 kilometer per hour",
+        ),
+        ("square-meter", Width::Short, "12.5", "12.5 m²"),
+        ("square-meter", Width::Long, "2", "2 square meters"),
+        // The denominator has a `perUnitPattern`.
+        ("kilogram-per-hour", Width::Short, "5", "5 kg/h"),
+        (
+            "kilogram-per-square-meter",
+            Width::Long,
+            "3",
+            "3 kilograms per square meter",
+        ),
+        // The denominator has no `perUnitPattern`, so the `per` pattern is used.
+        (
+            "kilogram-per-kilometer-hour",
+            Width::Short,
+            "5",
+            "5 kg/km⋅hr",
+        ),
+    ] {
+        let formatter = UnitsFormatter::try_new_for_measure_unit_unstable(
+            &provider,
+            locale!("en").into(),
+            &parser.try_from_str(unit).unwrap(),
+            options(width),
+        )
+        .unwrap();
+        let value = value.parse().unwrap();
+        assert_writeable_eq!(formatter.format_fixed_decimal(&value), expected, "{unit}");
+    }
+
+    let formatter = MixedUnitsFormatter::try_new_unstable(
+        &provider,
+        locale!("en").into(),
+        &parser.try_mixed_from_str("foot-and-inch").unwrap(),
+        options(Width::Short),
+    )
+    .unwrap();
+    let values = ["5".parse().unwrap(), "11".parse().unwrap()];
+    assert_writeable_eq!(formatter.format(&values), "5 ft, 11 in");
+}
@@ -6,4 +6,5 @@ pub(crate) mod data;
 pub(crate) mod essentials;
 pub(crate) mod helpers;
 pub(crate) mod info;
+pub(crate) mod preferences;
 pub(crate) mod trie;
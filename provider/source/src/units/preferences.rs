@@ -0,0 +1,162 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use core::str::FromStr;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashSet};
+
+use crate::cldr_serde;
+use crate::SourceDataProvider;
+use icu::experimental::measure::parser::MeasureUnitParser;
+use icu::experimental::units::provider::{
+    UnitPreference, UnitsInfoV1, UnitsPreferences, UnitsPreferencesV1,
+};
+use icu::experimental::units::ratio::IcuRatio;
+use icu_provider::prelude::*;
+use num_traits::Zero;
+use zerotrie::ZeroTrieSimpleAscii;
+use zerovec::VarZeroVec;
+
+use super::helpers::flatten_fraction;
+
+impl DataProvider<UnitsPreferencesV1> for SourceDataProvider {
+    fn load(&self, _req: DataRequest) -> Result<DataResponse<UnitsPreferencesV1>, DataError> {
+        self.check_req::<UnitsPreferencesV1>(_req)?;
+
+        let preferences_data: &cldr_serde::units::preferences::Resource = self
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/unitPreferenceData.json")?;
+
+        // The units info is used to validate the preferred units.
+        let units_info = DataProvider::<UnitsInfoV1>::load(self, Default::default())?.payload;
+        let parser =
+            MeasureUnitParser::from_payload(units_info.get().units_conversion_trie.as_borrowed());
+
+        let mut index = BTreeMap::<Vec<u8>, usize>::new();
+        let mut preferences = Vec::<UnitPreference>::new();
+
+        for (category, usages) in &preferences_data.supplemental.unit_preference_data {
+            for (usage, regions) in usages {
+                for (region_list, group) in regions {
+                    let start = preferences.len();
+                    for (i, preference) in group.iter().enumerate() {
+                        parser.try_mixed_from_str(&preference.unit).map_err(|_| {
+                            DataError::custom("the preferred unit is not valid")
+                                .with_display_context(&preference.unit)
+                        })?;
+
+                        // The last preference is the fallback for all the smaller values.
+                        let geq = if i + 1 == group.len() {
+                            IcuRatio::zero()
+                        } else {
+                            // CLDR defaults the threshold to 1.
+                            let geq = IcuRatio::from_str(preference.geq.as_deref().unwrap_or("1"))
+                                .map_err(|e| {
+                                    DataError::custom("the threshold is not valid")
+                                        .with_debug_context(&e)
+                                })?;
+                            // A zero threshold marks the end of a group.
+                            if geq.is_zero() || geq.is_negative() {
+                                return Err(DataError::custom(
+                                    "only the last preference may have a non-positive threshold",
+                                )
+                                .with_display_context(&preference.unit));
+                            }
+                            geq
+                        };
+
+                        let (geq_num, geq_den, _) = flatten_fraction(geq);
+                        preferences.push(UnitPreference {
+                            geq_num: geq_num.into(),
+                            geq_den: geq_den.into(),
+                            unit: Cow::Owned(preference.unit.clone()),
+                            skeleton: Cow::Owned(preference.skeleton.clone().unwrap_or_default()),
+                        });
+                    }
+
+                    for region in region_list.split_whitespace() {
+                        index.insert(format!("{category}/{usage}/{region}").into_bytes(), start);
+                    }
+                }
+            }
+        }
+
+        let index = ZeroTrieSimpleAscii::try_from(&index).map_err(|e| {
+            DataError::custom("Could not create ZeroTrie from unitPreferenceData.json data")
+                .with_display_context(&e)
+        })?;
+
+        let result = UnitsPreferences {
+            index: index.convert_store(),
+            preferences: VarZeroVec::from(&preferences),
+        };
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(result),
+        })
+    }
+}
+
+impl crate::IterableDataProviderCached<UnitsPreferencesV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        Ok(HashSet::from_iter([Default::default()]))
+    }
+}
+
+#[test]
+fn test_basic() {
+    use zerofrom::ZeroFrom;
+
+    let provider = SourceDataProvider::new_testing();
+
+    let preferences: DataResponse<UnitsPreferencesV1> = provider.load(Default::default()).unwrap();
+    let preferences = preferences.payload.get();
+
+    let group = |key: &str| {
+        let start = preferences.index.get(key).unwrap();
+        let mut group = Vec::new();
+        for preference in preferences.preferences.iter().skip(start) {
+            let preference = UnitPreference::zero_from(preference);
+            let is_last = preference.geq_num.iter().all(|b| b == 0);
+            group.push((
+                preference.unit.into_owned(),
+                preference.skeleton.into_owned(),
+            ));
+            if is_last {
+                break;
+            }
+        }
+        group
+    };
+
+    let road = group("length/road/US");
+    assert_eq!(
+        road,
+        [
+            ("mile", ""),
+            ("foot", "precision-increment/50"),
+            ("foot", "precision-increment/10"),
+        ]
+        .map(|(unit, skeleton)| (unit.to_string(), skeleton.to_string()))
+    );
+    // Preferences for the same unit are told apart by their skeletons.
+    assert_eq!(road.iter().collect::<HashSet<_>>().len(), road.len());
+
+    let units = |key: &str| {
+        group(key)
+            .into_iter()
+            .map(|(unit, _)| unit)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(units("length/person-height/GB"), vec!["foot-and-inch"]);
+    assert_eq!(units("mass/person/001"), vec!["kilogram"]);
+    assert_eq!(
+        group("temperature/default/PR"),
+        group("temperature/default/US")
+    );
+    assert_eq!(units("temperature/default/US"), vec!["fahrenheit"]);
+    assert!(preferences.index.get("length/person-height/FR").is_none());
+}
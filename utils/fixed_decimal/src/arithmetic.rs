@@ -0,0 +1,923 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This file contains exact arithmetic and comparison on [`UnsignedFixedDecimal`]
+//! and [`SignedFixedDecimal`].
+//!
+//! All operations work on the exact decimal digits. The magnitude limits of the
+//! types still apply: like the integer operators, the `+`, `-` and `*` operators panic
+//! if the result cannot be represented, and the `checked_*` methods return a [`LimitError`].
+
+use core::cmp::{self, Ordering};
+use core::iter;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use smallvec::SmallVec;
+
+use crate::{
+    LimitError, Sign, SignedFixedDecimal, SignedRoundingMode, UnsignedFixedDecimal,
+    UnsignedRoundingMode,
+};
+
+/// Digits in ascending order of magnitude.
+type Digits = SmallVec<[u8; 16]>;
+
+/// Gets the digit at `magnitude`, which may be outside of the `i16` range.
+fn digit_at(x: &UnsignedFixedDecimal, magnitude: i32) -> u8 {
+    i16::try_from(magnitude).map(|m| x.digit_at(m)).unwrap_or(0)
+}
+
+fn lower_magnitude(x: &UnsignedFixedDecimal) -> i32 {
+    i32::from(*x.magnitude_range().start())
+}
+
+fn upper_magnitude(x: &UnsignedFixedDecimal) -> i32 {
+    i32::from(*x.magnitude_range().end())
+}
+
+/// Returns the upper magnitude of `x` if it has leading zeros, and 0 otherwise.
+///
+/// Sums keep the leading zeros of their operands, but not their magnitude: 100 - 99.99
+/// is 0.01, not 000.01.
+fn padded_upper_magnitude(x: &UnsignedFixedDecimal) -> i32 {
+    if x.nonzero_magnitude_start() < *x.magnitude_range().end() {
+        upper_magnitude(x)
+    } else {
+        0
+    }
+}
+
+/// Returns the digits of `x` from its smallest to its largest nonzero digit,
+/// together with the magnitude of the former.
+fn ascending_digits(x: &UnsignedFixedDecimal) -> (i32, Digits) {
+    let low = x.nonzero_magnitude_end();
+    let high = x.nonzero_magnitude_start();
+    (
+        i32::from(low),
+        (low..=high).map(|m| x.digit_at(m)).collect(),
+    )
+}
+
+/// Compares the numeric values of `a` and `b`.
+fn cmp_value(a: &UnsignedFixedDecimal, b: &UnsignedFixedDecimal) -> Ordering {
+    if a.is_zero() || b.is_zero() {
+        return b.is_zero().cmp(&a.is_zero());
+    }
+    a.nonzero_magnitude_start()
+        .cmp(&b.nonzero_magnitude_start())
+        .then_with(|| {
+            let low = cmp::min(a.nonzero_magnitude_end(), b.nonzero_magnitude_end());
+            (low..=a.nonzero_magnitude_start())
+                .rev()
+                .map(|m| a.digit_at(m).cmp(&b.digit_at(m)))
+                .find(|o| o.is_ne())
+                .unwrap_or(Ordering::Equal)
+        })
+}
+
+/// Computes `a + b`.
+fn add_abs(
+    a: &UnsignedFixedDecimal,
+    b: &UnsignedFixedDecimal,
+) -> Result<UnsignedFixedDecimal, LimitError> {
+    let low = i32::from(cmp::min(
+        a.nonzero_magnitude_end(),
+        b.nonzero_magnitude_end(),
+    ));
+    let high = i32::from(cmp::max(
+        a.nonzero_magnitude_start(),
+        b.nonzero_magnitude_start(),
+    )) + 1;
+    let mut digits = Digits::new();
+    let mut carry = 0;
+    for m in low..=high {
+        let sum = digit_at(a, m) + digit_at(b, m) + carry;
+        digits.push(sum % 10);
+        carry = sum / 10;
+    }
+    UnsignedFixedDecimal::from_ascending_digits(
+        low,
+        &digits,
+        cmp::min(lower_magnitude(a), lower_magnitude(b)),
+        cmp::max(padded_upper_magnitude(a), padded_upper_magnitude(b)),
+    )
+}
+
+/// Computes `a - b`, where `a >= b`.
+fn sub_abs(
+    a: &UnsignedFixedDecimal,
+    b: &UnsignedFixedDecimal,
+) -> Result<UnsignedFixedDecimal, LimitError> {
+    debug_assert!(cmp_value(a, b).is_ge());
+    let low = i32::from(cmp::min(
+        a.nonzero_magnitude_end(),
+        b.nonzero_magnitude_end(),
+    ));
+    let high = i32::from(a.nonzero_magnitude_start());
+    let mut digits = Digits::new();
+    let mut borrow = 0;
+    for m in low..=high {
+        let difference = 10 + digit_at(a, m) - digit_at(b, m) - borrow;
+        digits.push(difference % 10);
+        borrow = 1 - difference / 10;
+    }
+    UnsignedFixedDecimal::from_ascending_digits(
+        low,
+        &digits,
+        cmp::min(lower_magnitude(a), lower_magnitude(b)),
+        cmp::max(padded_upper_magnitude(a), padded_upper_magnitude(b)),
+    )
+}
+
+/// Computes `a * b`.
+fn mul_abs(
+    a: &UnsignedFixedDecimal,
+    b: &UnsignedFixedDecimal,
+) -> Result<UnsignedFixedDecimal, LimitError> {
+    let (low_a, digits_a) = ascending_digits(a);
+    let (low_b, digits_b) = ascending_digits(b);
+    // Each column sums at most 81 * u16::MAX, which fits in a u32.
+    let mut columns: SmallVec<[u32; 32]> = iter::repeat(0)
+        .take(digits_a.len() + digits_b.len())
+        .collect();
+    for (i, x) in digits_a.iter().enumerate() {
+        for (column, y) in columns.iter_mut().skip(i).zip(digits_b.iter()) {
+            *column += u32::from(x * y);
+        }
+    }
+    let mut digits = Digits::new();
+    let mut carry = 0;
+    for column in columns {
+        let sum = column + carry;
+        digits.push((sum % 10) as u8);
+        carry = sum / 10;
+    }
+    while carry > 0 {
+        digits.push((carry % 10) as u8);
+        carry /= 10;
+    }
+    UnsignedFixedDecimal::from_ascending_digits(
+        low_a + low_b,
+        &digits,
+        lower_magnitude(a) + lower_magnitude(b),
+        0,
+    )
+}
+
+/// Compares two unsigned integers given as ascending digits without leading zeros.
+fn cmp_digits(a: &[u8], b: &[u8]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+/// Computes `a -= b` for two unsigned integers given as ascending digits without
+/// leading zeros, where `a >= b`.
+fn sub_digits(a: &mut Digits, b: &[u8]) {
+    let mut borrow = 0;
+    for (i, x) in a.iter_mut().enumerate() {
+        let y = b.get(i).copied().unwrap_or(0);
+        let difference = 10 + *x - y - borrow;
+        *x = difference % 10;
+        borrow = 1 - difference / 10;
+    }
+    while a.last() == Some(&0) {
+        a.pop();
+    }
+}
+
+/// Divides `a` by `b`, truncating to digits at or above magnitude `position - 1`.
+///
+/// If the division is inexact and the digit at `position - 1` is 0 or 5, that digit
+/// is incremented so that rounding at `position` sees the correct side of the halfway
+/// point. Unlike appending a digit below `position - 1`, this works for all positions
+/// above `i16::MIN`.
+fn div_abs_for_rounding(
+    a: &UnsignedFixedDecimal,
+    b: &UnsignedFixedDecimal,
+    position: i16,
+) -> Result<UnsignedFixedDecimal, LimitError> {
+    let low = i32::from(position) - 1;
+    let (low_a, digits_a) = ascending_digits(a);
+    let (low_b, digits_b) = ascending_digits(b);
+    // a / b / 10^low = (digits_a * 10^shift) / digits_b
+    let shift = low_a - low_b - low;
+    let mut divisor = Digits::new();
+    divisor.extend(iter::repeat(0).take(cmp::max(-shift, 0) as usize));
+    divisor.extend_from_slice(&digits_b);
+    let dividend = digits_a
+        .iter()
+        .rev()
+        .copied()
+        .chain(iter::repeat(0).take(cmp::max(shift, 0) as usize));
+
+    let mut quotient = Digits::new();
+    let mut remainder = Digits::new();
+    for d in dividend {
+        if d != 0 || !remainder.is_empty() {
+            remainder.insert(0, d);
+        }
+        let mut q = 0;
+        while cmp_digits(&remainder, &divisor).is_ge() {
+            sub_digits(&mut remainder, &divisor);
+            q += 1;
+        }
+        quotient.push(q);
+    }
+    if let Some(last @ (0 | 5)) = quotient.last_mut() {
+        *last += u8::from(!remainder.is_empty());
+    }
+    quotient.reverse();
+    UnsignedFixedDecimal::from_ascending_digits(low, &quotient, low, 0)
+}
+
+/// Computes `a + b` for signed operands, given as negativity and absolute value.
+fn add_signed(
+    a_negative: bool,
+    a: &UnsignedFixedDecimal,
+    b_negative: bool,
+    b: &UnsignedFixedDecimal,
+) -> Result<SignedFixedDecimal, LimitError> {
+    let (negative, absolute) = if a_negative == b_negative {
+        (a_negative, add_abs(a, b)?)
+    } else if cmp_value(a, b).is_ge() {
+        (a_negative, sub_abs(a, b)?)
+    } else {
+        (b_negative, sub_abs(b, a)?)
+    };
+    Ok(signed(negative, absolute))
+}
+
+/// Creates a [`SignedFixedDecimal`] that is negative if `negative` is true and
+/// `absolute` is not zero.
+fn signed(negative: bool, absolute: UnsignedFixedDecimal) -> SignedFixedDecimal {
+    let sign = if negative && !absolute.is_zero() {
+        Sign::Negative
+    } else {
+        Sign::None
+    };
+    SignedFixedDecimal::new(sign, absolute)
+}
+
+fn is_negative(x: &SignedFixedDecimal) -> bool {
+    x.sign == Sign::Negative && !x.absolute.is_zero()
+}
+
+/// Unwraps the result of an operator, panicking on overflow like the integer operators.
+#[allow(clippy::panic)] // the operators have no way to report the error
+fn unwrap_or_overflow<T>(result: Result<T, LimitError>, operation: &str) -> T {
+    match result {
+        Ok(value) => value,
+        Err(LimitError) => panic!("attempt to {operation} with overflow"),
+    }
+}
+
+fn add_unsigned(a: &UnsignedFixedDecimal, b: &UnsignedFixedDecimal) -> UnsignedFixedDecimal {
+    unwrap_or_overflow(a.checked_add(b), "add")
+}
+
+fn mul_unsigned(a: &UnsignedFixedDecimal, b: &UnsignedFixedDecimal) -> UnsignedFixedDecimal {
+    unwrap_or_overflow(a.checked_mul(b), "multiply")
+}
+
+fn add_signed_decimals(a: &SignedFixedDecimal, b: &SignedFixedDecimal) -> SignedFixedDecimal {
+    unwrap_or_overflow(a.checked_add(b), "add")
+}
+
+fn sub_signed_decimals(a: &SignedFixedDecimal, b: &SignedFixedDecimal) -> SignedFixedDecimal {
+    unwrap_or_overflow(a.checked_sub(b), "subtract")
+}
+
+fn mul_signed_decimals(a: &SignedFixedDecimal, b: &SignedFixedDecimal) -> SignedFixedDecimal {
+    unwrap_or_overflow(a.checked_mul(b), "multiply")
+}
+
+macro_rules! impl_binary_op {
+    ($ty:ty, $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $f:ident) => {
+        impl $trait<&$ty> for &$ty {
+            type Output = $ty;
+            fn $method(self, rhs: &$ty) -> $ty {
+                $f(self, rhs)
+            }
+        }
+
+        impl $trait for $ty {
+            type Output = $ty;
+            fn $method(self, rhs: $ty) -> $ty {
+                $f(&self, &rhs)
+            }
+        }
+
+        impl $assign_trait<&$ty> for $ty {
+            fn $assign_method(&mut self, rhs: &$ty) {
+                *self = $f(self, rhs);
+            }
+        }
+
+        impl $assign_trait for $ty {
+            fn $assign_method(&mut self, rhs: $ty) {
+                *self = $f(self, &rhs);
+            }
+        }
+    };
+}
+
+impl_binary_op!(
+    UnsignedFixedDecimal,
+    Add,
+    add,
+    AddAssign,
+    add_assign,
+    add_unsigned
+);
+impl_binary_op!(
+    UnsignedFixedDecimal,
+    Mul,
+    mul,
+    MulAssign,
+    mul_assign,
+    mul_unsigned
+);
+impl_binary_op!(
+    SignedFixedDecimal,
+    Add,
+    add,
+    AddAssign,
+    add_assign,
+    add_signed_decimals
+);
+impl_binary_op!(
+    SignedFixedDecimal,
+    Sub,
+    sub,
+    SubAssign,
+    sub_assign,
+    sub_signed_decimals
+);
+impl_binary_op!(
+    SignedFixedDecimal,
+    Mul,
+    mul,
+    MulAssign,
+    mul_assign,
+    mul_signed_decimals
+);
+
+/// Subtracting two [`UnsignedFixedDecimal`]s results in a [`SignedFixedDecimal`].
+///
+/// Panics if the result exceeds the magnitude limits; see
+/// [`UnsignedFixedDecimal::checked_sub`].
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::UnsignedFixedDecimal;
+///
+/// let a: UnsignedFixedDecimal = "1.5".parse().unwrap();
+/// let b: UnsignedFixedDecimal = "2.25".parse().unwrap();
+/// assert_eq!((&a - &b).to_string(), "-0.75");
+/// assert_eq!((b - a).to_string(), "0.75");
+/// ```
+impl Sub<&UnsignedFixedDecimal> for &UnsignedFixedDecimal {
+    type Output = SignedFixedDecimal;
+    fn sub(self, rhs: &UnsignedFixedDecimal) -> SignedFixedDecimal {
+        unwrap_or_overflow(self.checked_sub(rhs), "subtract")
+    }
+}
+
+impl Sub for UnsignedFixedDecimal {
+    type Output = SignedFixedDecimal;
+    fn sub(self, rhs: UnsignedFixedDecimal) -> SignedFixedDecimal {
+        &self - &rhs
+    }
+}
+
+/// Negation flips the sign between [`Sign::Negative`] and no sign.
+///
+/// An explicit [`Sign::Positive`] becomes [`Sign::Negative`], and zero keeps its
+/// digits but changes its sign just like any other number.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::SignedFixedDecimal;
+///
+/// let dec: SignedFixedDecimal = "-1.50".parse().unwrap();
+/// assert_eq!((-dec).to_string(), "1.50");
+/// ```
+impl Neg for SignedFixedDecimal {
+    type Output = SignedFixedDecimal;
+    fn neg(mut self) -> SignedFixedDecimal {
+        self.sign = match self.sign {
+            Sign::Negative => Sign::None,
+            Sign::None | Sign::Positive => Sign::Negative,
+        };
+        self
+    }
+}
+
+impl Neg for &SignedFixedDecimal {
+    type Output = SignedFixedDecimal;
+    fn neg(self) -> SignedFixedDecimal {
+        -self.clone()
+    }
+}
+
+/// [`UnsignedFixedDecimal`]s are ordered by their numeric value.
+///
+/// To be consistent with equality, numbers with the same value but different
+/// numbers of leading or trailing zeros are not equal: they are ordered by
+/// their number of leading zeros and then by their number of trailing zeros.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::UnsignedFixedDecimal;
+///
+/// let a: UnsignedFixedDecimal = "9.5".parse().unwrap();
+/// let b: UnsignedFixedDecimal = "10".parse().unwrap();
+/// let c: UnsignedFixedDecimal = "10.0".parse().unwrap();
+/// assert!(a < b);
+/// assert!(b < c);
+/// ```
+impl Ord for UnsignedFixedDecimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_value(self, other)
+            .then_with(|| upper_magnitude(self).cmp(&upper_magnitude(other)))
+            .then_with(|| lower_magnitude(other).cmp(&lower_magnitude(self)))
+    }
+}
+
+impl PartialOrd for UnsignedFixedDecimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// [`SignedFixedDecimal`]s are ordered by their numeric value, with negative zero
+/// equal in value to zero.
+///
+/// To be consistent with equality, numbers with the same value but a different
+/// representation are not equal: they are ordered first by sign (negative, none,
+/// positive) and then as [`UnsignedFixedDecimal`]s.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::SignedFixedDecimal;
+///
+/// let a: SignedFixedDecimal = "-10".parse().unwrap();
+/// let b: SignedFixedDecimal = "-9.5".parse().unwrap();
+/// let c: SignedFixedDecimal = "0".parse().unwrap();
+/// assert!(a < b);
+/// assert!(b < c);
+/// ```
+impl Ord for SignedFixedDecimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        fn sign_rank(sign: Sign) -> u8 {
+            match sign {
+                Sign::Negative => 0,
+                Sign::None => 1,
+                Sign::Positive => 2,
+            }
+        }
+        let value = match (is_negative(self), is_negative(other)) {
+            (false, false) => cmp_value(&self.absolute, &other.absolute),
+            (true, true) => cmp_value(&other.absolute, &self.absolute),
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+        };
+        value
+            .then_with(|| sign_rank(self.sign).cmp(&sign_rank(other.sign)))
+            .then_with(|| self.absolute.cmp(&other.absolute))
+    }
+}
+
+impl PartialOrd for SignedFixedDecimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl UnsignedFixedDecimal {
+    /// Computes `self + rhs`, returning an error if the result exceeds the magnitude limits.
+    ///
+    /// The `+` operator panics in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{LimitError, UnsignedFixedDecimal};
+    ///
+    /// let a: UnsignedFixedDecimal = "19.99".parse().unwrap();
+    /// let b: UnsignedFixedDecimal = "0.01".parse().unwrap();
+    /// assert_eq!(a.checked_add(&b).unwrap().to_string(), "20.00");
+    ///
+    /// let mut big = UnsignedFixedDecimal::from(9u32);
+    /// big.multiply_pow10(i16::MAX);
+    /// assert_eq!(big.checked_add(&big), Err(LimitError));
+    /// ```
+    pub fn checked_add(&self, rhs: &Self) -> Result<Self, LimitError> {
+        add_abs(self, rhs)
+    }
+
+    /// Computes `self - rhs`, returning an error if the result exceeds the magnitude limits.
+    ///
+    /// The `-` operator panics in that case.
+    pub fn checked_sub(&self, rhs: &Self) -> Result<SignedFixedDecimal, LimitError> {
+        add_signed(false, self, true, rhs)
+    }
+
+    /// Computes `self * rhs`, returning an error if the result exceeds the magnitude limits.
+    ///
+    /// The `*` operator panics in that case.
+    pub fn checked_mul(&self, rhs: &Self) -> Result<Self, LimitError> {
+        mul_abs(self, rhs)
+    }
+
+    /// Divides this number by `divisor`, rounding the quotient at `position` using the
+    /// specified rounding mode.
+    ///
+    /// The quotient shows fraction digits down to `position`. Returns `None` if `divisor`
+    /// is zero, if the quotient exceeds the limits of [`UnsignedFixedDecimal`], or if `position` is
+    /// `i16::MIN`, which leaves no room for the digit that decides the rounding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{UnsignedFixedDecimal, UnsignedRoundingMode};
+    ///
+    /// let total: UnsignedFixedDecimal = "100".parse().unwrap();
+    /// let parts = UnsignedFixedDecimal::from(3u32);
+    /// let share = total
+    ///     .divided_by(&parts, -2, UnsignedRoundingMode::HalfEven)
+    ///     .unwrap();
+    /// assert_eq!(share.to_string(), "33.33");
+    ///
+    /// assert_eq!(
+    ///     total.divided_by(&UnsignedFixedDecimal::from(0u32), -2, UnsignedRoundingMode::HalfEven),
+    ///     None
+    /// );
+    /// ```
+    pub fn divided_by(
+        &self,
+        divisor: &Self,
+        position: i16,
+        mode: UnsignedRoundingMode,
+    ) -> Option<Self> {
+        if divisor.is_zero() {
+            return None;
+        }
+        let mut quotient = div_abs_for_rounding(self, divisor, position).ok()?;
+        quotient.round_with_mode(position, mode);
+        quotient.pad_end(position);
+        Some(quotient)
+    }
+}
+
+impl SignedFixedDecimal {
+    /// Computes `self + rhs`, returning an error if the result exceeds the magnitude limits.
+    ///
+    /// The `+` operator panics in that case.
+    pub fn checked_add(&self, rhs: &Self) -> Result<Self, LimitError> {
+        add_signed(
+            is_negative(self),
+            &self.absolute,
+            is_negative(rhs),
+            &rhs.absolute,
+        )
+    }
+
+    /// Computes `self - rhs`, returning an error if the result exceeds the magnitude limits.
+    ///
+    /// The `-` operator panics in that case.
+    pub fn checked_sub(&self, rhs: &Self) -> Result<Self, LimitError> {
+        add_signed(
+            is_negative(self),
+            &self.absolute,
+            !is_negative(rhs),
+            &rhs.absolute,
+        )
+    }
+
+    /// Computes `self * rhs`, returning an error if the result exceeds the magnitude limits.
+    ///
+    /// The `*` operator panics in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{LimitError, SignedFixedDecimal};
+    ///
+    /// let a: SignedFixedDecimal = "-1.5".parse().unwrap();
+    /// let b: SignedFixedDecimal = "0.2".parse().unwrap();
+    /// assert_eq!(a.checked_mul(&b).unwrap().to_string(), "-0.30");
+    ///
+    /// let mut tiny = SignedFixedDecimal::from(1);
+    /// tiny.multiply_pow10(i16::MIN + 1);
+    /// assert_eq!(tiny.checked_mul(&tiny), Err(LimitError));
+    /// ```
+    pub fn checked_mul(&self, rhs: &Self) -> Result<Self, LimitError> {
+        mul_abs(&self.absolute, &rhs.absolute)
+            .map(|absolute| signed(is_negative(self) != is_negative(rhs), absolute))
+    }
+
+    /// Divides this number by `divisor`, rounding the quotient at `position` using the
+    /// specified rounding mode.
+    ///
+    /// The quotient shows fraction digits down to `position`. Returns `None` if `divisor`
+    /// is zero, if the quotient exceeds the limits of [`SignedFixedDecimal`], or if `position` is
+    /// `i16::MIN`, which leaves no room for the digit that decides the rounding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{SignedFixedDecimal, SignedRoundingMode};
+    ///
+    /// let a: SignedFixedDecimal = "-10".parse().unwrap();
+    /// let b: SignedFixedDecimal = "4".parse().unwrap();
+    /// assert_eq!(
+    ///     a.divided_by(&b, 0, SignedRoundingMode::Floor).unwrap().to_string(),
+    ///     "-3"
+    /// );
+    /// assert_eq!(
+    ///     a.divided_by(&b, -2, SignedRoundingMode::Floor).unwrap().to_string(),
+    ///     "-2.50"
+    /// );
+    /// ```
+    pub fn divided_by(
+        &self,
+        divisor: &Self,
+        position: i16,
+        mode: SignedRoundingMode,
+    ) -> Option<Self> {
+        if divisor.absolute.is_zero() {
+            return None;
+        }
+        let absolute = div_abs_for_rounding(&self.absolute, &divisor.absolute, position).ok()?;
+        let mut quotient = signed(is_negative(self) != is_negative(divisor), absolute);
+        quotient.round_with_mode(position, mode);
+        quotient.pad_end(position);
+        if quotient.absolute.is_zero() {
+            quotient.sign = Sign::None;
+        }
+        Some(quotient)
+    }
+}
+
+#[test]
+fn test_add_sub() {
+    let cases: [(&str, &str, &str, &str); 11] = [
+        // (a, b, a + b, a - b)
+        ("1", "2", "3", "-1"),
+        ("0.1", "0.2", "0.3", "-0.1"),
+        ("1.50", "2.5", "4.00", "-1.00"),
+        ("999.99", "0.01", "1000.00", "999.98"),
+        ("0012", "3", "0015", "0009"),
+        ("-5", "3", "-2", "-8"),
+        ("-5", "-7.25", "-12.25", "2.25"),
+        ("5", "5", "10", "0"),
+        ("100", "99.99", "199.99", "0.01"),
+        ("0", "-0.001", "-0.001", "0.001"),
+        (
+            "123456789012345678901234567890",
+            "0.00000000000000000001",
+            "123456789012345678901234567890.00000000000000000001",
+            "123456789012345678901234567889.99999999999999999999",
+        ),
+    ];
+    for (a, b, sum, difference) in cases {
+        let a: SignedFixedDecimal = a.parse().unwrap();
+        let b: SignedFixedDecimal = b.parse().unwrap();
+        assert_eq!((&a + &b).to_string(), sum, "{a} + {b}");
+        assert_eq!((&b + &a).to_string(), sum, "{b} + {a}");
+        assert_eq!((&a - &b).to_string(), difference, "{a} - {b}");
+        let mut c = a.clone();
+        c -= &b;
+        c += b;
+        assert!(cmp_value(&c.absolute, &a.absolute).is_eq(), "{a}");
+    }
+}
+
+#[test]
+fn test_unsigned_ops() {
+    let a: UnsignedFixedDecimal = "19.99".parse().unwrap();
+    let b: UnsignedFixedDecimal = "3".parse().unwrap();
+    assert_eq!((&a + &b).to_string(), "22.99");
+    assert_eq!((&a * &b).to_string(), "59.97");
+    assert_eq!((&b - &a).to_string(), "-16.99");
+    let mut c = a.clone();
+    c *= b;
+    c += UnsignedFixedDecimal::from(1u32);
+    assert_eq!(c.to_string(), "60.97");
+}
+
+#[test]
+fn test_mul() {
+    let cases: [(&str, &str, &str); 7] = [
+        ("2", "3", "6"),
+        ("1.50", "2.0", "3.000"),
+        ("-1.5", "2", "-3.0"),
+        ("-1.5", "-0.2", "0.30"),
+        ("0.00", "-7", "0.00"),
+        (
+            "99999999999999999999",
+            "99999999999999999999",
+            "9999999999999999999800000000000000000001",
+        ),
+        ("0.001", "0.001", "0.000001"),
+    ];
+    for (a, b, product) in cases {
+        let a: SignedFixedDecimal = a.parse().unwrap();
+        let b: SignedFixedDecimal = b.parse().unwrap();
+        assert_eq!((&a * &b).to_string(), product, "{a} * {b}");
+        assert_eq!((b * a).to_string(), product);
+    }
+
+    let mut big = SignedFixedDecimal::from(5);
+    big.multiply_pow10(i16::MAX - 1);
+    assert_eq!(big.checked_mul(&big), Err(LimitError));
+    assert_eq!(
+        big.checked_mul(&SignedFixedDecimal::from(-1)),
+        Ok(-big.clone())
+    );
+}
+
+#[test]
+fn test_checked_overflow() {
+    let mut big = UnsignedFixedDecimal::from(9u32);
+    big.multiply_pow10(i16::MAX);
+    let one = UnsignedFixedDecimal::from(1u32);
+    assert_eq!(big.checked_add(&big), Err(LimitError));
+    assert_eq!(big.checked_mul(&big), Err(LimitError));
+    assert!(big.checked_add(&one).is_ok());
+    assert!(big.checked_sub(&big).is_ok());
+
+    let big = SignedFixedDecimal::new(Sign::Negative, big);
+    assert_eq!(big.checked_add(&big), Err(LimitError));
+    assert_eq!(big.checked_sub(&-big.clone()), Err(LimitError));
+    assert!(big.checked_sub(&big).is_ok());
+}
+
+#[test]
+#[should_panic(expected = "attempt to add with overflow")]
+fn test_add_overflow_panics() {
+    let mut big = SignedFixedDecimal::from(9);
+    big.multiply_pow10(i16::MAX);
+    let _ = &big + &big;
+}
+
+#[test]
+#[should_panic(expected = "attempt to multiply with overflow")]
+fn test_mul_overflow_panics() {
+    let mut tiny = UnsignedFixedDecimal::from(1u32);
+    tiny.multiply_pow10(i16::MIN + 1);
+    let _ = &tiny * &tiny;
+}
+
+#[test]
+fn test_div() {
+    use crate::UnsignedRoundingMode::*;
+    let cases: [(&str, &str, i16, SignedRoundingMode, &str); 16] = [
+        ("10", "4", 0, SignedRoundingMode::Floor, "2"),
+        ("10", "4", 0, SignedRoundingMode::Unsigned(HalfEven), "2"),
+        ("10", "4", 0, SignedRoundingMode::Unsigned(HalfExpand), "3"),
+        ("10", "4", -1, SignedRoundingMode::Unsigned(Trunc), "2.5"),
+        ("10", "4", -3, SignedRoundingMode::Unsigned(Trunc), "2.500"),
+        ("-10", "4", 0, SignedRoundingMode::Floor, "-3"),
+        ("-10", "4", 0, SignedRoundingMode::Ceil, "-2"),
+        (
+            "1",
+            "3",
+            -4,
+            SignedRoundingMode::Unsigned(HalfExpand),
+            "0.3333",
+        ),
+        (
+            "2",
+            "3",
+            -4,
+            SignedRoundingMode::Unsigned(HalfTrunc),
+            "0.6667",
+        ),
+        // Exactly halfway only if the remainder is zero
+        (
+            "1.0000001",
+            "2",
+            -7,
+            SignedRoundingMode::Unsigned(HalfTrunc),
+            "0.5000000",
+        ),
+        (
+            "1.00000011",
+            "2",
+            -7,
+            SignedRoundingMode::Unsigned(HalfTrunc),
+            "0.5000001",
+        ),
+        (
+            "12345",
+            "0.05",
+            2,
+            SignedRoundingMode::Unsigned(Trunc),
+            "246900",
+        ),
+        // The rounding digit is 0 or 5 and the division is inexact
+        ("16", "3", 1, SignedRoundingMode::Unsigned(HalfEven), "10"),
+        ("31", "3", 1, SignedRoundingMode::Unsigned(Expand), "20"),
+        (
+            "-0.001",
+            "7",
+            -2,
+            SignedRoundingMode::Unsigned(Trunc),
+            "0.00",
+        ),
+        (
+            "7",
+            "-0.001",
+            0,
+            SignedRoundingMode::Unsigned(Trunc),
+            "-7000",
+        ),
+    ];
+    for (a, b, position, mode, quotient) in cases {
+        let a: SignedFixedDecimal = a.parse().unwrap();
+        let b: SignedFixedDecimal = b.parse().unwrap();
+        assert_eq!(
+            a.divided_by(&b, position, mode).unwrap().to_string(),
+            quotient,
+            "{a} / {b} at {position} with {mode:?}"
+        );
+    }
+
+    let zero: SignedFixedDecimal = "-0.0".parse().unwrap();
+    assert_eq!(
+        SignedFixedDecimal::from(1).divided_by(&zero, 0, SignedRoundingMode::Floor),
+        None
+    );
+
+    // The lowest positions
+    let two = UnsignedFixedDecimal::from(2u32);
+    let three = UnsignedFixedDecimal::from(3u32);
+    let quotient = two.divided_by(&three, i16::MIN + 1, HalfExpand).unwrap();
+    assert_eq!(quotient.nonzero_magnitude_end(), i16::MIN + 1);
+    assert_eq!(quotient.digit_at(i16::MIN + 1), 7);
+    assert_eq!(quotient.digit_at(i16::MIN + 2), 6);
+    assert_eq!(two.divided_by(&three, i16::MIN, HalfExpand), None);
+}
+
+#[test]
+fn test_ord() {
+    let sorted = [
+        "-100", "-9.99", "-1", "-0.5", "-0", "0", "0.0", "00", "+0", "0.001", "1", "+1", "1.5",
+        "10",
+    ];
+    let decimals: SmallVec<[SignedFixedDecimal; 16]> =
+        sorted.iter().map(|s| s.parse().unwrap()).collect();
+    for (i, a) in decimals.iter().enumerate() {
+        for (j, b) in decimals.iter().enumerate() {
+            assert_eq!(a.cmp(b), i.cmp(&j), "{a} vs {b}");
+            assert_eq!(a == b, i == j, "{a} vs {b}");
+        }
+    }
+}
+
+#[test]
+fn test_ops_against_i128() {
+    use rand::{Rng, SeedableRng};
+    let mut rng = rand_pcg::Lcg64Xsh32::seed_from_u64(42);
+    for _ in 0..10000 {
+        let a: i64 = rng.gen_range(-1_000_000_000..1_000_000_000);
+        let b: i64 = rng.gen_range(-1_000_000..1_000_000);
+        let (x, y) = (i128::from(a), i128::from(b));
+        // Interpret as amounts with 3 and 2 fraction digits
+        let scaled = |value: i128, scale: i16| {
+            let mut dec = SignedFixedDecimal::from(value);
+            dec.multiply_pow10(-scale);
+            dec.pad_end(-scale);
+            dec
+        };
+        let da = scaled(x, 3);
+        let db = scaled(y, 2);
+        assert_eq!(&da + &db, scaled(x + y * 10, 3), "{da} + {db}");
+        assert_eq!(&da - &db, scaled(x - y * 10, 3), "{da} - {db}");
+        assert_eq!(&da * &db, scaled(x * y, 5), "{da} * {db}");
+        assert_eq!(
+            cmp_value(&da.absolute, &db.absolute),
+            x.abs().cmp(&(y.abs() * 10)),
+            "{da} <=> {db}"
+        );
+        if b != 0 {
+            let quotient = da
+                .divided_by(
+                    &db,
+                    -4,
+                    SignedRoundingMode::Unsigned(UnsignedRoundingMode::Trunc),
+                )
+                .unwrap();
+            // (x / 1000) / (y / 100) = x * 10^4 / (y * 10) / 10^4
+            assert_eq!(quotient, scaled(x * 1000 / y, 4), "{da} / {db}");
+        }
+    }
+}
@@ -10,10 +10,10 @@ use core::fmt;
 use core::ops::RangeInclusive;
 use core::str::FromStr;
 
-use crate::{uint_iterator::IntIterator, IncrementLike, NoIncrement};
 #[cfg(feature = "ryu")]
-use crate::{FloatPrecision, LimitError};
-use crate::{ParseError, RoundingIncrement, UnsignedRoundingMode};
+use crate::FloatPrecision;
+use crate::{uint_iterator::IntIterator, IncrementLike, NoIncrement};
+use crate::{LimitError, ParseError, RoundingIncrement, UnsignedRoundingMode};
 
 // UnsignedFixedDecimal assumes usize (digits.len()) is at least as big as a u16
 #[cfg(not(any(
@@ -84,7 +84,7 @@ compile_error!("The fixed_decimal crate only works if usizes are at least the si
 /// dec.multiply_pow10(-2);
 /// assert_eq!("2.50", dec.to_string());
 /// ```````
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct UnsignedFixedDecimal {
     /// List of digits; digits\[0\] is the most significant.
     ///
@@ -199,6 +199,69 @@ impl UnsignedFixedDecimal {
         Ok(result)
     }
 
+    /// Initialize a [`UnsignedFixedDecimal`] with a slice of digits in ascending order of
+    /// magnitude, starting with the digit at magnitude `low`. Leading and trailing zeros are
+    /// shown down to `lower_magnitude` and up to `upper_magnitude`.
+    ///
+    /// Magnitudes are `i32` so that intermediate results of arithmetic can be passed in;
+    /// an error is returned if the result does not fit in `i16`.
+    pub(crate) fn from_ascending_digits(
+        low: i32,
+        digits: &[u8],
+        lower_magnitude: i32,
+        upper_magnitude: i32,
+    ) -> Result<Self, LimitError> {
+        let to_i16 = |m: i32| i16::try_from(m).map_err(|_| LimitError);
+        let mut result: Self = Default::default();
+        let first_nonzero = digits.iter().position(|&d| d != 0);
+        let last_nonzero = digits.iter().rposition(|&d| d != 0);
+        if let (Some(first), Some(last)) = (first_nonzero, last_nonzero) {
+            result.magnitude = to_i16(low + last as i32)?;
+            let end = to_i16(low + first as i32)?;
+            result.lower_magnitude = cmp::min(to_i16(cmp::min(lower_magnitude, 0))?, end);
+            result.upper_magnitude =
+                cmp::max(to_i16(cmp::max(upper_magnitude, 0))?, result.magnitude);
+            result
+                .digits
+                .extend(digits.iter().take(last + 1).skip(first).rev().copied());
+        } else {
+            result.lower_magnitude = to_i16(cmp::min(lower_magnitude, 0))?;
+            result.upper_magnitude = to_i16(cmp::max(upper_magnitude, 0))?;
+        }
+        #[cfg(debug_assertions)]
+        result.check_invariants();
+        Ok(result)
+    }
+
+    /// Constructs a [`UnsignedFixedDecimal`] with the value `mantissa × 10^(-scale)`.
+    ///
+    /// This is the representation used by many decimal libraries. The number shows `scale`
+    /// fraction digits, including trailing zeros, so the conversion is lossless.
+    ///
+    /// Returns an error if the number exceeds the magnitude limits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::UnsignedFixedDecimal;
+    ///
+    /// let dec = UnsignedFixedDecimal::try_from_mantissa_and_scale(1250, 2).unwrap();
+    /// assert_eq!("12.50", dec.to_string());
+    ///
+    /// let dec = UnsignedFixedDecimal::try_from_mantissa_and_scale(125, -2).unwrap();
+    /// assert_eq!("12500", dec.to_string());
+    /// ```
+    pub fn try_from_mantissa_and_scale(mantissa: u128, scale: i16) -> Result<Self, LimitError> {
+        let mut result = Self::from(mantissa);
+        let delta = scale.checked_neg().ok_or(LimitError)?;
+        if i32::from(result.upper_magnitude) + i32::from(delta) > i32::from(i16::MAX) {
+            return Err(LimitError);
+        }
+        result.multiply_pow10(delta);
+        result.pad_end(delta);
+        Ok(result)
+    }
+
     /// Gets the digit at the specified order of magnitude. Returns 0 if the magnitude is out of
     /// range of the currently visible digits.
     ///
@@ -2139,14 +2202,53 @@ impl UnsignedFixedDecimal {
     /// assert_writeable_eq!(decimal, "12345678000");
     /// ```
     pub fn try_from_f64(float: f64, precision: FloatPrecision) -> Result<Self, LimitError> {
-        let mut decimal = Self::new_from_f64_raw(float)?;
-        let n_digits = decimal.digits.len();
+        if !float.is_finite() || float.is_sign_negative() {
+            return Err(LimitError);
+        }
+        Self::new_from_float_raw(float)?.with_float_precision(precision)
+    }
+
+    /// Constructs a [`UnsignedFixedDecimal`] from an f32.
+    ///
+    /// This works like [`UnsignedFixedDecimal::try_from_f64`], except that
+    /// [`FloatPrecision::RoundTrip`] results in the shortest decimal that round-trips
+    /// through f32, which can be much shorter than the one for the same value as f64.
+    ///
+    /// This function can be made available with the `"ryu"` Cargo feature.
+    ///
+    /// NOTE:
+    ///   Negative numbers are not supported.
+    ///
+    /// ```rust
+    /// use fixed_decimal::{UnsignedFixedDecimal, FloatPrecision};
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let decimal =
+    ///     UnsignedFixedDecimal::try_from_f32(0.1, FloatPrecision::RoundTrip)
+    ///         .expect("Finite quantity");
+    /// assert_writeable_eq!(decimal, "0.1");
+    ///
+    /// let decimal =
+    ///     UnsignedFixedDecimal::try_from_f64(0.1f32 as f64, FloatPrecision::RoundTrip)
+    ///         .expect("Finite quantity");
+    /// assert_writeable_eq!(decimal, "0.10000000149011612");
+    /// ```
+    pub fn try_from_f32(float: f32, precision: FloatPrecision) -> Result<Self, LimitError> {
+        if !float.is_finite() || float.is_sign_negative() {
+            return Err(LimitError);
+        }
+        Self::new_from_float_raw(float)?.with_float_precision(precision)
+    }
+
+    /// Applies the [`FloatPrecision`] to a number that was parsed from a float.
+    fn with_float_precision(mut self, precision: FloatPrecision) -> Result<Self, LimitError> {
+        let n_digits = self.digits.len();
         // magnitude of the lowest digit in self.digits
-        let lowest_magnitude = decimal.magnitude - n_digits as i16 + 1;
+        let lowest_magnitude = self.magnitude - n_digits as i16 + 1;
         // ryū will usually tack on a `.0` to integers which gets included when parsing.
         // Explicitly remove it before doing anything else
-        if lowest_magnitude >= 0 && decimal.lower_magnitude < 0 {
-            decimal.lower_magnitude = 0;
+        if lowest_magnitude >= 0 && self.lower_magnitude < 0 {
+            self.lower_magnitude = 0;
         }
         match precision {
             FloatPrecision::RoundTrip => (),
@@ -2156,33 +2258,30 @@ impl UnsignedFixedDecimal {
                 }
             }
             FloatPrecision::Magnitude(mag) => {
-                decimal.round(mag);
+                self.round(mag);
             }
             FloatPrecision::SignificantDigits(sig) => {
                 if sig == 0 {
                     return Err(LimitError);
                 }
 
-                let position = decimal.magnitude - (sig as i16) + 1;
-                let old_magnitude = decimal.magnitude;
-                decimal.round(position);
+                let position = self.magnitude - (sig as i16) + 1;
+                let old_magnitude = self.magnitude;
+                self.round(position);
 
                 // This means the significant digits has been increased by 1.
-                if decimal.magnitude > old_magnitude {
-                    decimal.lower_magnitude = cmp::min(0, position + 1);
+                if self.magnitude > old_magnitude {
+                    self.lower_magnitude = cmp::min(0, position + 1);
                 }
             }
         }
         #[cfg(debug_assertions)]
-        decimal.check_invariants();
-        Ok(decimal)
+        self.check_invariants();
+        Ok(self)
     }
 
-    /// Internal function for parsing directly from floats using ryū
-    fn new_from_f64_raw(float: f64) -> Result<Self, LimitError> {
-        if !float.is_finite() || float.is_sign_negative() {
-            return Err(LimitError);
-        }
+    /// Internal function for parsing directly from finite, non-negative floats using ryū
+    fn new_from_float_raw(float: impl ryu::Float) -> Result<Self, LimitError> {
         // note: this does not heap allocate
         let mut buf = ryu::Buffer::new();
         let formatted = buf.format_finite(float);
@@ -7,6 +7,8 @@
 //! This crate provides [`SignedFixedDecimal`] and [`UnsignedFixedDecimal`], essential APIs for representing numbers in a human-readable format.
 //! These types are particularly useful for formatting and plural rule selection, and are optimized for operations on individual digits.
 //!
+//! They also support exact arithmetic with the standard operators, comparison, and division rounded at a given position.
+//!
 //! # Examples
 //!
 //! ```
@@ -52,6 +54,7 @@
     )
 )]
 
+mod arithmetic;
 mod compact;
 mod decimal;
 mod integer;
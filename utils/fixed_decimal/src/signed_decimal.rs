@@ -7,11 +7,11 @@ use core::ops::{Deref, DerefMut};
 use core::str::FromStr;
 
 use crate::uint_iterator::IntIterator;
-use crate::{variations::Signed, UnsignedFixedDecimal};
 #[cfg(feature = "ryu")]
-use crate::{FloatPrecision, LimitError};
+use crate::FloatPrecision;
+use crate::{variations::Signed, UnsignedFixedDecimal};
 use crate::{
-    IncrementLike, NoIncrement, ParseError, RoundingIncrement, Sign, SignDisplay,
+    IncrementLike, LimitError, NoIncrement, ParseError, RoundingIncrement, Sign, SignDisplay,
     SignedRoundingMode, UnsignedRoundingMode,
 };
 
@@ -31,7 +31,9 @@ use crate::{
 /// To create a [`SignedFixedDecimal`] with fractional digits, you have several options:
 /// - Create it from an integer and then call [`UnsignedFixedDecimal::multiply_pow10`] (you can also call `multiply_pow10` directly on the [`SignedFixedDecimal`]).
 /// - Create it from a string.
-/// - When the `ryu` feature is enabled, create it from a floating point value using [`SignedFixedDecimal::try_from_f64`].
+/// - Create it from a mantissa and scale using [`SignedFixedDecimal::try_from_mantissa_and_scale`].
+/// - When the `ryu` feature is enabled, create it from a floating point value using [`SignedFixedDecimal::try_from_f64`]
+///   or [`SignedFixedDecimal::try_from_f32`].
 ///
 /// # Examples
 ///
@@ -51,6 +53,43 @@ impl SignedFixedDecimal {
         SignedFixedDecimal { sign, absolute }
     }
 
+    /// Constructs a [`SignedFixedDecimal`] with the value `mantissa × 10^(-scale)`.
+    ///
+    /// This matches the (mantissa, scale) representation of decimal libraries such as
+    /// `rust_decimal`. Trailing zeros implied by the scale are retained, so the conversion is
+    /// lossless.
+    ///
+    /// Returns an error if the number exceeds the magnitude limits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::SignedFixedDecimal;
+    ///
+    /// let dec = SignedFixedDecimal::try_from_mantissa_and_scale(-1250, 2).unwrap();
+    /// assert_eq!("-12.50", dec.to_string());
+    ///
+    /// let dec = SignedFixedDecimal::try_from_mantissa_and_scale(i128::MIN, 40).unwrap();
+    /// assert_eq!(
+    ///     "-0.0170141183460469231731687303715884105728",
+    ///     dec.to_string()
+    /// );
+    /// ```
+    pub fn try_from_mantissa_and_scale(mantissa: i128, scale: i16) -> Result<Self, LimitError> {
+        let sign = if mantissa < 0 {
+            Sign::Negative
+        } else {
+            Sign::None
+        };
+        Ok(SignedFixedDecimal {
+            sign,
+            absolute: UnsignedFixedDecimal::try_from_mantissa_and_scale(
+                mantissa.unsigned_abs(),
+                scale,
+            )?,
+        })
+    }
+
     #[inline]
     /// Parses a [`SignedFixedDecimal`].
     pub fn try_from_str(s: &str) -> Result<Self, ParseError> {
@@ -265,6 +304,41 @@ impl SignedFixedDecimal {
             }),
         }
     }
+
+    /// Constructs a [`SignedFixedDecimal`] from an f32.
+    ///
+    /// This works like [`SignedFixedDecimal::try_from_f64`], except that
+    /// [`FloatPrecision::RoundTrip`] yields the shortest decimal that round-trips through f32
+    /// rather than through f64.
+    ///
+    /// This function can be made available with the `"ryu"` Cargo feature.
+    ///
+    /// ```rust
+    /// use fixed_decimal::{SignedFixedDecimal, FloatPrecision};
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let decimal =
+    ///     SignedFixedDecimal::try_from_f32(-1.1, FloatPrecision::RoundTrip)
+    ///         .expect("Finite quantity");
+    /// assert_writeable_eq!(decimal, "-1.1");
+    ///
+    /// let decimal =
+    ///     SignedFixedDecimal::try_from_f32(-0.0, FloatPrecision::Integer)
+    ///         .expect("Negative zero");
+    /// assert_writeable_eq!(decimal, "-0");
+    /// ```
+    pub fn try_from_f32(float: f32, precision: FloatPrecision) -> Result<Self, LimitError> {
+        match float.is_sign_negative() {
+            true => Ok(SignedFixedDecimal {
+                sign: Sign::Negative,
+                absolute: UnsignedFixedDecimal::try_from_f32(-float, precision)?,
+            }),
+            false => Ok(SignedFixedDecimal {
+                sign: Sign::None,
+                absolute: UnsignedFixedDecimal::try_from_f32(float, precision)?,
+            }),
+        }
+    }
 }
 
 impl Deref for SignedFixedDecimal {
@@ -1196,6 +1270,81 @@ fn test_ui128_limits() {
     }
 }
 
+#[test]
+fn test_mantissa_and_scale() {
+    #[derive(Debug)]
+    struct TestCase {
+        pub mantissa: i128,
+        pub scale: i16,
+        pub expected: Option<&'static str>,
+    }
+    let cases = [
+        TestCase {
+            mantissa: 0,
+            scale: 0,
+            expected: Some("0"),
+        },
+        TestCase {
+            mantissa: 0,
+            scale: 3,
+            expected: Some("0.000"),
+        },
+        TestCase {
+            mantissa: 1250,
+            scale: 2,
+            expected: Some("12.50"),
+        },
+        TestCase {
+            mantissa: -1250,
+            scale: -2,
+            expected: Some("-125000"),
+        },
+        TestCase {
+            mantissa: i128::MAX,
+            scale: 0,
+            expected: Some("170141183460469231731687303715884105727"),
+        },
+        TestCase {
+            mantissa: 5,
+            scale: i16::MIN,
+            expected: None,
+        },
+        TestCase {
+            mantissa: 10,
+            scale: -i16::MAX,
+            expected: None,
+        },
+    ];
+    for cas in &cases {
+        let result = SignedFixedDecimal::try_from_mantissa_and_scale(cas.mantissa, cas.scale);
+        match cas.expected {
+            Some(expected) => {
+                let dec = result.unwrap_or_else(|_| panic!("{cas:?}"));
+                writeable::assert_writeable_eq!(dec, expected, "{cas:?}");
+            }
+            None => assert_eq!(result, Err(LimitError), "{cas:?}"),
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "ryu")]
+fn test_f32() {
+    let cases: [(f32, &str); 5] = [
+        (0.1, "0.1"),
+        (-3.4028235e38, "-340282350000000000000000000000000000000"),
+        (1.0e-45, "0.000000000000000000000000000000000000000000001"),
+        (16777216.0, "16777216"),
+        (-0.0, "-0"),
+    ];
+    for (input, expected) in cases {
+        let dec = SignedFixedDecimal::try_from_f32(input, FloatPrecision::RoundTrip).unwrap();
+        writeable::assert_writeable_eq!(dec, expected, "{input:?}");
+    }
+    assert!(SignedFixedDecimal::try_from_f32(f32::NAN, FloatPrecision::RoundTrip).is_err());
+    assert!(SignedFixedDecimal::try_from_f32(f32::INFINITY, FloatPrecision::RoundTrip).is_err());
+}
+
 #[test]
 fn test_zero_str_bounds() {
     #[derive(Debug)]
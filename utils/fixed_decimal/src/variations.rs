@@ -47,7 +47,7 @@ pub enum SignDisplay {
 
 /// The `Signed` struct represents a numeric value with an associated sign.
 #[non_exhaustive]
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct Signed<T> {
     pub sign: Sign,
     pub absolute: T,